@@ -0,0 +1,118 @@
+//! A ball bouncing around the window, whose bounces are heard from where
+//! they happen.
+use coffee::audio::{Audio, Listener, Sound};
+use coffee::graphics::{
+    Color, Frame, GpuSettings, Mesh, Point, Shape, Vector, Window,
+    WindowSettings,
+};
+use coffee::load::Task;
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    Bounce::run(WindowSettings {
+        title: String::from("Bounce - Coffee"),
+        size: (1280, 720),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })
+}
+
+const BALL_RADIUS: f32 = 20.0;
+const SAMPLE_RATE: u32 = 44_100;
+
+struct Bounce {
+    audio: Audio,
+    blip: Sound,
+    listener: Listener,
+    position: Point,
+    velocity: Vector,
+}
+
+impl Game for Bounce {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(window: &Window) -> Task<Bounce> {
+        let audio = window.audio();
+        let (width, height) = (window.width(), window.height());
+
+        Task::succeed(move || Bounce {
+            audio,
+            blip: blip(),
+            // The listener is in the center of the screen, and it can hear
+            // anything inside of the window
+            listener: Listener::new(
+                Point::new(width / 2.0, height / 2.0),
+                width / 2.0,
+            ),
+            position: Point::new(width / 2.0, height / 2.0),
+            velocity: Vector::new(9.0, 6.0),
+        })
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        self.position += self.velocity;
+
+        let bounds = (window.width(), window.height());
+        let mut bounced = false;
+
+        for axis in 0..2 {
+            let max = if axis == 0 { bounds.0 } else { bounds.1 };
+
+            if self.position[axis] < BALL_RADIUS
+                || self.position[axis] > max - BALL_RADIUS
+            {
+                self.position[axis] =
+                    self.position[axis].max(BALL_RADIUS).min(max - BALL_RADIUS);
+                self.velocity[axis] = -self.velocity[axis];
+
+                bounced = true;
+            }
+        }
+
+        if bounced {
+            let _ =
+                self.audio
+                    .play_at(&self.blip, self.position, &self.listener);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::BLACK);
+
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Circle {
+                center: self.position,
+                radius: BALL_RADIUS,
+            },
+            Color::WHITE,
+        );
+
+        mesh.draw(&mut frame.as_target());
+
+        Ok(())
+    }
+}
+
+// A short beep that fades out
+fn blip() -> Sound {
+    let length = SAMPLE_RATE as usize / 10;
+
+    let samples = (0..length)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let fade = 1.0 - i as f32 / length as f32;
+            let wave = (t * 880.0 * 2.0 * std::f32::consts::PI).sin();
+
+            (wave * fade * f32::from(i16::max_value()) * 0.5) as i16
+        })
+        .collect();
+
+    Sound::from_samples(1, SAMPLE_RATE, samples)
+}
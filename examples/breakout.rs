@@ -0,0 +1,252 @@
+//! A small breakout game with a pause button on top of the playfield.
+//!
+//! Clicking launches the ball. Clicks on the pause button are consumed by the
+//! user interface, so they never launch the ball.
+use coffee::graphics::{
+    Color, Frame, GpuSettings, Mesh, Point, Rectangle, Shape, Vector, Window,
+    WindowSettings,
+};
+use coffee::input::{mouse, Mouse};
+use coffee::load::Task;
+use coffee::ui::{
+    button, Align, Button, Column, Element, Renderer, Text, UserInterface,
+};
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    <Breakout as UserInterface>::run(WindowSettings {
+        title: String::from("Breakout - Coffee"),
+        size: (800, 600),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })
+}
+
+const PADDLE_WIDTH: f32 = 100.0;
+const PADDLE_HEIGHT: f32 = 16.0;
+const BALL_RADIUS: f32 = 8.0;
+const BALL_SPEED: f32 = 6.0;
+const BRICK_ROWS: usize = 5;
+const BRICK_COLUMNS: usize = 10;
+const BRICK_HEIGHT: f32 = 24.0;
+const BRICKS_TOP: f32 = 80.0;
+
+struct Breakout {
+    paddle: f32,
+    ball: Point,
+    velocity: Option<Vector>,
+    bricks: Vec<Rectangle<f32>>,
+    is_paused: bool,
+    pause_button: button::State,
+}
+
+impl Breakout {
+    fn reset_ball(&mut self, height: f32) {
+        self.ball =
+            Point::new(self.paddle, height - PADDLE_HEIGHT * 2.0 - BALL_RADIUS);
+        self.velocity = None;
+    }
+}
+
+impl Game for Breakout {
+    type Input = Mouse;
+    type LoadingScreen = ();
+
+    fn load(window: &Window) -> Task<Breakout> {
+        let (width, height) = (window.width(), window.height());
+
+        Task::succeed(move || {
+            let brick_width = width / BRICK_COLUMNS as f32;
+
+            let bricks = (0..BRICK_ROWS)
+                .flat_map(|row| {
+                    (0..BRICK_COLUMNS).map(move |column| Rectangle {
+                        x: column as f32 * brick_width + 2.0,
+                        y: BRICKS_TOP + row as f32 * BRICK_HEIGHT + 2.0,
+                        width: brick_width - 4.0,
+                        height: BRICK_HEIGHT - 4.0,
+                    })
+                })
+                .collect();
+
+            let mut breakout = Breakout {
+                paddle: width / 2.0,
+                ball: Point::new(0.0, 0.0),
+                velocity: None,
+                bricks,
+                is_paused: false,
+                pause_button: button::State::new(),
+            };
+
+            breakout.reset_ball(height);
+            breakout
+        })
+    }
+
+    fn interact(
+        &mut self,
+        mouse: &mut Mouse,
+        window: &mut Window,
+    ) -> Result<()> {
+        if self.is_paused {
+            return Ok(());
+        }
+
+        self.paddle = mouse
+            .cursor_position()
+            .x
+            .max(PADDLE_WIDTH / 2.0)
+            .min(window.width() - PADDLE_WIDTH / 2.0);
+
+        // Presses over the pause button never reach the `Mouse`, so this only
+        // happens when clicking on the playfield
+        if self.velocity.is_none()
+            && mouse.was_button_clicked(mouse::Button::Left)
+        {
+            self.velocity = Some(Vector::new(BALL_SPEED, -BALL_SPEED));
+        }
+
+        Ok(())
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        if self.is_paused {
+            return Ok(());
+        }
+
+        let (width, height) = (window.width(), window.height());
+
+        let mut velocity = match self.velocity {
+            Some(velocity) => velocity,
+            None => {
+                self.reset_ball(height);
+                return Ok(());
+            }
+        };
+
+        self.ball += velocity;
+
+        if self.ball.x < BALL_RADIUS || self.ball.x > width - BALL_RADIUS {
+            velocity.x = -velocity.x;
+        }
+
+        if self.ball.y < BALL_RADIUS {
+            velocity.y = -velocity.y;
+        }
+
+        let paddle_top = height - PADDLE_HEIGHT * 2.0;
+
+        if velocity.y > 0.0
+            && self.ball.y + BALL_RADIUS >= paddle_top
+            && (self.ball.x - self.paddle).abs() <= PADDLE_WIDTH / 2.0
+        {
+            // The further from the center, the sharper the bounce
+            let offset = (self.ball.x - self.paddle) / (PADDLE_WIDTH / 2.0);
+
+            velocity = Vector::new(offset * BALL_SPEED, -BALL_SPEED);
+        }
+
+        let ball = self.ball;
+
+        if let Some(hit) = self.bricks.iter().position(|brick| {
+            Rectangle {
+                x: brick.x - BALL_RADIUS,
+                y: brick.y - BALL_RADIUS,
+                width: brick.width + BALL_RADIUS * 2.0,
+                height: brick.height + BALL_RADIUS * 2.0,
+            }
+            .contains(ball)
+        }) {
+            let _ = self.bricks.remove(hit);
+            velocity.y = -velocity.y;
+        }
+
+        self.velocity = Some(velocity);
+
+        if self.ball.y > height {
+            self.reset_ball(height);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::BLACK);
+
+        let height = frame.height();
+        let mut mesh = Mesh::new();
+
+        for brick in &self.bricks {
+            mesh.fill(Shape::Rectangle(*brick), Color::from_rgb(200, 80, 80));
+        }
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: self.paddle - PADDLE_WIDTH / 2.0,
+                y: height - PADDLE_HEIGHT * 2.0,
+                width: PADDLE_WIDTH,
+                height: PADDLE_HEIGHT,
+            }),
+            Color::WHITE,
+        );
+
+        mesh.fill(
+            Shape::Circle {
+                center: self.ball,
+                radius: BALL_RADIUS,
+            },
+            Color::WHITE,
+        );
+
+        mesh.draw(&mut frame.as_target());
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    TogglePause,
+}
+
+impl UserInterface for Breakout {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::TogglePause => {
+                self.is_paused = !self.is_paused;
+            }
+        }
+    }
+
+    // While the game is paused, the whole window acts as a modal menu
+    fn captures_mouse(&self) -> bool {
+        self.is_paused
+    }
+
+    fn layout(&mut self, window: &Window) -> Element<Message> {
+        let label = if self.is_paused { "Resume" } else { "Pause" };
+
+        let layout = Column::new()
+            .width(window.width() as u32)
+            .height(window.height() as u32)
+            .padding(20)
+            .spacing(20)
+            .align_items(Align::End)
+            .push(
+                Button::new(&mut self.pause_button, label)
+                    .width(120)
+                    .on_press(Message::TogglePause),
+            );
+
+        if self.is_paused {
+            layout.push(Text::new("Paused").size(50)).into()
+        } else {
+            layout.into()
+        }
+    }
+}
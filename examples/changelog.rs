@@ -0,0 +1,138 @@
+//! A scrollable changelog, showing paragraphs of formatted text.
+use coffee::graphics::{Color, Frame, GpuSettings, Window, WindowSettings};
+use coffee::load::Task;
+use coffee::ui::{
+    slider, Column, Element, Justify, Paragraph, Renderer, Row, Slider, Text,
+    UserInterface,
+};
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    <Changelog as UserInterface>::run(WindowSettings {
+        title: String::from("Changelog - Coffee"),
+        size: (1280, 1024),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })
+}
+
+// The entries of the changelog, written using the markup of `Paragraph`
+const ENTRIES: &[&str] = &[
+    "**Version 1.4.0** - *The winter update*\n\
+     Snow covers the northern valleys. The [blacksmith]{#e0a040} in \
+     Eastwatch now sells *frost-tempered* weapons, and a new quest starts \
+     when you talk to the **old ranger** at the gates.",
+    "**Version 1.3.2**\n\
+     Fixed a [crash]{#ff5050} when saving while a dialog was open. Loading \
+     a save no longer resets your *key bindings*.",
+    "**Version 1.3.1**\n\
+     Improved the performance of large battles. Archers now pick their \
+     targets **much** faster, and their arrows no longer go through walls.",
+    "**Version 1.3.0** - *Co-op*\n\
+     You can now play the campaign with a friend! Press [F2]{#80c0ff} to \
+     open the lobby and share the code of your game. Progress is saved for \
+     **both** players.",
+    "**Version 1.2.0**\n\
+     Added a photo mode. Press [P]{#80c0ff} to pause the game and move the \
+     camera freely. Use *Shift* to move faster.",
+    "**Version 1.1.1**\n\
+     Prices in shops are now rounded to whole coins. The dragon in the \
+     *Ashen Peaks* is [slightly less angry]{#ff8040} than before.",
+    "**Version 1.1.0**\n\
+     Added support for gamepads. Every menu can be navigated with the \
+     d-pad, and \\*asterisks\\* are no longer eaten by the chat.",
+    "**Version 1.0.0**\n\
+     The first release. Thank you for playing!",
+];
+
+// The amount of entries shown at the same time
+const VISIBLE_ENTRIES: usize = 4;
+
+struct Changelog {
+    first_entry: usize,
+    scroll: slider::State,
+}
+
+impl Game for Changelog {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Changelog> {
+        Task::succeed(|| Changelog {
+            first_entry: 0,
+            scroll: slider::State::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color {
+            r: 0.15,
+            g: 0.15,
+            b: 0.2,
+            a: 1.0,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Scrolled(f32),
+}
+
+impl UserInterface for Changelog {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::Scrolled(value) => {
+                // The top of a vertical slider is its maximum value
+                self.first_entry =
+                    last_entry().saturating_sub(value.round() as usize);
+            }
+        }
+    }
+
+    fn layout(&mut self, window: &Window) -> Element<Message> {
+        let entries = ENTRIES
+            .iter()
+            .skip(self.first_entry)
+            .take(VISIBLE_ENTRIES)
+            .fold(Column::new().spacing(30), |column, entry| {
+                column.push(Paragraph::with_markup(entry).size(24))
+            });
+
+        let scroll = Slider::new(
+            &mut self.scroll,
+            0.0..=last_entry() as f32,
+            (last_entry() - self.first_entry) as f32,
+            Message::Scrolled,
+        )
+        .step(1.0)
+        .orientation(slider::Orientation::Vertical)
+        .height(700);
+
+        Column::new()
+            .width(window.width() as u32)
+            .height(window.height() as u32)
+            .padding(40)
+            .spacing(30)
+            .push(Text::new("What's new").size(50))
+            .push(
+                Row::new()
+                    .spacing(30)
+                    .justify_content(Justify::SpaceBetween)
+                    .push(entries.max_width(1000))
+                    .push(scroll),
+            )
+            .into()
+    }
+}
+
+fn last_entry() -> usize {
+    ENTRIES.len().saturating_sub(VISIBLE_ENTRIES)
+}
@@ -1,6 +1,6 @@
 use coffee::graphics::{
-    Color, Font, Frame, Image, Point, Quad, Rectangle, Text, Window,
-    WindowSettings,
+    Color, Font, Frame, GpuSettings, Image, Point, Quad, Rectangle, Text,
+    Window, WindowSettings,
 };
 use coffee::load::{loading_screen::ProgressBar, Join, Task};
 use coffee::{Game, Result, Timer};
@@ -12,6 +12,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -50,7 +51,7 @@ impl Game for Colors {
         Task::stage("Loading view...", Colors::load())
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color::new(0.5, 0.5, 0.5, 1.0));
 
         let target = &mut frame.as_target();
@@ -78,5 +79,7 @@ impl Game for Colors {
         });
 
         self.font.draw(target);
+
+        Ok(())
     }
 }
@@ -0,0 +1,383 @@
+//! A color picker, built as a custom widget outside of Coffee.
+use coffee::graphics::{
+    Color, Frame, GpuSettings, HorizontalAlignment, Point, Rectangle, Shape,
+    Text, VerticalAlignment, Window, WindowSettings,
+};
+use coffee::input::{mouse, ButtonState};
+use coffee::load::Task;
+use coffee::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+use coffee::ui::{self, Align, Column, Justify, Renderer, UserInterface};
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    <ColorPicking as UserInterface>::run(WindowSettings {
+        title: String::from("Color picker - Coffee"),
+        size: (1280, 1024),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })
+}
+
+struct ColorPicking {
+    color: Hsv,
+    picker: picker::State,
+}
+
+impl Game for ColorPicking {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<ColorPicking> {
+        Task::succeed(|| ColorPicking {
+            color: Hsv {
+                hue: 200.0,
+                saturation: 0.6,
+                value: 0.5,
+            },
+            picker: picker::State::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(self.color.to_color());
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    ColorChanged(Hsv),
+}
+
+impl UserInterface for ColorPicking {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::ColorChanged(color) => {
+                self.color = color;
+            }
+        }
+    }
+
+    fn layout(&mut self, window: &Window) -> ui::Element<Message> {
+        Column::new()
+            .width(window.width() as u32)
+            .height(window.height() as u32)
+            .align_items(Align::Center)
+            .justify_content(Justify::Center)
+            .push(picker::ColorPicker::new(
+                &mut self.picker,
+                self.color,
+                Message::ColorChanged,
+            ))
+            .into()
+    }
+}
+
+/// A color in the HSV color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    fn to_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.value - chroma;
+
+        Color::new(r + m, g + m, b + m, 1.0)
+    }
+}
+
+mod picker {
+    use super::*;
+
+    const SQUARE_SIZE: f32 = 240.0;
+    const CELLS: u16 = 24;
+    const SPACING: f32 = 20.0;
+    const HUE_WIDTH: f32 = 40.0;
+    const LABEL_HEIGHT: f32 = 40.0;
+
+    /// A square to pick the saturation and the value of a color, and a bar
+    /// to pick its hue.
+    pub struct ColorPicker<'a, Message> {
+        state: &'a mut State,
+        color: Hsv,
+        on_change: Box<dyn Fn(Hsv) -> Message>,
+    }
+
+    impl<'a, Message> std::fmt::Debug for ColorPicker<'a, Message> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ColorPicker")
+                .field("state", &self.state)
+                .field("color", &self.color)
+                .finish()
+        }
+    }
+
+    impl<'a, Message> ColorPicker<'a, Message> {
+        pub fn new<F>(state: &'a mut State, color: Hsv, on_change: F) -> Self
+        where
+            F: 'static + Fn(Hsv) -> Message,
+        {
+            ColorPicker {
+                state,
+                color,
+                on_change: Box::new(on_change),
+            }
+        }
+
+        fn pick(
+            &self,
+            part: Part,
+            bounds: Rectangle<f32>,
+            cursor_position: Point,
+            messages: &mut Vec<Message>,
+        ) {
+            let part_bounds = part.bounds(bounds);
+
+            let x = ((cursor_position.x - part_bounds.x) / part_bounds.width)
+                .max(0.0)
+                .min(1.0);
+            let y = ((cursor_position.y - part_bounds.y) / part_bounds.height)
+                .max(0.0)
+                .min(1.0);
+
+            let color = match part {
+                Part::Square => Hsv {
+                    saturation: x,
+                    value: 1.0 - y,
+                    ..self.color
+                },
+                Part::Hue => Hsv {
+                    hue: y * 359.9,
+                    ..self.color
+                },
+            };
+
+            messages.push((self.on_change)(color));
+        }
+    }
+
+    // The widget only works with the built-in renderer, so it can use its
+    // drawing methods directly
+    impl<'a, Message> Widget<Message, Renderer> for ColorPicker<'a, Message> {
+        fn node(&self, _renderer: &Renderer) -> Node {
+            Node::new(
+                Style::default()
+                    .width((SQUARE_SIZE + SPACING + HUE_WIDTH) as u32)
+                    .height((SQUARE_SIZE + LABEL_HEIGHT) as u32),
+            )
+        }
+
+        fn on_event(
+            &mut self,
+            event: Event,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            messages: &mut Vec<Message>,
+        ) {
+            let bounds = layout.bounds();
+
+            match event {
+                Event::Mouse(mouse::Event::Input {
+                    button: mouse::Button::Left,
+                    state: ButtonState::Pressed,
+                    ..
+                }) => {
+                    let part = [Part::Square, Part::Hue].iter().cloned().find(
+                        |part| part.bounds(bounds).contains(cursor_position),
+                    );
+
+                    if let Some(part) = part {
+                        self.pick(part, bounds, cursor_position, messages);
+                        self.state.dragging = Some(part);
+                    }
+                }
+                Event::Mouse(mouse::Event::Input {
+                    button: mouse::Button::Left,
+                    state: ButtonState::Released,
+                    ..
+                }) => {
+                    self.state.dragging = None;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(part) = self.state.dragging {
+                        self.pick(part, bounds, cursor_position, messages);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        fn draw(
+            &self,
+            renderer: &mut Renderer,
+            layout: Layout<'_>,
+            cursor_position: Point,
+        ) -> MouseCursor {
+            let bounds = layout.bounds();
+            let square = Part::Square.bounds(bounds);
+            let hue = Part::Hue.bounds(bounds);
+            let cell = SQUARE_SIZE / f32::from(CELLS);
+
+            for row in 0..CELLS {
+                for column in 0..CELLS {
+                    let color = Hsv {
+                        saturation: f32::from(column) / f32::from(CELLS - 1),
+                        value: 1.0 - f32::from(row) / f32::from(CELLS - 1),
+                        ..self.color
+                    };
+
+                    renderer.fill(
+                        Shape::Rectangle(Rectangle {
+                            x: square.x + f32::from(column) * cell,
+                            y: square.y + f32::from(row) * cell,
+                            width: cell,
+                            height: cell,
+                        }),
+                        color.to_color(),
+                    );
+                }
+
+                let color = Hsv {
+                    hue: f32::from(row) / f32::from(CELLS) * 360.0,
+                    saturation: 1.0,
+                    value: 1.0,
+                };
+
+                renderer.fill(
+                    Shape::Rectangle(Rectangle {
+                        y: hue.y + f32::from(row) * cell,
+                        height: cell,
+                        ..hue
+                    }),
+                    color.to_color(),
+                );
+            }
+
+            // The marker is cut at the edges of the square
+            renderer.clip(square, |renderer| {
+                renderer.stroke(
+                    Shape::Circle {
+                        center: Point::new(
+                            square.x + self.color.saturation * square.width,
+                            square.y + (1.0 - self.color.value) * square.height,
+                        ),
+                        radius: 8.0,
+                    },
+                    Color::WHITE,
+                    2.0,
+                );
+            });
+
+            renderer.stroke(
+                Shape::Rectangle(Rectangle {
+                    y: hue.y + self.color.hue / 360.0 * hue.height - 2.0,
+                    height: 4.0,
+                    ..hue
+                }),
+                Color::WHITE,
+                2.0,
+            );
+
+            let [r, g, b, _] = self.color.to_color().to_rgba();
+            let label = format!("#{:02X}{:02X}{:02X}", r, g, b);
+
+            renderer.draw_text(Text {
+                content: &label,
+                position: Point::new(bounds.x, square.y + square.height),
+                bounds: (bounds.width, LABEL_HEIGHT),
+                size: 20.0,
+                color: renderer
+                    .theme()
+                    .map(|theme| theme.text)
+                    .unwrap_or(Color::WHITE),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..Text::default()
+            });
+
+            if self.state.dragging.is_some() {
+                MouseCursor::Grabbing
+            } else if square.contains(cursor_position)
+                || hue.contains(cursor_position)
+            {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            }
+        }
+
+        // The size of the widget never changes
+        fn hash(&self, _state: &mut Hasher) {}
+    }
+
+    impl<'a, Message> From<ColorPicker<'a, Message>>
+        for Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+    {
+        fn from(picker: ColorPicker<'a, Message>) -> Self {
+            Element::new(picker)
+        }
+    }
+
+    /// The state of a [`ColorPicker`] that persists between frames.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct State {
+        dragging: Option<Part>,
+    }
+
+    impl State {
+        pub fn new() -> State {
+            State::default()
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Part {
+        Square,
+        Hue,
+    }
+
+    impl Part {
+        fn bounds(self, bounds: Rectangle<f32>) -> Rectangle<f32> {
+            match self {
+                Part::Square => Rectangle {
+                    x: bounds.x,
+                    y: bounds.y,
+                    width: SQUARE_SIZE,
+                    height: SQUARE_SIZE,
+                },
+                Part::Hue => Rectangle {
+                    x: bounds.x + SQUARE_SIZE + SPACING,
+                    y: bounds.y,
+                    width: HUE_WIDTH,
+                    height: SQUARE_SIZE,
+                },
+            }
+        }
+    }
+}
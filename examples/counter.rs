@@ -1,5 +1,5 @@
 use coffee::graphics::{
-    Color, Frame, HorizontalAlignment, VerticalAlignment, Window,
+    Color, Frame, GpuSettings, HorizontalAlignment, VerticalAlignment, Window,
     WindowSettings,
 };
 use coffee::load::Task;
@@ -10,13 +10,18 @@ use coffee::ui::{
 use coffee::{Game, Result, Timer};
 
 pub fn main() -> Result<()> {
-    <Counter as UserInterface>::run(WindowSettings {
+    let counter = <Counter as UserInterface>::run_returning(WindowSettings {
         title: String::from("Counter - Coffee"),
         size: (1280, 1024),
         resizable: false,
         fullscreen: false,
         maximized: false,
-    })
+        gpu: GpuSettings::default(),
+    })?;
+
+    println!("Final value: {}", counter.value);
+
+    Ok(())
 }
 
 struct Counter {
@@ -37,13 +42,15 @@ impl Game for Counter {
         })
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
             b: 0.6,
             a: 1.0,
         });
+
+        Ok(())
     }
 }
 
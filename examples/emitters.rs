@@ -0,0 +1,214 @@
+//! A few particle effects built with emitters: a fountain of sparks, a column
+//! of smoke, a trail of sparkles following the mouse, and explosions on
+//! click.
+//!
+//! The fountain spawns thousands of particles per second, so this example
+//! doubles as a benchmark for batched drawing.
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use coffee::graphics::{
+    Batch, Color, Frame, GpuSettings, Image, Mesh, Point, Rectangle, Vector,
+    Window, WindowSettings,
+};
+use coffee::input::{mouse, KeyboardAndMouse};
+use coffee::load::Task;
+use coffee::particles::{Curve, Emitter};
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    let settings = WindowSettings {
+        title: String::from("Emitters - Coffee"),
+        size: (1280, 1024),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    };
+
+    // Run `cargo run --example emitters -- --bench <frames>` to benchmark
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.as_slice() {
+        [flag, frames] if flag == "--bench" => {
+            let frames = frames.parse().expect("Invalid amount of frames");
+            let report = Emitters::run_bench(settings, frames)?;
+
+            println!("{}", report.to_json());
+
+            Ok(())
+        }
+        _ => Emitters::run(settings),
+    }
+}
+
+struct Emitters {
+    fountain: Emitter,
+    sparkles: Emitter,
+    smoke: Emitter,
+    explosions: Vec<Emitter>,
+    batch: Batch,
+}
+
+impl Emitters {
+    // The palette used by the textured particles, one pixel per color
+    const SPARK: u16 = 0;
+    const SPARKLE: u16 = 1;
+    const FLASH: u16 = 2;
+
+    fn load_palette() -> Task<Image> {
+        Task::using_gpu(|gpu| {
+            Image::from_colors(
+                gpu,
+                &[
+                    Color::from_rgb(255, 140, 30),
+                    Color::from_rgb(120, 200, 255),
+                    Color::from_rgb(255, 250, 220),
+                ],
+            )
+        })
+    }
+
+    fn source(color: u16) -> Rectangle<u16> {
+        Rectangle {
+            x: color,
+            y: 0,
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn fountain(position: Point) -> Emitter {
+        Emitter::new()
+            .position(position)
+            .source(Self::source(Self::SPARK))
+            .rate(10_000.0)
+            .capacity(30_000)
+            .lifetime(Duration::from_millis(1500), Duration::from_millis(2500))
+            .direction(-PI / 2.0, PI / 5.0)
+            .speed(400.0, 700.0)
+            .acceleration(Vector::new(0.0, 500.0))
+            .size(Curve::linear(3.0, 1.0))
+    }
+
+    fn sparkles() -> Emitter {
+        Emitter::new()
+            .source(Self::source(Self::SPARKLE))
+            .rate(200.0)
+            .lifetime(Duration::from_millis(300), Duration::from_millis(800))
+            .direction(0.0, 2.0 * PI)
+            .speed(10.0, 60.0)
+            .size(Curve::new(vec![(0.0, 1.0), (0.2, 6.0), (1.0, 0.0)]))
+            .seed(1)
+    }
+
+    fn smoke(position: Point) -> Emitter {
+        Emitter::new()
+            .position(position)
+            .rate(40.0)
+            .capacity(500)
+            .lifetime(Duration::from_secs(3), Duration::from_secs(5))
+            .direction(-PI / 2.0, PI / 8.0)
+            .speed(30.0, 80.0)
+            .acceleration(Vector::new(8.0, -5.0))
+            .size(Curve::linear(10.0, 60.0))
+            .color(Curve::new(vec![
+                (0.0, Color::new(0.6, 0.6, 0.6, 0.0)),
+                (0.1, Color::new(0.5, 0.5, 0.5, 0.2)),
+                (1.0, Color::new(0.3, 0.3, 0.3, 0.0)),
+            ]))
+            .seed(2)
+    }
+
+    fn explosion(position: Point, seed: u64) -> Emitter {
+        let mut explosion = Emitter::new()
+            .position(position)
+            .source(Self::source(Self::FLASH))
+            .rate(0.0)
+            .capacity(1_000)
+            .lifetime(Duration::from_millis(400), Duration::from_millis(1200))
+            .direction(0.0, 2.0 * PI)
+            .speed(100.0, 500.0)
+            .acceleration(Vector::new(0.0, 300.0))
+            .size(Curve::linear(5.0, 1.0))
+            .seed(seed);
+
+        explosion.burst(1_000);
+        explosion
+    }
+}
+
+impl Game for Emitters {
+    type Input = KeyboardAndMouse;
+    type LoadingScreen = ();
+
+    fn load(window: &Window) -> Task<Emitters> {
+        let width = window.width();
+        let height = window.height();
+
+        Self::load_palette().map(move |palette| Emitters {
+            fountain: Self::fountain(Point::new(width / 3.0, height)),
+            sparkles: Self::sparkles(),
+            smoke: Self::smoke(Point::new(width * 2.0 / 3.0, height)),
+            explosions: Vec::new(),
+            batch: Batch::new(palette),
+        })
+    }
+
+    fn interact(
+        &mut self,
+        input: &mut KeyboardAndMouse,
+        _window: &mut Window,
+    ) -> Result<()> {
+        let mouse = input.mouse();
+
+        self.sparkles.set_position(mouse.cursor_position());
+
+        for position in mouse.button_clicks(mouse::Button::Left) {
+            // Every spot explodes in its own way
+            let seed = ((position.x as u64) << 32) | position.y as u64;
+
+            self.explosions.push(Self::explosion(*position, seed));
+        }
+
+        Ok(())
+    }
+
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        self.fountain.update(1);
+        self.sparkles.update(1);
+        self.smoke.update(1);
+
+        for explosion in &mut self.explosions {
+            explosion.update(1);
+        }
+
+        self.explosions.retain(|explosion| !explosion.is_empty());
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::BLACK);
+
+        let mut target = frame.as_target();
+
+        // The smoke is colored over its life, so it is drawn as a mesh
+        let mut mesh = Mesh::new();
+        self.smoke.draw_mesh(&mut mesh);
+        mesh.draw(&mut target);
+
+        // Textured particles share a single batch
+        self.batch.clear();
+        self.fountain.draw(&mut self.batch);
+        self.sparkles.draw(&mut self.batch);
+
+        for explosion in &self.explosions {
+            explosion.draw(&mut self.batch);
+        }
+
+        self.batch.draw(&mut target);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 //! An example that showcases gamepad events
-use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::graphics::{
+    Color, Frame, GpuSettings, Mesh, Point, Shape, Vector, Window,
+    WindowSettings,
+};
 use coffee::input::{self, gamepad, Input};
 use coffee::load::Task;
 use coffee::ui::{
@@ -14,19 +17,26 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
 struct Gamepad {
+    tracker: gamepad::Gamepad,
     last_event: Option<gamepad::Event>,
 }
 
 impl Input for Gamepad {
     fn new() -> Gamepad {
-        Gamepad { last_event: None }
+        Gamepad {
+            tracker: gamepad::Gamepad::new(),
+            last_event: None,
+        }
     }
 
     fn update(&mut self, event: input::Event) {
+        self.tracker.update(event);
+
         match event {
             input::Event::Gamepad { event, .. } => {
                 self.last_event = Some(event);
@@ -35,36 +45,86 @@ impl Input for Gamepad {
         }
     }
 
-    fn clear(&mut self) {}
+    fn clear(&mut self) {
+        self.tracker.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.tracker.clear_edges();
+    }
 }
 
 struct GamepadExample {
     last_event: String,
+    position: Point,
+    velocity: Vector,
+}
+
+impl GamepadExample {
+    const SPEED: f32 = 10.0;
+    const RADIUS: f32 = 20.0;
 }
 
 impl Game for GamepadExample {
     type Input = Gamepad;
     type LoadingScreen = ();
 
-    fn load(_window: &Window) -> Task<GamepadExample> {
-        Task::succeed(|| GamepadExample {
+    fn load(window: &Window) -> Task<GamepadExample> {
+        let center = Point::new(window.width() / 2.0, window.height() / 2.0);
+
+        Task::succeed(move || GamepadExample {
             last_event: "None".to_string(),
+            position: center,
+            velocity: Vector::new(0.0, 0.0),
         })
     }
 
-    fn interact(&mut self, gamepad: &mut Gamepad, _window: &mut Window) {
+    fn interact(
+        &mut self,
+        gamepad: &mut Gamepad,
+        _window: &mut Window,
+    ) -> Result<()> {
         if let Some(event) = gamepad.last_event {
             self.last_event = format!("{:#?}", event);
         }
+
+        self.velocity = gamepad.tracker.left_stick() * Self::SPEED;
+
+        Ok(())
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        let position = self.position + self.velocity;
+
+        self.position = Point::new(
+            position.x.max(0.0).min(window.width()),
+            position.y.max(0.0).min(window.height()),
+        );
+
+        Ok(())
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
             b: 0.6,
             a: 1.0,
         });
+
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Circle {
+                center: self.position,
+                radius: Self::RADIUS,
+            },
+            Color::WHITE,
+        );
+
+        mesh.draw(&mut frame.as_target());
+
+        Ok(())
     }
 }
 
@@ -85,7 +145,8 @@ impl UserInterface for GamepadExample {
                     .max_width(500)
                     .spacing(20)
                     .push(Text::new("Last gamepad event:").size(30))
-                    .push(Text::new(&self.last_event)),
+                    .push(Text::new(&self.last_event))
+                    .push(Text::new("Use the left stick to move the circle.")),
             )
             .into()
     }
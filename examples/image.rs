@@ -1,6 +1,6 @@
 use coffee::graphics::{
-    self, Color, Frame, HorizontalAlignment, VerticalAlignment, Window,
-    WindowSettings,
+    self, Color, Frame, GpuSettings, HorizontalAlignment, VerticalAlignment,
+    Window, WindowSettings,
 };
 use coffee::load::Task;
 use coffee::ui::{
@@ -15,6 +15,7 @@ pub fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -31,13 +32,15 @@ impl Game for ImageScreen {
             .map(|image| ImageScreen { image })
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
             b: 0.6,
             a: 1.0,
         });
+
+        Ok(())
     }
 }
 
@@ -2,7 +2,7 @@
 use std::collections::HashSet;
 
 use coffee::graphics::{
-    Color, Frame, Image, Point, Rectangle, Sprite, Vector, Window,
+    Color, Frame, GpuSettings, Image, Point, Rectangle, Sprite, Vector, Window,
     WindowSettings,
 };
 use coffee::input::{self, keyboard, mouse, Input};
@@ -19,12 +19,13 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
 struct CustomInput {
     cursor_position: Point,
-    mouse_wheel: Point,
+    mouse_wheel: Option<mouse::ScrollDelta>,
     keys_pressed: HashSet<keyboard::KeyCode>,
     mouse_buttons_pressed: HashSet<mouse::Button>,
     text_buffer: String,
@@ -34,7 +35,7 @@ impl Input for CustomInput {
     fn new() -> CustomInput {
         CustomInput {
             cursor_position: Point::new(0.0, 0.0),
-            mouse_wheel: Point::new(0.0, 0.0),
+            mouse_wheel: None,
             keys_pressed: HashSet::new(),
             mouse_buttons_pressed: HashSet::new(),
             text_buffer: String::new(),
@@ -43,11 +44,13 @@ impl Input for CustomInput {
 
     fn update(&mut self, event: input::Event) {
         match event {
-            input::Event::Mouse(mouse_event) => match mouse_event {
+            input::Event::Mouse {
+                event: mouse_event, ..
+            } => match mouse_event {
                 mouse::Event::CursorMoved { x, y } => {
                     self.cursor_position = Point::new(x, y);
                 }
-                mouse::Event::Input { state, button } => match state {
+                mouse::Event::Input { state, button, .. } => match state {
                     input::ButtonState::Pressed => {
                         self.mouse_buttons_pressed.insert(button);
                     }
@@ -55,16 +58,23 @@ impl Input for CustomInput {
                         self.mouse_buttons_pressed.remove(&button);
                     }
                 },
-                mouse::Event::WheelScrolled { delta_x, delta_y } => {
-                    self.mouse_wheel = Point::new(delta_x, delta_y);
+                mouse::Event::WheelScrolled { delta } => {
+                    self.mouse_wheel = Some(delta);
                 }
                 _ => {}
             },
-            input::Event::Keyboard(keyboard_event) => match keyboard_event {
+            input::Event::Keyboard {
+                event: keyboard_event,
+                ..
+            } => match keyboard_event {
                 keyboard::Event::TextEntered { character } => {
                     self.text_buffer.push(character);
                 }
-                keyboard::Event::Input { key_code, state } => match state {
+                keyboard::Event::Input {
+                    key_code: Some(key_code),
+                    state,
+                    ..
+                } => match state {
                     input::ButtonState::Pressed => {
                         self.keys_pressed.insert(key_code);
                     }
@@ -72,6 +82,7 @@ impl Input for CustomInput {
                         self.keys_pressed.remove(&key_code);
                     }
                 },
+                _ => {}
             },
             _ => {}
         }
@@ -85,7 +96,7 @@ impl Input for CustomInput {
 struct InputExample {
     palette: Image,
     cursor_position: Point,
-    mouse_wheel: Point,
+    mouse_wheel: Option<mouse::ScrollDelta>,
     keys_pressed: HashSet<keyboard::KeyCode>,
     mouse_buttons_pressed: HashSet<mouse::Button>,
     text_buffer: String,
@@ -104,7 +115,7 @@ impl Game for InputExample {
             |palette| InputExample {
                 palette,
                 cursor_position: Point::new(0.0, 0.0),
-                mouse_wheel: Point::new(0.0, 0.0),
+                mouse_wheel: None,
                 keys_pressed: HashSet::new(),
                 mouse_buttons_pressed: HashSet::new(),
                 text_buffer: String::with_capacity(Self::MAX_TEXTSIZE),
@@ -112,7 +123,11 @@ impl Game for InputExample {
         )
     }
 
-    fn interact(&mut self, input: &mut CustomInput, _window: &mut Window) {
+    fn interact(
+        &mut self,
+        input: &mut CustomInput,
+        _window: &mut Window,
+    ) -> Result<()> {
         self.cursor_position = input.cursor_position;
         self.mouse_wheel = input.mouse_wheel;
         self.keys_pressed = input.keys_pressed.clone();
@@ -134,9 +149,11 @@ impl Game for InputExample {
                 }
             }
         }
+
+        Ok(())
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
@@ -158,6 +175,8 @@ impl Game for InputExample {
             },
             &mut frame.as_target(),
         );
+
+        Ok(())
     }
 }
 
@@ -182,16 +201,23 @@ impl UserInterface for InputExample {
             .collect::<Vec<_>>()
             .join(", ");
 
+        let mouse_wheel = match self.mouse_wheel {
+            Some(mouse::ScrollDelta::Lines { x, y }) => {
+                format!("{}, {} lines", x, y)
+            }
+            Some(mouse::ScrollDelta::Pixels { x, y }) => {
+                format!("{}, {} pixels", x, y)
+            }
+            None => String::from("None"),
+        };
+
         let content = Column::new()
             .max_width(800)
             .spacing(20)
             .push(label_and_value("Pressed keys:", &keys))
             .push(label_and_value("Text buffer (type):", &self.text_buffer))
             .push(label_and_value("Pressed mouse buttons:", &mouse_buttons))
-            .push(label_and_value(
-                "Last mouse wheel scroll:",
-                &format!("{}, {}", self.mouse_wheel.x, self.mouse_wheel.y),
-            ));
+            .push(label_and_value("Last mouse wheel scroll:", &mouse_wheel));
 
         Column::new()
             .width(window.width() as u32)
@@ -1,6 +1,6 @@
 use coffee::graphics::{
-    Color, Frame, HorizontalAlignment, Mesh, Point, Rectangle, Shape, Window,
-    WindowSettings,
+    Color, Frame, GpuSettings, HorizontalAlignment, Mesh, Point, Rectangle,
+    Shape, Window, WindowSettings,
 };
 use coffee::input::mouse::{self, Mouse};
 use coffee::load::Task;
@@ -19,6 +19,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -76,7 +77,11 @@ impl Game for Example {
         })
     }
 
-    fn interact(&mut self, mouse: &mut Mouse, _window: &mut Window) {
+    fn interact(
+        &mut self,
+        mouse: &mut Mouse,
+        _window: &mut Window,
+    ) -> Result<()> {
         match self.shape {
             ShapeOption::Polyline => {
                 self.polyline_points
@@ -84,9 +89,11 @@ impl Game for Example {
             }
             _ => {}
         }
+
+        Ok(())
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
@@ -128,6 +135,8 @@ impl Game for Example {
         }
 
         mesh.draw(&mut frame.as_target());
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,115 @@
+//! Compares loading a directory of images serially and in parallel.
+//!
+//! Pass a directory of images to benchmark it, or nothing to generate a few
+//! large images in a temporary directory:
+//!
+//! ```text
+//! cargo run --release --example parallel_loading -- path/to/images
+//! ```
+use coffee::graphics::{Frame, GpuSettings, Image, Window, WindowSettings};
+use coffee::headless::Runner;
+use coffee::image;
+use coffee::load::Task;
+use coffee::{Game, Result, Timer};
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+fn main() -> Result<()> {
+    let directory = match std::env::args().nth(1) {
+        Some(directory) => PathBuf::from(directory),
+        None => generate_images(64)?,
+    };
+
+    let paths = image_paths(&directory)?;
+
+    // Warm up the file system cache, so both runs read from memory
+    for path in &paths {
+        let _ = std::fs::read(path)?;
+    }
+
+    let mut runner = Runner::<Benchmark>::new(WindowSettings {
+        title: String::from("Parallel loading - Coffee"),
+        size: (640, 480),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })?;
+
+    println!("Loading {} images from {:?}", paths.len(), directory);
+
+    let started_at = Instant::now();
+    let _ = runner.load(Image::load_dir(&directory))?;
+    println!("Serial:   {:.2?}", started_at.elapsed());
+
+    let started_at = Instant::now();
+    let _ =
+        runner.load(Task::parallel(paths.iter().map(Image::job).collect()))?;
+    println!("Parallel: {:.2?}", started_at.elapsed());
+
+    Ok(())
+}
+
+struct Benchmark;
+
+impl Game for Benchmark {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Benchmark> {
+        Task::succeed(|| Benchmark)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn image_paths(directory: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        let is_image = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| {
+                Image::EXTENSIONS.contains(&extension.to_lowercase().as_str())
+            })
+            .unwrap_or(false);
+
+        if is_image {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+fn generate_images(amount: u32) -> Result<PathBuf> {
+    let directory = std::env::temp_dir().join("coffee-parallel-loading");
+    std::fs::create_dir_all(&directory)?;
+
+    for i in 0..amount {
+        let path = directory.join(format!("{}.png", i));
+
+        if path.exists() {
+            continue;
+        }
+
+        // Noise does not compress well, so decoding takes a while
+        let noise = image::RgbaImage::from_fn(1024, 1024, |x, y| {
+            let hash = (x ^ y.rotate_left(16) ^ i).wrapping_mul(2_654_435_761);
+
+            image::Rgba([(hash >> 24) as u8, (hash >> 16) as u8, 0, 255])
+        });
+
+        noise.save(path)?;
+    }
+
+    Ok(directory)
+}
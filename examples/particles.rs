@@ -6,30 +6,51 @@ use rayon::prelude::*;
 use std::{thread, time};
 
 use coffee::graphics::{
-    Batch, Color, Frame, Image, Point, Rectangle, Sprite, Vector, Window,
-    WindowSettings,
+    Batch, Color, Frame, GpuSettings, Image, Point, Rectangle, Sprite, Vector,
+    Window, WindowSettings,
 };
 use coffee::input::{keyboard, mouse, KeyboardAndMouse};
 use coffee::load::{loading_screen::ProgressBar, Join, Task};
+use coffee::nalgebra;
 use coffee::ui::{Checkbox, Column, Element, Justify, Renderer, UserInterface};
 use coffee::{Game, Result, Timer};
 
 fn main() -> Result<()> {
-    <Particles as UserInterface>::run(WindowSettings {
+    let settings = WindowSettings {
         title: String::from("Particles - Coffee"),
         size: (1280, 1024),
         resizable: false,
         fullscreen: false,
         maximized: false,
-    })
+        gpu: GpuSettings::default(),
+    };
+
+    // Run `cargo run --example particles -- --bench <frames>` to benchmark
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.as_slice() {
+        [flag, frames] if flag == "--bench" => {
+            let frames = frames.parse().expect("Invalid amount of frames");
+            let report =
+                <Particles as UserInterface>::run_bench(settings, frames)?;
+
+            println!("{}", report.to_json());
+
+            Ok(())
+        }
+        _ => <Particles as UserInterface>::run(settings),
+    }
 }
 
 struct Particles {
     particles: Vec<Particle>,
     gravity_centers: Vec<Point>,
+    clicks: Vec<Point>,
 
     batch: Batch,
     interpolate: bool,
+    is_toggling_interpolation: bool,
+    has_toggled_fullscreen: bool,
 }
 
 impl Particles {
@@ -84,29 +105,50 @@ impl Game for Particles {
             .map(|(particles, palette, _)| Particles {
                 particles,
                 gravity_centers: vec![Point::new(0.0, 0.0)],
+                clicks: Vec::new(),
                 batch: Batch::new(palette),
                 interpolate: true,
+                is_toggling_interpolation: false,
+                has_toggled_fullscreen: false,
             })
     }
 
-    fn interact(&mut self, input: &mut KeyboardAndMouse, window: &mut Window) {
+    fn interact(
+        &mut self,
+        input: &mut KeyboardAndMouse,
+        window: &mut Window,
+    ) -> Result<()> {
         let mouse = input.mouse();
         let keyboard = input.keyboard();
 
         self.gravity_centers[0] = mouse.cursor_position();
-        self.gravity_centers
-            .extend(mouse.button_clicks(mouse::Button::Left));
 
-        if keyboard.was_key_released(keyboard::KeyCode::I) {
-            self.interpolate = !self.interpolate;
-        }
+        // Edges last until the next update, which may be a few frames away
+        // at 20 ticks per second. Therefore, we act on them in `update`.
+        self.clicks = mouse.button_clicks(mouse::Button::Left).to_vec();
+        self.is_toggling_interpolation =
+            keyboard.was_key_released(keyboard::KeyCode::I);
 
-        if keyboard.was_key_released(keyboard::KeyCode::F) {
+        // The window can only be changed here, so we remember we did it
+        if keyboard.was_key_released(keyboard::KeyCode::F)
+            && !self.has_toggled_fullscreen
+        {
             window.toggle_fullscreen();
+            self.has_toggled_fullscreen = true;
         }
+
+        Ok(())
     }
 
-    fn update(&mut self, _window: &Window) {
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        self.gravity_centers.extend(self.clicks.drain(..));
+        self.has_toggled_fullscreen = false;
+
+        if self.is_toggling_interpolation {
+            self.interpolate = !self.interpolate;
+            self.is_toggling_interpolation = false;
+        }
+
         let gravity_centers = self.gravity_centers.clone();
 
         // Update particles in parallel! <3 rayon
@@ -117,16 +159,22 @@ impl Game for Particles {
                     let distance = particle.position - gravity_center;
 
                     -((Self::G * Self::CENTER_MASS) * distance.normalize())
-                        / distance.norm_squared().max(1000.0)
+                        / nalgebra::distance_squared(
+                            &particle.position,
+                            gravity_center,
+                        )
+                        .max(1000.0)
                 })
                 .sum();
 
             particle.velocity += particle.acceleration;
             particle.position += particle.velocity;
         });
+
+        Ok(())
     }
 
-    fn draw(&mut self, frame: &mut Frame, timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, timer: &Timer) -> Result<()> {
         frame.clear(Color::BLACK);
 
         // When interpolating, we need to know how close the next tick is
@@ -161,6 +209,8 @@ impl Game for Particles {
 
         // Draw particles all at once!
         self.batch.draw(&mut frame.as_target());
+
+        Ok(())
     }
 }
 
@@ -211,8 +261,8 @@ impl Particle {
                 rng.gen_range(0.0, max_x),
                 rng.gen_range(0.0, max_y),
             ),
-            velocity: Vector::new(0.0, 0.0),
-            acceleration: Vector::new(0.0, 0.0),
+            velocity: nalgebra::zero(),
+            acceleration: nalgebra::zero(),
         }
     }
 }
@@ -1,10 +1,11 @@
 use coffee::graphics::{
-    Color, Frame, HorizontalAlignment, VerticalAlignment, Window,
+    Color, Frame, GpuSettings, HorizontalAlignment, VerticalAlignment, Window,
     WindowSettings,
 };
 use coffee::load::Task;
 use coffee::ui::{
-    Align, Column, Element, Justify, ProgressBar, Renderer, Text, UserInterface,
+    Align, Column, Element, Justify, ProgressBar, Renderer, Spinner, Text,
+    UserInterface,
 };
 use coffee::{Game, Result, Timer};
 
@@ -15,6 +16,7 @@ pub fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -30,7 +32,7 @@ impl Game for Progress {
         Task::succeed(|| Progress { value: 0.0 })
     }
 
-    fn draw(&mut self, frame: &mut Frame, timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
@@ -44,6 +46,8 @@ impl Game for Progress {
             }
             self.value += 0.002;
         }
+
+        Ok(())
     }
 }
 
@@ -67,7 +71,8 @@ impl UserInterface for Progress {
                     .horizontal_alignment(HorizontalAlignment::Center)
                     .vertical_alignment(VerticalAlignment::Center),
             )
-            .push(ProgressBar::new(self.value).width(400))
+            .push(ProgressBar::new(0.0..=1.0, self.value).width(400))
+            .push(Spinner::new(window.total_time()))
             .into()
     }
 }
@@ -1,16 +1,17 @@
 use coffee::graphics::{
-    Color, Frame, Mesh, Rectangle, Shape, Window, WindowSettings,
+    Color, Frame, GpuSettings, Mesh, Rectangle, Shape, Window, WindowSettings,
 };
 use coffee::load::Task;
-use coffee::{Game, Timer};
+use coffee::{Game, Result, Timer};
 
-fn main() -> coffee::Result<()> {
+fn main() -> Result<()> {
     Example::run(WindowSettings {
         title: String::from("Rectangle - Coffee"),
         size: (1280, 1024),
         resizable: true,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -24,7 +25,7 @@ impl Game for Example {
         Task::succeed(|| Example)
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color::BLACK);
         let mut mesh = Mesh::new();
         mesh.fill(
@@ -37,5 +38,7 @@ impl Game for Example {
             Color::WHITE,
         );
         mesh.draw(&mut frame.as_target());
+
+        Ok(())
     }
 }
@@ -0,0 +1,291 @@
+use coffee::graphics::{
+    Color, Font, Frame, GpuSettings, Mesh, Point, Rectangle, Shape, Text,
+    Window, WindowSettings,
+};
+use coffee::input::keyboard::KeyCode;
+use coffee::input::KeyboardAndMouse;
+use coffee::load::{loading_screen::ProgressBar, Task};
+use coffee::scene::{Director, Scene, Script, Transition};
+use coffee::{Game, Result, Timer};
+
+fn main() -> Result<()> {
+    Director::<Scenes>::run(WindowSettings {
+        title: String::from("Scenes - Coffee"),
+        size: (1280, 1024),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    })
+}
+
+type Input = KeyboardAndMouse;
+
+struct Scenes;
+
+impl Script for Scenes {
+    type Input = Input;
+    type LoadingScreen = ProgressBar;
+
+    fn first_scene(_window: &Window) -> Task<Box<dyn Scene<Input>>> {
+        Title::load().map(|title| Box::new(title) as Box<dyn Scene<Input>>)
+    }
+}
+
+fn load_font() -> Task<Font> {
+    Font::load_from_bytes(include_bytes!(
+        "../resources/font/Inconsolata-Regular.ttf"
+    ))
+}
+
+fn draw_text(font: &mut Font, frame: &mut Frame, content: &str, y: f32) {
+    font.add(Text {
+        content,
+        position: Point::new(40.0, y),
+        size: 40.0,
+        color: Color::WHITE,
+        ..Text::default()
+    });
+
+    font.draw(&mut frame.as_target());
+}
+
+struct Title {
+    font: Font,
+    next: Option<Transition<Input>>,
+}
+
+impl Title {
+    fn load() -> Task<Title> {
+        load_font().map(|font| Title { font, next: None })
+    }
+}
+
+impl Scene<Input> for Title {
+    fn interact(
+        &mut self,
+        input: &mut Input,
+        _window: &mut Window,
+    ) -> Result<()> {
+        if input.was_key_released(KeyCode::Return) {
+            self.next = Some(Transition::replace(Task::stage(
+                "Loading level...",
+                Gameplay::load(),
+            )));
+        } else if input.was_key_released(KeyCode::Escape) {
+            self.next = Some(Transition::Pop);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::BLACK);
+
+        draw_text(
+            &mut self.font,
+            frame,
+            "Press Enter to start or Escape to quit",
+            40.0,
+        );
+
+        Ok(())
+    }
+
+    fn transition(&mut self) -> Option<Transition<Input>> {
+        self.next.take()
+    }
+}
+
+struct Gameplay {
+    font: Font,
+    position: f32,
+    remaining_ticks: u32,
+    next: Option<Transition<Input>>,
+}
+
+impl Gameplay {
+    const DURATION: u32 = 60 * 10;
+
+    fn load() -> Task<Gameplay> {
+        load_font().map(|font| Gameplay {
+            font,
+            position: 0.0,
+            remaining_ticks: Self::DURATION,
+            next: None,
+        })
+    }
+}
+
+impl Scene<Input> for Gameplay {
+    fn interact(
+        &mut self,
+        input: &mut Input,
+        _window: &mut Window,
+    ) -> Result<()> {
+        if input.is_key_pressed(KeyCode::Left) {
+            self.position -= 5.0;
+        }
+
+        if input.is_key_pressed(KeyCode::Right) {
+            self.position += 5.0;
+        }
+
+        if input.was_key_released(KeyCode::P) {
+            self.next = Some(Transition::push(Pause::load()));
+        }
+
+        Ok(())
+    }
+
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        self.remaining_ticks = self.remaining_ticks.saturating_sub(1);
+
+        if self.remaining_ticks == 0 && self.next.is_none() {
+            let score = self.position.abs() as u32;
+
+            self.next = Some(Transition::replace(GameOver::load(score)));
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::new(0.1, 0.2, 0.3, 1.0));
+
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: frame.width() / 2.0 + self.position - 25.0,
+                y: frame.height() / 2.0 - 25.0,
+                width: 50.0,
+                height: 50.0,
+            }),
+            Color::WHITE,
+        );
+
+        mesh.draw(&mut frame.as_target());
+
+        draw_text(
+            &mut self.font,
+            frame,
+            &format!(
+                "Move with the arrows! Time left: {}s (P to pause)",
+                self.remaining_ticks / 60 + 1
+            ),
+            40.0,
+        );
+
+        Ok(())
+    }
+
+    fn transition(&mut self) -> Option<Transition<Input>> {
+        self.next.take()
+    }
+}
+
+struct Pause {
+    font: Font,
+    is_done: bool,
+}
+
+impl Pause {
+    fn load() -> Task<Pause> {
+        load_font().map(|font| Pause {
+            font,
+            is_done: false,
+        })
+    }
+}
+
+impl Scene<Input> for Pause {
+    fn interact(
+        &mut self,
+        input: &mut Input,
+        _window: &mut Window,
+    ) -> Result<()> {
+        self.is_done = input.was_key_released(KeyCode::P);
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: frame.width(),
+                height: frame.height(),
+            }),
+            Color::new(0.0, 0.0, 0.0, 0.7),
+        );
+
+        mesh.draw(&mut frame.as_target());
+
+        draw_text(&mut self.font, frame, "Paused (P to resume)", 100.0);
+
+        Ok(())
+    }
+
+    fn transition(&mut self) -> Option<Transition<Input>> {
+        if self.is_done {
+            Some(Transition::Pop)
+        } else {
+            None
+        }
+    }
+
+    fn is_translucent(&self) -> bool {
+        true
+    }
+}
+
+struct GameOver {
+    font: Font,
+    score: u32,
+    next: Option<Transition<Input>>,
+}
+
+impl GameOver {
+    fn load(score: u32) -> Task<GameOver> {
+        load_font().map(move |font| GameOver {
+            font,
+            score,
+            next: None,
+        })
+    }
+}
+
+impl Scene<Input> for GameOver {
+    fn interact(
+        &mut self,
+        input: &mut Input,
+        _window: &mut Window,
+    ) -> Result<()> {
+        if input.was_key_released(KeyCode::Return) {
+            self.next = Some(Transition::replace(Title::load()));
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
+        frame.clear(Color::new(0.3, 0.0, 0.0, 1.0));
+
+        draw_text(
+            &mut self.font,
+            frame,
+            &format!("Game over! Score: {} (Enter to continue)", self.score),
+            40.0,
+        );
+
+        Ok(())
+    }
+
+    fn transition(&mut self) -> Option<Transition<Input>> {
+        self.next.take()
+    }
+}
@@ -1,13 +1,14 @@
 extern crate coffee;
 
 use coffee::graphics::{
-    Color, Font, Frame, Mesh, Point, Rectangle, Shape, Text, Window, WindowSettings,
+    Color, Font, Frame, GpuSettings, Mesh, Point, Rectangle, Shape, Text,
+    Window, WindowSettings,
 };
 use coffee::input::keyboard::KeyCode;
 use coffee::input::{self, keyboard, Input};
 
 use coffee::load::Task;
-use coffee::{Game, Timer};
+use coffee::{Game, Result, Timer};
 
 use rand::seq::IteratorRandom;
 
@@ -17,6 +18,7 @@ fn main() {
         size: (900, 600),
         resizable: false,
         maximized: false,
+        gpu: GpuSettings::default(),
         fullscreen: false,
     })
     .expect("An error occured while starting the game");
@@ -223,8 +225,14 @@ impl Game for SnakeGame {
             last_key: None,
         })
     }
-    fn update(&mut self, _window: &Window) {}
-    fn interact(&mut self, input: &mut CustomInput, _window: &mut Window) {
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        Ok(())
+    }
+    fn interact(
+        &mut self,
+        input: &mut CustomInput,
+        _window: &mut Window,
+    ) -> Result<()> {
         if input.keys_pressed.len() != 0 {
             let key = input.keys_pressed[0];
             match key {
@@ -243,8 +251,10 @@ impl Game for SnakeGame {
                 _ => (),
             }
         }
+
+        Ok(())
     }
-    fn draw(&mut self, frame: &mut Frame, timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, timer: &Timer) -> Result<()> {
         if timer.has_ticked() && !self.snake.ate_himself() {
             self.ticks += 1;
             if self.ticks == self.speed {
@@ -289,6 +299,8 @@ impl Game for SnakeGame {
             });
             font.draw(&mut frame.as_target());
         }
+
+        Ok(())
     }
 }
 
@@ -305,8 +317,15 @@ impl Input for CustomInput {
 
     fn update(&mut self, event: input::Event) {
         match event {
-            input::Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::Input { key_code, state } => match state {
+            input::Event::Keyboard {
+                event: keyboard_event,
+                ..
+            } => match keyboard_event {
+                keyboard::Event::Input {
+                    key_code: Some(key_code),
+                    state,
+                    ..
+                } => match state {
                     input::ButtonState::Pressed => {
                         self.keys_pressed.push(key_code);
                     }
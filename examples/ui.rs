@@ -1,10 +1,10 @@
 use coffee::graphics::{
-    Color, Frame, HorizontalAlignment, Window, WindowSettings,
+    Color, Frame, GpuSettings, HorizontalAlignment, Window, WindowSettings,
 };
 use coffee::load::Task;
 use coffee::ui::{
     button, slider, Align, Button, Checkbox, Column, Element, Justify, Radio,
-    Renderer, Row, Slider, Text, UserInterface,
+    RadioGroup, Renderer, Row, Slider, Text, Theme, UserInterface,
 };
 use coffee::{Game, Result, Timer};
 
@@ -15,6 +15,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -22,6 +23,7 @@ struct Tour {
     steps: Steps,
     back_button: button::State,
     next_button: button::State,
+    theme: ThemeChoice,
 }
 
 impl Game for Tour {
@@ -33,16 +35,19 @@ impl Game for Tour {
             steps: Steps::new(),
             back_button: button::State::new(),
             next_button: button::State::new(),
+            theme: ThemeChoice::Classic,
         })
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
             b: 0.6,
             a: 1.0,
         });
+
+        Ok(())
     }
 }
 
@@ -61,6 +66,17 @@ impl UserInterface for Tour {
             Message::StepMessage(step_msg) => {
                 self.steps.update(step_msg);
             }
+            Message::ThemeSelected(theme) => {
+                self.theme = theme;
+            }
+        }
+    }
+
+    fn theme(&self) -> Option<Theme> {
+        match self.theme {
+            ThemeChoice::Classic => None,
+            ThemeChoice::Dark => Some(Theme::dark()),
+            ThemeChoice::Light => Some(Theme::light()),
         }
     }
 
@@ -69,6 +85,7 @@ impl UserInterface for Tour {
             steps,
             back_button,
             next_button,
+            theme,
         } = self;
 
         let mut controls = Row::new();
@@ -83,17 +100,28 @@ impl UserInterface for Tour {
 
         controls = controls.push(Column::new());
 
-        if steps.can_continue() {
-            controls = controls.push(
-                Button::new(next_button, "Next").on_press(Message::NextPressed),
-            );
-        }
+        controls = controls.push(
+            Button::new(next_button, "Next")
+                .on_press(Message::NextPressed)
+                .disabled(!steps.can_continue()),
+        );
 
         let content = Column::new()
             .max_width(500)
             .spacing(20)
             .push(steps.layout().map(Message::StepMessage))
-            .push(controls);
+            .push(controls)
+            .push(
+                RadioGroup::new(
+                    &ThemeChoice::all()
+                        .iter()
+                        .map(|&choice| (choice, choice.into()))
+                        .collect::<Vec<(ThemeChoice, &str)>>(),
+                    Some(*theme),
+                    Message::ThemeSelected,
+                )
+                .horizontal(),
+            );
 
         Column::new()
             .width(window.width() as u32)
@@ -111,6 +139,30 @@ enum Message {
     BackPressed,
     NextPressed,
     StepMessage(StepMessage),
+    ThemeSelected(ThemeChoice),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThemeChoice {
+    Classic,
+    Dark,
+    Light,
+}
+
+impl ThemeChoice {
+    fn all() -> [ThemeChoice; 3] {
+        [ThemeChoice::Classic, ThemeChoice::Dark, ThemeChoice::Light]
+    }
+}
+
+impl From<ThemeChoice> for &str {
+    fn from(theme: ThemeChoice) -> &'static str {
+        match theme {
+            ThemeChoice::Classic => "Classic",
+            ThemeChoice::Dark => "Dark",
+            ThemeChoice::Light => "Light",
+        }
+    }
 }
 
 struct Steps {
@@ -404,12 +456,15 @@ impl<'a> Step {
                 "The following slider lets you choose an integer from \
                  0 to 100:",
             ))
-            .push(Slider::new(
-                state,
-                0.0..=100.0,
-                value as f32,
-                StepMessage::SliderChanged,
-            ))
+            .push(
+                Slider::new(
+                    state,
+                    0.0..=100.0,
+                    value as f32,
+                    StepMessage::SliderChanged,
+                )
+                .step(1.0),
+            )
             .push(
                 Text::new(&value.to_string())
                     .horizontal_alignment(HorizontalAlignment::Center),
@@ -0,0 +1,66 @@
+//! Load and play sound effects and music.
+//!
+//! This module is only available when the `audio` feature is enabled.
+//!
+//! A [`Sound`] is decoded into memory once, when it is loaded. Then, it can
+//! be played as many times as you want using an [`Audio`] output, even while
+//! other instances of the same [`Sound`] are still playing.
+//!
+//! A [`Music`] track is streamed from disk instead, and it can loop without
+//! gaps. An [`Audio`] output plays one track at a time, and it can
+//! [crossfade] between tracks.
+//!
+//! Everything is played in a mixer [`Group`]. Sounds are played in the "sfx"
+//! group by default, and music in the "music" group. Use them to implement
+//! volume settings in your game.
+//!
+//! Sounds can also be played at a position in the world with
+//! [`Audio::play_at`], which pans them depending on where they are relative
+//! to a [`Listener`].
+//!
+//! The playback speed of sounds, music tracks, and whole groups can be
+//! changed while they play. Slowing down the master group is an easy way to
+//! implement a slow-motion effect.
+//!
+//! ```no_run
+//! use coffee::audio::{Audio, Sound};
+//! use coffee::load::{Join, Task};
+//!
+//! struct Sounds {
+//!     audio: Audio,
+//!     brick_hit: Sound,
+//! }
+//!
+//! fn load_sounds() -> Task<Sounds> {
+//!     (
+//!         Task::succeed(Audio::new),
+//!         Sound::load("resources/brick_hit.ogg"),
+//!     )
+//!         .join()
+//!         .map(|(audio, brick_hit)| Sounds { audio, brick_hit })
+//! }
+//!
+//! fn on_brick_hit(sounds: &Sounds) {
+//!     let _ = sounds.audio.play(&sounds.brick_hit);
+//! }
+//! ```
+//!
+//! [`Sound`]: struct.Sound.html
+//! [`Audio`]: struct.Audio.html
+//! [`Music`]: struct.Music.html
+//! [crossfade]: struct.Audio.html#method.crossfade_to
+//! [`Group`]: struct.Group.html
+//! [`Audio::play_at`]: struct.Audio.html#method.play_at
+//! [`Listener`]: struct.Listener.html
+mod mixer;
+mod music;
+mod output;
+mod resampler;
+mod sound;
+mod spatial;
+
+pub use mixer::Group;
+pub use music::{Loop, Music, MusicHandle};
+pub use output::{Audio, PlayHandle, PlayOptions};
+pub use sound::Sound;
+pub use spatial::Listener;
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::audio::resampler::Speed;
+
+// The amount of samples played between checks of the volumes
+pub(super) const CONTROL_INTERVAL: u32 = 256;
+
+/// A named group of sounds and music sharing a volume, like "music" or
+/// "sfx".
+///
+/// The effective volume of anything played is the volume of its [`Group`],
+/// times the volume of the master [`Group`], times its own volume. Changes
+/// affect everything already playing immediately.
+///
+/// You can obtain a [`Group`] with [`Audio::group`] or [`Audio::master`].
+///
+/// [`Group`]: struct.Group.html
+/// [`Audio::group`]: struct.Audio.html#method.group
+/// [`Audio::master`]: struct.Audio.html#method.master
+#[derive(Debug, Clone)]
+pub struct Group {
+    controls: Arc<Controls>,
+}
+
+impl Group {
+    /// Sets the volume of the [`Group`].
+    ///
+    /// `1.0` is the original volume, and `0.0` mutes it.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn set_volume(&self, volume: f32) {
+        self.controls
+            .volume
+            .store(volume.max(0.0).to_bits(), Ordering::SeqCst);
+    }
+
+    /// Returns the volume of the [`Group`].
+    ///
+    /// It does not change when the [`Group`] is muted.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.controls.volume.load(Ordering::SeqCst))
+    }
+
+    /// Sets the playback speed of the [`Group`], which changes the pitch of
+    /// everything played in it.
+    ///
+    /// It is combined with the speed of the master [`Group`] and the one of
+    /// each sound. Lowering the speed of the master [`Group`] is an easy way
+    /// to slow down all the audio of your game, like during slow-motion.
+    ///
+    /// The speed is clamped between `0.0` and `4.0`. A speed of `0.0` pauses
+    /// everything played in the [`Group`].
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn set_speed(&self, speed: f32) {
+        self.controls.speed.set(speed);
+    }
+
+    /// Returns the playback speed of the [`Group`].
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn speed(&self) -> f32 {
+        self.controls.speed.get()
+    }
+
+    /// Mutes the [`Group`], keeping its volume for when it is unmuted.
+    ///
+    /// Anything played in a muted [`Group`] keeps playing silently.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn mute(&self) {
+        self.controls.is_muted.store(true, Ordering::SeqCst);
+    }
+
+    /// Unmutes the [`Group`], restoring its volume.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn unmute(&self) {
+        self.controls.is_muted.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns whether the [`Group`] is muted or not.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn is_muted(&self) -> bool {
+        self.controls.is_muted.load(Ordering::SeqCst)
+    }
+}
+
+// The groups of an audio output
+#[derive(Debug)]
+pub(super) struct Mixer {
+    master: Arc<Controls>,
+    groups: Mutex<HashMap<String, Arc<Controls>>>,
+}
+
+impl Mixer {
+    pub(super) fn new() -> Mixer {
+        Mixer {
+            master: Arc::new(Controls::new()),
+            groups: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(super) fn master(&self) -> Group {
+        Group {
+            controls: self.master.clone(),
+        }
+    }
+
+    pub(super) fn group(&self, name: &str) -> Group {
+        // The lock is never held while panicking
+        let mut groups = self
+            .groups
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let controls = groups
+            .entry(String::from(name))
+            .or_insert_with(|| Arc::new(Controls::new()));
+
+        Group {
+            controls: controls.clone(),
+        }
+    }
+
+    // The volumes applied to anything played in the given group
+    pub(super) fn mix(&self, name: &str) -> Mix {
+        Mix {
+            group: self.group(name).controls,
+            master: self.master.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Controls {
+    volume: AtomicU32,
+    speed: Speed,
+    is_muted: AtomicBool,
+}
+
+impl Controls {
+    fn new() -> Controls {
+        Controls {
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            speed: Speed::new(1.0),
+            is_muted: AtomicBool::new(false),
+        }
+    }
+
+    fn gain(&self) -> f32 {
+        if self.is_muted.load(Ordering::Relaxed) {
+            0.0
+        } else {
+            f32::from_bits(self.volume.load(Ordering::Relaxed))
+        }
+    }
+}
+
+// The volumes of a group and the master group, read on the audio thread
+#[derive(Debug, Clone)]
+pub(super) struct Mix {
+    group: Arc<Controls>,
+    master: Arc<Controls>,
+}
+
+impl Mix {
+    pub(super) fn gain(&self) -> f32 {
+        self.group.gain() * self.master.gain()
+    }
+
+    pub(super) fn speed(&self) -> f32 {
+        self.group.speed.get() * self.master.speed.get()
+    }
+}
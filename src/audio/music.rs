@@ -0,0 +1,479 @@
+use std::fs::File;
+use std::io::{self, BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::audio::mixer::{Mix, CONTROL_INTERVAL};
+use crate::audio::resampler::{Resampler, Speed};
+use crate::load::{Bundle, Task};
+use crate::{Error, Result};
+
+// The fade applied when pausing, resuming, stopping, or changing the volume,
+// which avoids clicks
+const SHORT_FADE: Duration = Duration::from_millis(20);
+
+/// A music track, streamed from disk while it plays.
+///
+/// Unlike a [`Sound`], a [`Music`] track is not decoded into memory. It is
+/// decoded little by little while it plays, which makes it a better fit for
+/// long tracks.
+///
+/// Cloning a [`Music`] track is cheap.
+///
+/// WAV and Ogg Vorbis files are supported.
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+#[derive(Debug, Clone)]
+pub struct Music {
+    origin: Origin,
+    channels: u16,
+    sample_rate: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Origin {
+    File(PathBuf),
+    Bundled(&'static [u8]),
+}
+
+impl Music {
+    /// Creates a [`Task`] that opens the [`Music`] track in the given path.
+    ///
+    /// The file is only checked while loading. It is read again every time
+    /// the [`Music`] track is played.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Music`]: struct.Music.html
+    pub fn open<P: Into<PathBuf>>(path: P) -> Task<Music> {
+        let path = path.into();
+
+        Task::new(move || {
+            let origin = match Bundle::installed()
+                .and_then(|bundle| bundle.get(&path))
+            {
+                Some(bytes) => Origin::Bundled(bytes),
+                None => Origin::File(path.clone()),
+            };
+
+            let decoder = decode(&origin).map_err(|error| Error::Asset {
+                path,
+                error: Box::new(error),
+            })?;
+
+            Ok(Music {
+                origin,
+                channels: rodio::Source::channels(&decoder),
+                sample_rate: rodio::Source::sample_rate(&decoder),
+            })
+        })
+    }
+
+    // Creates a new source that plays the track from the beginning
+    pub(super) fn source(
+        &self,
+        looping: Loop,
+        controls: Arc<Controls>,
+        mix: Mix,
+        fade_in: Duration,
+    ) -> Option<Source> {
+        let decoder = decode(&self.origin).ok()?;
+        let channels = u64::from(self.channels.max(1));
+
+        let (loop_start, loop_end) = match looping {
+            Loop::Never => (None, None),
+            Loop::Forever => (Some(0), None),
+            Loop::Between { start, end } => (
+                Some(start * channels),
+                end.filter(|end| *end > start).map(|end| end * channels),
+            ),
+        };
+
+        Some(Source {
+            stream: Stream {
+                origin: self.origin.clone(),
+                decoder,
+                position: 0,
+                loop_start,
+                loop_end,
+            },
+            resampler: Resampler::new(self.channels),
+            rate: 1.0,
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            controls,
+            mix,
+            gain: 0.0,
+            // An impossible target, so the first update always starts a fade
+            target: -1.0,
+            step: 0.0,
+            until_update: 0,
+            fade: fade_in.max(SHORT_FADE),
+            is_paused: false,
+            is_stopping: false,
+        })
+    }
+}
+
+/// How a [`Music`] track loops.
+///
+/// Loop points are given in samples per channel, which is the same as the
+/// amount of sample frames from the beginning of the track.
+///
+/// Looping is gapless: the first sample of a loop is played right after the
+/// last one.
+///
+/// [`Music`]: struct.Music.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loop {
+    /// The track plays once.
+    Never,
+
+    /// The whole track loops forever.
+    Forever,
+
+    /// The track plays from the beginning and, once it reaches the `end`
+    /// sample, it jumps back to the `start` sample, forever.
+    ///
+    /// If `end` is `None`, the track loops when it finishes.
+    Between {
+        /// The first sample of the loop.
+        start: u64,
+
+        /// The sample where the loop ends, if it is not the end of the
+        /// track. It is ignored if it is not after `start`.
+        end: Option<u64>,
+    },
+}
+
+/// A [`Music`] track being played.
+///
+/// It is returned by [`Audio::play_music`] and [`Audio::crossfade_to`].
+/// Dropping it does not stop the track.
+///
+/// [`Music`]: struct.Music.html
+/// [`Audio::play_music`]: struct.Audio.html#method.play_music
+/// [`Audio::crossfade_to`]: struct.Audio.html#method.crossfade_to
+#[derive(Debug, Clone)]
+pub struct MusicHandle {
+    controls: Arc<Controls>,
+}
+
+impl MusicHandle {
+    pub(super) fn new(controls: Arc<Controls>) -> MusicHandle {
+        MusicHandle { controls }
+    }
+
+    /// Pauses the [`Music`] track.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn pause(&self) {
+        self.controls.is_paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes the [`Music`] track, if it was paused.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn resume(&self) {
+        self.controls.is_paused.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns whether the [`Music`] track is paused or not.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn is_paused(&self) -> bool {
+        self.controls.is_paused.load(Ordering::SeqCst)
+    }
+
+    /// Sets the volume of the [`Music`] track.
+    ///
+    /// `1.0` is the original volume of the [`Music`] track, and `0.0` mutes
+    /// it.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn set_volume(&self, volume: f32) {
+        self.controls
+            .volume
+            .store(volume.max(0.0).to_bits(), Ordering::SeqCst);
+    }
+
+    /// Sets the playback speed of the [`Music`] track, which also changes its
+    /// pitch.
+    ///
+    /// `1.0` is the original speed. The speed is clamped between `0.0` and
+    /// `4.0`, and a speed of `0.0` pauses the [`Music`] track.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn set_speed(&self, speed: f32) {
+        self.controls.speed.set(speed);
+    }
+
+    /// Stops the [`Music`] track, fading it out quickly to avoid clicks.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn stop(&self) {
+        self.fade_out(SHORT_FADE);
+    }
+
+    /// Returns whether the [`Music`] track finished playing or was stopped.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn is_finished(&self) -> bool {
+        self.controls.is_finished.load(Ordering::SeqCst)
+    }
+
+    // Stops the track after fading it out during the given duration
+    pub(super) fn fade_out(&self, duration: Duration) {
+        self.controls
+            .fade_out
+            .store(duration.as_millis() as u32, Ordering::SeqCst);
+
+        self.controls.is_stopping.store(true, Ordering::SeqCst);
+    }
+}
+
+// The controls of a playing track, shared with the audio thread
+#[derive(Debug)]
+pub(super) struct Controls {
+    volume: AtomicU32,
+    speed: Speed,
+    is_paused: AtomicBool,
+    is_stopping: AtomicBool,
+    fade_out: AtomicU32,
+    is_finished: AtomicBool,
+}
+
+impl Controls {
+    pub(super) fn new() -> Controls {
+        Controls {
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            speed: Speed::new(1.0),
+            is_paused: AtomicBool::new(false),
+            is_stopping: AtomicBool::new(false),
+            fade_out: AtomicU32::new(0),
+            is_finished: AtomicBool::new(false),
+        }
+    }
+
+    pub(super) fn finish(&self) {
+        self.is_finished.store(true, Ordering::SeqCst);
+    }
+}
+
+// A playing track, decoded on the audio thread
+pub(super) struct Source {
+    stream: Stream,
+    resampler: Resampler,
+    rate: f32,
+    channels: u16,
+    sample_rate: u32,
+    controls: Arc<Controls>,
+    mix: Mix,
+    gain: f32,
+    target: f32,
+    step: f32,
+    until_update: u32,
+    fade: Duration,
+    is_paused: bool,
+    is_stopping: bool,
+}
+
+impl Source {
+    // Reads the controls and moves the gain towards its new target
+    fn update(&mut self) {
+        let controls = &self.controls;
+
+        self.is_paused = controls.is_paused.load(Ordering::SeqCst);
+        self.rate = controls.speed.get() * self.mix.speed();
+
+        if !self.is_stopping && controls.is_stopping.load(Ordering::SeqCst) {
+            self.is_stopping = true;
+            self.fade = Duration::from_millis(u64::from(
+                controls.fade_out.load(Ordering::SeqCst),
+            ))
+            .max(SHORT_FADE);
+        }
+
+        let target = if self.is_paused || self.is_stopping {
+            0.0
+        } else {
+            f32::from_bits(controls.volume.load(Ordering::SeqCst))
+                * self.mix.gain()
+        };
+
+        if (target - self.target).abs() > std::f32::EPSILON {
+            let samples = self.fade.as_secs_f32()
+                * self.sample_rate as f32
+                * f32::from(self.channels);
+
+            self.target = target;
+            self.step = (target - self.gain).abs() / samples.max(1.0);
+            self.fade = SHORT_FADE;
+        }
+    }
+}
+
+// The decoded samples of a playing track
+struct Stream {
+    origin: Origin,
+    decoder: rodio::Decoder<Reader>,
+    position: u64,
+    loop_start: Option<u64>,
+    loop_end: Option<u64>,
+}
+
+impl Stream {
+    // Decodes the next sample, looping if needed
+    fn next_sample(&mut self) -> Option<i16> {
+        if let (Some(start), Some(end)) = (self.loop_start, self.loop_end) {
+            if self.position >= end {
+                self.restart(start)?;
+            }
+        }
+
+        match self.decoder.next() {
+            Some(sample) => {
+                self.position += 1;
+
+                Some(sample)
+            }
+            None => {
+                let start = self.loop_start?;
+
+                // An empty loop would never produce a sample
+                if self.position <= start {
+                    return None;
+                }
+
+                self.restart(start)?;
+                self.position += 1;
+
+                self.decoder.next()
+            }
+        }
+    }
+
+    fn restart(&mut self, start: u64) -> Option<()> {
+        self.decoder = decode(&self.origin).ok()?;
+
+        for _ in 0..start {
+            let _ = self.decoder.next()?;
+        }
+
+        self.position = start;
+
+        Some(())
+    }
+}
+
+impl Iterator for Source {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if self.until_update == 0 {
+            self.update();
+            self.until_update = CONTROL_INTERVAL;
+        }
+
+        self.until_update -= 1;
+
+        if self.gain < self.target {
+            self.gain = (self.gain + self.step).min(self.target);
+        } else {
+            self.gain = (self.gain - self.step).max(self.target);
+        }
+
+        let is_silent = self.gain <= 0.0;
+
+        if self.is_stopping && is_silent {
+            self.controls.finish();
+            return None;
+        }
+
+        // Paused tracks produce silence without advancing
+        if self.is_paused && is_silent {
+            return Some(0);
+        }
+
+        let stream = &mut self.stream;
+
+        let sample = self.resampler.next(self.rate, |frame| {
+            for sample in frame.iter_mut() {
+                match stream.next_sample() {
+                    Some(next) => *sample = next,
+                    None => return false,
+                }
+            }
+
+            true
+        });
+
+        match sample {
+            Some((_, sample)) => Some((sample * self.gain) as i16),
+            None => {
+                self.controls.finish();
+                None
+            }
+        }
+    }
+}
+
+impl rodio::Source for Source {
+    // The channels and the sample rate never change
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        self.controls.finish();
+    }
+}
+
+// The bytes of a track, read from disk or from the installed bundle
+enum Reader {
+    File(BufReader<File>),
+    Bundled(Cursor<&'static [u8]>),
+}
+
+impl Read for Reader {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Reader::File(file) => file.read(buffer),
+            Reader::Bundled(bytes) => bytes.read(buffer),
+        }
+    }
+}
+
+impl Seek for Reader {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        match self {
+            Reader::File(file) => file.seek(position),
+            Reader::Bundled(bytes) => bytes.seek(position),
+        }
+    }
+}
+
+fn decode(origin: &Origin) -> Result<rodio::Decoder<Reader>> {
+    let reader = match origin {
+        Origin::File(path) => Reader::File(BufReader::new(File::open(path)?)),
+        Origin::Bundled(bytes) => Reader::Bundled(Cursor::new(bytes)),
+    };
+
+    rodio::Decoder::new(reader).map_err(|error| Error::Audio(error.to_string()))
+}
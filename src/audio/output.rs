@@ -0,0 +1,419 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::audio::mixer::{Group, Mixer};
+use crate::audio::music::{Controls, Loop, Music, MusicHandle};
+use crate::audio::resampler::Speed;
+use crate::audio::spatial::{Listener, Panning};
+use crate::audio::Sound;
+use crate::graphics::Point;
+
+/// An audio output, used to play a [`Sound`].
+///
+/// Cloning an [`Audio`] output is cheap, it only clones a handle to the
+/// output device.
+///
+/// If no output device is available, the [`Audio`] output is silent: sounds
+/// can still be played, but they will not be heard. This way, your game keeps
+/// working in machines without speakers.
+///
+/// You can create one with [`Audio::new`] when loading your game or obtain
+/// the one of the [`Window`] with [`Window::audio`].
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Audio`]: struct.Audio.html
+/// [`Audio::new`]: #method.new
+/// [`Window`]: ../graphics/struct.Window.html
+/// [`Window::audio`]: ../graphics/struct.Window.html#method.audio
+#[derive(Clone)]
+pub struct Audio {
+    device: Option<Arc<rodio::Device>>,
+    mixer: Arc<Mixer>,
+    music: Arc<Mutex<Option<MusicHandle>>>,
+}
+
+impl Audio {
+    /// The name of the [`Group`] where sounds are played by default.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub const SFX: &'static str = "sfx";
+
+    /// The name of the [`Group`] where music is played.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub const MUSIC: &'static str = "music";
+
+    /// Opens the default output device of the system.
+    ///
+    /// If it fails, a warning is logged and a silent [`Audio`] output is
+    /// returned instead.
+    ///
+    /// [`Audio`]: struct.Audio.html
+    pub fn new() -> Audio {
+        let device = rodio::default_output_device();
+
+        if device.is_none() {
+            log::warn!("No audio output device found, sounds will not play");
+        }
+
+        Audio::with_device(device)
+    }
+
+    /// Creates a silent [`Audio`] output, which plays nothing.
+    ///
+    /// [`Audio`]: struct.Audio.html
+    pub fn silent() -> Audio {
+        Audio::with_device(None)
+    }
+
+    fn with_device(device: Option<rodio::Device>) -> Audio {
+        Audio {
+            device: device.map(Arc::new),
+            mixer: Arc::new(Mixer::new()),
+            music: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns whether the [`Audio`] output is silent or not.
+    ///
+    /// [`Audio`]: struct.Audio.html
+    pub fn is_silent(&self) -> bool {
+        self.device.is_none()
+    }
+
+    /// Returns the mixer [`Group`] with the given name, creating it if it
+    /// does not exist yet.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn group(&self, name: &str) -> Group {
+        self.mixer.group(name)
+    }
+
+    /// Returns the master [`Group`], whose volume applies to everything
+    /// played.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn master(&self) -> Group {
+        self.mixer.master()
+    }
+
+    /// Starts playing the given [`Sound`] in the [`SFX`] group.
+    ///
+    /// The same [`Sound`] can be played many times at once. Use the returned
+    /// [`PlayHandle`] to control this instance. Dropping the [`PlayHandle`]
+    /// does not stop it.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`SFX`]: #associatedconstant.SFX
+    /// [`PlayHandle`]: struct.PlayHandle.html
+    pub fn play(&self, sound: &Sound) -> PlayHandle {
+        self.play_in(Audio::SFX, sound)
+    }
+
+    /// Starts playing the given [`Sound`] in the mixer [`Group`] with the
+    /// given name.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Group`]: struct.Group.html
+    pub fn play_in(&self, group: &str, sound: &Sound) -> PlayHandle {
+        self.play_sound(group, sound, PlayOptions::default(), None)
+    }
+
+    /// Starts playing the given [`Sound`] in the [`SFX`] group, using the
+    /// given [`PlayOptions`].
+    ///
+    /// Varying the speed slightly every time a [`Sound`] is played is an
+    /// easy way to make it less repetitive.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`SFX`]: #associatedconstant.SFX
+    /// [`PlayOptions`]: struct.PlayOptions.html
+    pub fn play_with(&self, sound: &Sound, options: PlayOptions) -> PlayHandle {
+        let panning = if options.pan.abs() > std::f32::EPSILON {
+            Some(Arc::new(Panning::with_pan(options.pan)))
+        } else {
+            None
+        };
+
+        self.play_sound(Audio::SFX, sound, options, panning)
+    }
+
+    /// Starts playing the given [`Sound`] in the [`SFX`] group, as if it
+    /// came from the given position in the world.
+    ///
+    /// The [`Sound`] is panned and attenuated depending on where it is
+    /// relative to the [`Listener`]. Use [`PlayHandle::set_position`] to move
+    /// it while it plays.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`SFX`]: #associatedconstant.SFX
+    /// [`Listener`]: struct.Listener.html
+    /// [`PlayHandle::set_position`]: struct.PlayHandle.html#method.set_position
+    pub fn play_at(
+        &self,
+        sound: &Sound,
+        position: Point,
+        listener: &Listener,
+    ) -> PlayHandle {
+        let panning = Arc::new(Panning::new(position, listener));
+
+        self.play_sound(
+            Audio::SFX,
+            sound,
+            PlayOptions::default(),
+            Some(panning),
+        )
+    }
+
+    fn play_sound(
+        &self,
+        group: &str,
+        sound: &Sound,
+        options: PlayOptions,
+        panning: Option<Arc<Panning>>,
+    ) -> PlayHandle {
+        let speed = Arc::new(Speed::new(options.speed));
+
+        let sink = self.device.as_ref().map(|device| {
+            let sink = rodio::Sink::new(device);
+            sink.set_volume(options.volume.max(0.0));
+            sink.append(sound.source(
+                self.mixer.mix(group),
+                speed.clone(),
+                panning.clone(),
+            ));
+
+            sink
+        });
+
+        PlayHandle {
+            sink,
+            speed,
+            panning,
+        }
+    }
+
+    /// Starts playing the given [`Music`] track in the [`MUSIC`] group,
+    /// stopping the current one.
+    ///
+    /// An [`Audio`] output plays a single [`Music`] track at a time, apart
+    /// from crossfades. Use the returned [`MusicHandle`] to control it.
+    ///
+    /// [`Music`]: struct.Music.html
+    /// [`MUSIC`]: #associatedconstant.MUSIC
+    /// [`Audio`]: struct.Audio.html
+    /// [`MusicHandle`]: struct.MusicHandle.html
+    pub fn play_music(&self, music: &Music, looping: Loop) -> MusicHandle {
+        self.switch_music(music, looping, Duration::from_secs(0))
+    }
+
+    /// Fades out the current [`Music`] track while the given one fades in,
+    /// during the given duration.
+    ///
+    /// This is useful to change the music smoothly between scenes.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn crossfade_to(
+        &self,
+        music: &Music,
+        looping: Loop,
+        duration: Duration,
+    ) -> MusicHandle {
+        self.switch_music(music, looping, duration)
+    }
+
+    /// Stops the current [`Music`] track, fading it out quickly to avoid
+    /// clicks.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn stop_music(&self) {
+        if let Some(current) = self.lock_music().take() {
+            current.stop();
+        }
+    }
+
+    /// Returns the [`MusicHandle`] of the current [`Music`] track, if any.
+    ///
+    /// [`MusicHandle`]: struct.MusicHandle.html
+    /// [`Music`]: struct.Music.html
+    pub fn current_music(&self) -> Option<MusicHandle> {
+        self.lock_music()
+            .clone()
+            .filter(|current| !current.is_finished())
+    }
+
+    fn switch_music(
+        &self,
+        music: &Music,
+        looping: Loop,
+        fade: Duration,
+    ) -> MusicHandle {
+        let controls = Arc::new(Controls::new());
+        let handle = MusicHandle::new(controls.clone());
+
+        let source = self.device.as_ref().and_then(|device| {
+            music
+                .source(
+                    looping,
+                    controls.clone(),
+                    self.mixer.mix(Audio::MUSIC),
+                    fade,
+                )
+                .map(|source| (device, source))
+        });
+
+        match source {
+            Some((device, source)) => {
+                let sink = rodio::Sink::new(device);
+                sink.append(source);
+                sink.detach();
+            }
+            None => controls.finish(),
+        }
+
+        if let Some(previous) = self.lock_music().replace(handle.clone()) {
+            previous.fade_out(fade);
+        }
+
+        handle
+    }
+
+    fn lock_music(&self) -> std::sync::MutexGuard<'_, Option<MusicHandle>> {
+        // The lock is never held while panicking
+        self.music.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+/// The options used to play a [`Sound`] with [`Audio::play_with`].
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Audio::play_with`]: struct.Audio.html#method.play_with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayOptions {
+    /// The volume of the [`Sound`]. `1.0` is its original volume.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub volume: f32,
+
+    /// The playback speed of the [`Sound`], which also changes its pitch.
+    /// `1.0` is its original speed.
+    ///
+    /// It is clamped between `0.0` and `4.0`, and a speed of `0.0` pauses
+    /// the [`Sound`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub speed: f32,
+
+    /// The stereo pan of the [`Sound`], from `-1.0` (left) to `1.0`
+    /// (right).
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub pan: f32,
+}
+
+impl Default for PlayOptions {
+    fn default() -> PlayOptions {
+        PlayOptions {
+            volume: 1.0,
+            speed: 1.0,
+            pan: 0.0,
+        }
+    }
+}
+
+impl Default for Audio {
+    fn default() -> Audio {
+        Audio::new()
+    }
+}
+
+impl std::fmt::Debug for Audio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Audio {{ is_silent: {} }}", self.is_silent())
+    }
+}
+
+/// A [`Sound`] being played.
+///
+/// It is returned by [`Audio::play`].
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Audio::play`]: struct.Audio.html#method.play
+pub struct PlayHandle {
+    sink: Option<rodio::Sink>,
+    speed: Arc<Speed>,
+    panning: Option<Arc<Panning>>,
+}
+
+impl PlayHandle {
+    /// Stops the [`Sound`] being played.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn stop(&self) {
+        if let Some(sink) = &self.sink {
+            sink.stop();
+        }
+    }
+
+    /// Sets the volume of the [`Sound`] being played.
+    ///
+    /// `1.0` is the original volume of the [`Sound`], and `0.0` mutes it.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn set_volume(&self, volume: f32) {
+        if let Some(sink) = &self.sink {
+            sink.set_volume(volume.max(0.0));
+        }
+    }
+
+    /// Sets the playback speed of the [`Sound`] being played, which also
+    /// changes its pitch.
+    ///
+    /// `1.0` is the original speed. The speed is clamped between `0.0` and
+    /// `4.0`, and a speed of `0.0` pauses the [`Sound`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn set_speed(&self, speed: f32) {
+        self.speed.set(speed);
+    }
+
+    /// Moves the [`Sound`] being played to the given position in the world,
+    /// relative to the given [`Listener`].
+    ///
+    /// It only has an effect on sounds played with [`Audio::play_at`]. It is
+    /// safe to call once the [`Sound`] has finished.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Listener`]: struct.Listener.html
+    /// [`Audio::play_at`]: struct.Audio.html#method.play_at
+    pub fn set_position(&self, position: Point, listener: &Listener) {
+        if let Some(panning) = &self.panning {
+            panning.set(position, listener);
+        }
+    }
+
+    /// Returns whether the [`Sound`] finished playing or was stopped.
+    ///
+    /// It is always true when using a silent [`Audio`] output.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Audio`]: struct.Audio.html
+    pub fn is_finished(&self) -> bool {
+        self.sink.as_ref().map_or(true, rodio::Sink::empty)
+    }
+}
+
+impl Drop for PlayHandle {
+    fn drop(&mut self) {
+        // Let the sound play until the end
+        if let Some(sink) = self.sink.take() {
+            sink.detach();
+        }
+    }
+}
+
+impl std::fmt::Debug for PlayHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PlayHandle {{ is_finished: {} }}", self.is_finished())
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// The maximum playback speed, as a multiple of the original one
+pub(super) const MAX_SPEED: f32 = 4.0;
+
+// Changes the playback speed of a stream of interleaved samples using linear
+// interpolation between frames
+#[derive(Debug)]
+pub(super) struct Resampler {
+    channels: usize,
+    current: Vec<i16>,
+    next: Vec<i16>,
+    has_next: bool,
+    fraction: f32,
+    channel: usize,
+    is_started: bool,
+}
+
+impl Resampler {
+    pub(super) fn new(channels: u16) -> Resampler {
+        let channels = usize::from(channels.max(1));
+
+        Resampler {
+            channels,
+            current: vec![0; channels],
+            next: vec![0; channels],
+            has_next: false,
+            fraction: 0.0,
+            channel: 0,
+            is_started: false,
+        }
+    }
+
+    // Produces the next sample and its channel, reading frames with the given
+    // function when needed. The function fills the given frame and returns
+    // false once the stream ends.
+    //
+    // A speed of zero produces silence without advancing.
+    pub(super) fn next<F>(
+        &mut self,
+        speed: f32,
+        mut read_frame: F,
+    ) -> Option<(usize, f32)>
+    where
+        F: FnMut(&mut [i16]) -> bool,
+    {
+        if !self.is_started {
+            if !read_frame(&mut self.current) {
+                return None;
+            }
+
+            self.has_next = read_frame(&mut self.next);
+            self.is_started = true;
+        } else if self.channel == 0 {
+            self.fraction += speed.max(0.0).min(MAX_SPEED);
+
+            while self.fraction >= 1.0 {
+                if !self.has_next {
+                    return None;
+                }
+
+                std::mem::swap(&mut self.current, &mut self.next);
+
+                self.has_next = read_frame(&mut self.next);
+                self.fraction -= 1.0;
+            }
+        }
+
+        let channel = self.channel;
+        self.channel = (self.channel + 1) % self.channels;
+
+        if speed <= 0.0 {
+            return Some((channel, 0.0));
+        }
+
+        let current = f32::from(self.current[channel]);
+
+        let sample = if self.has_next {
+            let next = f32::from(self.next[channel]);
+
+            current + (next - current) * self.fraction
+        } else {
+            current
+        };
+
+        Some((channel, sample))
+    }
+}
+
+// The playback speed of a sound or a music track, shared with the audio
+// thread
+#[derive(Debug)]
+pub(super) struct Speed(AtomicU32);
+
+impl Speed {
+    pub(super) fn new(speed: f32) -> Speed {
+        Speed(AtomicU32::new(clamp(speed).to_bits()))
+    }
+
+    pub(super) fn set(&self, speed: f32) {
+        self.0.store(clamp(speed).to_bits(), Ordering::Relaxed);
+    }
+
+    pub(super) fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+fn clamp(speed: f32) -> f32 {
+    if speed.is_nan() {
+        1.0
+    } else {
+        speed.max(0.0).min(MAX_SPEED)
+    }
+}
@@ -0,0 +1,236 @@
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::audio::mixer::{Mix, CONTROL_INTERVAL};
+use crate::audio::resampler::{Resampler, Speed};
+use crate::audio::spatial::Panning;
+use crate::load::{self, Task};
+use crate::{Error, Result};
+
+/// A sound decoded into memory.
+///
+/// Cloning a [`Sound`] is cheap, it only clones a handle to its samples.
+///
+/// WAV and Ogg Vorbis files are supported.
+///
+/// [`Sound`]: struct.Sound.html
+#[derive(Clone)]
+pub struct Sound {
+    samples: Arc<[i16]>,
+    channels: u16,
+    sample_rate: u32,
+}
+
+impl Sound {
+    /// Creates a [`Task`] that loads a [`Sound`] from the given path.
+    ///
+    /// The whole file is decoded while loading, so playing the [`Sound`]
+    /// later does not need to read or decode anything.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Sound`]: struct.Sound.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Sound> {
+        let path = path.into();
+
+        Task::new(move || {
+            load::read(&path)
+                .and_then(|bytes| Sound::from_bytes(&bytes))
+                .map_err(|error| Error::Asset {
+                    path,
+                    error: Box::new(error),
+                })
+        })
+    }
+
+    /// Decodes a [`Sound`] from the bytes of a WAV or Ogg Vorbis file.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn from_bytes(bytes: &[u8]) -> Result<Sound> {
+        let decoder = rodio::Decoder::new(Cursor::new(bytes.to_vec()))
+            .map_err(|error| Error::Audio(error.to_string()))?;
+
+        let channels = rodio::Source::channels(&decoder);
+        let sample_rate = rodio::Source::sample_rate(&decoder);
+        let samples: Vec<i16> = decoder.collect();
+
+        Ok(Sound {
+            samples: samples.into(),
+            channels,
+            sample_rate,
+        })
+    }
+
+    /// Creates a [`Sound`] from interleaved samples.
+    ///
+    /// This is useful to play sounds generated by your game.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn from_samples(
+        channels: u16,
+        sample_rate: u32,
+        samples: Vec<i16>,
+    ) -> Sound {
+        Sound {
+            samples: samples.into(),
+            channels: channels.max(1),
+            sample_rate: sample_rate.max(1),
+        }
+    }
+
+    /// Returns the duration of the [`Sound`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn duration(&self) -> Duration {
+        duration(self.samples.len(), self.channels, self.sample_rate)
+    }
+
+    // Creates a new source that plays the sound from the beginning
+    pub(super) fn source(
+        &self,
+        mix: Mix,
+        speed: Arc<Speed>,
+        panning: Option<Arc<Panning>>,
+    ) -> Source {
+        Source {
+            samples: self.samples.clone(),
+            position: 0,
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            resampler: Resampler::new(self.channels),
+            mix,
+            speed,
+            panning,
+            gain: 1.0,
+            rate: 1.0,
+            gains: (1.0, 1.0),
+            pending: None,
+            until_update: 0,
+        }
+    }
+}
+
+impl std::fmt::Debug for Sound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sound")
+            .field("channels", &self.channels)
+            .field("sample_rate", &self.sample_rate)
+            .field("duration", &self.duration())
+            .finish()
+    }
+}
+
+// A playing instance of a sound, sharing its samples with the other ones
+pub(super) struct Source {
+    samples: Arc<[i16]>,
+    position: usize,
+    channels: u16,
+    sample_rate: u32,
+    resampler: Resampler,
+    mix: Mix,
+    speed: Arc<Speed>,
+    panning: Option<Arc<Panning>>,
+    gain: f32,
+    rate: f32,
+    gains: (f32, f32),
+    // The right channel of a panned mono sample, which is played next
+    pending: Option<i16>,
+    until_update: u32,
+}
+
+impl Source {
+    // Panned mono sounds are played in stereo
+    fn is_upmixed(&self) -> bool {
+        self.panning.is_some() && self.channels == 1
+    }
+}
+
+impl Iterator for Source {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if self.until_update == 0 {
+            self.gain = self.mix.gain();
+            self.rate = self.speed.get() * self.mix.speed();
+            self.gains = self
+                .panning
+                .as_ref()
+                .map_or((1.0, 1.0), |panning| panning.gains());
+            self.until_update = CONTROL_INTERVAL;
+        }
+
+        self.until_update -= 1;
+
+        if let Some(right) = self.pending.take() {
+            return Some(right);
+        }
+
+        let samples = &self.samples;
+        let position = &mut self.position;
+
+        let (channel, sample) = self.resampler.next(self.rate, |frame| {
+            let next = *position + frame.len();
+
+            match samples.get(*position..next) {
+                Some(samples) => {
+                    frame.copy_from_slice(samples);
+                    *position = next;
+
+                    true
+                }
+                None => false,
+            }
+        })?;
+
+        let sample = sample * self.gain;
+        let (left, right) = self.gains;
+
+        if self.is_upmixed() {
+            self.pending = Some((sample * right) as i16);
+
+            return Some((sample * left) as i16);
+        }
+
+        let gain = match channel {
+            0 => left,
+            1 => right,
+            _ => left.max(right),
+        };
+
+        Some((sample * gain) as i16)
+    }
+}
+
+impl rodio::Source for Source {
+    // The channels and the sample rate never change
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        if self.is_upmixed() {
+            2
+        } else {
+            self.channels
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(duration(
+            self.samples.len(),
+            self.channels,
+            self.sample_rate,
+        ))
+    }
+}
+
+fn duration(samples: usize, channels: u16, sample_rate: u32) -> Duration {
+    let frames = samples / usize::from(channels.max(1));
+
+    Duration::from_secs_f64(frames as f64 / f64::from(sample_rate.max(1)))
+}
@@ -0,0 +1,104 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::graphics::Point;
+
+/// The point in the world where sounds are heard from, usually the center
+/// of the camera.
+///
+/// Sounds played with [`Audio::play_at`] are panned depending on their
+/// horizontal offset from the [`Listener`], and they get quieter as they get
+/// farther from it. They cannot be heard outside of its range.
+///
+/// [`Audio::play_at`]: struct.Audio.html#method.play_at
+/// [`Listener`]: struct.Listener.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Listener {
+    /// The position of the [`Listener`] in the world.
+    ///
+    /// [`Listener`]: struct.Listener.html
+    pub position: Point,
+
+    /// The distance from which sounds cannot be heard anymore.
+    pub range: f32,
+}
+
+impl Listener {
+    /// Creates a new [`Listener`] at the given position with the given range.
+    ///
+    /// [`Listener`]: struct.Listener.html
+    pub fn new(position: Point, range: f32) -> Listener {
+        Listener { position, range }
+    }
+
+    /// Returns the volumes of the left and right channels of a sound in the
+    /// given position, from `0.0` to `1.0`.
+    pub fn gains(&self, position: Point) -> (f32, f32) {
+        let range = self.range.max(std::f32::EPSILON);
+        let offset = position - self.position;
+
+        let attenuation = (1.0 - offset.norm() / range).max(0.0);
+        let (left, right) = pan_gains(offset.x / range);
+
+        (left * attenuation, right * attenuation)
+    }
+}
+
+// The volumes of the left and right channels for the given pan, from `-1.0`
+// (left) to `1.0` (right)
+fn pan_gains(pan: f32) -> (f32, f32) {
+    let pan = if pan.is_nan() {
+        0.0
+    } else {
+        pan.max(-1.0).min(1.0)
+    };
+
+    // The center keeps the original volume in both channels, while the sides
+    // fade out the opposite channel
+    let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
+    let left = (std::f32::consts::SQRT_2 * angle.cos()).min(1.0);
+    let right = (std::f32::consts::SQRT_2 * angle.sin()).min(1.0);
+
+    (left, right)
+}
+
+// The volumes of the channels of a positional sound, shared with the audio
+// thread
+#[derive(Debug)]
+pub(super) struct Panning {
+    left: AtomicU32,
+    right: AtomicU32,
+}
+
+impl Panning {
+    pub(super) fn new(position: Point, listener: &Listener) -> Panning {
+        let (left, right) = listener.gains(position);
+
+        Panning {
+            left: AtomicU32::new(left.to_bits()),
+            right: AtomicU32::new(right.to_bits()),
+        }
+    }
+
+    pub(super) fn with_pan(pan: f32) -> Panning {
+        let (left, right) = pan_gains(pan);
+
+        Panning {
+            left: AtomicU32::new(left.to_bits()),
+            right: AtomicU32::new(right.to_bits()),
+        }
+    }
+
+    pub(super) fn set(&self, position: Point, listener: &Listener) {
+        let (left, right) = listener.gains(position);
+
+        self.left.store(left.to_bits(), Ordering::Relaxed);
+        self.right.store(right.to_bits(), Ordering::Relaxed);
+    }
+
+    pub(super) fn gains(&self) -> (f32, f32) {
+        (
+            f32::from_bits(self.left.load(Ordering::Relaxed)),
+            f32::from_bits(self.right.load(Ordering::Relaxed)),
+        )
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::{Duration, Instant};
+
+/// The results of a benchmark run.
+///
+/// You can obtain it by running your game with [`Game::run_bench`].
+///
+/// [`Game::run_bench`]: trait.Game.html#method.run_bench
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    frame_times: Vec<Duration>,
+    load_duration: Duration,
+    interact_duration: Duration,
+    update_duration: Duration,
+    draw_duration: Duration,
+    present_duration: Duration,
+    ticks: u64,
+}
+
+impl BenchReport {
+    /// Returns the amount of frames rendered.
+    pub fn frames(&self) -> usize {
+        self.frame_times.len()
+    }
+
+    /// Returns the amount of updates performed.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the time spent loading the game.
+    pub fn load_duration(&self) -> Duration {
+        self.load_duration
+    }
+
+    /// Returns the total time spent rendering frames.
+    pub fn total_duration(&self) -> Duration {
+        self.frame_times.iter().sum()
+    }
+
+    /// Returns the mean frame time.
+    pub fn mean(&self) -> Duration {
+        self.per_frame(self.total_duration())
+    }
+
+    /// Returns the median frame time.
+    pub fn median(&self) -> Duration {
+        self.percentile(50.0)
+    }
+
+    /// Returns the 95th percentile of the frame times.
+    pub fn p95(&self) -> Duration {
+        self.percentile(95.0)
+    }
+
+    /// Returns the 99th percentile of the frame times.
+    pub fn p99(&self) -> Duration {
+        self.percentile(99.0)
+    }
+
+    /// Returns the given percentile of the frame times, using the
+    /// nearest-rank method.
+    ///
+    /// The percentile is clamped to the `[0.0, 100.0]` interval.
+    pub fn percentile(&self, percentile: f32) -> Duration {
+        if self.frame_times.is_empty() {
+            return Duration::from_secs(0);
+        }
+
+        let mut frame_times = self.frame_times.clone();
+        frame_times.sort();
+
+        let rank = (percentile.max(0.0).min(100.0) / 100.0
+            * frame_times.len() as f32)
+            .ceil() as usize;
+
+        frame_times[rank.max(1) - 1]
+    }
+
+    /// Returns the mean time spent in [`Game::interact`] per frame.
+    ///
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    pub fn interact_duration(&self) -> Duration {
+        self.per_frame(self.interact_duration)
+    }
+
+    /// Returns the mean time spent in [`Game::update`] per frame.
+    ///
+    /// [`Game::update`]: trait.Game.html#method.update
+    pub fn update_duration(&self) -> Duration {
+        self.per_frame(self.update_duration)
+    }
+
+    /// Returns the mean time spent in [`Game::draw`] per frame.
+    ///
+    /// [`Game::draw`]: trait.Game.html#tymethod.draw
+    pub fn draw_duration(&self) -> Duration {
+        self.per_frame(self.draw_duration)
+    }
+
+    /// Returns the mean time spent presenting a frame.
+    pub fn present_duration(&self) -> Duration {
+        self.per_frame(self.present_duration)
+    }
+
+    /// Returns the report in JSON format.
+    ///
+    /// All the durations are in milliseconds.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"frames\":{},\"ticks\":{},\"load\":{},\"total\":{},\
+             \"mean\":{},\"median\":{},\"p95\":{},\"p99\":{},\
+             \"sections\":{{\"interact\":{},\"update\":{},\"draw\":{},\
+             \"present\":{}}}}}",
+            self.frames(),
+            self.ticks,
+            millis(self.load_duration),
+            millis(self.total_duration()),
+            millis(self.mean()),
+            millis(self.median()),
+            millis(self.p95()),
+            millis(self.p99()),
+            millis(self.interact_duration()),
+            millis(self.update_duration()),
+            millis(self.draw_duration()),
+            millis(self.present_duration()),
+        )
+    }
+
+    fn per_frame(&self, duration: Duration) -> Duration {
+        match self.frame_times.len() {
+            0 => Duration::from_secs(0),
+            frames => duration / frames as u32,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Recorder {
+    frames: u32,
+    report: BenchReport,
+    last_frame: Instant,
+    section_started: Instant,
+}
+
+impl Recorder {
+    pub fn new(frames: u32) -> Recorder {
+        let now = Instant::now();
+
+        Recorder {
+            frames,
+            report: BenchReport {
+                frame_times: Vec::with_capacity(frames as usize),
+                load_duration: Duration::from_secs(0),
+                interact_duration: Duration::from_secs(0),
+                update_duration: Duration::from_secs(0),
+                draw_duration: Duration::from_secs(0),
+                present_duration: Duration::from_secs(0),
+                ticks: 0,
+            },
+            last_frame: now,
+            section_started: now,
+        }
+    }
+
+    pub fn loading_finished(&mut self, load_duration: Duration) {
+        self.report.load_duration = load_duration;
+        self.last_frame = Instant::now();
+    }
+
+    pub fn section_started(&mut self) {
+        self.section_started = Instant::now();
+    }
+
+    pub fn interact_finished(&mut self) {
+        self.report.interact_duration += self.section_started.elapsed();
+    }
+
+    pub fn update_finished(&mut self, ticks: u64) {
+        self.report.update_duration += self.section_started.elapsed();
+        self.report.ticks += ticks;
+    }
+
+    pub fn draw_finished(&mut self) {
+        self.report.draw_duration += self.section_started.elapsed();
+    }
+
+    pub fn present_finished(&mut self) {
+        self.report.present_duration += self.section_started.elapsed();
+    }
+
+    pub fn frame_finished(&mut self) {
+        let now = Instant::now();
+
+        self.report.frame_times.push(now - self.last_frame);
+        self.last_frame = now;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.report.frame_times.len() >= self.frames as usize
+    }
+
+    pub fn into_report(self) -> BenchReport {
+        self.report
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+/// The game time of your game.
+///
+/// A [`Clock`] keeps track of game time, which can be paused and scaled
+/// independently of the real time of the game loop. It is useful to freeze
+/// cooldowns while a pause menu is open, or to implement slow motion.
+///
+/// Game time advances by the duration of a tick, multiplied by the
+/// [`scale`], every time your [`Game`] is updated. Pausing a [`Clock`] only
+/// stops game time: [`Game::update`] keeps being called, so you can still
+/// animate menus using the real time provided by [`Window::total_time`].
+///
+/// You can access the [`Clock`] of your [`Game`] with [`Window::clock`] and
+/// control it in [`Game::interact`] with [`Window::clock_mut`].
+///
+/// [`Clock`]: struct.Clock.html
+/// [`scale`]: #method.scale
+/// [`Game`]: trait.Game.html
+/// [`Game::update`]: trait.Game.html#method.update
+/// [`Game::interact`]: trait.Game.html#method.interact
+/// [`Window::total_time`]: graphics/struct.Window.html#method.total_time
+/// [`Window::clock`]: graphics/struct.Window.html#method.clock
+/// [`Window::clock_mut`]: graphics/struct.Window.html#method.clock_mut
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clock {
+    game_time: Duration,
+    delta: Duration,
+    scale: f32,
+    is_paused: bool,
+}
+
+impl Clock {
+    pub(crate) fn new() -> Clock {
+        Clock {
+            game_time: Duration::from_secs(0),
+            delta: Duration::from_secs(0),
+            scale: 1.0,
+            is_paused: false,
+        }
+    }
+
+    /// Returns the elapsed game time, including the current tick.
+    pub fn game_time(&self) -> Duration {
+        self.game_time
+    }
+
+    /// Returns the game time that elapsed during the current tick.
+    ///
+    /// It is zero while the [`Clock`] is paused.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns the scale of the [`Clock`].
+    ///
+    /// By default, it is `1.0`.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Sets the scale of the [`Clock`].
+    ///
+    /// For instance, a scale of `0.5` makes game time advance at half speed.
+    /// Negative scales are treated as `0.0`.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.max(0.0);
+    }
+
+    /// Pauses the [`Clock`], stopping game time.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+
+    /// Resumes the [`Clock`].
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn resume(&mut self) {
+        self.is_paused = false;
+    }
+
+    /// Returns true if the [`Clock`] is paused.
+    ///
+    /// [`Clock`]: struct.Clock.html
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    pub(crate) fn advance(&mut self, tick_duration: Duration) {
+        self.delta = if self.is_paused {
+            Duration::from_secs(0)
+        } else {
+            tick_duration.mul_f64(f64::from(self.scale))
+        };
+
+        self.game_time += self.delta;
+    }
+}
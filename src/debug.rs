@@ -1,11 +1,117 @@
+use crate::input::keyboard::KeyCode;
+
+use std::time::Duration;
+
 #[cfg(not(any(debug_assertions, feature = "debug")))]
 mod null;
 
 #[cfg(any(debug_assertions, feature = "debug"))]
 mod basic;
 
+#[cfg(any(debug_assertions, feature = "debug"))]
+mod capture;
+
+#[cfg(any(debug_assertions, feature = "debug"))]
+mod console;
+
 #[cfg(not(any(debug_assertions, feature = "debug")))]
 pub use null::Debug;
 
+#[cfg(not(any(debug_assertions, feature = "debug")))]
+pub(crate) use null::Sections;
+
 #[cfg(any(debug_assertions, feature = "debug"))]
 pub use basic::Debug;
+
+#[cfg(any(debug_assertions, feature = "debug"))]
+pub(crate) use basic::Sections;
+
+/// A corner of the screen where the [`Debug`] view can be placed.
+///
+/// [`Debug`]: struct.Debug.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCorner {
+    /// The top left corner.
+    TopLeft,
+
+    /// The top right corner.
+    TopRight,
+
+    /// The bottom left corner.
+    BottomLeft,
+
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl Default for DebugCorner {
+    fn default() -> DebugCorner {
+        DebugCorner::TopLeft
+    }
+}
+
+/// The level of a message logged to the console of the [`Debug`] view.
+///
+/// Levels are ordered by verbosity: [`Error`] is the least verbose and
+/// [`Trace`] the most.
+///
+/// [`Debug`]: struct.Debug.html
+/// [`Error`]: #variant.Error
+/// [`Trace`]: #variant.Trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// A serious error.
+    Error,
+
+    /// A potential problem.
+    Warn,
+
+    /// Useful information.
+    Info,
+
+    /// Lower priority information.
+    Debug,
+
+    /// Very low priority, often extremely verbose, information.
+    Trace,
+}
+
+/// The timings of a section of the [`Debug`] view.
+///
+/// You can obtain them with [`Debug::timings`].
+///
+/// [`Debug`]: struct.Debug.html
+/// [`Debug::timings`]: struct.Debug.html#method.timings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugSection {
+    /// The name of the section.
+    pub name: String,
+
+    /// The time spent in the section during the last frame.
+    pub last: Duration,
+
+    /// The average time spent in the section during the recent frames.
+    pub average: Duration,
+
+    /// The worst time spent in the section during the recent frames.
+    pub worst: Duration,
+}
+
+/// The controls of the debug view, shared between the [`Debug`] and the
+/// window.
+///
+/// [`Debug`]: struct.Debug.html
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Controls {
+    pub is_visible: bool,
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for Controls {
+    fn default() -> Controls {
+        Controls {
+            is_visible: cfg!(feature = "debug"),
+            toggle_key: None,
+        }
+    }
+}
@@ -1,13 +1,25 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use std::time;
 
+use super::capture::{Capture, Span};
+use super::console::Console;
+use super::{Controls, DebugCorner, DebugSection, LogLevel};
 use crate::graphics;
+use crate::input::keyboard::KeyCode;
+use crate::{FrameTimeStats, Result, Timer};
 
 /// A bunch of performance information about your game. It can be drawn!
 ///
 /// ![Debug information][debug]
 ///
 /// This is the default debug information that will be shown when the
-/// [`Game::DEBUG_KEY`] is pressed.
+/// [`Game::DEBUG_KEY`] is pressed. You can also show and hide it in
+/// [`Game::interact`] with [`Window::show_debug`] and [`Window::hide_debug`].
 ///
 /// Overriding [`Game::debug`] gives you access to this struct, allowing you to
 /// implement your own debug view.
@@ -16,50 +28,107 @@ use crate::graphics;
 /// [debug]: https://github.com/hecrj/coffee/blob/50c9a857e476266d8bd37f705266bd66b77c0f2d/images/debug.png?raw=true
 /// [`Game::DEBUG_KEY`]: trait.Game.html#associatedconstant.DEBUG_KEY
 /// [`Game::debug`]: trait.Game.html#method.debug
+/// [`Game::interact`]: trait.Game.html#method.interact
+/// [`Window::show_debug`]: graphics/struct.Window.html#method.show_debug
+/// [`Window::hide_debug`]: graphics/struct.Window.html#method.hide_debug
+///
+/// # Console
+/// The [`Debug`] view also has a console showing the messages logged with
+/// [`Debug::log`]. If you enable the `log` feature, the records of the [`log`
+/// crate] are shown there too, unless you install a different logger.
+///
+/// While the [`Debug`] view is visible, you can:
+///
+///   * toggle the console with the `` ` `` key,
+///   * scroll it with `PageUp` and `PageDown`,
+///   * cycle the minimum [`LogLevel`] shown with `F11`.
+///
+/// [`Debug`]: struct.Debug.html
+/// [`Debug::log`]: struct.Debug.html#method.log
+/// [`log` crate]: https://docs.rs/log
+/// [`LogLevel`]: enum.LogLevel.html
 pub struct Debug {
     font: graphics::Font,
-    enabled: bool,
+    controls: Rc<Cell<Controls>>,
+    corner: DebugCorner,
+    scale: f32,
     load_start: time::Instant,
     load_duration: time::Duration,
-    frame_start: time::Instant,
-    frame_durations: TimeBuffer,
+    frame_time_stats: FrameTimeStats,
     interact_start: time::Instant,
-    interact_duration: time::Duration,
+    interact_durations: TimeBuffer,
     update_start: time::Instant,
     update_durations: TimeBuffer,
+    dropped_ticks: u64,
     draw_start: time::Instant,
     draw_durations: TimeBuffer,
     ui_start: time::Instant,
     ui_durations: TimeBuffer,
     debug_start: time::Instant,
     debug_durations: TimeBuffer,
-    text: Vec<(String, String)>,
+    gpu_wait_durations: TimeBuffer,
+    frame_stats: graphics::FrameStats,
+    gpu_stats: graphics::GpuStats,
+    memory_stats: graphics::GpuMemoryStats,
+    sections: Rc<RefCell<Sections>>,
+    timings: Vec<DebugSection>,
+    frame_index: u64,
+    capture: Option<Capture>,
+    console: Arc<Mutex<Console>>,
+    is_console_visible: bool,
+    console_scroll: usize,
+    console_filter: LogLevel,
+    text: Vec<(String, String, graphics::Color)>,
     draw_rate: u16,
     frames_until_refresh: u16,
 }
 
 impl Debug {
-    pub(crate) fn new(gpu: &mut graphics::Gpu) -> Self {
+    pub(crate) fn new(window: &mut graphics::Window) -> Self {
         let now = time::Instant::now();
+        let sections = window.debug_sections();
+        let controls = window.debug_controls();
+        let scale = window.scale_factor() as f32;
+        let console = Arc::new(Mutex::new(Console::new()));
+
+        #[cfg(feature = "log")]
+        super::console::Logger::install(console.clone());
 
         Self {
-            font: graphics::Font::from_bytes(gpu, graphics::Font::DEFAULT)
-                .expect("Load debug font"),
-            enabled: cfg!(feature = "debug"),
+            font: graphics::Font::from_bytes(
+                window.gpu(),
+                graphics::Font::DEFAULT,
+            )
+            .expect("Load debug font"),
+            controls,
+            corner: DebugCorner::default(),
+            scale,
             load_start: now,
             load_duration: time::Duration::from_secs(0),
-            frame_start: now,
-            frame_durations: TimeBuffer::new(200),
+            frame_time_stats: FrameTimeStats::default(),
             interact_start: now,
-            interact_duration: time::Duration::from_secs(0),
+            interact_durations: TimeBuffer::new(200),
             update_start: now,
             update_durations: TimeBuffer::new(200),
+            dropped_ticks: 0,
             draw_start: now,
             draw_durations: TimeBuffer::new(200),
             ui_start: now,
             ui_durations: TimeBuffer::new(200),
             debug_start: now,
             debug_durations: TimeBuffer::new(200),
+            gpu_wait_durations: TimeBuffer::new(200),
+            frame_stats: graphics::FrameStats::default(),
+            gpu_stats: graphics::GpuStats::default(),
+            memory_stats: graphics::GpuMemoryStats::default(),
+            sections,
+            timings: Vec::new(),
+            frame_index: 0,
+            capture: None,
+            console,
+            is_console_visible: false,
+            console_scroll: 0,
+            console_filter: LogLevel::Trace,
             text: Vec::new(),
             draw_rate: 10,
             frames_until_refresh: 0,
@@ -81,19 +150,105 @@ impl Debug {
         self.load_duration
     }
 
-    pub(crate) fn frame_started(&mut self) {
-        self.frame_start = time::Instant::now();
+    pub(crate) fn frame_finished(&mut self, timer: &Timer) {
+        self.frame_time_stats = timer.frame_time_stats();
+
+        let mut sections = self.sections.borrow_mut();
+
+        if let Some(capture) = &mut self.capture {
+            for span in sections.captured.drain(..) {
+                capture.record(span);
+            }
+
+            capture.frame_finished();
+        }
+
+        sections.frame_finished();
+        drop(sections);
+
+        self.snapshot_timings();
+        self.frame_index += 1;
     }
-    pub(crate) fn frame_finished(&mut self) {
-        self.frame_durations
-            .push(time::Instant::now() - self.frame_start);
+
+    fn snapshot_timings(&mut self) {
+        let built_in = [
+            ("Interact", &self.interact_durations),
+            ("Update", &self.update_durations),
+            ("Draw", &self.draw_durations),
+            ("UI", &self.ui_durations),
+            ("Debug", &self.debug_durations),
+            ("GPU wait", &self.gpu_wait_durations),
+        ];
+
+        self.timings.clear();
+
+        for (name, durations) in built_in.iter() {
+            self.timings.push(DebugSection {
+                name: String::from(*name),
+                last: durations.last(),
+                average: durations.average(),
+                worst: durations.worst(),
+            });
+        }
+
+        self.timings.push(DebugSection {
+            name: String::from("Frame"),
+            last: self.frame_time_stats.last,
+            average: self.frame_time_stats.average,
+            worst: self.frame_time_stats.worst,
+        });
+
+        for section in self.sections.borrow().sections.iter() {
+            self.timings.push(DebugSection {
+                name: section.label.clone(),
+                last: section.last,
+                average: section.durations.average(),
+                worst: section.durations.worst(),
+            });
+        }
+    }
+
+    /// Returns the timings of every section of the [`Debug`] view: the
+    /// built-in ones first, followed by the custom sections recorded with
+    /// [`time`] and [`Window::debug_time`].
+    ///
+    /// The timings are a snapshot taken when the current frame started, so
+    /// they stay the same during the whole frame. Use them to build your own
+    /// overlay or to export metrics.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    /// [`time`]: #method.time
+    /// [`Window::debug_time`]: graphics/struct.Window.html#method.debug_time
+    pub fn timings(&self) -> &[DebugSection] {
+        &self.timings
+    }
+
+    /// Returns the index of the current frame.
+    ///
+    /// It increases by one every time a frame finishes, when the
+    /// [`timings`] are refreshed.
+    ///
+    /// [`timings`]: #method.timings
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
     }
 
     /// Returns the average time spent per frame.
     ///
     /// It includes time spent on V-Sync, if enabled.
     pub fn frame_duration(&self) -> time::Duration {
-        self.frame_durations.average()
+        self.frame_time_stats.average
+    }
+
+    /// Returns the [`FrameTimeStats`] shown in the debug view.
+    ///
+    /// They are the same as the ones returned by
+    /// [`Timer::frame_time_stats`].
+    ///
+    /// [`FrameTimeStats`]: struct.FrameTimeStats.html
+    /// [`Timer::frame_time_stats`]: struct.Timer.html#method.frame_time_stats
+    pub fn frame_time_stats(&self) -> FrameTimeStats {
+        self.frame_time_stats
     }
 
     pub(crate) fn interact_started(&mut self) {
@@ -101,7 +256,9 @@ impl Debug {
     }
 
     pub(crate) fn interact_finished(&mut self) {
-        self.interact_duration = time::Instant::now() - self.interact_start;
+        self.interact_durations
+            .push(time::Instant::now() - self.interact_start);
+        self.capture_span("Interact", self.interact_start);
     }
 
     /// Returns the average time spent processing events and running
@@ -109,7 +266,7 @@ impl Debug {
     ///
     /// [`Game::interact`]: trait.Game.html#method.interact
     pub fn interact_duration(&self) -> time::Duration {
-        self.interact_duration
+        self.interact_durations.average()
     }
 
     pub(crate) fn update_started(&mut self) {
@@ -119,6 +276,7 @@ impl Debug {
     pub(crate) fn update_finished(&mut self) {
         self.update_durations
             .push(time::Instant::now() - self.update_start);
+        self.capture_span("Update", self.update_start);
     }
 
     /// Returns the average time spent running [`Game::update`].
@@ -128,6 +286,19 @@ impl Debug {
         self.update_durations.average()
     }
 
+    pub(crate) fn ticks_dropped(&mut self, ticks: u64) {
+        self.dropped_ticks += ticks;
+    }
+
+    /// Returns the total amount of ticks dropped because the game fell behind.
+    ///
+    /// Check out [`Game::MAX_TICKS_PER_FRAME`] for more details.
+    ///
+    /// [`Game::MAX_TICKS_PER_FRAME`]: trait.Game.html#associatedconstant.MAX_TICKS_PER_FRAME
+    pub fn dropped_ticks(&self) -> u64 {
+        self.dropped_ticks
+    }
+
     pub(crate) fn draw_started(&mut self) {
         self.draw_start = time::Instant::now();
     }
@@ -138,6 +309,8 @@ impl Debug {
         if duration.subsec_micros() > 0 {
             self.draw_durations.push(duration);
         }
+
+        self.capture_span("Draw", self.draw_start);
     }
 
     /// Returns the average time spent running [`Game::draw`].
@@ -153,6 +326,7 @@ impl Debug {
 
     pub(crate) fn ui_finished(&mut self) {
         self.ui_durations.push(time::Instant::now() - self.ui_start);
+        self.capture_span("UI", self.ui_start);
     }
 
     /// Returns the average time spent rendering the [`UserInterface`].
@@ -163,10 +337,214 @@ impl Debug {
     }
 
     pub(crate) fn toggle(&mut self) {
-        self.enabled = !self.enabled;
+        if self.is_visible() {
+            self.hide();
+        } else {
+            self.show();
+        }
+    }
+
+    /// Shows the [`Debug`] view.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn show(&mut self) {
+        self.update_controls(|controls| controls.is_visible = true);
         self.frames_until_refresh = 0;
     }
 
+    /// Hides the [`Debug`] view.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn hide(&mut self) {
+        self.update_controls(|controls| controls.is_visible = false);
+    }
+
+    /// Returns true if the [`Debug`] view is visible.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn is_visible(&self) -> bool {
+        self.controls.get().is_visible
+    }
+
+    /// Returns the key that toggles the [`Debug`] view, if any.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn toggle_key(&self) -> Option<KeyCode> {
+        self.controls.get().toggle_key
+    }
+
+    /// Sets the key that toggles the [`Debug`] view.
+    ///
+    /// It is [`Game::DEBUG_KEY`] by default. If `None`, the [`Debug`] view
+    /// can only be shown and hidden programmatically.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    /// [`Game::DEBUG_KEY`]: trait.Game.html#associatedconstant.DEBUG_KEY
+    pub fn set_toggle_key(&mut self, toggle_key: Option<KeyCode>) {
+        self.update_controls(|controls| controls.toggle_key = toggle_key);
+    }
+
+    /// Shows a value with the given name in the "Watches" section of the
+    /// [`Debug`] view.
+    ///
+    /// Call it every frame to keep the value up to date. Watches are listed
+    /// in the order they are first seen. A watch that is not updated during
+    /// a frame is grayed out, instead of disappearing, so you can notice
+    /// code paths that run intermittently.
+    ///
+    /// You can also watch values in [`Game::update`] and [`Game::interact`]
+    /// with [`Window::debug_watch`].
+    ///
+    /// [`Debug`]: struct.Debug.html
+    /// [`Game::update`]: trait.Game.html#method.update
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    /// [`Window::debug_watch`]: graphics/struct.Window.html#method.debug_watch
+    pub fn watch(&mut self, name: &str, value: impl fmt::Display) {
+        self.sections.borrow_mut().watch(name, value);
+    }
+
+    /// Logs a message to the console of the [`Debug`] view.
+    ///
+    /// The console keeps the last 500 lines. Lines longer than 200
+    /// characters are truncated.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn log(&mut self, level: LogLevel, message: &str) {
+        if let Ok(mut console) = self.console.lock() {
+            console.push(level, message);
+        }
+    }
+
+    /// Shows the console of the [`Debug`] view.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn show_console(&mut self) {
+        self.is_console_visible = true;
+    }
+
+    /// Hides the console of the [`Debug`] view.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn hide_console(&mut self) {
+        self.is_console_visible = false;
+    }
+
+    /// Sets the most verbose [`LogLevel`] shown in the console.
+    ///
+    /// By default, every message is shown.
+    ///
+    /// [`LogLevel`]: enum.LogLevel.html
+    pub fn set_console_filter(&mut self, level: LogLevel) {
+        self.console_filter = level;
+    }
+
+    pub(crate) fn key_released(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Grave => {
+                self.is_console_visible = !self.is_console_visible;
+            }
+            KeyCode::PageUp if self.is_console_visible => {
+                self.console_scroll += Self::CONSOLE_ROWS / 2;
+            }
+            KeyCode::PageDown if self.is_console_visible => {
+                self.console_scroll =
+                    self.console_scroll.saturating_sub(Self::CONSOLE_ROWS / 2);
+            }
+            KeyCode::F11 if self.is_console_visible => {
+                self.console_filter = more_verbose(self.console_filter);
+                self.console_scroll = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Sets the corner of the screen where the [`Debug`] view is drawn.
+    ///
+    /// It is the top left corner by default.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn set_corner(&mut self, corner: DebugCorner) {
+        self.corner = corner;
+    }
+
+    /// Sets the scale of the [`Debug`] view.
+    ///
+    /// It is the scale factor of the window by default, so the [`Debug`]
+    /// view stays readable on high DPI displays.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.max(0.1);
+    }
+
+    /// Starts capturing the timings of every frame into the file at the given
+    /// path, replacing the current capture, if any.
+    ///
+    /// The file uses the [trace event format], so you can open it with
+    /// `chrome://tracing` in Chromium-based browsers. Every section of every
+    /// frame is recorded as a complete event, including your custom sections
+    /// and the frame itself. The arguments of each event contain the frame
+    /// index and the wall-clock time, in microseconds since the Unix epoch.
+    ///
+    /// The file is written on a background thread. The game loop only pays
+    /// for a few small allocations per frame, and it is never blocked by the
+    /// writer: if more than 256 frames are waiting to be written, new frames
+    /// are dropped instead. The file is flushed after every frame, so it can
+    /// still be opened if your game exits abruptly during a capture.
+    ///
+    /// [trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+    pub fn start_capture<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        self.stop_capture()?;
+
+        self.capture = Some(Capture::start(path.as_ref())?);
+        self.sections.borrow_mut().is_capturing = true;
+
+        Ok(())
+    }
+
+    /// Stops the current capture, if any, and waits until its file has been
+    /// completely written.
+    pub fn stop_capture(&mut self) -> Result<()> {
+        self.sections.borrow_mut().is_capturing = false;
+
+        match self.capture.take() {
+            Some(capture) => Ok(capture.stop()?),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns true if the timings are being captured.
+    pub fn is_capturing(&self) -> bool {
+        self.capture.is_some()
+    }
+
+    /// Returns the amount of frames dropped by the current capture because
+    /// the file could not be written fast enough.
+    pub fn dropped_capture_frames(&self) -> u64 {
+        self.capture
+            .as_ref()
+            .map(Capture::dropped_frames)
+            .unwrap_or(0)
+    }
+
+    fn capture_span(&mut self, name: &'static str, start: time::Instant) {
+        if let Some(capture) = &mut self.capture {
+            capture.record(Span {
+                name: Cow::Borrowed(name),
+                category: "engine",
+                start,
+                duration: time::Instant::now() - start,
+            });
+        }
+    }
+
+    fn update_controls(&mut self, f: impl FnOnce(&mut Controls)) {
+        let mut controls = self.controls.get();
+        f(&mut controls);
+
+        self.controls.set(controls);
+    }
+
     pub(crate) fn debug_started(&mut self) {
         self.debug_start = time::Instant::now();
     }
@@ -174,6 +552,7 @@ impl Debug {
     pub(crate) fn debug_finished(&mut self) {
         self.debug_durations
             .push(time::Instant::now() - self.debug_start);
+        self.capture_span("Debug", self.debug_start);
     }
 
     /// Returns the average time spent running [`Game::debug`].
@@ -183,8 +562,63 @@ impl Debug {
         self.debug_durations.average()
     }
 
-    pub(crate) fn is_enabled(&self) -> bool {
-        self.enabled
+    pub(crate) fn frame_presented(
+        &mut self,
+        stats: graphics::FrameStats,
+        gpu_stats: graphics::GpuStats,
+        memory_stats: graphics::GpuMemoryStats,
+    ) {
+        self.gpu_wait_durations.push(stats.gpu_wait_time);
+        self.frame_stats = stats;
+        self.gpu_stats = gpu_stats;
+        self.memory_stats = memory_stats;
+    }
+
+    /// Returns the average time spent blocked waiting on the GPU.
+    ///
+    /// Check out [`FrameStats::gpu_wait_time`] for more details.
+    ///
+    /// [`FrameStats::gpu_wait_time`]: graphics/struct.FrameStats.html#structfield.gpu_wait_time
+    pub fn gpu_wait_duration(&self) -> time::Duration {
+        self.gpu_wait_durations.average()
+    }
+
+    /// Returns the [`FrameStats`] of the last presented frame.
+    ///
+    /// [`FrameStats`]: graphics/struct.FrameStats.html
+    pub fn frame_stats(&self) -> graphics::FrameStats {
+        self.frame_stats
+    }
+
+    /// Returns the [`GpuStats`] of the last presented frame.
+    ///
+    /// They include the draw calls issued by the [`Debug`] view itself.
+    ///
+    /// [`GpuStats`]: graphics/struct.GpuStats.html
+    /// [`Debug`]: struct.Debug.html
+    pub fn gpu_stats(&self) -> graphics::GpuStats {
+        self.gpu_stats
+    }
+
+    /// Runs the given function and records the time it takes in a custom
+    /// section with the given label.
+    ///
+    /// Custom sections are shown in the debug view after the built-in ones,
+    /// with their average duration and the duration in the last frame. A
+    /// section started inside another one is shown indented below it.
+    ///
+    /// You can also record custom sections in [`Game::update`] and
+    /// [`Game::interact`] with [`Window::debug_time`].
+    ///
+    /// [`Game::update`]: trait.Game.html#method.update
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    /// [`Window::debug_time`]: graphics/struct.Window.html#method.debug_time
+    pub fn time<T>(&mut self, label: &str, f: impl FnOnce() -> T) -> T {
+        self.sections.borrow_mut().begin(label);
+        let value = f();
+        self.sections.borrow_mut().end();
+
+        value
     }
 
     /// Draws the [`Debug`] information.
@@ -204,23 +638,40 @@ impl Debug {
     const MARGIN: f32 = 20.0;
     const ROW_HEIGHT: f32 = 25.0;
     const TITLE_WIDTH: f32 = 150.0;
+    const VALUE_WIDTH: f32 = 250.0;
+    const TEXT_SIZE: f32 = 20.0;
     const SHADOW_OFFSET: f32 = 2.0;
+    const CONSOLE_ROWS: usize = 12;
+    const CONSOLE_WIDTH: f32 = 800.0;
 
     fn refresh_text(&mut self) {
-        let frame_duration = self.frame_durations.average();
+        let frame_duration = self.frame_time_stats.average;
         let frame_micros = (frame_duration.as_secs() as u32 * 1_000_000
             + frame_duration.subsec_micros())
         .max(1);
 
         let fps = (1_000_000.0 / frame_micros as f32).round() as u32;
+        let missed = self.frame_stats.presents_missed.to_string() + " missed";
+        let dropped = if self.dropped_ticks > 0 {
+            Some(self.dropped_ticks.to_string() + " dropped")
+        } else {
+            None
+        };
+
         let rows = [
             ("Load:", self.load_duration, None),
-            ("Interact:", self.interact_duration, None),
-            ("Update:", self.update_duration(), None),
+            ("Interact:", self.interact_duration(), None),
+            ("Update:", self.update_duration(), dropped),
             ("Draw:", self.draw_duration(), None),
             ("UI:", self.ui_duration(), None),
             ("Debug:", self.debug_duration(), None),
+            ("GPU wait:", self.gpu_wait_duration(), Some(missed)),
             ("Frame:", frame_duration, Some(fps.to_string() + " fps")),
+            (
+                "Frame p95:",
+                self.frame_time_stats.p95,
+                Some(format_duration(&self.frame_time_stats.worst) + " worst"),
+            ),
         ];
 
         for (title, duration, extra) in rows.iter() {
@@ -229,58 +680,255 @@ impl Debug {
                 None => format_duration(duration),
             };
 
-            self.text.push((String::from(*title), formatted_duration));
+            self.text.push((
+                String::from(*title),
+                formatted_duration,
+                graphics::Color::WHITE,
+            ));
+        }
+
+        let gpu = &self.gpu_stats;
+
+        self.text.push((
+            String::from("Draw calls:"),
+            format!(
+                "{} ({} quads, {} vertices)",
+                gpu.draw_calls, gpu.quads, gpu.vertices
+            ),
+            graphics::Color::WHITE,
+        ));
+
+        self.text.push((
+            String::from("Uploads:"),
+            format!(
+                "{} KB ({} binds, {} canvases)",
+                gpu.bytes_uploaded / 1024,
+                gpu.texture_binds,
+                gpu.canvas_switches
+            ),
+            graphics::Color::WHITE,
+        ));
+
+        let memory = &self.memory_stats;
+
+        self.text.push((
+            String::from("GPU memory:"),
+            format!(
+                "{} KB in {} textures, {} KB in {} buffers",
+                memory.texture_bytes_estimated / 1024,
+                memory.textures_alive,
+                memory.buffer_bytes / 1024,
+                memory.buffers_alive
+            ),
+            graphics::Color::WHITE,
+        ));
+
+        let sections = self.sections.borrow();
+
+        for section in sections.sections.iter() {
+            let title = "  ".repeat(section.depth) + &section.label + ":";
+
+            self.text.push((
+                title,
+                format_duration(&section.durations.average())
+                    + " ("
+                    + &format_duration(&section.last)
+                    + " last)",
+                graphics::Color::WHITE,
+            ));
+        }
+
+        if !sections.watches.is_empty() {
+            self.text.push((
+                String::from("Watches"),
+                String::new(),
+                graphics::Color::WHITE,
+            ));
+        }
+
+        for watch in sections.watches.iter() {
+            let color = if watch.is_updated || watch.is_fresh {
+                graphics::Color::WHITE
+            } else {
+                graphics::Color::from_rgb(128, 128, 128)
+            };
+
+            self.text.push((
+                String::from("  ") + &watch.name + ":",
+                watch.value.clone(),
+                color,
+            ));
         }
     }
 
     fn draw_text(&mut self, frame: &mut graphics::Frame<'_>) {
-        for (row, (key, value)) in self.text.iter().enumerate() {
-            let y = row as f32 * Self::ROW_HEIGHT;
-
-            self.font.add(graphics::Text {
-                content: key,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::SHADOW_OFFSET,
-                    Self::MARGIN + y + Self::SHADOW_OFFSET,
-                ),
-                size: 20.0,
-                color: graphics::Color::BLACK,
-                ..graphics::Text::default()
-            });
+        let scale = self.scale;
+        let margin = Self::MARGIN * scale;
+        let shadow_offset = Self::SHADOW_OFFSET * scale;
+        let row_height = Self::ROW_HEIGHT * scale;
+        let title_width = Self::TITLE_WIDTH * scale;
+        let width = title_width + Self::VALUE_WIDTH * scale;
+        let height = self.text.len() as f32 * row_height;
 
-            self.font.add(graphics::Text {
-                content: key,
-                position: graphics::Point::new(Self::MARGIN, Self::MARGIN + y),
-                size: 20.0,
-                color: graphics::Color::WHITE,
-                ..graphics::Text::default()
-            });
+        let x = match self.corner {
+            DebugCorner::TopLeft | DebugCorner::BottomLeft => margin,
+            DebugCorner::TopRight | DebugCorner::BottomRight => {
+                frame.width() - margin - width
+            }
+        };
 
-            self.font.add(graphics::Text {
-                content: value,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::TITLE_WIDTH + Self::SHADOW_OFFSET,
-                    Self::MARGIN + y + Self::SHADOW_OFFSET,
-                ),
-                size: 20.0,
-                color: graphics::Color::BLACK,
-                ..graphics::Text::default()
-            });
+        let y = match self.corner {
+            DebugCorner::TopLeft | DebugCorner::TopRight => margin,
+            DebugCorner::BottomLeft | DebugCorner::BottomRight => {
+                frame.height() - margin - height
+            }
+        };
 
-            self.font.add(graphics::Text {
-                content: value,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::TITLE_WIDTH,
-                    Self::MARGIN + y,
-                ),
-                size: 20.0,
-                color: graphics::Color::WHITE,
-                ..graphics::Text::default()
-            });
+        for (row, (key, value, color)) in self.text.iter().enumerate() {
+            let row_y = y + row as f32 * row_height;
+
+            for (content, column_x) in
+                [(key, x), (value, x + title_width)].iter()
+            {
+                add_text(
+                    &mut self.font,
+                    content,
+                    graphics::Point::new(*column_x, row_y),
+                    Self::TEXT_SIZE * scale,
+                    *color,
+                    shadow_offset,
+                );
+            }
+        }
+
+        if self.is_console_visible {
+            self.add_console(frame);
         }
 
         self.font.draw(&mut frame.as_target());
     }
+
+    fn add_console(&mut self, frame: &graphics::Frame<'_>) {
+        let scale = self.scale;
+        let margin = Self::MARGIN * scale;
+        let shadow_offset = Self::SHADOW_OFFSET * scale;
+        let row_height = Self::ROW_HEIGHT * scale;
+        let width = Self::CONSOLE_WIDTH * scale;
+        let height = (Self::CONSOLE_ROWS + 1) as f32 * row_height;
+
+        let x = match self.corner {
+            DebugCorner::TopLeft | DebugCorner::BottomLeft => margin,
+            DebugCorner::TopRight | DebugCorner::BottomRight => {
+                frame.width() - margin - width
+            }
+        };
+
+        // The console is placed on the opposite side of the timings
+        let y = match self.corner {
+            DebugCorner::TopLeft | DebugCorner::TopRight => {
+                frame.height() - margin - height
+            }
+            DebugCorner::BottomLeft | DebugCorner::BottomRight => margin,
+        };
+
+        let console = match self.console.lock() {
+            Ok(console) => console,
+            Err(_) => return,
+        };
+
+        let filter = self.console_filter;
+        let lines: Vec<_> = console
+            .lines()
+            .rev()
+            .filter(|line| line.level <= filter)
+            .collect();
+
+        self.console_scroll = self
+            .console_scroll
+            .min(lines.len().saturating_sub(Self::CONSOLE_ROWS));
+
+        let title = format!(
+            "Console ({:?}) - {} more above",
+            filter,
+            lines
+                .len()
+                .saturating_sub(self.console_scroll + Self::CONSOLE_ROWS)
+        );
+
+        add_text(
+            &mut self.font,
+            &title,
+            graphics::Point::new(x, y),
+            Self::TEXT_SIZE * scale,
+            graphics::Color::WHITE,
+            shadow_offset,
+        );
+
+        let visible = lines
+            .iter()
+            .skip(self.console_scroll)
+            .take(Self::CONSOLE_ROWS)
+            .rev();
+
+        for (row, line) in visible.enumerate() {
+            add_text(
+                &mut self.font,
+                &line.message,
+                graphics::Point::new(x, y + (row + 1) as f32 * row_height),
+                Self::TEXT_SIZE * scale,
+                level_color(line.level),
+                shadow_offset,
+            );
+        }
+    }
+}
+
+fn add_text(
+    font: &mut graphics::Font,
+    content: &str,
+    position: graphics::Point,
+    size: f32,
+    color: graphics::Color,
+    shadow_offset: f32,
+) {
+    font.add(graphics::Text {
+        content,
+        position: graphics::Point::new(
+            position.x + shadow_offset,
+            position.y + shadow_offset,
+        ),
+        size,
+        color: graphics::Color::BLACK,
+        ..graphics::Text::default()
+    });
+
+    font.add(graphics::Text {
+        content,
+        position,
+        size,
+        color,
+        ..graphics::Text::default()
+    });
+}
+
+fn level_color(level: LogLevel) -> graphics::Color {
+    match level {
+        LogLevel::Error => graphics::Color::RED,
+        LogLevel::Warn => graphics::Color::from_rgb(255, 200, 0),
+        LogLevel::Info => graphics::Color::WHITE,
+        LogLevel::Debug => graphics::Color::from_rgb(150, 200, 255),
+        LogLevel::Trace => graphics::Color::from_rgb(160, 160, 160),
+    }
+}
+
+fn more_verbose(level: LogLevel) -> LogLevel {
+    match level {
+        LogLevel::Error => LogLevel::Warn,
+        LogLevel::Warn => LogLevel::Info,
+        LogLevel::Info => LogLevel::Debug,
+        LogLevel::Debug => LogLevel::Trace,
+        LogLevel::Trace => LogLevel::Error,
+    }
 }
 
 fn format_duration(duration: &time::Duration) -> String {
@@ -319,6 +967,119 @@ impl std::fmt::Debug for Debug {
     }
 }
 
+/// Custom sections recorded by the user.
+pub(crate) struct Sections {
+    sections: Vec<Section>,
+    open: Vec<(usize, time::Instant)>,
+    watches: Vec<Watch>,
+    is_capturing: bool,
+    captured: Vec<Span>,
+}
+
+struct Section {
+    label: String,
+    depth: usize,
+    current: time::Duration,
+    last: time::Duration,
+    durations: TimeBuffer,
+}
+
+struct Watch {
+    name: String,
+    value: String,
+    is_updated: bool,
+    is_fresh: bool,
+}
+
+impl Sections {
+    pub fn new() -> Sections {
+        Sections {
+            sections: Vec::new(),
+            open: Vec::new(),
+            watches: Vec::new(),
+            is_capturing: false,
+            captured: Vec::new(),
+        }
+    }
+
+    pub fn begin(&mut self, label: &str) {
+        let depth = self.open.len();
+
+        let index = match self.sections.iter().position(|section| {
+            section.depth == depth && section.label == label
+        }) {
+            Some(index) => index,
+            None => {
+                self.sections.push(Section {
+                    label: String::from(label),
+                    depth,
+                    current: time::Duration::from_secs(0),
+                    last: time::Duration::from_secs(0),
+                    durations: TimeBuffer::new(200),
+                });
+
+                self.sections.len() - 1
+            }
+        };
+
+        self.open.push((index, time::Instant::now()));
+    }
+
+    pub fn end(&mut self) {
+        if let Some((index, start)) = self.open.pop() {
+            let duration = time::Instant::now() - start;
+            let section = &mut self.sections[index];
+
+            section.current += duration;
+
+            if self.is_capturing {
+                self.captured.push(Span {
+                    name: Cow::Owned(section.label.clone()),
+                    category: "user",
+                    start,
+                    duration,
+                });
+            }
+        }
+    }
+
+    pub fn watch(&mut self, name: &str, value: impl fmt::Display) {
+        let index = match self.watches.iter().position(|w| w.name == name) {
+            Some(index) => index,
+            None => {
+                self.watches.push(Watch {
+                    name: String::from(name),
+                    value: String::new(),
+                    is_updated: false,
+                    is_fresh: false,
+                });
+
+                self.watches.len() - 1
+            }
+        };
+
+        let watch = &mut self.watches[index];
+        watch.value.clear();
+        let _ = write!(watch.value, "{}", value);
+        watch.is_updated = true;
+    }
+
+    fn frame_finished(&mut self) {
+        for watch in self.watches.iter_mut() {
+            watch.is_fresh = watch.is_updated;
+            watch.is_updated = false;
+        }
+
+        // Sections that did not run in this frame are recorded as 0, so the
+        // layout of the debug view stays stable
+        for section in self.sections.iter_mut() {
+            section.durations.push(section.current);
+            section.last = section.current;
+            section.current = time::Duration::from_secs(0);
+        }
+    }
+}
+
 struct TimeBuffer {
     head: usize,
     size: usize,
@@ -349,4 +1110,12 @@ impl TimeBuffer {
 
         sum / self.size.max(1) as u32
     }
+
+    fn last(&self) -> time::Duration {
+        self.contents[self.head]
+    }
+
+    fn worst(&self) -> time::Duration {
+        self.contents.iter().max().cloned().unwrap_or_default()
+    }
 }
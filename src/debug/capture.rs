@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The amount of frames that can wait to be written before new frames are
+/// dropped.
+const QUEUE_SIZE: usize = 256;
+
+/// A timed section of a frame.
+pub struct Span {
+    pub name: Cow<'static, str>,
+    pub category: &'static str,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+struct Frame {
+    index: u64,
+    spans: Vec<Span>,
+}
+
+/// A capture of debug timings in the trace event format.
+///
+/// Spans are buffered during a frame and sent to a writer thread once the
+/// frame finishes. The file is flushed after every frame and the JSON array
+/// is only closed when the capture stops, which the trace event format
+/// allows. Thus, the file stays readable if the game exits abruptly.
+pub struct Capture {
+    frame: u64,
+    frame_started: Instant,
+    spans: Vec<Span>,
+    dropped_frames: u64,
+    sender: Option<mpsc::SyncSender<Frame>>,
+    writer: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl Capture {
+    pub fn start(path: &Path) -> io::Result<Capture> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(b"[")?;
+        file.flush()?;
+
+        let started = Instant::now();
+        let started_at = SystemTime::now();
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_SIZE);
+
+        let writer = thread::Builder::new()
+            .name(String::from("coffee-debug-capture"))
+            .spawn(move || write_frames(file, receiver, started, started_at))?;
+
+        Ok(Capture {
+            frame: 0,
+            frame_started: started,
+            spans: Vec::new(),
+            dropped_frames: 0,
+            sender: Some(sender),
+            writer: Some(writer),
+        })
+    }
+
+    pub fn record(&mut self, span: Span) {
+        self.spans.push(span);
+    }
+
+    pub fn frame_finished(&mut self) {
+        let now = Instant::now();
+
+        self.spans.push(Span {
+            name: Cow::Borrowed("Frame"),
+            category: "engine",
+            start: self.frame_started,
+            duration: now - self.frame_started,
+        });
+
+        let frame = Frame {
+            index: self.frame,
+            spans: std::mem::replace(&mut self.spans, Vec::new()),
+        };
+
+        if let Some(sender) = &self.sender {
+            // We never block the game loop on the writer
+            if sender.try_send(frame).is_err() {
+                self.dropped_frames += 1;
+            }
+        }
+
+        self.frame += 1;
+        self.frame_started = now;
+    }
+
+    pub fn dropped_frames(&self) -> u64 {
+        self.dropped_frames
+    }
+
+    pub fn stop(mut self) -> io::Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        // Closing the channel lets the writer finish the file
+        drop(self.sender.take());
+
+        match self.writer.take() {
+            Some(writer) => writer.join().unwrap_or_else(|_| {
+                Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "the capture writer panicked",
+                ))
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+fn write_frames(
+    mut file: BufWriter<File>,
+    receiver: mpsc::Receiver<Frame>,
+    started: Instant,
+    started_at: SystemTime,
+) -> io::Result<()> {
+    let unix_start = started_at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros();
+
+    let mut is_first = true;
+
+    for frame in receiver.iter() {
+        for span in frame.spans {
+            let timestamp = span
+                .start
+                .checked_duration_since(started)
+                .unwrap_or_default()
+                .as_micros();
+
+            write!(
+                file,
+                "{}\n{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\
+                 \"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1,\
+                 \"args\":{{\"frame\":{},\"unix_time_us\":{}}}}}",
+                if is_first { "" } else { "," },
+                escape(&span.name),
+                span.category,
+                timestamp,
+                span.duration.as_micros(),
+                frame.index,
+                unix_start + timestamp,
+            )?;
+
+            is_first = false;
+        }
+
+        file.flush()?;
+    }
+
+    file.write_all(b"\n]\n")?;
+    file.flush()
+}
+
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+
+    for c in name.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
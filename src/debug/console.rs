@@ -0,0 +1,112 @@
+use super::LogLevel;
+
+use std::collections::VecDeque;
+
+#[cfg(feature = "log")]
+use std::sync::{Arc, Mutex};
+
+/// The maximum amount of lines kept in the console.
+const CAPACITY: usize = 500;
+
+/// The maximum amount of characters of a line. Longer lines are truncated.
+const MAX_LINE_LENGTH: usize = 200;
+
+/// A line of the console.
+pub struct Line {
+    pub level: LogLevel,
+    pub message: String,
+}
+
+/// The lines logged to the debug view.
+///
+/// Old lines are discarded once the console is full, so its memory usage is
+/// capped.
+pub struct Console {
+    lines: VecDeque<Line>,
+}
+
+impl Console {
+    pub fn new() -> Console {
+        Console {
+            lines: VecDeque::with_capacity(CAPACITY),
+        }
+    }
+
+    pub fn push(&mut self, level: LogLevel, message: &str) {
+        for line in message.lines() {
+            if self.lines.len() == CAPACITY {
+                let _ = self.lines.pop_front();
+            }
+
+            let message = match line.char_indices().nth(MAX_LINE_LENGTH) {
+                Some((end, _)) => String::from(&line[..end]) + "…",
+                None => String::from(line),
+            };
+
+            self.lines.push_back(Line { level, message });
+        }
+    }
+
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &Line> {
+        self.lines.iter()
+    }
+}
+
+/// A `log` backend that writes records to a [`Console`].
+///
+/// [`Console`]: struct.Console.html
+#[cfg(feature = "log")]
+pub struct Logger {
+    console: Arc<Mutex<Console>>,
+}
+
+#[cfg(feature = "log")]
+impl Logger {
+    /// Installs a [`Logger`] writing to the given [`Console`] as the global
+    /// logger, unless another logger is installed already.
+    ///
+    /// If the logger installed by [`init_logging`] is in use, the [`Console`]
+    /// subscribes to its records instead.
+    ///
+    /// [`Logger`]: struct.Logger.html
+    /// [`Console`]: struct.Console.html
+    /// [`init_logging`]: ../fn.init_logging.html
+    pub fn install(console: Arc<Mutex<Console>>) {
+        let logger = Box::new(Logger { console });
+
+        if let Err(logger) = crate::logging::subscribe(logger) {
+            if log::set_boxed_logger(logger).is_ok() {
+                log::set_max_level(log::LevelFilter::Info);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let level = match record.level() {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        };
+
+        let message = format!("[{}] {}", record.target(), record.args());
+
+        if let Ok(mut console) = self.console.lock() {
+            console.push(level, &message);
+        }
+    }
+
+    fn flush(&self) {}
+}
@@ -1,4 +1,9 @@
+use super::{DebugCorner, DebugSection, LogLevel};
 use crate::graphics;
+use crate::input::keyboard::KeyCode;
+use crate::{Result, Timer};
+
+use std::path::Path;
 
 // Null debug implementation
 #[allow(missing_debug_implementations)]
@@ -6,32 +11,130 @@ use crate::graphics;
 pub struct Debug {}
 
 impl Debug {
-    pub(crate) fn new(_gpu: &mut graphics::Gpu) -> Self {
+    pub(crate) fn new(_window: &mut graphics::Window) -> Self {
         Self {}
     }
 
     pub(crate) fn loading_started(&mut self) {}
     pub(crate) fn loading_finished(&mut self) {}
-    pub(crate) fn frame_started(&mut self) {}
-    pub(crate) fn frame_finished(&mut self) {}
+    pub(crate) fn frame_finished(&mut self, _timer: &Timer) {}
     pub(crate) fn interact_started(&mut self) {}
     pub(crate) fn interact_finished(&mut self) {}
     pub(crate) fn update_started(&mut self) {}
     pub(crate) fn update_finished(&mut self) {}
+    pub(crate) fn ticks_dropped(&mut self, _ticks: u64) {}
     pub(crate) fn draw_started(&mut self) {}
     pub(crate) fn draw_finished(&mut self) {}
     pub(crate) fn ui_started(&mut self) {}
     pub(crate) fn ui_finished(&mut self) {}
     pub(crate) fn debug_started(&mut self) {}
     pub(crate) fn debug_finished(&mut self) {}
+    pub(crate) fn frame_presented(
+        &mut self,
+        _stats: graphics::FrameStats,
+        _gpu_stats: graphics::GpuStats,
+        _memory_stats: graphics::GpuMemoryStats,
+    ) {
+    }
 
     #[allow(dead_code)]
     pub(crate) fn toggle(&mut self) {}
 
-    pub(crate) fn is_enabled(&self) -> bool {
+    #[allow(dead_code)]
+    pub(crate) fn key_released(&mut self, _key_code: KeyCode) {}
+
+    #[allow(missing_docs)]
+    pub fn time<T>(&mut self, _label: &str, f: impl FnOnce() -> T) -> T {
+        f()
+    }
+
+    #[allow(missing_docs)]
+    pub fn timings(&self) -> &[DebugSection] {
+        &[]
+    }
+
+    #[allow(missing_docs)]
+    pub fn frame_index(&self) -> u64 {
+        0
+    }
+
+    #[allow(missing_docs)]
+    pub fn show(&mut self) {}
+
+    #[allow(missing_docs)]
+    pub fn hide(&mut self) {}
+
+    #[allow(missing_docs)]
+    pub fn is_visible(&self) -> bool {
+        false
+    }
+
+    #[allow(missing_docs)]
+    pub fn toggle_key(&self) -> Option<KeyCode> {
+        None
+    }
+
+    #[allow(missing_docs)]
+    pub fn set_toggle_key(&mut self, _toggle_key: Option<KeyCode>) {}
+
+    #[allow(missing_docs)]
+    pub fn watch(&mut self, _name: &str, _value: impl std::fmt::Display) {}
+
+    #[allow(missing_docs)]
+    pub fn log(&mut self, _level: LogLevel, _message: &str) {}
+
+    #[allow(missing_docs)]
+    pub fn show_console(&mut self) {}
+
+    #[allow(missing_docs)]
+    pub fn hide_console(&mut self) {}
+
+    #[allow(missing_docs)]
+    pub fn set_console_filter(&mut self, _level: LogLevel) {}
+
+    #[allow(missing_docs)]
+    pub fn set_corner(&mut self, _corner: DebugCorner) {}
+
+    #[allow(missing_docs)]
+    pub fn set_scale(&mut self, _scale: f32) {}
+
+    #[allow(missing_docs)]
+    pub fn start_capture<P: AsRef<Path>>(&mut self, _path: P) -> Result<()> {
+        Ok(())
+    }
+
+    #[allow(missing_docs)]
+    pub fn stop_capture(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    #[allow(missing_docs)]
+    pub fn is_capturing(&self) -> bool {
         false
     }
 
+    #[allow(missing_docs)]
+    pub fn dropped_capture_frames(&self) -> u64 {
+        0
+    }
+
+    #[allow(missing_docs)]
+    pub fn gpu_stats(&self) -> graphics::GpuStats {
+        graphics::GpuStats::default()
+    }
+
     #[allow(missing_docs)]
     pub fn draw(&mut self, _frame: &mut graphics::Frame<'_>) {}
 }
+
+pub(crate) struct Sections {}
+
+impl Sections {
+    pub fn new() -> Sections {
+        Sections {}
+    }
+
+    pub fn begin(&mut self, _label: &str) {}
+    pub fn watch(&mut self, _name: &str, _value: impl std::fmt::Display) {}
+    pub fn end(&mut self) {}
+}
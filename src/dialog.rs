@@ -0,0 +1,225 @@
+//! Show native dialogs to open and save files.
+//!
+//! This module is only available when the `dialogs` feature is enabled.
+//!
+//! A [`FileDialog`] produces a [`Job`], which shows the dialog on another
+//! thread or process. Spawn it with a [`Handle`] to keep your game running
+//! and pumping events while the dialog is open:
+//!
+//! ```no_run
+//! use coffee::dialog::FileDialog;
+//! use coffee::graphics::Window;
+//! use coffee::load::Handle;
+//! use std::path::PathBuf;
+//! use std::task::Poll;
+//!
+//! struct Editor {
+//!     open_dialog: Option<Handle<Option<PathBuf>>>,
+//! }
+//!
+//! impl Editor {
+//!     fn open(&mut self) {
+//!         let dialog = FileDialog::new()
+//!             .title("Open level")
+//!             .filter("Levels", &["ron", "json"]);
+//!
+//!         self.open_dialog = Some(Handle::spawn(vec![dialog.open()]));
+//!     }
+//!
+//!     fn interact(&mut self, window: &mut Window) {
+//!         if let Some(dialog) = &mut self.open_dialog {
+//!             if let Poll::Ready(result) = dialog.poll(window.gpu()) {
+//!                 if let Ok(paths) = result {
+//!                     if let Some(Some(path)) = paths.into_iter().next() {
+//!                         // Load the level...
+//!                     }
+//!                 }
+//!
+//!                 self.open_dialog = None;
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Cancelling a dialog produces `None`, or an empty `Vec` when opening
+//! multiple files, never an error.
+//!
+//! [`FileDialog`]: struct.FileDialog.html
+//! [`Job`]: ../load/struct.Job.html
+//! [`Handle`]: ../load/struct.Handle.html
+use std::path::PathBuf;
+
+use crate::load::Job;
+
+/// A native dialog to open or save files.
+///
+/// Check out the [module documentation] to learn how to show it.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileDialog {
+    title: String,
+    default_path: String,
+    filters: Vec<(String, Vec<String>)>,
+}
+
+impl FileDialog {
+    /// Creates a new [`FileDialog`] without any filters.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn new() -> FileDialog {
+        FileDialog::default()
+    }
+
+    /// Sets the title of the [`FileDialog`].
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn title(mut self, title: &str) -> FileDialog {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Sets the path the [`FileDialog`] starts at.
+    ///
+    /// When saving, a file name can be used to suggest a name for the new
+    /// file.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn default_path(mut self, path: &str) -> FileDialog {
+        self.default_path = String::from(path);
+        self
+    }
+
+    /// Adds a filter to the [`FileDialog`], showing only the files with the
+    /// given extensions.
+    ///
+    /// ```
+    /// # use coffee::dialog::FileDialog;
+    /// let dialog = FileDialog::new().filter("Images", &["png", "jpg"]);
+    /// ```
+    ///
+    /// Not every platform supports choosing between filters, so all the
+    /// filters of a [`FileDialog`] may be combined into one.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn filter(mut self, name: &str, extensions: &[&str]) -> FileDialog {
+        self.filters.push((
+            String::from(name),
+            extensions
+                .iter()
+                .map(|extension| {
+                    format!("*.{}", extension.trim_start_matches('.'))
+                })
+                .collect(),
+        ));
+
+        self
+    }
+
+    /// Creates a [`Job`] that shows the [`FileDialog`] to open a file.
+    ///
+    /// It produces the chosen file, or `None` if the dialog is cancelled.
+    ///
+    /// [`Job`]: ../load/struct.Job.html
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn open(&self) -> Job<Option<PathBuf>> {
+        let dialog = self.clone();
+
+        Job::new(
+            move || {
+                let (patterns, description) = dialog.filter_patterns();
+
+                Ok(tinyfiledialogs::open_file_dialog(
+                    &dialog.title,
+                    &dialog.default_path,
+                    as_filter(&patterns, &description),
+                )
+                .map(PathBuf::from))
+            },
+            |_gpu, path| Ok(path),
+        )
+    }
+
+    /// Creates a [`Job`] that shows the [`FileDialog`] to open many files.
+    ///
+    /// It produces the chosen files, which are none if the dialog is
+    /// cancelled.
+    ///
+    /// [`Job`]: ../load/struct.Job.html
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn open_multiple(&self) -> Job<Vec<PathBuf>> {
+        let dialog = self.clone();
+
+        Job::new(
+            move || {
+                let (patterns, description) = dialog.filter_patterns();
+
+                Ok(tinyfiledialogs::open_file_dialog_multi(
+                    &dialog.title,
+                    &dialog.default_path,
+                    as_filter(&patterns, &description),
+                )
+                .unwrap_or_default()
+                .into_iter()
+                .map(PathBuf::from)
+                .collect())
+            },
+            |_gpu, paths| Ok(paths),
+        )
+    }
+
+    /// Creates a [`Job`] that shows the [`FileDialog`] to save a file.
+    ///
+    /// It produces the chosen file, or `None` if the dialog is cancelled.
+    ///
+    /// [`Job`]: ../load/struct.Job.html
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn save(&self) -> Job<Option<PathBuf>> {
+        let dialog = self.clone();
+
+        Job::new(
+            move || {
+                let (patterns, description) = dialog.filter_patterns();
+
+                Ok(tinyfiledialogs::save_file_dialog_with_filter(
+                    &dialog.title,
+                    &dialog.default_path,
+                    &patterns,
+                    &description,
+                )
+                .map(PathBuf::from))
+            },
+            |_gpu, path| Ok(path),
+        )
+    }
+
+    // Combines every filter into one, which is all most platforms support
+    fn filter_patterns(&self) -> (Vec<&str>, String) {
+        let patterns = self
+            .filters
+            .iter()
+            .flat_map(|(_, patterns)| patterns.iter().map(String::as_str))
+            .collect();
+
+        let description = self
+            .filters
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        (patterns, description)
+    }
+}
+
+fn as_filter<'a>(
+    patterns: &'a [&'a str],
+    description: &'a str,
+) -> Option<(&'a [&'a str], &'a str)> {
+    if patterns.is_empty() {
+        None
+    } else {
+        Some((patterns, description))
+    }
+}
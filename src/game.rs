@@ -1,11 +1,15 @@
+mod error_screen;
+pub(crate) mod guard;
 mod r#loop;
+pub(crate) mod step;
 
-pub(crate) use r#loop::Loop;
+pub(crate) use r#loop::{KeyboardState, Loop};
 
+use crate::bench;
 use crate::graphics::{CursorIcon, Frame, Window, WindowSettings};
 use crate::input::{keyboard, Input};
 use crate::load::{LoadingScreen, Task};
-use crate::{Debug, Result, Timer};
+use crate::{BenchReport, Debug, Result, Timer};
 
 /// The entrypoint of the engine. It describes your game logic.
 ///
@@ -51,12 +55,104 @@ pub trait Game {
     /// [`update`]: #method.update
     const TICKS_PER_SECOND: u16 = 60;
 
+    /// Defines how many times the [`slow_update`] function should be called
+    /// per second.
+    ///
+    /// Slow ticks are scheduled using regular ticks: [`slow_update`] is called
+    /// right after every n-th call to [`update`], where n is
+    /// [`TICKS_PER_SECOND`] divided by this value, rounded. Therefore, slow
+    /// ticks stay deterministic relative to regular ticks, even when ticks
+    /// are dropped.
+    ///
+    /// By default, it is set to `0`, which disables [`slow_update`].
+    ///
+    /// [`slow_update`]: #method.slow_update
+    /// [`update`]: #method.update
+    /// [`TICKS_PER_SECOND`]: #associatedconstant.TICKS_PER_SECOND
+    const SLOW_TICKS_PER_SECOND: u16 = 0;
+
+    /// Defines the maximum amount of times the [`Game`] can be updated in a
+    /// single frame.
+    ///
+    /// When an update or a frame takes too long, the game loop updates the
+    /// [`Game`] multiple times in the next frame to catch up. If it falls
+    /// behind more than this amount of ticks, the remaining ticks are dropped
+    /// and counted in [`Timer::dropped_ticks`]. This stops a slow update from
+    /// making the game loop fall behind forever.
+    ///
+    /// By default, it is set to `10`. It is always at least `1`.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Timer::dropped_ticks`]: struct.Timer.html#method.dropped_ticks
+    const MAX_TICKS_PER_FRAME: u16 = 10;
+
+    /// Defines whether the [`Game`] should pause when its window loses focus.
+    ///
+    /// While paused, [`interact`] and [`update`] are not called and the
+    /// [`Game`] is drawn at a reduced rate. The paused period is not caught up
+    /// when the window regains focus.
+    ///
+    /// By default, it is set to `false`. If you want custom behavior, like
+    /// pausing the gameplay while the music keeps playing, you can track the
+    /// focus events of the window in your [`Input`] instead.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`interact`]: #method.interact
+    /// [`update`]: #method.update
+    /// [`Input`]: #associatedtype.Input
+    const PAUSE_WHEN_UNFOCUSED: bool = false;
+
+    /// Defines whether the [`Game`] should pause while its window has no
+    /// area.
+    ///
+    /// The [`Game`] is never drawn while its window is minimized or resized
+    /// to zero width or height. By default, it is set to `false`, which means
+    /// that [`interact`] and [`update`] keep being called at the usual rate.
+    /// Otherwise, the [`Game`] pauses until the window grows again.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`interact`]: #method.interact
+    /// [`update`]: #method.update
+    const PAUSE_WHEN_MINIMIZED: bool = false;
+
+    /// Defines whether an error screen should be shown when [`load`] fails.
+    ///
+    /// The error screen describes the error and keeps the window open until
+    /// the player closes it or presses Escape. Then, the error is returned by
+    /// [`run`]. Otherwise, the window closes as soon as [`load`] fails, and
+    /// the error is only visible if the game was launched from a terminal.
+    ///
+    /// By default, it is set to `true`.
+    ///
+    /// [`load`]: #tymethod.load
+    /// [`run`]: #method.run
+    const SHOW_LOAD_ERRORS: bool = true;
+
+    /// Defines whether panics in [`interact`], [`update`], [`slow_update`]
+    /// and [`draw`] should be caught to leave the display usable.
+    ///
+    /// When one of these panics, the window leaves fullscreen and the cursor
+    /// is shown and released before the panic keeps unwinding. Otherwise, a
+    /// panic may leave the desktop in an unusable state.
+    ///
+    /// By default, it is set to `true`. Games built with `panic = "abort"`
+    /// cannot be cleaned up, so you may want to set it to `false` in that
+    /// case.
+    ///
+    /// [`interact`]: #method.interact
+    /// [`update`]: #method.update
+    /// [`slow_update`]: #method.slow_update
+    /// [`draw`]: #tymethod.draw
+    const CATCH_PANICS: bool = true;
+
     /// Defines the key that will be used to toggle the [`debug`] view. Set it to
     /// `None` if you want to disable it.
     ///
-    /// By default, it is set to `F12`.
+    /// By default, it is set to `F12`. You can change it while the game runs
+    /// with [`Window::set_debug_toggle_key`].
     ///
     /// [`debug`]: #method.debug
+    /// [`Window::set_debug_toggle_key`]: graphics/struct.Window.html#method.set_debug_toggle_key
     const DEBUG_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F12);
 
     /// Loads the [`Game`].
@@ -76,10 +172,14 @@ pub trait Game {
     ///
     /// This function will be called once per frame.
     ///
+    /// If it returns an error, the game loop ends and the error is returned by
+    /// [`run`].
+    ///
     /// [`Game`]: trait.Game.html
     /// [`graphics`]: graphics/index.html
     /// [`update`]: #method.update
-    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer);
+    /// [`run`]: #method.run
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) -> Result<()>;
 
     /// Consumes [`Input`] to let users interact with the [`Game`].
     ///
@@ -92,10 +192,17 @@ pub trait Game {
     /// to keep your view updated every frame in order to offer a smooth user
     /// experience independently of the [`TICKS_PER_SECOND`] setting.
     ///
+    /// Edges of the [`Input`], like key presses, are only cleared after every
+    /// [`update`]. Therefore, an edge may be seen by a few calls when the frame
+    /// rate is higher than [`TICKS_PER_SECOND`].
+    ///
     /// You can access the [`Window`]. For instance, you may want to toggle
     /// fullscreen mode based on some input, or maybe access the [`Gpu`]
     /// to prepare some assets before rendering.
     ///
+    /// If it returns an error, the game loop ends and the error is returned by
+    /// [`run`].
+    ///
     /// By default, it does nothing.
     ///
     /// [`Input`]: #associatedtype.Input
@@ -104,7 +211,14 @@ pub trait Game {
     /// [`TICKS_PER_SECOND`]: #associatedconstant.TICKS_PER_SECOND
     /// [`Window`]: graphics/struct.Window.html
     /// [`Gpu`]: graphics/struct.Gpu.html
-    fn interact(&mut self, _input: &mut Self::Input, _window: &mut Window) {}
+    /// [`run`]: #method.run
+    fn interact(
+        &mut self,
+        _input: &mut Self::Input,
+        _window: &mut Window,
+    ) -> Result<()> {
+        Ok(())
+    }
 
     /// Updates the [`Game`].
     ///
@@ -116,13 +230,44 @@ pub trait Game {
     ///
     /// Notice that you are also allowed to access [`Window`] data. This can be
     /// useful if your [`Game`] needs to know how much of the world is visible.
+    /// The [`Window`] also tells you the duration of a tick and the elapsed
+    /// game time, which are derived from the amount of updates and are
+    /// therefore deterministic. Avoid reading the wall clock here if you want
+    /// your updates to be reproducible; check out the [`headless`] module to
+    /// learn more.
+    ///
+    /// If it returns an error, the game loop ends and the error is returned by
+    /// [`run`].
     ///
     /// By default, it does nothing.
     ///
     /// [`Game`]: trait.Game.html
     /// [`TICKS_PER_SECOND`]: #associatedconstant.TICKS_PER_SECOND
     /// [`Window`]: graphics/struct.Window.html
-    fn update(&mut self, _window: &Window) {}
+    /// [`headless`]: headless/index.html#determinism
+    /// [`run`]: #method.run
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        Ok(())
+    }
+
+    /// Updates the slow parts of the [`Game`], like pathfinding or autosaves.
+    ///
+    /// The [`SLOW_TICKS_PER_SECOND`] constant defines how many times this
+    /// function will be called per second. It is always called right after
+    /// the [`update`] that completes a slow tick period.
+    ///
+    /// If it returns an error, the game loop ends and the error is returned by
+    /// [`run`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`SLOW_TICKS_PER_SECOND`]: #associatedconstant.SLOW_TICKS_PER_SECOND
+    /// [`update`]: #method.update
+    /// [`run`]: #method.run
+    fn slow_update(&mut self, _window: &Window) -> Result<()> {
+        Ok(())
+    }
 
     /// Defines the cursor icon of the window.
     ///
@@ -167,22 +312,153 @@ pub trait Game {
     /// Returns whether the game is finished or not.
     ///
     /// If this function returns true, the game will be closed gracefully.
+    /// Alternatively, you can use [`Window::close`].
     ///
     /// By default, it always returns false.
+    ///
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
     fn is_finished(&self) -> bool {
         false
     }
 
+    /// Handles the suspension of the game.
+    ///
+    /// This function is called when the application is sent to the
+    /// background and loses its graphics context, like when a mobile app is
+    /// minimized. While suspended, the [`Game`] is neither updated nor drawn.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    fn on_suspend(&mut self) {}
+
+    /// Handles the resumption of the game after a suspension.
+    ///
+    /// When this function is called, the swapchain of the [`Window`] has
+    /// already been recreated. However, the contents of any [`Canvas`] may
+    /// have been lost while suspended. Use [`Canvas::is_valid`] to find out
+    /// and draw them again here.
+    ///
+    /// The suspended period is not caught up by [`update`].
+    ///
+    /// If it returns an error, the game loop ends and the error is returned by
+    /// [`run`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Window`]: graphics/struct.Window.html
+    /// [`Canvas`]: graphics/struct.Canvas.html
+    /// [`Canvas::is_valid`]: graphics/struct.Canvas.html#method.is_valid
+    /// [`update`]: #method.update
+    /// [`run`]: #method.run
+    fn on_resume(&mut self, _window: &mut Window) -> Result<()> {
+        Ok(())
+    }
+
+    /// Handles the end of the game.
+    ///
+    /// This function is called once the game loop ends, right before the
+    /// [`Game`] is dropped. The game loop can end because [`is_finished`]
+    /// returned true, [`Window::close`] was called, or a close request was
+    /// accepted by [`on_close_request`].
+    ///
+    /// It is not called when the game loop ends because of an error or a
+    /// panic, as the state of the [`Game`] may be inconsistent.
+    ///
+    /// You can use it to save the state of your game, for instance.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`is_finished`]: #method.is_finished
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
+    /// [`on_close_request`]: #method.on_close_request
+    fn on_exit(&mut self) {}
+
+    /// Handles a panic.
+    ///
+    /// This function is called as soon as the thread running the [`Game`]
+    /// panics, before the panic message is printed. You can use it to write a
+    /// crash report, for instance. Panics in other threads are not reported.
+    ///
+    /// Keep in mind that it runs before the window is restored by
+    /// [`CATCH_PANICS`] and that the game state may be inconsistent, which is
+    /// why it does not have access to it.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`CATCH_PANICS`]: #associatedconstant.CATCH_PANICS
+    fn on_panic(_info: &std::panic::PanicInfo<'_>) {}
+
     /// Runs the [`Game`] with the given [`WindowSettings`].
     ///
     /// You probably want to call this in your `main` function to run your game!
     ///
+    /// It returns an error if the [`Game`] fails to load, if [`interact`],
+    /// [`update`] or [`draw`] fail, or if the graphics device is lost. When
+    /// the [`Game`] fails while running, the window leaves fullscreen mode and
+    /// shows the cursor before the error is returned.
+    ///
     /// [`Game`]: trait.Game.html
     /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`interact`]: #method.interact
+    /// [`update`]: #method.update
+    /// [`draw`]: #tymethod.draw
     fn run(window_settings: WindowSettings) -> Result<()>
+    where
+        Self: 'static + Sized,
+    {
+        <r#loop::Default as Loop<Self>>::run(window_settings).map(|_| ())
+    }
+
+    /// Runs the [`Game`] with the given [`WindowSettings`], like [`run`],
+    /// and returns it once the game loop ends.
+    ///
+    /// This is useful to build tools on top of Coffee. For instance, a level
+    /// editor can be closed with [`Window::close`] and then return the edited
+    /// level to the caller.
+    ///
+    /// The window and the graphics device are destroyed before returning.
+    /// Any graphics resources held by the [`Game`], like an [`Image`], stay
+    /// valid but become inert: there is nothing left to draw them on. They
+    /// can be safely dropped at any time.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`run`]: #method.run
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
+    /// [`Image`]: graphics/struct.Image.html
+    fn run_returning(window_settings: WindowSettings) -> Result<Self>
     where
         Self: 'static + Sized,
     {
         <r#loop::Default as Loop<Self>>::run(window_settings)
     }
+
+    /// Runs the [`Game`] until the given amount of frames has been rendered,
+    /// as fast as possible, and returns a [`BenchReport`].
+    ///
+    /// V-Sync is disabled and the loading screen is skipped, so the results
+    /// only depend on the performance of your [`Game`] and the engine. Use it
+    /// to detect performance regressions!
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`BenchReport`]: struct.BenchReport.html
+    fn run_bench(
+        window_settings: WindowSettings,
+        frames: u32,
+    ) -> Result<BenchReport>
+    where
+        Self: 'static + Sized,
+    {
+        let mut recorder = bench::Recorder::new(frames);
+
+        <r#loop::Default as Loop<Self>>::run_with(
+            window_settings,
+            Some(&mut recorder),
+        )?;
+
+        Ok(recorder.into_report())
+    }
 }
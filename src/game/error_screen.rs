@@ -0,0 +1,118 @@
+use crate::graphics::window::winit;
+use crate::graphics::{Color, Font, Point, Text, Window};
+use crate::{Error, Result};
+
+/// Shows the given loading error in the window until the player closes it or
+/// presses Escape.
+pub fn show(
+    error: &Error,
+    window: &mut Window,
+    event_loop: &mut winit::event_loop::EventLoop<()>,
+) {
+    use winit::platform::desktop::EventLoopExtDesktop;
+
+    let mut font = match Font::from_bytes(window.gpu(), Font::DEFAULT) {
+        Ok(font) => font,
+        Err(_) => return,
+    };
+
+    let description = describe(error);
+
+    window.restore();
+    window.request_redraw();
+
+    event_loop.run_return(|event, _, control_flow| {
+        *control_flow = winit::event_loop::ControlFlow::Wait;
+
+        match event {
+            winit::event::Event::WindowEvent { event, .. } => match event {
+                winit::event::WindowEvent::CloseRequested
+                | winit::event::WindowEvent::KeyboardInput {
+                    input:
+                        winit::event::KeyboardInput {
+                            virtual_keycode:
+                                Some(winit::event::VirtualKeyCode::Escape),
+                            ..
+                        },
+                    ..
+                } => {
+                    *control_flow = winit::event_loop::ControlFlow::Exit;
+                }
+                winit::event::WindowEvent::Resized(size) => {
+                    window.resize(size);
+                    window.request_redraw();
+                }
+                _ => {}
+            },
+            winit::event::Event::RedrawRequested { .. } => {
+                // The game has already failed, there is nothing else to report
+                let _ = draw(&mut font, &description, window)
+                    .and_then(|_| window.swap_buffers());
+            }
+            _ => {}
+        }
+    });
+}
+
+fn draw(font: &mut Font, description: &str, window: &mut Window) -> Result<()> {
+    let mut frame = window.frame()?;
+    let (width, height) = (frame.width(), frame.height());
+
+    frame.clear(Color::new(0.15, 0.05, 0.05, 1.0));
+
+    font.add(Text {
+        content: "The game failed to load",
+        position: Point::new(40.0, 40.0),
+        size: 30.0,
+        color: Color::WHITE,
+        ..Text::default()
+    });
+
+    font.add(Text {
+        content: description,
+        position: Point::new(40.0, 100.0),
+        bounds: (width - 80.0, height - 200.0),
+        size: 20.0,
+        color: Color::new(1.0, 0.8, 0.8, 1.0),
+        ..Text::default()
+    });
+
+    font.add(Text {
+        content: "Press Escape to exit",
+        position: Point::new(40.0, height - 60.0),
+        size: 20.0,
+        color: Color::WHITE,
+        ..Text::default()
+    });
+
+    font.draw(&mut frame.as_target());
+
+    Ok(())
+}
+
+fn describe(error: &Error) -> String {
+    let mut lines = Vec::new();
+    let mut current = error;
+
+    // Each level of context is indented one more level
+    loop {
+        let indentation = "  ".repeat(lines.len());
+
+        match current {
+            Error::Stage { title, error } => {
+                lines.push(format!("{}{}", indentation, title));
+                current = error.as_ref();
+            }
+            Error::Asset { path, error } => {
+                lines.push(format!("{}{}", indentation, path.display()));
+                current = error.as_ref();
+            }
+            error => {
+                lines.push(format!("{}{}", indentation, error));
+                break;
+            }
+        }
+    }
+
+    lines.join("\n")
+}
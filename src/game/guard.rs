@@ -0,0 +1,95 @@
+use crate::graphics::Window;
+
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe, PanicInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+type Callback = fn(&PanicInfo<'_>);
+
+static INSTALL: Once = Once::new();
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // The `Game::on_panic` callbacks of the games running in this thread
+    static CALLBACKS: RefCell<Vec<(usize, Callback)>> = RefCell::new(Vec::new());
+}
+
+/// Registers [`Game::on_panic`] to be called when the current thread panics.
+///
+/// A single panic hook is installed for the whole program the first time,
+/// chained with the previous one. It calls the callback of the last game
+/// registered in the panicking thread, if any. The callback is unregistered
+/// when the [`Hook`] is dropped.
+///
+/// [`Game::on_panic`]: ../trait.Game.html#method.on_panic
+/// [`Hook`]: struct.Hook.html
+pub struct Hook {
+    id: usize,
+}
+
+impl Hook {
+    pub fn install<G: super::Game>() -> Hook {
+        INSTALL.call_once(|| {
+            let previous = panic::take_hook();
+
+            panic::set_hook(Box::new(move |info| {
+                let callback = CALLBACKS
+                    .try_with(|callbacks| {
+                        callbacks
+                            .try_borrow()
+                            .ok()
+                            .and_then(|callbacks| callbacks.last().copied())
+                    })
+                    .ok()
+                    .and_then(|callback| callback);
+
+                if let Some((_, callback)) = callback {
+                    callback(info);
+                }
+
+                previous(info);
+            }));
+        });
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+        CALLBACKS.with(|callbacks| {
+            callbacks.borrow_mut().push((id, G::on_panic as Callback))
+        });
+
+        Hook { id }
+    }
+}
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        let id = self.id;
+
+        // The thread-local storage may be gone if the thread is exiting
+        let _ = CALLBACKS.try_with(|callbacks| {
+            callbacks.borrow_mut().retain(|(other, _)| *other != id)
+        });
+    }
+}
+
+/// Runs a callback of the game, leaving the window usable if it panics.
+///
+/// The panic keeps unwinding afterwards.
+pub fn run<G: super::Game, T>(
+    window: &mut Window,
+    callback: impl FnOnce(&mut Window) -> T,
+) -> T {
+    if !G::CATCH_PANICS {
+        return callback(window);
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(|| callback(&mut *window))) {
+        Ok(value) => value,
+        Err(payload) => {
+            window.restore();
+
+            panic::resume_unwind(payload)
+        }
+    }
+}
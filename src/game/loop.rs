@@ -1,10 +1,18 @@
+use crate::bench;
 use crate::debug::Debug;
 use crate::graphics::window::winit;
 use crate::graphics::{Window, WindowSettings};
-use crate::input::{self, gamepad, keyboard, mouse, window, Input};
+use crate::input::{self, keyboard, mouse, window, Input};
 use crate::load::{Join, LoadingScreen, Task};
-use crate::{Result, Timer};
+use crate::{Error, Result, Timer};
+
+use super::{error_screen, guard, step};
+
+use std::collections::HashSet;
 use std::convert::TryInto;
+use std::time::{Duration, Instant};
+
+const PAUSED_FRAME_INTERVAL: Duration = Duration::from_millis(100);
 
 pub trait Loop<Game: super::Game> {
     type Attributes;
@@ -17,7 +25,12 @@ pub trait Loop<Game: super::Game> {
 
     fn load(window: &Window) -> Task<Self::Attributes>;
 
-    fn on_input(&mut self, input: &mut Game::Input, event: input::Event) {
+    fn on_input(
+        &mut self,
+        _game: &mut Game,
+        input: &mut Game::Input,
+        event: input::Event,
+    ) {
         input.update(event);
     }
 
@@ -27,96 +40,238 @@ pub trait Loop<Game: super::Game> {
         _input: &mut Game::Input,
         _window: &mut Window,
         _debug: &mut Debug,
-    ) {
+    ) -> Result<()> {
+        Ok(())
     }
 
-    fn run(window_settings: WindowSettings) -> Result<()>
+    fn run(window_settings: WindowSettings) -> Result<Game>
     where
         Self: 'static + Sized,
         Game: 'static,
         Game::Input: 'static,
     {
+        Self::run_with(window_settings, None)
+    }
+
+    fn run_with(
+        window_settings: WindowSettings,
+        mut bench: Option<&mut bench::Recorder>,
+    ) -> Result<Game>
+    where
+        Self: 'static + Sized,
+        Game: 'static,
+        Game::Input: 'static,
+    {
+        use winit::platform::desktop::EventLoopExtDesktop;
+
+        let _hook = guard::Hook::install::<Game>();
+
         // Window creation
-        let event_loop = winit::event_loop::EventLoop::new();
-        let mut window = Window::new(window_settings, &event_loop)?;
-        let mut debug = Debug::new(window.gpu());
+        let mut event_loop = winit::event_loop::EventLoop::new();
+        let mut window = Window::with_attributes(
+            window_settings,
+            true,
+            bench.is_none(),
+            &event_loop,
+        )?;
+        let mut debug = Debug::new(&mut window);
+        debug.set_toggle_key(Game::DEBUG_KEY);
 
         // Loading
+        let load_started = Instant::now();
         debug.loading_started();
-        let (mut game, configuration) = {
-            let mut loading_screen = Game::LoadingScreen::new(window.gpu())?;
+        let task = (Game::load(&window), Self::load(&window)).join();
+
+        let loaded = if bench.is_some() {
+            // Benchmarks skip the loading screen and its minimum duration
+            task.run(window.gpu())
+        } else {
+            Game::LoadingScreen::new(window.gpu()).and_then(
+                |mut loading_screen| loading_screen.run(task, &mut window),
+            )
+        };
 
-            loading_screen.run(
-                (Game::load(&window), Self::load(&window)).join(),
-                &mut window,
-            )?
+        let (mut game, configuration) = match loaded {
+            Ok(loaded) => loaded,
+            Err(error) => {
+                if Game::SHOW_LOAD_ERRORS && bench.is_none() {
+                    error_screen::show(&error, &mut window, &mut event_loop);
+                }
+
+                return Err(error);
+            }
         };
 
+        if let Some(bench) = &mut bench {
+            bench.loading_finished(load_started.elapsed());
+        }
+
         let mut game_loop = Self::new(configuration, &mut game, &mut window);
         let mut input = Game::Input::new();
-        let mut gamepads = gamepad::Tracker::new();
+        let mut events = step::Events::default();
+        let mut is_suspended = false;
         debug.loading_finished();
 
         let mut timer = Timer::new(Game::TICKS_PER_SECOND);
 
         // Initialization
-        debug.frame_started();
         timer.update();
 
-        event_loop.run(move |event, _, control_flow| match event {
+        let mut result = Ok(());
+        let outcome = &mut result;
+
+        event_loop.run_return(|event, _, control_flow| match event {
             winit::event::Event::NewEvents(_) => {
                 debug.interact_started();
             }
             winit::event::Event::MainEventsCleared => {
-                if let Some(tracker) = &mut gamepads {
-                    while let Some((id, event, time)) = tracker.next_event() {
-                        game_loop.on_input(
+                events.deliver(&mut window, |event| {
+                    game_loop.on_input(&mut game, &mut input, event)
+                });
+
+                let is_idle = is_suspended || events.is_idle::<Game>(&window);
+
+                if is_idle {
+                    input.clear();
+                    input.clear_edges();
+                    debug.interact_finished();
+
+                    *control_flow = winit::event_loop::ControlFlow::WaitUntil(
+                        Instant::now() + PAUSED_FRAME_INTERVAL,
+                    );
+                } else {
+                    if let Some(bench) = &mut bench {
+                        bench.section_started();
+                    }
+
+                    let interaction =
+                        step::interact(&mut game, &mut input, &mut window);
+                    debug.interact_finished();
+
+                    if let Some(bench) = &mut bench {
+                        bench.interact_finished();
+                        bench.section_started();
+                    }
+
+                    let update = interaction.and_then(|_| {
+                        step::update(
+                            &mut game,
                             &mut input,
-                            input::Event::Gamepad { id, event, time },
-                        );
+                            &mut window,
+                            &mut timer,
+                            Some(&mut debug),
+                        )
+                    });
+
+                    match update {
+                        Ok(ticks) => {
+                            if let Some(bench) = &mut bench {
+                                bench.update_finished(u64::from(ticks));
+                            }
+                        }
+                        Err(error) => {
+                            fail(error, outcome, &mut window, control_flow);
+                            return;
+                        }
                     }
                 }
 
-                game.interact(&mut input, &mut window);
-                input.clear();
-                debug.interact_finished();
+                if window.is_minimized() {
+                    // Nothing can be drawn, so we wait for the next tick
+                    // instead of redrawing
+                    if is_idle {
+                        timer.skip();
+                    } else {
+                        timer.update();
 
-                if timer.tick() {
-                    debug.update_started();
-                    game.update(&window);
-                    debug.update_finished();
+                        *control_flow =
+                            winit::event_loop::ControlFlow::WaitUntil(
+                                Instant::now() + timer.tick_duration(),
+                            );
+                    }
+                } else if !is_suspended {
+                    window.request_redraw();
                 }
 
-                window.request_redraw();
-
-                if game.is_finished() {
+                if game.is_finished() || window.is_closed() {
                     *control_flow = winit::event_loop::ControlFlow::Exit;
                 }
             }
-            winit::event::Event::RedrawRequested { .. } => {
+            winit::event::Event::RedrawRequested { .. } if !is_suspended => {
+                if window.is_minimized() {
+                    return;
+                }
+
+                if let Some(bench) = &mut bench {
+                    bench.section_started();
+                }
+
                 debug.draw_started();
-                game.draw(&mut window.frame(), &timer);
+                let drawing = guard::run::<Game, _>(&mut window, |window| {
+                    game.draw(&mut window.frame()?, &timer)
+                });
                 debug.draw_finished();
 
-                game_loop.after_draw(
+                if let Some(bench) = &mut bench {
+                    bench.draw_finished();
+                }
+
+                if let Err(error) = drawing {
+                    fail(error, outcome, &mut window, control_flow);
+                    return;
+                }
+
+                let after_drawing = game_loop.after_draw(
                     &mut game,
                     &mut input,
                     &mut window,
                     &mut debug,
                 );
 
-                if debug.is_enabled() {
-                    debug.debug_started();
-                    game.debug(&input, &mut window.frame(), &mut debug);
-                    debug.debug_finished();
+                if let Err(error) = after_drawing {
+                    fail(error, outcome, &mut window, control_flow);
+                    return;
+                }
+
+                let debugging = step::draw_debug(
+                    &mut game,
+                    &input,
+                    &mut window,
+                    &mut debug,
+                );
+
+                if let Err(error) = debugging {
+                    fail(error, outcome, &mut window, control_flow);
+                    return;
                 }
 
-                window.swap_buffers();
-                debug.frame_finished();
+                if let Some(bench) = &mut bench {
+                    bench.section_started();
+                }
 
-                debug.frame_started();
-                window.request_redraw();
-                timer.update();
+                if let Err(error) = step::present(&mut window, &mut debug) {
+                    fail(error, outcome, &mut window, control_flow);
+                    return;
+                }
+
+                if let Some(bench) = &mut bench {
+                    bench.present_finished();
+                    bench.frame_finished();
+
+                    if bench.is_finished() {
+                        *control_flow = winit::event_loop::ControlFlow::Exit;
+                    }
+                }
+
+                if events.is_paused() {
+                    // The paused period must not be caught up on resume
+                    timer.skip();
+                } else {
+                    window.request_redraw();
+                    timer.update();
+                }
+
+                debug.frame_finished(&timer);
             }
             winit::event::Event::WindowEvent { event, .. } => match event {
                 winit::event::WindowEvent::CloseRequested => {
@@ -126,90 +281,233 @@ pub trait Loop<Game: super::Game> {
                 }
                 winit::event::WindowEvent::Resized(logical_size) => {
                     window.resize(logical_size);
+
+                    if !window.is_minimized() {
+                        *control_flow = winit::event_loop::ControlFlow::Poll;
+                    }
                 }
                 _ => {
-                    match event {
-                        winit::event::WindowEvent::KeyboardInput {
-                            input:
-                                winit::event::KeyboardInput {
-                                    virtual_keycode,
-                                    state: winit::event::ElementState::Released,
-                                    ..
-                                },
-                            ..
-                        } if Game::DEBUG_KEY.is_some() => {
-                            if virtual_keycode == Game::DEBUG_KEY {
-                                debug.toggle();
-                            }
-                        }
-                        _ => {}
-                    }
+                    let is_resumed = events.handle::<Game>(
+                        event,
+                        &mut debug,
+                        bench.is_none(),
+                    );
 
-                    if let Some(input_event) = try_into_input_event(event) {
-                        game_loop.on_input(&mut input, input_event);
+                    if is_resumed {
+                        *control_flow = winit::event_loop::ControlFlow::Poll;
                     }
                 }
             },
+            winit::event::Event::Suspended => {
+                is_suspended = true;
+                game.on_suspend();
+            }
+            winit::event::Event::Resumed if is_suspended => {
+                is_suspended = false;
+                window.resume();
+
+                // The suspended period must not be caught up
+                timer.skip();
+
+                if let Err(error) = game.on_resume(&mut window) {
+                    fail(error, outcome, &mut window, control_flow);
+                    return;
+                }
+            }
+            winit::event::Event::LoopDestroyed => {
+                // A game that failed may be in an inconsistent state
+                if outcome.is_ok() {
+                    game.on_exit();
+                }
+            }
             _ => {}
         });
+
+        result.map(|()| game)
+    }
+}
+
+fn fail(
+    error: Error,
+    result: &mut Result<()>,
+    window: &mut Window,
+    control_flow: &mut winit::event_loop::ControlFlow,
+) {
+    // Leave the desktop usable before reporting the error
+    window.restore();
+
+    *result = Err(error);
+    *control_flow = winit::event_loop::ControlFlow::Exit;
+}
+
+#[derive(Default)]
+pub(crate) struct KeyboardState {
+    modifiers: keyboard::Modifiers,
+    pressed_keys: HashSet<keyboard::ScanCode>,
+}
+
+impl KeyboardState {
+    /// Turns a window event into input events, if any.
+    pub fn process(
+        &mut self,
+        event: winit::event::WindowEvent<'_>,
+        time: Instant,
+        events: &mut Vec<input::Event>,
+    ) {
+        match event {
+            winit::event::WindowEvent::Focused(_) => {
+                // Keys may have changed while unfocused
+                *self = KeyboardState::default();
+
+                events.push(input::Event::Keyboard {
+                    event: keyboard::Event::ModifiersChanged {
+                        modifiers: self.modifiers,
+                    },
+                    time,
+                });
+            }
+            winit::event::WindowEvent::ModifiersChanged(state) => {
+                self.modifiers = keyboard::Modifiers {
+                    shift: state.shift(),
+                    ctrl: state.ctrl(),
+                    alt: state.alt(),
+                    logo: state.logo(),
+                };
+            }
+            _ => {}
+        }
+
+        if let Some(input_event) = try_into_input_event(event, time, self) {
+            events.push(keyboard::translate(input_event));
+        }
     }
 }
 
 fn try_into_input_event(
     event: winit::event::WindowEvent<'_>,
+    time: Instant,
+    keyboard_state: &mut KeyboardState,
 ) -> Option<input::Event> {
+    let modifiers = keyboard_state.modifiers;
+
     match event {
         winit::event::WindowEvent::KeyboardInput {
             input:
                 winit::event::KeyboardInput {
                     state,
-                    virtual_keycode: Some(key_code),
+                    virtual_keycode: key_code,
+                    scancode: scan_code,
                     ..
                 },
             ..
-        } => Some(input::Event::Keyboard(keyboard::Event::Input {
-            state,
-            key_code,
-        })),
+        } => {
+            let repeat = match state {
+                input::ButtonState::Pressed => {
+                    !keyboard_state.pressed_keys.insert(scan_code)
+                }
+                input::ButtonState::Released => {
+                    let _ = keyboard_state.pressed_keys.remove(&scan_code);
+                    false
+                }
+            };
+
+            Some(input::Event::Keyboard {
+                event: keyboard::Event::Input {
+                    state,
+                    key_code,
+                    scan_code,
+                    modifiers,
+                    repeat,
+                },
+                time,
+            })
+        }
         winit::event::WindowEvent::ReceivedCharacter(codepoint) => {
-            Some(input::Event::Keyboard(keyboard::Event::TextEntered {
-                character: codepoint,
-            }))
+            if modifiers.ctrl || modifiers.logo || codepoint.is_control() {
+                None
+            } else {
+                Some(input::Event::Keyboard {
+                    event: keyboard::Event::TextEntered {
+                        character: codepoint,
+                    },
+                    time,
+                })
+            }
+        }
+        winit::event::WindowEvent::ModifiersChanged(_) => {
+            Some(input::Event::Keyboard {
+                event: keyboard::Event::ModifiersChanged { modifiers },
+                time,
+            })
         }
         winit::event::WindowEvent::MouseInput { state, button, .. } => {
-            Some(input::Event::Mouse(mouse::Event::Input { state, button }))
+            Some(input::Event::Mouse {
+                event: mouse::Event::Input {
+                    state,
+                    button,
+                    modifiers,
+                },
+                time,
+            })
+        }
+        winit::event::WindowEvent::MouseWheel { delta, .. } => {
+            let delta = match delta {
+                winit::event::MouseScrollDelta::LineDelta(x, y) => {
+                    mouse::ScrollDelta::Lines { x, y }
+                }
+                winit::event::MouseScrollDelta::PixelDelta(position) => {
+                    mouse::ScrollDelta::Pixels {
+                        x: position.x as f32,
+                        y: position.y as f32,
+                    }
+                }
+            };
+
+            Some(input::Event::Mouse {
+                event: mouse::Event::WheelScrolled { delta },
+                time,
+            })
         }
-        winit::event::WindowEvent::MouseWheel { delta, .. } => match delta {
-            winit::event::MouseScrollDelta::LineDelta(x, y) => {
-                Some(input::Event::Mouse(mouse::Event::WheelScrolled {
-                    delta_x: x,
-                    delta_y: y,
-                }))
-            }
-            _ => None,
-        },
         winit::event::WindowEvent::CursorMoved { position, .. } => {
-            Some(input::Event::Mouse(mouse::Event::CursorMoved {
-                x: position.x as f32,
-                y: position.y as f32,
-            }))
+            Some(input::Event::Mouse {
+                event: mouse::Event::CursorMoved {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                },
+                time,
+            })
         }
         winit::event::WindowEvent::CursorEntered { .. } => {
-            Some(input::Event::Mouse(mouse::Event::CursorEntered))
+            Some(input::Event::Mouse {
+                event: mouse::Event::CursorEntered,
+                time,
+            })
         }
         winit::event::WindowEvent::CursorLeft { .. } => {
-            Some(input::Event::Mouse(mouse::Event::CursorLeft))
+            Some(input::Event::Mouse {
+                event: mouse::Event::CursorLeft,
+                time,
+            })
         }
         winit::event::WindowEvent::Focused(focus) => Some(if focus == true {
-            input::Event::Window(window::Event::Focused)
+            input::Event::Window {
+                event: window::Event::Focused,
+                time,
+            }
         } else {
-            input::Event::Window(window::Event::Unfocused)
+            input::Event::Window {
+                event: window::Event::Unfocused,
+                time,
+            }
         }),
         winit::event::WindowEvent::Moved(position) => {
-            Some(input::Event::Window(window::Event::Moved {
-                x: position.x as f32,
-                y: position.y as f32,
-            }))
+            Some(input::Event::Window {
+                event: window::Event::Moved {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                },
+                time,
+            })
         }
         _ => None,
     }
@@ -241,7 +539,9 @@ where
         _input: &mut Game::Input,
         window: &mut Window,
         _debug: &mut Debug,
-    ) {
+    ) -> Result<()> {
         window.update_cursor(game.cursor_icon().try_into().ok());
+
+        Ok(())
     }
 }
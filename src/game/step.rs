@@ -0,0 +1,242 @@
+//! The steps of a frame, shared by every way of running a [`Game`]: the game
+//! loop, a [`runner::Runner`], and a [`headless::Runner`].
+//!
+//! [`Game`]: ../trait.Game.html
+//! [`runner::Runner`]: ../../runner/struct.Runner.html
+//! [`headless::Runner`]: ../../headless/struct.Runner.html
+use super::{guard, Game, KeyboardState};
+use crate::debug::Debug;
+use crate::graphics::window::winit;
+use crate::graphics::Window;
+use crate::input::{self, Input};
+use crate::{Result, Timer};
+
+use std::time::Instant;
+
+/// The input events of a window waiting to be delivered to a [`Game`].
+///
+/// [`Game`]: ../trait.Game.html
+#[derive(Default)]
+pub(crate) struct Events {
+    queue: Vec<input::Event>,
+    keyboard_state: KeyboardState,
+    is_paused: bool,
+}
+
+impl Events {
+    /// Queues the input events produced by a window event.
+    ///
+    /// Close requests and resizes must be handled by the caller. Returns true
+    /// if the window regained focus and the [`Game`] is not paused anymore.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn handle<G: Game>(
+        &mut self,
+        event: winit::event::WindowEvent<'_>,
+        debug: &mut Debug,
+        can_pause: bool,
+    ) -> bool {
+        let time = Instant::now();
+        let mut is_resumed = false;
+
+        if let winit::event::WindowEvent::KeyboardInput {
+            input:
+                winit::event::KeyboardInput {
+                    virtual_keycode: Some(key_code),
+                    state: winit::event::ElementState::Released,
+                    ..
+                },
+            ..
+        } = event
+        {
+            if Some(key_code) == debug.toggle_key() {
+                debug.toggle();
+            } else if debug.is_visible() {
+                debug.key_released(key_code);
+            }
+        }
+
+        if let winit::event::WindowEvent::Focused(focused) = event {
+            if G::PAUSE_WHEN_UNFOCUSED && can_pause {
+                is_resumed = self.is_paused && focused;
+                self.is_paused = !focused;
+            }
+        }
+
+        self.keyboard_state.process(event, time, &mut self.queue);
+
+        is_resumed
+    }
+
+    /// Returns true if the [`Game`] is paused because its window is not
+    /// focused.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Returns true if the [`Game`] should not be updated.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn is_idle<G: Game>(&self, window: &Window) -> bool {
+        self.is_paused || (G::PAUSE_WHEN_MINIMIZED && window.is_minimized())
+    }
+
+    /// Delivers the queued events, together with gamepad and virtual cursor
+    /// events, in chronological order.
+    pub fn deliver<F>(&mut self, window: &mut Window, mut on_input: F)
+    where
+        F: FnMut(input::Event),
+    {
+        if let Some(tracker) = window.gamepads() {
+            while let Some((id, event, time)) = tracker.next_event() {
+                self.queue.push(input::Event::Gamepad { id, event, time });
+            }
+        }
+
+        // Gamepad events are polled, so we sort all the events to deliver
+        // them in chronological order
+        self.queue.sort_by_key(input::Event::time);
+
+        for event in self.queue.drain(..) {
+            on_input(event);
+
+            if let Some(synthetic_event) = window
+                .virtual_cursor_mut()
+                .and_then(|cursor| cursor.process(event))
+            {
+                on_input(synthetic_event);
+            }
+        }
+
+        let (width, height) = (window.width(), window.height());
+
+        if let Some(synthetic_event) = window
+            .virtual_cursor_mut()
+            .and_then(|cursor| cursor.tick(width, height))
+        {
+            on_input(synthetic_event);
+        }
+    }
+}
+
+/// Lets the [`Game`] interact with its [`Input`], and clears it.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Input`]: ../input/trait.Input.html
+pub(crate) fn interact<G: Game>(
+    game: &mut G,
+    input: &mut G::Input,
+    window: &mut Window,
+) -> Result<()> {
+    let interaction =
+        guard::run::<G, _>(window, |window| game.interact(input, window));
+    input.clear();
+
+    interaction
+}
+
+/// Updates the [`Game`] once and clears the edges of its [`Input`]. The tick
+/// must have been counted by the [`Timer`] already.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Input`]: ../input/trait.Input.html
+/// [`Timer`]: ../struct.Timer.html
+pub(crate) fn tick<G: Game>(
+    game: &mut G,
+    input: &mut G::Input,
+    window: &mut Window,
+    timer: &Timer,
+) -> Result<()> {
+    window.sync_ticks(timer);
+
+    let mut result = guard::run::<G, _>(window, |window| game.update(window));
+
+    if result.is_ok() && timer.is_slow_tick(G::SLOW_TICKS_PER_SECOND) {
+        result = guard::run::<G, _>(window, |window| game.slow_update(window));
+    }
+
+    input.clear_edges();
+
+    result
+}
+
+/// Updates the [`Game`] as many times as the [`Timer`] needs to catch up, up
+/// to [`Game::MAX_TICKS_PER_FRAME`]. Any remaining backlog is dropped.
+///
+/// Returns the amount of ticks run. The [`Debug`] view, if any, records their
+/// timings.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Timer`]: ../struct.Timer.html
+/// [`Debug`]: ../struct.Debug.html
+/// [`Game::MAX_TICKS_PER_FRAME`]: ../trait.Game.html#associatedconstant.MAX_TICKS_PER_FRAME
+pub(crate) fn update<G: Game>(
+    game: &mut G,
+    input: &mut G::Input,
+    window: &mut Window,
+    timer: &mut Timer,
+    mut debug: Option<&mut Debug>,
+) -> Result<u16> {
+    let max_ticks = G::MAX_TICKS_PER_FRAME.max(1);
+    let mut ticks = 0;
+
+    while ticks < max_ticks && timer.tick() {
+        if let Some(debug) = debug.as_mut() {
+            debug.update_started();
+        }
+
+        let result = tick(game, input, window, timer);
+
+        if let Some(debug) = debug.as_mut() {
+            debug.update_finished();
+        }
+
+        result?;
+        ticks += 1;
+    }
+
+    if ticks == max_ticks {
+        let dropped = timer.drop_backlog();
+
+        if let Some(debug) = debug {
+            debug.ticks_dropped(dropped);
+        }
+    }
+
+    Ok(ticks)
+}
+
+/// Draws the [`Debug`] view on top of the current frame, if it is visible.
+///
+/// [`Debug`]: ../struct.Debug.html
+pub(crate) fn draw_debug<G: Game>(
+    game: &mut G,
+    input: &G::Input,
+    window: &mut Window,
+    debug: &mut Debug,
+) -> Result<()> {
+    if debug.is_visible() {
+        let mut frame = window.frame()?;
+
+        debug.debug_started();
+        game.debug(input, &mut frame, debug);
+        debug.debug_finished();
+    }
+
+    Ok(())
+}
+
+/// Presents the current frame and records its statistics.
+pub(crate) fn present(window: &mut Window, debug: &mut Debug) -> Result<()> {
+    window.swap_buffers()?;
+
+    debug.frame_presented(
+        window.frame_stats(),
+        window.gpu_stats(),
+        window.gpu().memory_stats(),
+    );
+
+    Ok(())
+}
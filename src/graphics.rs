@@ -40,8 +40,7 @@
 //!
 //! ```
 //! use coffee::graphics::{Color, Frame, Window};
-//! use coffee::{Game, Timer};
-//! # use coffee::Result;
+//! use coffee::{Game, Result, Timer};
 //! # use coffee::graphics::Gpu;
 //! # use coffee::load::Task;
 //! #
@@ -57,11 +56,13 @@
 //! #
 //!     // ...
 //!
-//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
 //!         frame.clear(Color::BLACK);
 //!
 //!         // Use your resources here...
 //!         // self.image.draw(Sprite { ... }, &mut frame.as_target());
+//!
+//!         Ok(())
 //!     }
 //! }
 //! ```
@@ -105,10 +106,14 @@ mod backend_wgpu;
 ))]
 use backend_wgpu as gpu;
 
+mod adapter;
 mod batch;
 mod canvas;
 mod color;
+mod coordinates;
 mod font;
+mod gpu_memory;
+mod gpu_stats;
 mod image;
 mod mesh;
 mod point;
@@ -125,11 +130,18 @@ pub mod texture_array;
 pub(crate) mod window;
 
 pub use self::image::Image;
+pub use adapter::{
+    AdapterInfo, Backend, DeviceType, GpuSettings, PowerPreference,
+    BACKEND_VARIABLE,
+};
 pub use batch::Batch;
 pub use canvas::Canvas;
 pub use color::Color;
+pub use coordinates::Coordinates;
 pub use font::Font;
 pub use gpu::Gpu;
+pub use gpu_memory::GpuMemoryStats;
+pub use gpu_stats::GpuStats;
 pub use mesh::Mesh;
 pub use point::Point;
 pub use quad::{IntoQuad, Quad};
@@ -137,8 +149,13 @@ pub use rectangle::Rectangle;
 pub use shape::Shape;
 pub use sprite::Sprite;
 pub use target::Target;
-pub use text::{HorizontalAlignment, Text, VerticalAlignment};
+pub use text::{
+    FontStyle, HorizontalAlignment, RichText, Text, TextSpan, VerticalAlignment,
+};
 pub use texture_array::TextureArray;
 pub use transformation::Transformation;
 pub use vector::Vector;
-pub use window::{CursorIcon, Frame, Settings as WindowSettings, Window};
+pub use window::{
+    Clipboard, CursorIcon, Frame, FrameStats, Settings as WindowSettings,
+    Window,
+};
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use crate::{Error, Result};
+
+/// The name of the environment variable that overrides the [`Backend`] of
+/// the [`Gpu`].
+///
+/// It lets players debug black screens or broken drivers without changing
+/// the game. For instance, `COFFEE_BACKEND=vulkan`. It takes precedence over
+/// [`GpuSettings::backend_override`].
+///
+/// [`Backend`]: enum.Backend.html
+/// [`Gpu`]: struct.Gpu.html
+/// [`GpuSettings::backend_override`]: struct.GpuSettings.html#structfield.backend_override
+pub const BACKEND_VARIABLE: &str = "COFFEE_BACKEND";
+
+/// The graphics adapter configuration of a window.
+///
+/// [`Backend`] and [`PowerPreference`] selection is only supported by the
+/// `vulkan`, `metal`, `dx11`, and `dx12` features. The `opengl` feature uses
+/// the adapter chosen by the driver, and it fails if it does not match the
+/// settings.
+///
+/// [`Backend`]: enum.Backend.html
+/// [`PowerPreference`]: enum.PowerPreference.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GpuSettings {
+    /// Whether a low power or a high performance adapter is preferred.
+    pub power_preference: PowerPreference,
+
+    /// The [`Backend`] that must be used, if any.
+    ///
+    /// [`Backend`]: enum.Backend.html
+    pub backend_override: Option<Backend>,
+
+    /// A part of the name of the adapter that must be used, if any. The
+    /// comparison ignores case.
+    pub adapter_name_filter: Option<String>,
+}
+
+impl GpuSettings {
+    // Returns the backend override, taking the environment into account
+    pub(crate) fn backend(&self) -> Result<Option<Backend>> {
+        match std::env::var(BACKEND_VARIABLE) {
+            Ok(value) => Backend::parse(&value).map(Some).ok_or_else(|| {
+                Error::AdapterSelection {
+                    reason: format!(
+                        "{} has an unknown value \"{}\", expected one of \
+                         vulkan, metal, dx12, dx11, or gl",
+                        BACKEND_VARIABLE, value
+                    ),
+                    adapters: Vec::new(),
+                }
+            }),
+            Err(_) => Ok(self.backend_override),
+        }
+    }
+
+    // Returns whether the adapter with the given name matches the filter
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        self.adapter_name_filter.as_ref().map_or(true, |filter| {
+            name.to_lowercase().contains(&filter.to_lowercase())
+        })
+    }
+}
+
+/// The kind of graphics adapter preferred by a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerPreference {
+    /// An adapter that saves power, like an integrated GPU.
+    LowPower,
+
+    /// A fast adapter, like a discrete GPU.
+    HighPerformance,
+}
+
+impl Default for PowerPreference {
+    fn default() -> PowerPreference {
+        PowerPreference::HighPerformance
+    }
+}
+
+/// A graphics API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Vulkan.
+    Vulkan,
+
+    /// Metal.
+    Metal,
+
+    /// Direct3D 12.
+    Dx12,
+
+    /// Direct3D 11.
+    Dx11,
+
+    /// OpenGL.
+    Gl,
+}
+
+impl Backend {
+    fn parse(name: &str) -> Option<Backend> {
+        match name.trim().to_lowercase().as_str() {
+            "vulkan" | "vk" => Some(Backend::Vulkan),
+            "metal" => Some(Backend::Metal),
+            "dx12" | "d3d12" => Some(Backend::Dx12),
+            "dx11" | "d3d11" => Some(Backend::Dx11),
+            "gl" | "opengl" => Some(Backend::Gl),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Backend::Vulkan => "Vulkan",
+            Backend::Metal => "Metal",
+            Backend::Dx12 => "Direct3D 12",
+            Backend::Dx11 => "Direct3D 11",
+            Backend::Gl => "OpenGL",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// The kind of a graphics adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceType {
+    /// A GPU integrated with the CPU.
+    IntegratedGpu,
+
+    /// A dedicated GPU.
+    DiscreteGpu,
+
+    /// A GPU in a virtualized environment.
+    VirtualGpu,
+
+    /// A software renderer running on the CPU.
+    Cpu,
+
+    /// An unknown kind of adapter.
+    Other,
+}
+
+/// Information about a graphics adapter.
+///
+/// You can obtain the one in use with [`Gpu::adapter_info`].
+///
+/// [`Gpu::adapter_info`]: struct.Gpu.html#method.adapter_info
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterInfo {
+    /// The name of the adapter.
+    pub name: String,
+
+    /// The [`Backend`] used to talk to the adapter.
+    ///
+    /// [`Backend`]: enum.Backend.html
+    pub backend: Backend,
+
+    /// The kind of the adapter.
+    pub device_type: DeviceType,
+}
+
+impl fmt::Display for AdapterInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {:?})",
+            self.name, self.backend, self.device_type
+        )
+    }
+}
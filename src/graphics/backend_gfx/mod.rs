@@ -16,8 +16,13 @@ pub use types::TargetView;
 use gfx::{self, Device};
 use gfx_device_gl as gl;
 
-use crate::graphics::{Color, Transformation};
-use crate::Result;
+use crate::graphics::gpu_memory::MemoryTracker;
+use crate::graphics::{
+    AdapterInfo, Backend, Color, DeviceType, GpuMemoryStats, GpuSettings,
+    GpuStats, Transformation,
+};
+use crate::load::Cache;
+use crate::{Error, Result};
 
 /// A link between your game and a graphics processor.
 ///
@@ -36,15 +41,49 @@ pub struct Gpu {
     encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer>,
     triangle_pipeline: triangle::Pipeline,
     quad_pipeline: quad::Pipeline,
+    generation: u64,
+    adapter_info: AdapterInfo,
+    memory: MemoryTracker,
+    stats: GpuStats,
+    cache: Cache,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
+        vsync: bool,
+        settings: &GpuSettings,
         events_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<(Gpu, Surface)> {
+        let backend = settings.backend()?;
+
         let (surface, device, mut factory) =
-            Surface::new(builder, events_loop)?;
+            Surface::new(builder, vsync, events_loop)?;
+
+        // The driver chooses the adapter, so we can only check it
+        let adapter_info = AdapterInfo {
+            name: String::from(device.get_info().platform_name.renderer),
+            backend: Backend::Gl,
+            device_type: DeviceType::Other,
+        };
+
+        let reason = match backend {
+            Some(backend) if backend != Backend::Gl => Some(format!(
+                "the {} backend is not supported by the `opengl` feature",
+                backend
+            )),
+            _ if !settings.matches(&adapter_info.name) => {
+                Some(String::from("no adapter matched the settings"))
+            }
+            _ => None,
+        };
+
+        if let Some(reason) = reason {
+            return Err(Error::AdapterSelection {
+                reason,
+                adapters: vec![adapter_info],
+            });
+        }
 
         let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
             factory.create_command_buffer().into();
@@ -65,11 +104,52 @@ impl Gpu {
                 encoder,
                 triangle_pipeline,
                 quad_pipeline,
+                generation: 0,
+                adapter_info,
+                memory: MemoryTracker::default(),
+                stats: GpuStats::default(),
+                cache: Cache::default(),
             },
             surface,
         ))
     }
 
+    pub(super) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub(super) fn lose_contents(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Returns information about the graphics adapter used by the [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn adapter_info(&self) -> AdapterInfo {
+        self.adapter_info.clone()
+    }
+
+    /// Returns the [`GpuMemoryStats`] of the [`Gpu`].
+    ///
+    /// [`GpuMemoryStats`]: struct.GpuMemoryStats.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn memory_stats(&self) -> GpuMemoryStats {
+        let mut stats = self.memory.stats();
+
+        self.quad_pipeline.count_buffers(&mut stats);
+        self.triangle_pipeline.count_buffers(&mut stats);
+
+        stats
+    }
+
+    /// Returns the asset [`Cache`] of the [`Gpu`].
+    ///
+    /// [`Cache`]: ../load/struct.Cache.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn cache(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+
     pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
         let typed_render_target: gfx::handle::RenderTargetView<
             gl::Resources,
@@ -80,6 +160,17 @@ impl Gpu {
             .clear(&typed_render_target, color.into_linear())
     }
 
+    pub(super) fn stats_mut(&mut self) -> &mut GpuStats {
+        &mut self.stats
+    }
+
+    pub(super) fn take_stats(&mut self) -> GpuStats {
+        self.memory.next_frame();
+        self.cache.prune();
+
+        std::mem::replace(&mut self.stats, GpuStats::default())
+    }
+
     fn flush(&mut self) {
         self.encoder.flush(&mut self.device);
     }
@@ -92,14 +183,36 @@ impl Gpu {
         &mut self,
         image: &image::DynamicImage,
     ) -> Texture {
-        Texture::new(&mut self.factory, image)
+        Texture::new(&mut self.factory, image).track(&mut self.memory)
+    }
+
+    pub(super) fn create_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+    ) -> Texture {
+        Texture::new_empty(&mut self.factory, width, height)
+            .track(&mut self.memory)
+    }
+
+    pub(super) fn texture_pixels(image: &image::DynamicImage) -> Vec<u8> {
+        Texture::pixels(image)
+    }
+
+    pub(super) fn upload_texture_rows(
+        &mut self,
+        texture: &Texture,
+        y: u16,
+        pixels: &[u8],
+    ) {
+        texture.upload_rows(&mut self.encoder, y, pixels)
     }
 
     pub(super) fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
     ) -> Texture {
-        Texture::new_array(&mut self.factory, layers)
+        Texture::new_array(&mut self.factory, layers).track(&mut self.memory)
     }
 
     pub(super) fn create_drawable_texture(
@@ -108,6 +221,7 @@ impl Gpu {
         height: u16,
     ) -> texture::Drawable {
         texture::Drawable::new(&mut self.factory, width, height)
+            .track(&mut self.memory)
     }
 
     pub(super) fn read_drawable_texture_pixels(
@@ -4,7 +4,7 @@ use gfx_device_gl as gl;
 
 use super::format;
 use super::texture::Texture;
-use crate::graphics::{self, Transformation};
+use crate::graphics::{self, GpuMemoryStats, Transformation};
 
 const MAX_INSTANCES: u32 = 100_000;
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
@@ -172,6 +172,20 @@ impl Pipeline {
             i += MAX_INSTANCES as usize;
         }
     }
+
+    pub fn count_buffers(&self, stats: &mut GpuMemoryStats) {
+        let indices = match &self.slice.buffer {
+            gfx::IndexBuffer::Index16(buffer) => buffer.get_info().size,
+            gfx::IndexBuffer::Index32(buffer) => buffer.get_info().size,
+            gfx::IndexBuffer::Auto => 0,
+        };
+
+        stats.buffers_alive += 4;
+        stats.buffer_bytes += (self.data.vertices.get_info().size
+            + self.data.globals.get_info().size
+            + self.data.instances.get_info().size
+            + indices) as u64;
+    }
 }
 
 pub struct Shader {
@@ -1,16 +1,21 @@
 use gfx_device_gl as gl;
+use std::time::Instant;
 
 use super::{format, Gpu, TargetView};
+use crate::graphics::FrameStats;
 use crate::{Error, Result};
 
 pub struct Surface {
     context: glutin::WindowedContext<glutin::PossiblyCurrent>,
     target: TargetView,
+    last_present: Instant,
+    stats: FrameStats,
 }
 
 impl Surface {
     pub(super) fn new(
         builder: winit::window::WindowBuilder,
+        vsync: bool,
         event_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<(Self, gl::Device, gl::Factory)> {
         let gl_builder = glutin::ContextBuilder::new()
@@ -19,7 +24,7 @@ impl Surface {
             .with_multisampling(0)
             // 24 color bits, 8 alpha bits
             .with_pixel_format(24, 8)
-            .with_vsync(true);
+            .with_vsync(vsync);
 
         let (context, device, factory, target, _depth) = init_raw(
             builder,
@@ -30,13 +35,26 @@ impl Surface {
         )
         .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
-        Ok((Self { context, target }, device, factory))
+        Ok((
+            Self {
+                context,
+                target,
+                last_present: Instant::now(),
+                stats: FrameStats::default(),
+            },
+            device,
+            factory,
+        ))
     }
 
     pub fn window(&self) -> &winit::window::Window {
         self.context.window()
     }
 
+    pub fn acquire(&mut self, _gpu: &mut Gpu) -> Result<()> {
+        Ok(())
+    }
+
     pub fn target(&self) -> &TargetView {
         &self.target
     }
@@ -46,6 +64,9 @@ impl Surface {
         _gpu: &mut Gpu,
         size: winit::dpi::PhysicalSize<u32>,
     ) {
+        #[cfg(feature = "log")]
+        log::debug!("Resizing surface to {}x{}", size.width, size.height);
+
         self.context.resize(size);
 
         let dimensions = self.target.get_dimensions();
@@ -57,6 +78,7 @@ impl Surface {
             format::DEPTH,
         ) {
             self.target = target;
+            self.stats.swapchain_recreations += 1;
         }
     }
 
@@ -64,10 +86,29 @@ impl Surface {
         self.context.window().request_redraw();
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+    pub fn frame_stats(&self) -> FrameStats {
+        self.stats
+    }
+
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> Result<()> {
+        let wait_start = Instant::now();
+
         gpu.flush();
-        self.context.swap_buffers().expect("Buffer swap");
+
+        self.context.swap_buffers().map_err(|error| match error {
+            glutin::ContextError::ContextLost => Error::DeviceLost,
+            error => Error::Graphics(error.to_string()),
+        })?;
+
         gpu.cleanup();
+
+        let now = Instant::now();
+
+        self.stats.cpu_frame_time = wait_start - self.last_present;
+        self.stats.gpu_wait_time = now - wait_start;
+        self.last_present = now;
+
+        Ok(())
     }
 }
 
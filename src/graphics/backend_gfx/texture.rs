@@ -1,4 +1,5 @@
 use image;
+use std::rc::Rc;
 
 use gfx::format::{ChannelTyped, SurfaceTyped};
 use gfx::memory::Typed;
@@ -8,6 +9,7 @@ use gfx_device_gl as gl;
 
 use super::format::{Channel, Surface};
 use super::types::{RawTexture, ShaderResource, TargetView};
+use crate::graphics::gpu_memory::{Allocation, MemoryTracker};
 use crate::graphics::vector::Vector;
 use crate::graphics::Transformation;
 
@@ -18,6 +20,7 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    allocation: Option<Rc<Allocation>>,
 }
 
 impl Texture {
@@ -44,9 +47,66 @@ impl Texture {
             width,
             height,
             layers: 1,
+            allocation: None,
         }
     }
 
+    pub(super) fn new_empty(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+    ) -> Texture {
+        let (raw, view) = create_texture_array(
+            factory,
+            width,
+            height,
+            None,
+            gfx::memory::Bind::SHADER_RESOURCE
+                | gfx::memory::Bind::TRANSFER_SRC
+                | gfx::memory::Bind::TRANSFER_DST,
+        );
+
+        Texture {
+            raw,
+            view,
+            width,
+            height,
+            layers: 1,
+            allocation: None,
+        }
+    }
+
+    pub(super) fn pixels(image: &image::DynamicImage) -> Vec<u8> {
+        image.to_rgba().into_raw()
+    }
+
+    pub(super) fn upload_rows(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        y: u16,
+        pixels: &[u8],
+    ) {
+        let rows = (pixels.len() / (4 * self.width as usize)) as u16;
+
+        encoder
+            .update_texture_raw(
+                &self.raw,
+                None,
+                gfx::texture::RawImageInfo {
+                    xoffset: 0,
+                    yoffset: y,
+                    zoffset: 0,
+                    width: self.width,
+                    height: rows,
+                    depth: 1,
+                    format: <gfx::format::Srgba8 as gfx::format::Formatted>::get_format(),
+                    mipmap: 0,
+                },
+                pixels,
+            )
+            .expect("Upload texture rows");
+    }
+
     pub(super) fn new_array(
         factory: &mut gl::Factory,
         layers: &[image::DynamicImage],
@@ -75,6 +135,7 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            allocation: None,
         }
     }
 
@@ -86,6 +147,10 @@ impl Texture {
         &self.view
     }
 
+    pub fn is_same(&self, other: &Texture) -> bool {
+        self.raw == other.raw
+    }
+
     pub fn width(&self) -> u16 {
         self.width
     }
@@ -93,6 +158,24 @@ impl Texture {
     pub fn height(&self) -> u16 {
         self.height
     }
+
+    pub fn is_shared(&self) -> bool {
+        // Every clone shares the allocation of a tracked texture
+        self.allocation
+            .as_ref()
+            .map(|allocation| Rc::strong_count(allocation) > 1)
+            .unwrap_or(true)
+    }
+
+    pub(super) fn track(mut self, memory: &mut MemoryTracker) -> Texture {
+        self.allocation = Some(Rc::new(memory.texture(
+            self.width,
+            self.height,
+            self.layers,
+        )));
+
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -119,6 +202,7 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            allocation: None,
         };
 
         let render_desc = gfx::texture::RenderDesc {
@@ -138,6 +222,11 @@ impl Drawable {
         &self.texture
     }
 
+    pub(super) fn track(mut self, memory: &mut MemoryTracker) -> Drawable {
+        self.texture = self.texture.track(memory);
+        self
+    }
+
     pub fn target(&self) -> &TargetView {
         &self.target
     }
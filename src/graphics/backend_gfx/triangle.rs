@@ -3,7 +3,7 @@ use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
-use crate::graphics::Transformation;
+use crate::graphics::{GpuMemoryStats, Transformation};
 
 gfx_defines! {
     vertex Vertex {
@@ -160,6 +160,14 @@ impl Pipeline {
 
         encoder.draw(&slice, &self.shader.state, &self.data);
     }
+
+    pub fn count_buffers(&self, stats: &mut GpuMemoryStats) {
+        stats.buffers_alive += 3;
+        stats.buffer_bytes += (self.data.vertices.get_info().size
+            + self.data.globals.get_info().size
+            + self.indices.get_info().size)
+            as u64;
+    }
 }
 
 pub struct Shader {
@@ -1,12 +1,16 @@
 use crate::graphics::gpu::TargetView;
 use crate::graphics::{
-    HorizontalAlignment, Text, Transformation, VerticalAlignment,
+    FontStyle, HorizontalAlignment, Point, RichText, Text, Transformation,
+    VerticalAlignment,
 };
 
 use wgpu_glyph::GlyphCruncher;
 
+use std::collections::HashMap;
+
 pub struct Font {
     glyphs: wgpu_glyph::GlyphBrush<'static, ()>,
+    styles: HashMap<FontStyle, wgpu_glyph::FontId>,
 }
 
 impl Font {
@@ -16,9 +20,16 @@ impl Font {
                 .expect("Load font")
                 .texture_filter_method(wgpu::FilterMode::Nearest)
                 .build(device, wgpu::TextureFormat::Bgra8UnormSrgb),
+            styles: HashMap::new(),
         }
     }
 
+    pub fn add_style(&mut self, style: FontStyle, bytes: &'static [u8]) {
+        let id = self.glyphs.add_font_bytes(bytes);
+
+        let _ = self.styles.insert(style, id);
+    }
+
     pub fn add(&mut self, text: Text<'_>) {
         let section: wgpu_glyph::Section<'_> = text.into();
         self.glyphs.queue(section);
@@ -34,6 +45,21 @@ impl Font {
         }
     }
 
+    pub fn add_rich(&mut self, text: RichText<'_>) {
+        let section = self.varied_section(&text);
+        self.glyphs.queue(section);
+    }
+
+    pub fn measure_rich(&mut self, text: RichText<'_>) -> (f32, f32) {
+        let section = self.varied_section(&text);
+        let bounds = self.glyphs.glyph_bounds(section);
+
+        match bounds {
+            Some(bounds) => (bounds.width(), bounds.height()),
+            None => (0.0, 0.0),
+        }
+    }
+
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
@@ -50,27 +76,88 @@ impl Font {
             )
             .expect("Draw font");
     }
-}
 
-impl<'a> From<Text<'a>> for wgpu_glyph::Section<'a> {
-    fn from(text: Text<'a>) -> wgpu_glyph::Section<'a> {
-        let x = match text.horizontal_alignment {
-            HorizontalAlignment::Left => text.position.x,
-            HorizontalAlignment::Center => {
-                text.position.x + text.bounds.0 / 2.0
-            }
-            HorizontalAlignment::Right => text.position.x + text.bounds.0,
+    // The face used for a style, falling back to the closest one available
+    fn font_id(&self, style: FontStyle) -> wgpu_glyph::FontId {
+        let fallbacks: &[FontStyle] = match style {
+            FontStyle::Regular => &[],
+            FontStyle::Bold => &[FontStyle::Bold],
+            FontStyle::Italic => &[FontStyle::Italic],
+            FontStyle::BoldItalic => &[FontStyle::BoldItalic, FontStyle::Bold],
         };
 
-        let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
-            VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
-            VerticalAlignment::Bottom => text.position.y + text.bounds.1,
-        };
+        fallbacks
+            .iter()
+            .find_map(|style| self.styles.get(style))
+            .cloned()
+            .unwrap_or(wgpu_glyph::FontId(0))
+    }
 
+    fn varied_section<'a>(
+        &self,
+        text: &RichText<'a>,
+    ) -> wgpu_glyph::VariedSection<'a> {
+        wgpu_glyph::VariedSection {
+            text: text
+                .spans
+                .iter()
+                .map(|span| wgpu_glyph::SectionText {
+                    text: span.content,
+                    scale: wgpu_glyph::Scale {
+                        x: text.size,
+                        y: text.size,
+                    },
+                    color: span.color.into_linear(),
+                    font_id: self.font_id(span.style),
+                })
+                .collect(),
+            screen_position: anchor(
+                text.position,
+                text.bounds,
+                text.horizontal_alignment,
+                text.vertical_alignment,
+            ),
+            bounds: text.bounds,
+            layout: wgpu_glyph::Layout::default()
+                .h_align(text.horizontal_alignment.into())
+                .v_align(text.vertical_alignment.into()),
+            ..Default::default()
+        }
+    }
+}
+
+// The point where aligned text starts
+fn anchor(
+    position: Point,
+    bounds: (f32, f32),
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> (f32, f32) {
+    let x = match horizontal_alignment {
+        HorizontalAlignment::Left => position.x,
+        HorizontalAlignment::Center => position.x + bounds.0 / 2.0,
+        HorizontalAlignment::Right => position.x + bounds.0,
+    };
+
+    let y = match vertical_alignment {
+        VerticalAlignment::Top => position.y,
+        VerticalAlignment::Center => position.y + bounds.1 / 2.0,
+        VerticalAlignment::Bottom => position.y + bounds.1,
+    };
+
+    (x, y)
+}
+
+impl<'a> From<Text<'a>> for wgpu_glyph::Section<'a> {
+    fn from(text: Text<'a>) -> wgpu_glyph::Section<'a> {
         wgpu_glyph::Section {
             text: &text.content,
-            screen_position: (x, y),
+            screen_position: anchor(
+                text.position,
+                text.bounds,
+                text.horizontal_alignment,
+                text.vertical_alignment,
+            ),
             scale: wgpu_glyph::Scale {
                 x: text.size,
                 y: text.size,
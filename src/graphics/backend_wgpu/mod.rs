@@ -12,7 +12,12 @@ pub use texture::Texture;
 pub use triangle::Vertex;
 pub use types::TargetView;
 
-use crate::graphics::{Color, Transformation};
+use crate::graphics::gpu_memory::MemoryTracker;
+use crate::graphics::{
+    AdapterInfo, Backend, Color, DeviceType, GpuMemoryStats, GpuSettings,
+    GpuStats, PowerPreference, Transformation,
+};
+use crate::load::Cache;
 use crate::{Error, Result};
 
 #[allow(missing_debug_implementations)]
@@ -23,28 +28,28 @@ pub struct Gpu {
     quad_pipeline: quad::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     encoder: wgpu::CommandEncoder,
+    generation: u64,
+    adapter_info: AdapterInfo,
+    memory: MemoryTracker,
+    stats: GpuStats,
+    cache: Cache,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
+        vsync: bool,
+        settings: &GpuSettings,
         event_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<(Gpu, Surface)> {
+        let adapter = select_adapter(settings)?;
+        let adapter_info = adapter_info(&adapter);
+
         let window = builder
             .build(event_loop)
             .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
         let (mut device, queue) = futures::executor::block_on(async {
-            let adapter = wgpu::Adapter::request(
-                &wgpu::RequestAdapterOptions {
-                    power_preference: wgpu::PowerPreference::HighPerformance,
-                    compatible_surface: None,
-                },
-                wgpu::BackendBit::all(),
-            )
-            .await
-            .expect("Request adapter");
-
             let (device, queue) = adapter
                 .request_device(&wgpu::DeviceDescriptor {
                     extensions: wgpu::Extensions {
@@ -57,7 +62,7 @@ impl Gpu {
             (device, queue)
         });
 
-        let surface = Surface::new(window, &device);
+        let surface = Surface::new(window, vsync, &device);
 
         let quad_pipeline = quad::Pipeline::new(&mut device);
         let triangle_pipeline = triangle::Pipeline::new(&mut device);
@@ -74,11 +79,52 @@ impl Gpu {
                 quad_pipeline,
                 triangle_pipeline,
                 encoder,
+                generation: 0,
+                adapter_info,
+                memory: MemoryTracker::default(),
+                stats: GpuStats::default(),
+                cache: Cache::default(),
             },
             surface,
         ))
     }
 
+    pub(super) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub(super) fn lose_contents(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Returns information about the graphics adapter used by the [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn adapter_info(&self) -> AdapterInfo {
+        self.adapter_info.clone()
+    }
+
+    /// Returns the [`GpuMemoryStats`] of the [`Gpu`].
+    ///
+    /// [`GpuMemoryStats`]: struct.GpuMemoryStats.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn memory_stats(&self) -> GpuMemoryStats {
+        let mut stats = self.memory.stats();
+
+        self.quad_pipeline.count_buffers(&mut stats);
+        self.triangle_pipeline.count_buffers(&mut stats);
+
+        stats
+    }
+
+    /// Returns the asset [`Cache`] of the [`Gpu`].
+    ///
+    /// [`Cache`]: ../load/struct.Cache.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn cache(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+
     pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
         let [r, g, b, a] = color.into_linear();
 
@@ -99,11 +145,51 @@ impl Gpu {
         });
     }
 
+    pub(super) fn stats_mut(&mut self) -> &mut GpuStats {
+        &mut self.stats
+    }
+
+    pub(super) fn take_stats(&mut self) -> GpuStats {
+        self.memory.next_frame();
+        self.cache.prune();
+
+        std::mem::replace(&mut self.stats, GpuStats::default())
+    }
+
     pub(super) fn upload_texture(
         &mut self,
         image: &image::DynamicImage,
     ) -> Texture {
         Texture::new(&mut self.device, &self.queue, &self.quad_pipeline, image)
+            .track(&mut self.memory)
+    }
+
+    pub(super) fn create_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+    ) -> Texture {
+        Texture::new_empty(
+            &mut self.device,
+            &self.queue,
+            &self.quad_pipeline,
+            width,
+            height,
+        )
+        .track(&mut self.memory)
+    }
+
+    pub(super) fn texture_pixels(image: &image::DynamicImage) -> Vec<u8> {
+        Texture::pixels(image)
+    }
+
+    pub(super) fn upload_texture_rows(
+        &mut self,
+        texture: &Texture,
+        y: u16,
+        pixels: &[u8],
+    ) {
+        texture.upload_rows(&mut self.device, &self.queue, y, pixels)
     }
 
     pub(super) fn upload_texture_array(
@@ -116,6 +202,7 @@ impl Gpu {
             &self.quad_pipeline,
             layers,
         )
+        .track(&mut self.memory)
     }
 
     pub(super) fn create_drawable_texture(
@@ -130,6 +217,7 @@ impl Gpu {
             width,
             height,
         )
+        .track(&mut self.memory)
     }
 
     pub(super) fn read_drawable_texture_pixels(
@@ -194,3 +282,75 @@ impl Gpu {
         font.draw(&mut self.device, &mut self.encoder, target, transformation);
     }
 }
+
+fn select_adapter(settings: &GpuSettings) -> Result<wgpu::Adapter> {
+    let backends = match settings.backend()? {
+        None => wgpu::BackendBit::all(),
+        Some(Backend::Vulkan) => wgpu::BackendBit::VULKAN,
+        Some(Backend::Metal) => wgpu::BackendBit::METAL,
+        Some(Backend::Dx12) => wgpu::BackendBit::DX12,
+        Some(Backend::Dx11) => wgpu::BackendBit::DX11,
+        Some(Backend::Gl) => {
+            return Err(Error::AdapterSelection {
+                reason: String::from(
+                    "the OpenGL backend requires the `opengl` feature",
+                ),
+                adapters: wgpu::Adapter::enumerate(wgpu::BackendBit::all())
+                    .iter()
+                    .map(adapter_info)
+                    .collect(),
+            });
+        }
+    };
+
+    let adapter = if settings.adapter_name_filter.is_some() {
+        wgpu::Adapter::enumerate(backends)
+            .into_iter()
+            .find(|adapter| settings.matches(&adapter.get_info().name))
+    } else {
+        futures::executor::block_on(wgpu::Adapter::request(
+            &wgpu::RequestAdapterOptions {
+                power_preference: match settings.power_preference {
+                    PowerPreference::LowPower => {
+                        wgpu::PowerPreference::LowPower
+                    }
+                    PowerPreference::HighPerformance => {
+                        wgpu::PowerPreference::HighPerformance
+                    }
+                },
+                compatible_surface: None,
+            },
+            backends,
+        ))
+    };
+
+    adapter.ok_or_else(|| Error::AdapterSelection {
+        reason: String::from("no adapter matched the settings"),
+        adapters: wgpu::Adapter::enumerate(wgpu::BackendBit::all())
+            .iter()
+            .map(adapter_info)
+            .collect(),
+    })
+}
+
+fn adapter_info(adapter: &wgpu::Adapter) -> AdapterInfo {
+    let info = adapter.get_info();
+
+    AdapterInfo {
+        name: info.name,
+        backend: match info.backend {
+            wgpu::Backend::Vulkan => Backend::Vulkan,
+            wgpu::Backend::Metal => Backend::Metal,
+            wgpu::Backend::Dx12 => Backend::Dx12,
+            wgpu::Backend::Dx11 => Backend::Dx11,
+            _ => Backend::Gl,
+        },
+        device_type: match info.device_type {
+            wgpu::DeviceType::IntegratedGpu => DeviceType::IntegratedGpu,
+            wgpu::DeviceType::DiscreteGpu => DeviceType::DiscreteGpu,
+            wgpu::DeviceType::VirtualGpu => DeviceType::VirtualGpu,
+            wgpu::DeviceType::Cpu => DeviceType::Cpu,
+            wgpu::DeviceType::Other => DeviceType::Other,
+        },
+    }
+}
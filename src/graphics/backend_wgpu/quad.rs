@@ -1,6 +1,6 @@
 use std::mem;
 
-use crate::graphics::{self, Transformation};
+use crate::graphics::{self, GpuMemoryStats, Transformation};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
@@ -305,6 +305,15 @@ impl Pipeline {
             i += Quad::MAX;
         }
     }
+
+    pub fn count_buffers(&self, stats: &mut GpuMemoryStats) {
+        stats.buffers_alive += 4;
+        stats.buffer_bytes += (mem::size_of::<[f32; 16]>()
+            + mem::size_of_val(&QUAD_VERTS)
+            + mem::size_of_val(&QUAD_INDICES)
+            + mem::size_of::<Quad>() * Quad::MAX)
+            as u64;
+    }
 }
 
 #[derive(Clone, Copy, AsBytes)]
@@ -1,29 +1,52 @@
+use std::time::{Duration, Instant};
+
 use super::{Gpu, TargetView};
+use crate::graphics::FrameStats;
+use crate::{Error, Result};
+
+// The amount of times we try to acquire the next frame before giving up
+const MAX_ACQUIRE_ATTEMPTS: u32 = 3;
 
 pub struct Surface {
     window: winit::window::Window,
     surface: wgpu::Surface,
     swap_chain: wgpu::SwapChain,
     extent: wgpu::Extent3d,
+    present_mode: wgpu::PresentMode,
     output: Option<wgpu::SwapChainOutput>,
+    last_present: Instant,
+    acquire_wait: Duration,
+    stats: FrameStats,
 }
 
 impl Surface {
     pub fn new(
         window: winit::window::Window,
+        vsync: bool,
         device: &wgpu::Device,
     ) -> Surface {
         let surface = wgpu::Surface::create(&window);
         let size = window.inner_size();
 
-        let (swap_chain, extent) = new_swap_chain(device, &surface, size);
+        let present_mode = if vsync {
+            wgpu::PresentMode::Mailbox
+        } else {
+            wgpu::PresentMode::Immediate
+        };
+
+        let (swap_chain, extent) =
+            new_swap_chain(device, &surface, size, present_mode);
 
         Surface {
             window,
             surface,
             swap_chain,
             extent,
+            present_mode,
             output: None,
+            last_present: Instant::now(),
+            acquire_wait: Duration::from_secs(0),
+            stats: FrameStats::default(),
         }
     }
 
@@ -31,17 +54,47 @@ impl Surface {
         &self.window
     }
 
-    pub fn target(&mut self) -> &TargetView {
-        if self.output.is_none() {
-            let output = self
-                .swap_chain
-                .get_next_texture()
-                .expect("Get next texture");
-
-            self.output = Some(output);
+    pub fn acquire(&mut self, gpu: &mut Gpu) -> Result<()> {
+        if self.output.is_some() {
+            return Ok(());
         }
 
-        &self.output.as_ref().unwrap().view
+        let acquire_start = Instant::now();
+        let mut attempts = 0;
+
+        let output = loop {
+            match self.swap_chain.get_next_texture() {
+                Ok(output) => break output,
+                Err(_) => {
+                    self.stats.presents_missed += 1;
+                    attempts += 1;
+
+                    if attempts == MAX_ACQUIRE_ATTEMPTS {
+                        return Err(Error::DeviceLost);
+                    }
+
+                    // wgpu reports an outdated swap chain as a time out, so
+                    // we recreate it before trying again
+                    if attempts > 1 {
+                        let size = self.window.inner_size();
+                        self.resize(gpu, size);
+                    }
+                }
+            }
+        };
+
+        self.acquire_wait += Instant::now() - acquire_start;
+        self.output = Some(output);
+
+        Ok(())
+    }
+
+    pub fn target(&self) -> &TargetView {
+        &self
+            .output
+            .as_ref()
+            .expect("Acquire a frame before drawing to the surface")
+            .view
     }
 
     pub fn resize(
@@ -49,15 +102,23 @@ impl Surface {
         gpu: &mut Gpu,
         size: winit::dpi::PhysicalSize<u32>,
     ) {
+        #[cfg(feature = "log")]
+        log::debug!("Recreating swap chain at {}x{}", size.width, size.height);
+
         let (swap_chain, extent) =
-            new_swap_chain(&gpu.device, &self.surface, size);
+            new_swap_chain(&gpu.device, &self.surface, size, self.present_mode);
 
         self.swap_chain = swap_chain;
         self.extent = extent;
         self.output = None;
+        self.stats.swapchain_recreations += 1;
+    }
+
+    pub fn frame_stats(&self) -> FrameStats {
+        self.stats
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> Result<()> {
         let new_encoder = gpu.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
                 label: Some("coffee::backend::surface blit"),
@@ -71,6 +132,19 @@ impl Surface {
         gpu.queue.submit(&[encoder.finish()]);
 
         self.output = None;
+
+        let now = Instant::now();
+        let frame_time = now - self.last_present;
+
+        self.stats.gpu_wait_time = self.acquire_wait;
+        self.stats.cpu_frame_time = frame_time
+            .checked_sub(self.acquire_wait)
+            .unwrap_or_default();
+
+        self.acquire_wait = Duration::from_secs(0);
+        self.last_present = now;
+
+        Ok(())
     }
 
     pub fn request_redraw(&mut self) {
@@ -82,6 +156,7 @@ fn new_swap_chain(
     device: &wgpu::Device,
     surface: &wgpu::Surface,
     size: winit::dpi::PhysicalSize<u32>,
+    present_mode: wgpu::PresentMode,
 ) -> (wgpu::SwapChain, wgpu::Extent3d) {
     let swap_chain = device.create_swap_chain(
         surface,
@@ -90,7 +165,7 @@ fn new_swap_chain(
             format: wgpu::TextureFormat::Bgra8UnormSrgb,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Mailbox,
+            present_mode,
         },
     );
 
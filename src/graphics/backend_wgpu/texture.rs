@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use super::types::TargetView;
 use crate::graphics::gpu::quad::{self, Pipeline};
+use crate::graphics::gpu_memory::{Allocation, MemoryTracker};
 use crate::graphics::Transformation;
 
 #[derive(Clone)]
@@ -13,6 +14,7 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    allocation: Option<Rc<Allocation>>,
 }
 
 impl fmt::Debug for Texture {
@@ -53,9 +55,87 @@ impl Texture {
             width,
             height,
             layers: 1,
+            allocation: None,
         }
     }
 
+    pub(super) fn new_empty(
+        device: &mut wgpu::Device,
+        queue: &wgpu::Queue,
+        pipeline: &Pipeline,
+        width: u16,
+        height: u16,
+    ) -> Texture {
+        let (texture, view, binding) = create_texture_array(
+            device,
+            queue,
+            pipeline,
+            u32::from(width),
+            u32::from(height),
+            None,
+            wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        );
+
+        Texture {
+            raw: Rc::new(texture),
+            view: Rc::new(view),
+            binding: Rc::new(binding),
+            width,
+            height,
+            layers: 1,
+            allocation: None,
+        }
+    }
+
+    pub(super) fn pixels(image: &image::DynamicImage) -> Vec<u8> {
+        image.to_bgra().into_raw()
+    }
+
+    pub(super) fn upload_rows(
+        &self,
+        device: &mut wgpu::Device,
+        queue: &wgpu::Queue,
+        y: u16,
+        pixels: &[u8],
+    ) {
+        let width = u32::from(self.width);
+        let rows = pixels.len() as u32 / (4 * width);
+
+        let temp_buf =
+            device.create_buffer_with_data(pixels, wgpu::BufferUsage::COPY_SRC);
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("coffee::backend::texture rows upload"),
+            });
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &temp_buf,
+                offset: 0,
+                bytes_per_row: 4 * width,
+                rows_per_image: rows,
+            },
+            wgpu::TextureCopyView {
+                texture: &self.raw,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0,
+                    y: u32::from(y),
+                    z: 0,
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height: rows,
+                depth: 1,
+            },
+        );
+
+        queue.submit(&[encoder.finish()]);
+    }
+
     pub(super) fn new_array(
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
@@ -88,6 +168,7 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            allocation: None,
         }
     }
 
@@ -99,6 +180,10 @@ impl Texture {
         &self.binding
     }
 
+    pub fn is_same(&self, other: &Texture) -> bool {
+        Rc::ptr_eq(&self.raw, &other.raw)
+    }
+
     pub fn width(&self) -> u16 {
         self.width
     }
@@ -106,6 +191,20 @@ impl Texture {
     pub fn height(&self) -> u16 {
         self.height
     }
+
+    pub fn is_shared(&self) -> bool {
+        Rc::strong_count(&self.raw) > 1
+    }
+
+    pub(super) fn track(mut self, memory: &mut MemoryTracker) -> Texture {
+        self.allocation = Some(Rc::new(memory.texture(
+            self.width,
+            self.height,
+            self.layers,
+        )));
+
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -140,6 +239,7 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            allocation: None,
         };
 
         Drawable { texture }
@@ -149,6 +249,11 @@ impl Drawable {
         &self.texture
     }
 
+    pub(super) fn track(mut self, memory: &mut MemoryTracker) -> Drawable {
+        self.texture = self.texture.track(memory);
+        self
+    }
+
     pub fn target(&self) -> &TargetView {
         self.texture().view()
     }
@@ -1,6 +1,6 @@
 use std::mem;
 
-use crate::graphics::Transformation;
+use crate::graphics::{GpuMemoryStats, Transformation};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
@@ -247,6 +247,16 @@ impl Pipeline {
             render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
         }
     }
+
+    pub fn count_buffers(&self, stats: &mut GpuMemoryStats) {
+        let size = self.buffer_size as usize;
+
+        stats.buffers_alive += 3;
+        stats.buffer_bytes += (mem::size_of::<[f32; 16]>()
+            + mem::size_of::<Vertex>() * size
+            + mem::size_of::<u32>() * size)
+            as u64;
+    }
 }
 
 #[derive(Debug, Clone, Copy, AsBytes)]
@@ -42,6 +42,18 @@ impl Batch {
         self.instances.push(instance);
     }
 
+    /// Returns true if the [`Batch`] has no quads.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    // Returns the image used by the batch
+    pub(crate) fn image(&self) -> &Image {
+        &self.image
+    }
+
     /// Draws the [`Batch`] on the given [`Target`].
     ///
     /// [`Batch`]: struct.Batch.html
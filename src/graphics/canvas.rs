@@ -1,7 +1,7 @@
 use crate::graphics::gpu::{self, texture, Gpu};
 use crate::graphics::{IntoQuad, Target};
 use crate::load::Task;
-use crate::Result;
+use crate::{Error, Result};
 
 /// An off-screen rendering target.
 ///
@@ -11,15 +11,26 @@ use crate::Result;
 #[derive(Clone)]
 pub struct Canvas {
     drawable: texture::Drawable,
+    generation: u64,
 }
 
 impl Canvas {
     /// Creates a new [`Canvas`] with the given size.
     ///
+    /// It fails if the width or the height is zero.
+    ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn new(gpu: &mut Gpu, width: u16, height: u16) -> Result<Canvas> {
+        if width == 0 || height == 0 {
+            return Err(Error::Graphics(format!(
+                "Cannot create a canvas of size {}x{}",
+                width, height
+            )));
+        }
+
         Ok(Canvas {
             drawable: gpu.create_drawable_texture(width, height),
+            generation: gpu.generation(),
         })
     }
 
@@ -45,12 +56,26 @@ impl Canvas {
         self.drawable.texture().height()
     }
 
+    /// Returns true if the contents of the [`Canvas`] are still valid.
+    ///
+    /// The contents of a [`Canvas`] may be lost when the game is suspended.
+    /// In that case, draw them again in [`Game::on_resume`]. Viewing the
+    /// [`Canvas`] as a [`Target`] makes it valid again.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Game::on_resume`]: ../trait.Game.html#method.on_resume
+    /// [`Target`]: struct.Target.html
+    pub fn is_valid(&self, gpu: &Gpu) -> bool {
+        self.generation == gpu.generation()
+    }
+
     /// Views the [`Canvas`] as a [`Target`].
     ///
     /// [`Canvas`]: struct.Canvas.html
     /// [`Target`]: struct.Target.html
     pub fn as_target<'a>(&'a mut self, gpu: &'a mut Gpu) -> Target<'a> {
         let texture = self.drawable.texture();
+        self.generation = gpu.generation();
 
         Target::with_transformation(
             gpu,
@@ -83,6 +108,20 @@ impl Canvas {
     pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
         gpu.read_drawable_texture_pixels(&self.drawable)
     }
+
+    /// Reads the pixels of the [`Canvas`] into an [`RgbaImage`] of the
+    /// [`image` crate].
+    ///
+    /// _Note:_ This is a very slow operation. It is only available when the
+    /// `image` feature is enabled.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`RgbaImage`]: https://docs.rs/image/0.21.1/image/type.RgbaImage.html
+    /// [`image` crate]: https://docs.rs/image
+    #[cfg(feature = "image")]
+    pub fn to_image(&self, gpu: &mut Gpu) -> image::RgbaImage {
+        self.read_pixels(gpu).to_rgba()
+    }
 }
 
 impl std::fmt::Debug for Canvas {
@@ -0,0 +1,66 @@
+use crate::graphics::{Point, Vector};
+
+/// Convert a [`Point`] or a [`Vector`] from and into plain coordinates.
+///
+/// Use it if you do not want to deal with [`nalgebra`] in your game.
+///
+/// ```
+/// use coffee::graphics::{Coordinates, Point};
+///
+/// let point = Point::from_tuple((10.0, 20.0));
+///
+/// assert_eq!(point.to_array(), [10.0, 20.0]);
+/// ```
+///
+/// [`Point`]: type.Point.html
+/// [`Vector`]: type.Vector.html
+/// [`nalgebra`]: ../nalgebra/index.html
+pub trait Coordinates {
+    /// Creates a value from its `(x, y)` coordinates.
+    fn from_tuple(coordinates: (f32, f32)) -> Self;
+
+    /// Creates a value from its `[x, y]` coordinates.
+    fn from_array(coordinates: [f32; 2]) -> Self;
+
+    /// Returns the `(x, y)` coordinates of the value.
+    fn to_tuple(&self) -> (f32, f32);
+
+    /// Returns the `[x, y]` coordinates of the value.
+    fn to_array(&self) -> [f32; 2];
+}
+
+impl Coordinates for Point {
+    fn from_tuple((x, y): (f32, f32)) -> Point {
+        Point::new(x, y)
+    }
+
+    fn from_array([x, y]: [f32; 2]) -> Point {
+        Point::new(x, y)
+    }
+
+    fn to_tuple(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn to_array(&self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl Coordinates for Vector {
+    fn from_tuple((x, y): (f32, f32)) -> Vector {
+        Vector::new(x, y)
+    }
+
+    fn from_array([x, y]: [f32; 2]) -> Vector {
+        Vector::new(x, y)
+    }
+
+    fn to_tuple(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn to_array(&self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+}
@@ -1,11 +1,22 @@
 use crate::graphics::gpu;
-use crate::graphics::{Gpu, Target, Text};
-use crate::load::Task;
-use crate::Result;
+use crate::graphics::{FontStyle, Gpu, RichText, Target, Text};
+use crate::load::{self, Task};
+use crate::{Error, Result};
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 /// A collection of text with the same font.
+///
+/// Cloning a [`Font`] is cheap, it only clones a handle. Clones share the
+/// same glyph cache on the GPU, the same styles, and the same queue of text
+/// to draw.
+///
+/// [`Font`]: struct.Font.html
+#[derive(Clone)]
 #[allow(missing_debug_implementations)]
-pub struct Font(gpu::Font);
+pub struct Font(Rc<RefCell<gpu::Font>>);
 
 impl Font {
     pub(crate) const DEFAULT: &'static [u8] =
@@ -15,7 +26,7 @@ impl Font {
     ///
     /// [`Font`]: struct.Font.html
     pub fn from_bytes(gpu: &mut Gpu, bytes: &'static [u8]) -> Result<Font> {
-        Ok(Font(gpu.upload_font(bytes)))
+        Ok(Font(Rc::new(RefCell::new(gpu.upload_font(bytes)))))
     }
 
     /// Creates a [`Task`] that loads a [`Font`] from raw data.
@@ -26,26 +37,116 @@ impl Font {
         Task::using_gpu(move |gpu| Font::from_bytes(gpu, bytes))
     }
 
+    /// Creates a [`Task`] that loads a [`Font`] from the given path.
+    ///
+    /// The font is read from the installed [`Bundle`], if it contains the
+    /// path. Otherwise, it is read from the filesystem and kept in memory for
+    /// the rest of the program.
+    ///
+    /// If a [`Font`] has already been loaded from the same path, the [`Task`]
+    /// produces a cheap clone of it instead. Read the [`Cache`] docs to learn
+    /// more.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    /// [`Bundle`]: ../load/struct.Bundle.html
+    /// [`Cache`]: ../load/struct.Cache.html
+    pub fn load_from_path<P: Into<PathBuf>>(path: P) -> Task<Font> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| {
+            if let Some(font) = gpu.cache().font(&path) {
+                return Ok(font);
+            }
+
+            let bytes = match load::read_static(&path) {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    return Err(Error::Asset {
+                        path,
+                        error: Box::new(error.into()),
+                    })
+                }
+            };
+
+            let font = Font::from_bytes(gpu, bytes)?;
+            gpu.cache().insert_font(path, font.clone());
+
+            Ok(font)
+        })
+    }
+
+    /// Adds a face with the given [`FontStyle`] to this [`Font`], from raw
+    /// data.
+    ///
+    /// [`RichText`] spans with that style will use it. Spans with a style the
+    /// [`Font`] does not have use the closest face available: bold italic
+    /// falls back to bold, and any style falls back to regular.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use coffee::graphics::{Font, FontStyle};
+    /// use coffee::load::Task;
+    ///
+    /// # const REGULAR: &[u8] = &[];
+    /// # const BOLD: &[u8] = &[];
+    /// let font: Task<Font> = Font::load_from_bytes(REGULAR).map(|mut font| {
+    ///     font.add_style(FontStyle::Bold, BOLD);
+    ///     font
+    /// });
+    /// ```
+    ///
+    /// The style is added to every clone of this [`Font`].
+    ///
+    /// [`FontStyle`]: enum.FontStyle.html
+    /// [`Font`]: struct.Font.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn add_style(&mut self, style: FontStyle, bytes: &'static [u8]) {
+        self.0.borrow_mut().add_style(style, bytes)
+    }
+
     /// Adds [`Text`] to this [`Font`].
     ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: struct.Font.html
     pub fn add(&mut self, text: Text<'_>) {
-        self.0.add(text)
+        self.0.borrow_mut().add(text)
     }
 
     /// Computes the layout bounds of the given [`Text`].
     ///
     /// [`Text`]: struct.Text.html
     pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        self.0.measure(text)
+        self.0.borrow_mut().measure(text)
     }
 
-    /// Renders and flushes all the text added to this [`Font`].
+    /// Adds [`RichText`] to this [`Font`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Font`]: struct.Font.html
+    pub fn add_rich(&mut self, text: RichText<'_>) {
+        self.0.borrow_mut().add_rich(text)
+    }
+
+    /// Computes the layout bounds of the given [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn measure_rich(&mut self, text: RichText<'_>) -> (f32, f32) {
+        self.0.borrow_mut().measure_rich(text)
+    }
+
+    /// Renders and flushes all the text added to this [`Font`] and its
+    /// clones.
     ///
     /// [`Font`]: struct.Font.html
     #[inline]
     pub fn draw(&mut self, target: &mut Target<'_>) {
-        target.draw_font(&mut self.0)
+        target.draw_font(&mut self.0.borrow_mut())
+    }
+
+    // Returns whether other clones of the font are alive
+    pub(crate) fn is_shared(&self) -> bool {
+        Rc::strong_count(&self.0) > 1
     }
 }
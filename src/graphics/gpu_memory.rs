@@ -0,0 +1,140 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The amount of consecutive frames creating a texture with the same size
+/// before a possible leak is reported.
+const LEAK_FRAMES: u32 = 120;
+
+/// The GPU resources kept alive by a [`Gpu`].
+///
+/// They are estimated by Coffee from the resources it creates, like the
+/// textures of an [`Image`], a [`Canvas`], or a [`TextureArray`], and the
+/// buffers used to draw. The memory used internally by the driver and by
+/// font caches is not included.
+///
+/// A [`Mesh`] lives on the CPU until it is drawn, so it is not included
+/// either.
+///
+/// A steady growth of `textures_alive` usually means some resources are
+/// kept alive by mistake. GPU resources are freed as soon as the last
+/// clone of their owner is dropped.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Image`]: struct.Image.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`TextureArray`]: struct.TextureArray.html
+/// [`Mesh`]: struct.Mesh.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GpuMemoryStats {
+    /// The amount of textures alive.
+    pub textures_alive: u32,
+
+    /// The estimated amount of bytes used by the textures alive.
+    pub texture_bytes_estimated: u64,
+
+    /// The amount of buffers alive.
+    pub buffers_alive: u32,
+
+    /// The amount of bytes used by the buffers alive.
+    pub buffer_bytes: u64,
+}
+
+// Keeps track of the textures created by a `Gpu`
+#[derive(Debug, Default)]
+pub(crate) struct MemoryTracker {
+    textures: Rc<Cell<(u32, u64)>>,
+    frame: u64,
+    creations: HashMap<(u16, u16, u16), Creation>,
+}
+
+#[derive(Debug)]
+struct Creation {
+    last_frame: u64,
+    streak: u32,
+}
+
+impl MemoryTracker {
+    pub fn texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        layers: u16,
+    ) -> Allocation {
+        let bytes =
+            4 * u64::from(width) * u64::from(height) * u64::from(layers);
+        let (alive, total) = self.textures.get();
+
+        self.textures.set((alive + 1, total + bytes));
+        self.record((width, height, layers));
+
+        Allocation {
+            textures: self.textures.clone(),
+            bytes,
+        }
+    }
+
+    pub fn stats(&self) -> GpuMemoryStats {
+        let (textures_alive, texture_bytes_estimated) = self.textures.get();
+
+        GpuMemoryStats {
+            textures_alive,
+            texture_bytes_estimated,
+            buffers_alive: 0,
+            buffer_bytes: 0,
+        }
+    }
+
+    pub fn next_frame(&mut self) {
+        let frame = self.frame;
+
+        self.frame += 1;
+        self.creations
+            .retain(|_, creation| creation.last_frame == frame);
+    }
+
+    // Detects textures created every frame with the same size, which is the
+    // usual pattern of a leak
+    fn record(&mut self, key: (u16, u16, u16)) {
+        let frame = self.frame;
+
+        let creation = self.creations.entry(key).or_insert(Creation {
+            last_frame: frame,
+            streak: 1,
+        });
+
+        if creation.last_frame + 1 == frame {
+            creation.last_frame = frame;
+            creation.streak += 1;
+
+            if creation.streak == LEAK_FRAMES {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    "A {}x{} texture with {} layer(s) was created in each of \
+                     the last {} frames. Reuse it, or make sure it is not \
+                     kept alive by mistake.",
+                    key.0,
+                    key.1,
+                    key.2,
+                    LEAK_FRAMES
+                );
+            }
+        }
+    }
+}
+
+// A texture counted by a `MemoryTracker`, until it is dropped
+#[derive(Debug)]
+pub(crate) struct Allocation {
+    textures: Rc<Cell<(u32, u64)>>,
+    bytes: u64,
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        let (alive, total) = self.textures.get();
+
+        self.textures
+            .set((alive.saturating_sub(1), total.saturating_sub(self.bytes)));
+    }
+}
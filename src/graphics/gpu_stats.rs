@@ -0,0 +1,35 @@
+/// Draw statistics of a [`Gpu`] during a frame.
+///
+/// They are reset every time a frame is presented. You can obtain the ones
+/// of the last presented frame with [`Window::gpu_stats`] or
+/// [`Debug::gpu_stats`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Window::gpu_stats`]: struct.Window.html#method.gpu_stats
+/// [`Debug::gpu_stats`]: ../struct.Debug.html#method.gpu_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GpuStats {
+    /// The amount of draw calls issued.
+    pub draw_calls: u32,
+
+    /// The amount of quads submitted, including the ones of a [`Batch`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    pub quads: u32,
+
+    /// The amount of vertices submitted by meshes.
+    pub vertices: u32,
+
+    /// The amount of times a texture was bound to draw quads or text.
+    pub texture_binds: u32,
+
+    /// The amount of times a [`Canvas`] was used as a [`Target`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    pub canvas_switches: u32,
+
+    /// The amount of bytes uploaded to the GPU, including textures, vertices,
+    /// indices and quad instances.
+    pub bytes_uploaded: u64,
+}
@@ -1,11 +1,16 @@
-use std::fs::File;
-use std::io::Read;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use image::GenericImageView;
 
 use crate::graphics::gpu::{self, Texture};
 use crate::graphics::{Color, Gpu, IntoQuad, Target};
-use crate::load::Task;
-use crate::Result;
+use crate::load::{self, Job, Task, Worker};
+use crate::{Error, Result};
+
+static UPLOAD_BUDGET: AtomicUsize =
+    AtomicUsize::new(Image::DEFAULT_UPLOAD_BUDGET);
 
 /// A loaded image.
 ///
@@ -21,32 +26,150 @@ pub struct Image {
 }
 
 impl Image {
+    /// The file extensions of the image formats that can be loaded.
+    pub const EXTENSIONS: &'static [&'static str] = &[
+        "png", "jpg", "jpeg", "gif", "bmp", "ico", "tif", "tiff", "webp",
+        "tga", "pnm", "pbm", "pgm", "ppm",
+    ];
+
+    /// The default amount of bytes uploaded to the GPU between loading screen
+    /// frames: 4 MiB.
+    pub const DEFAULT_UPLOAD_BUDGET: usize = 4 * 1024 * 1024;
+
+    /// Sets the amount of bytes uploaded to the GPU between loading screen
+    /// frames by [`Image::load`].
+    ///
+    /// Large images are uploaded in bands of rows that fit in this budget,
+    /// letting the loading screen draw in between. A lower budget keeps the
+    /// loading screen smoother, while a higher one makes loading faster.
+    ///
+    /// [`Image::load`]: #method.load
+    pub fn set_upload_budget(bytes: usize) {
+        UPLOAD_BUDGET.store(bytes, Ordering::Relaxed);
+    }
+
     /// Loads an [`Image`] from the given path.
     ///
     /// [`Image`]: struct.Image.html
     pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Image> {
-        let image = {
-            let mut buf = Vec::new();
-            let mut reader = File::open(path)?;
-            let _ = reader.read_to_end(&mut buf)?;
-            image::load_from_memory(&buf)?
-        };
+        let image = image::load_from_memory(&load::read(path)?)?;
 
         Image::from_image(gpu, &image)
     }
 
     /// Creates a [`Task`] that loads an [`Image`] from the given path.
     ///
+    /// If an [`Image`] has already been loaded from the same path, the
+    /// [`Task`] produces a cheap clone of it instead. Read the [`Cache`] docs
+    /// to learn more.
+    ///
     /// [`Task`]: ../load/struct.Task.html
     /// [`Image`]: struct.Image.html
+    /// [`Cache`]: ../load/struct.Cache.html
     pub fn load<P: Into<PathBuf>>(path: P) -> Task<Image> {
         let p = path.into();
 
-        Task::using_gpu(move |gpu| Image::new(gpu, &p))
+        Task::sequence(1.0, move |worker| {
+            worker.check()?;
+
+            let cached =
+                worker.gpu().ok().and_then(|gpu| gpu.cache().image(&p));
+
+            if let Some(image) = cached {
+                worker.notify_progress(1.0);
+
+                return Ok(image);
+            }
+
+            match Image::upload_progressively(worker, &p) {
+                Ok(image) => {
+                    worker.gpu()?.cache().insert_image(p, image.clone());
+
+                    Ok(image)
+                }
+                Err(error) => Err(Error::Asset {
+                    path: p,
+                    error: Box::new(error),
+                }),
+            }
+        })
+    }
+
+    fn upload_progressively(
+        worker: &mut Worker<'_>,
+        path: &Path,
+    ) -> Result<Image> {
+        let image = image::load_from_memory(&load::read(path)?)?;
+        let (width, height) = image.dimensions();
+        let pixels = Gpu::texture_pixels(&image);
+
+        let row_size = (4 * width as usize).max(1);
+        let rows_per_band =
+            (UPLOAD_BUDGET.load(Ordering::Relaxed) / row_size).max(1);
+        let band_size = rows_per_band * row_size;
+        let bands = (pixels.len() + band_size - 1) / band_size;
+
+        let texture = worker.gpu()?.create_texture(width as u16, height as u16);
+
+        for (i, band) in pixels.chunks(band_size).enumerate() {
+            let gpu = worker.gpu()?;
+
+            gpu.stats_mut().bytes_uploaded += band.len() as u64;
+            gpu.upload_texture_rows(&texture, (i * rows_per_band) as u16, band);
+
+            worker.notify_progress(1.0 / bands as f32);
+        }
+
+        if bands == 0 {
+            worker.notify_progress(1.0);
+        }
+
+        Ok(Image { texture })
+    }
+
+    /// Creates a [`Task`] that loads every image in a directory.
+    ///
+    /// Files with an unsupported extension are skipped. The images are keyed
+    /// by file stem. Read [`load::from_dir`] to learn more about the ordering
+    /// and the possible errors.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`load::from_dir`]: ../load/fn.from_dir.html
+    pub fn load_dir<P: Into<PathBuf>>(path: P) -> Task<HashMap<String, Image>> {
+        load::from_dir(path, false, |path| {
+            let extension = path.extension()?.to_str()?.to_lowercase();
+
+            if Image::EXTENSIONS.contains(&extension.as_str()) {
+                Some(Image::load(path))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Creates a [`Job`] that loads an [`Image`] from the given path.
+    ///
+    /// The image is decoded on a separate thread when the [`Job`] is run with
+    /// [`Task::parallel`].
+    ///
+    /// [`Job`]: ../load/struct.Job.html
+    /// [`Image`]: struct.Image.html
+    /// [`Task::parallel`]: ../load/struct.Task.html#method.parallel
+    pub fn job<P: Into<PathBuf>>(path: P) -> Job<Image> {
+        let p = path.into();
+
+        Job::new(
+            move || Ok(image::load_from_memory(&load::read(p)?)?),
+            |gpu, image| Image::from_image(gpu, &image),
+        )
     }
 
     /// Creates an [`Image`] from a [`DynamicImage`] of the [`image` crate].
     ///
+    /// Any color type is supported, it is converted to RGBA internally. Use
+    /// the [`image` crate] re-exported by Coffee to build it, so the versions
+    /// match.
+    ///
     /// [`Image`]: struct.Image.html
     /// [`DynamicImage`]: https://docs.rs/image/0.21.1/image/enum.DynamicImage.html
     /// [`image` crate]: https://docs.rs/image
@@ -54,6 +177,9 @@ impl Image {
         gpu: &mut Gpu,
         image: &image::DynamicImage,
     ) -> Result<Image> {
+        let (width, height) = image.dimensions();
+        gpu.stats_mut().bytes_uploaded += 4 * width as u64 * height as u64;
+
         let texture = gpu.upload_texture(&image);
 
         Ok(Image { texture })
@@ -96,6 +222,16 @@ impl Image {
         self.texture.height()
     }
 
+    // Returns whether other clones of the image are alive
+    pub(crate) fn is_shared(&self) -> bool {
+        self.texture.is_shared()
+    }
+
+    // Returns whether both images use the same texture on the GPU
+    pub(crate) fn is_same(&self, other: &Image) -> bool {
+        self.texture.is_same(&other.texture)
+    }
+
     /// Draws the [`Image`] on the given [`Target`].
     ///
     /// [`Image`]: struct.Image.html
@@ -180,6 +180,20 @@ impl Mesh {
         }
     }
 
+    // Adds the triangles of another mesh, which are drawn on top
+    pub(crate) fn append(&mut self, other: Mesh) {
+        let offset = self.buffers.vertices.len() as u32;
+
+        self.buffers.vertices.extend(other.buffers.vertices);
+        self.buffers.indices.extend(
+            other
+                .buffers
+                .indices
+                .into_iter()
+                .map(|index| index + offset),
+        );
+    }
+
     /// Draws the [`Mesh`] on the given [`Target`].
     ///
     /// [`Mesh`]: struct.Mesh.html
@@ -1,4 +1,11 @@
 use nalgebra;
 
 /// A 2D point.
+///
+/// When the `mint` feature is enabled, it can be converted from and into a
+/// [`mint::Point2<f32>`] using `From` and `Into`. This lets you use any math
+/// library compatible with [`mint`] in your game.
+///
+/// [`mint::Point2<f32>`]: https://docs.rs/mint/0.5/mint/struct.Point2.html
+/// [`mint`]: https://docs.rs/mint
 pub type Point = nalgebra::Point2<f32>;
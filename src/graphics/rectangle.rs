@@ -21,7 +21,9 @@ impl Rectangle<f32> {
     ///
     /// [`Point`]: type.Point.html
     /// [`Rectangle`]: struct.Rectangle.html
-    pub fn contains(&self, point: Point) -> bool {
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        let point = point.into();
+
         self.x <= point.x
             && point.x <= self.x + self.width
             && self.y <= point.y
@@ -33,9 +35,6 @@ impl Rectangle<f32> {
     /// [`Point`]: type.Point.html
     /// [`Rectangle`]: struct.Rectangle.html
     pub fn center(&self) -> Point {
-        Point::new(
-            self.x + self.width / 2.0, 
-            self.y + self.height / 2.0,
-        )
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
     }
 }
@@ -1,6 +1,8 @@
 use crate::graphics::gpu::{self, Font, Gpu, TargetView, Texture, Vertex};
 use crate::graphics::{Color, Transformation};
 
+use std::mem;
+
 /// A rendering target.
 ///
 /// In Coffee, all the draw operations need an explicit [`Target`]. You can
@@ -40,6 +42,8 @@ impl<'a> Target<'a> {
         height: f32,
         transformation: Transformation,
     ) -> Self {
+        gpu.stats_mut().canvas_switches += 1;
+
         let mut target = Self::new(gpu, view, width, height);
         target.transformation = transformation * target.transformation;
         target
@@ -100,6 +104,12 @@ impl<'a> Target<'a> {
         vertices: &[Vertex],
         indices: &[u32],
     ) {
+        let stats = self.gpu.stats_mut();
+        stats.draw_calls += 1;
+        stats.vertices += vertices.len() as u32;
+        stats.bytes_uploaded +=
+            (mem::size_of_val(vertices) + mem::size_of_val(indices)) as u64;
+
         self.gpu.draw_triangles(
             vertices,
             indices,
@@ -113,6 +123,12 @@ impl<'a> Target<'a> {
         texture: &Texture,
         instances: &[gpu::Quad],
     ) {
+        let stats = self.gpu.stats_mut();
+        stats.draw_calls += 1;
+        stats.quads += instances.len() as u32;
+        stats.texture_binds += 1;
+        stats.bytes_uploaded += mem::size_of_val(instances) as u64;
+
         self.gpu.draw_texture_quads(
             texture,
             instances,
@@ -122,6 +138,10 @@ impl<'a> Target<'a> {
     }
 
     pub(in crate::graphics) fn draw_font(&mut self, font: &mut Font) {
+        let stats = self.gpu.stats_mut();
+        stats.draw_calls += 1;
+        stats.texture_binds += 1;
+
         self.gpu.draw_font(font, &self.view, self.transformation);
     }
 }
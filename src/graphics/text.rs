@@ -42,6 +42,92 @@ impl Default for Text<'static> {
     }
 }
 
+/// A section of text made of spans with different colors and styles.
+///
+/// The spans flow one after the other, wrapping inside the bounds like a
+/// single [`Text`].
+///
+/// [`Text`]: struct.Text.html
+#[derive(Clone, PartialEq, Debug)]
+pub struct RichText<'a> {
+    /// Text spans
+    pub spans: &'a [TextSpan<'a>],
+
+    /// Text position
+    pub position: Point,
+
+    /// Text bounds, in screen coordinates
+    pub bounds: (f32, f32),
+
+    /// Text size
+    pub size: f32,
+
+    /// Text horizontal alignment
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// Text vertical alignment
+    pub vertical_alignment: VerticalAlignment,
+}
+
+impl Default for RichText<'static> {
+    #[inline]
+    fn default() -> RichText<'static> {
+        RichText {
+            spans: &[],
+            position: Point::new(0.0, 0.0),
+            bounds: (f32::INFINITY, f32::INFINITY),
+            size: 16.0,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+}
+
+/// A piece of [`RichText`] with its own color and style.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextSpan<'a> {
+    /// Span content
+    pub content: &'a str,
+
+    /// Span color
+    pub color: Color,
+
+    /// Span [`FontStyle`]
+    ///
+    /// [`FontStyle`]: enum.FontStyle.html
+    pub style: FontStyle,
+}
+
+/// The style of the glyphs of some text.
+///
+/// A [`Font`] uses its regular face for the styles it does not have. You can
+/// add faces to a [`Font`] with [`Font::add_style`].
+///
+/// [`Font`]: struct.Font.html
+/// [`Font::add_style`]: struct.Font.html#method.add_style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    /// Regular glyphs
+    Regular,
+
+    /// Bold glyphs
+    Bold,
+
+    /// Italic glyphs
+    Italic,
+
+    /// Bold and italic glyphs
+    BoldItalic,
+}
+
+impl Default for FontStyle {
+    fn default() -> FontStyle {
+        FontStyle::Regular
+    }
+}
+
 /// The horizontal alignment of some resource.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HorizontalAlignment {
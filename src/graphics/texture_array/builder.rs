@@ -101,6 +101,9 @@ impl Builder {
             })
             .collect();
 
+        gpu.stats_mut().bytes_uploaded +=
+            images.len() as u64 * 4 * self.width as u64 * self.height as u64;
+
         let texture = gpu.upload_texture_array(&images[..]);
 
         TextureArray {
@@ -95,7 +95,7 @@ impl Loader {
     where
         F: 'static + Fn(TextureArray, Indices) -> Result<T>,
     {
-        let total_work = self.paths.len() as u32 + 1;
+        let total_work = self.paths.len() as f32 + 1.0;
 
         Task::sequence(total_work, move |task| {
             let mut builder = Builder::new(self.width, self.height);
@@ -103,16 +103,18 @@ impl Loader {
             let mut indices = Vec::new();
 
             while let Some(next) = work_todo.pop_front() {
+                task.check()?;
+
                 let index = builder.add(next)?;
                 indices.push(index);
 
-                task.notify_progress(1);
+                task.notify_progress(1.0);
             }
 
             let result =
-                on_completion(builder.build(task.gpu()), Indices(indices))?;
+                on_completion(builder.build(task.gpu()?), Indices(indices))?;
 
-            task.notify_progress(1);
+            task.notify_progress(1.0);
 
             Ok(result)
         })
@@ -37,7 +37,9 @@ impl Transformation {
     /// Creates a translate transformation.
     ///
     /// You can use this to pan your camera, for example.
-    pub fn translate(translation: Vector) -> Transformation {
+    pub fn translate(translation: impl Into<Vector>) -> Transformation {
+        let translation = translation.into();
+
         Transformation(Matrix3::new_translation(&Vector::new(
             translation.x,
             translation.y,
@@ -55,8 +57,8 @@ impl Transformation {
     ///
     /// It allows you to scale each axis independently. You should rarely need
     /// this.
-    pub fn nonuniform_scale(scale: Vector) -> Transformation {
-        Transformation(Matrix3::new_nonuniform_scaling(&scale))
+    pub fn nonuniform_scale(scale: impl Into<Vector>) -> Transformation {
+        Transformation(Matrix3::new_nonuniform_scaling(&scale.into()))
     }
 
     /// Creates a rotation transformation (in radians).
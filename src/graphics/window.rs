@@ -1,15 +1,35 @@
+mod clipboard;
 mod cursor_icon;
 mod frame;
+mod frame_stats;
 mod settings;
 
 pub(crate) use winit;
 
+pub use clipboard::Clipboard;
 pub use cursor_icon::CursorIcon;
 pub use frame::Frame;
+pub use frame_stats::FrameStats;
 pub use settings::Settings;
 
+#[cfg(feature = "audio")]
+use crate::audio::Audio;
+use crate::debug;
+#[cfg(feature = "dialogs")]
+use crate::dialog::FileDialog;
 use crate::graphics::gpu::{self, Gpu};
-use crate::Result;
+use crate::graphics::{GpuStats, Point};
+use crate::input::keyboard::KeyCode;
+use crate::input::{gamepad, VirtualCursor};
+#[cfg(feature = "dialogs")]
+use crate::load::Task;
+use crate::{Clock, Error, Result, Timer};
+
+use std::cell::{Cell, RefCell};
+#[cfg(feature = "dialogs")]
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
 
 /// An open window.
 ///
@@ -17,52 +37,368 @@ use crate::Result;
 ///
 /// [`Game`]: ../trait.Game.html
 pub struct Window {
-    gpu: Gpu,
-    surface: gpu::Surface,
+    device: Option<Device>,
     width: f32,
     height: f32,
     is_fullscreen: bool,
     cursor_icon: Option<winit::window::CursorIcon>,
+    clipboard: Clipboard,
+    gamepads: Option<gamepad::Tracker>,
+    virtual_cursor: Option<VirtualCursor>,
+    is_closed: Cell<bool>,
+    tick_duration: Duration,
+    total_ticks: u64,
+    clock: Clock,
+    gpu_stats: GpuStats,
+    debug_sections: Rc<RefCell<debug::Sections>>,
+    debug_controls: Rc<Cell<debug::Controls>>,
+    #[cfg(feature = "audio")]
+    audio: Audio,
+}
+
+// The graphics context of a `Window`, which a stub has none of
+struct Device {
+    gpu: Gpu,
+    surface: gpu::Surface,
 }
 
 impl Window {
     pub(crate) fn new(
         settings: Settings,
         event_loop: &winit::event_loop::EventLoop<()>,
+    ) -> Result<Window> {
+        Self::with_attributes(settings, true, true, event_loop)
+    }
+
+    pub(crate) fn with_attributes(
+        settings: Settings,
+        is_visible: bool,
+        vsync: bool,
+        event_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<Window> {
         let (width, height) = settings.size;
         let is_fullscreen = settings.fullscreen;
+        let gpu_settings = settings.gpu.clone();
+
+        let (gpu, surface) = Gpu::for_window(
+            settings.into_builder(event_loop).with_visible(is_visible),
+            vsync,
+            &gpu_settings,
+            event_loop,
+        )?;
 
-        let (gpu, surface) =
-            Gpu::for_window(settings.into_builder(event_loop), event_loop)?;
+        #[cfg(feature = "log")]
+        log::info!("Using graphics adapter {}", gpu.adapter_info());
 
         Ok(Window {
             is_fullscreen,
-            gpu,
-            surface,
+            ..Window::with_device(Some(Device { gpu, surface }), width, height)
+        })
+    }
+
+    pub(crate) fn stub(width: u32, height: u32) -> Window {
+        // Headless runs should not depend on the connected gamepads
+        Window {
+            gamepads: None,
+            ..Window::with_device(None, width, height)
+        }
+    }
+
+    fn with_device(device: Option<Device>, width: u32, height: u32) -> Window {
+        Window {
+            device,
+            is_fullscreen: false,
             width: width as f32,
             height: height as f32,
             cursor_icon: Some(winit::window::CursorIcon::Default),
-        })
+            clipboard: Clipboard::new(),
+            gamepads: gamepad::Tracker::new(),
+            virtual_cursor: None,
+            is_closed: Cell::new(false),
+            tick_duration: Duration::from_secs(0),
+            total_ticks: 0,
+            clock: Clock::new(),
+            gpu_stats: GpuStats::default(),
+            debug_sections: Rc::new(RefCell::new(debug::Sections::new())),
+            debug_controls: Rc::new(Cell::new(debug::Controls::default())),
+            #[cfg(feature = "audio")]
+            audio: Audio::new(),
+        }
+    }
+
+    /// Closes the [`Window`], ending the game loop gracefully.
+    ///
+    /// The game loop ends after the current frame. Then, [`Game::on_exit`] is
+    /// called and the game state is dropped.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Game::on_exit`]: ../trait.Game.html#method.on_exit
+    pub fn close(&self) {
+        self.is_closed.set(true);
+    }
+
+    /// Returns true if [`close`] has been called.
+    ///
+    /// [`close`]: #method.close
+    pub fn is_closed(&self) -> bool {
+        self.is_closed.get()
+    }
+
+    /// Returns true if the [`Window`] has no area.
+    ///
+    /// This happens when the window is minimized on some platforms, or when
+    /// it is resized to zero width or height. Nothing is drawn while the
+    /// [`Window`] has no area.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn is_minimized(&self) -> bool {
+        self.width < 1.0 || self.height < 1.0
+    }
+
+    /// Returns the duration of a tick, defined by
+    /// [`Game::TICKS_PER_SECOND`].
+    ///
+    /// Use it in [`Game::update`] to express rates in units per second.
+    ///
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    pub fn tick_duration(&self) -> Duration {
+        self.tick_duration
+    }
+
+    /// Returns the total amount of ticks since the game started, including
+    /// the current one.
+    ///
+    /// See [`Timer::ticks`].
+    ///
+    /// [`Timer::ticks`]: ../struct.Timer.html#method.ticks
+    pub fn total_ticks(&self) -> u64 {
+        self.total_ticks
+    }
+
+    /// Returns the elapsed time of all the ticks so far, including the
+    /// current one.
+    ///
+    /// Unlike the game time of the [`Clock`], it cannot be paused nor scaled.
+    /// See [`Timer::total_time`].
+    ///
+    /// [`Clock`]: ../struct.Clock.html
+    /// [`Timer::total_time`]: ../struct.Timer.html#method.total_time
+    pub fn total_time(&self) -> Duration {
+        crate::timer::game_time(self.tick_duration, self.total_ticks)
+    }
+
+    pub(crate) fn sync_ticks(&mut self, timer: &Timer) {
+        self.tick_duration = timer.tick_duration();
+
+        while self.total_ticks < timer.ticks() {
+            self.clock.advance(self.tick_duration);
+            self.total_ticks += 1;
+        }
+    }
+
+    /// Returns the [`Clock`] that keeps track of game time.
+    ///
+    /// [`Clock`]: ../struct.Clock.html
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the [`Clock`] that keeps track of game time, allowing you to
+    /// pause it or change its scale.
+    ///
+    /// [`Clock`]: ../struct.Clock.html
+    pub fn clock_mut(&mut self) -> &mut Clock {
+        &mut self.clock
     }
 
     /// Returns the [`Gpu`] linked to the [`Window`].
     ///
+    /// # Panics
+    /// It panics if the [`Window`] has no graphics device, which only happens
+    /// in a headless [`Runner`] created with [`Runner::without_gpu`].
+    ///
     /// [`Gpu`]: struct.Gpu.html
     /// [`Window`]: struct.Window.html
+    /// [`Runner`]: ../headless/struct.Runner.html
+    /// [`Runner::without_gpu`]: ../headless/struct.Runner.html#method.without_gpu
     pub fn gpu(&mut self) -> &mut Gpu {
-        &mut self.gpu
+        match self.try_gpu() {
+            Ok(gpu) => gpu,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    pub(crate) fn try_gpu(&mut self) -> Result<&mut Gpu> {
+        self.device
+            .as_mut()
+            .map(|device| &mut device.gpu)
+            .ok_or(Error::GpuUnavailable)
+    }
+
+    pub(crate) fn frame(&mut self) -> Result<Frame<'_>> {
+        let Device { gpu, surface } =
+            self.device.as_mut().ok_or(Error::GpuUnavailable)?;
+
+        surface.acquire(gpu)?;
+
+        Ok(Frame::new(self))
+    }
+
+    pub(crate) fn target(&mut self) -> (&mut Gpu, &gpu::TargetView) {
+        let Device { gpu, surface } =
+            self.device.as_mut().expect("Acquired frame");
+
+        (gpu, surface.target())
     }
 
-    pub(crate) fn frame(&mut self) -> Frame<'_> {
-        Frame::new(self)
+    /// Returns the [`Clipboard`] of the platform.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// Returns the [`Audio`] output of the [`Window`].
+    ///
+    /// It is opened when the [`Window`] is created, and it is silent if no
+    /// output device is available.
+    ///
+    /// This method is only available when the `audio` feature is enabled.
+    ///
+    /// [`Audio`]: ../audio/struct.Audio.html
+    /// [`Window`]: struct.Window.html
+    #[cfg(feature = "audio")]
+    pub fn audio(&self) -> Audio {
+        self.audio.clone()
+    }
+
+    /// Creates a [`Task`] that shows a native dialog to open a file with one
+    /// of the given filters.
+    ///
+    /// It produces the chosen file, or `None` if the dialog is cancelled.
+    /// Filters are pairs of a name and some extensions, like
+    /// `("Images", &["png", "jpg"])`.
+    ///
+    /// Running a [`Task`] blocks the game loop until it finishes. Spawn the
+    /// jobs of a [`FileDialog`] with a [`Handle`] instead to keep your game
+    /// running while the dialog is open.
+    ///
+    /// This method is only available when the `dialogs` feature is enabled.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`FileDialog`]: ../dialog/struct.FileDialog.html
+    /// [`Handle`]: ../load/struct.Handle.html
+    #[cfg(feature = "dialogs")]
+    pub fn open_file_dialog(
+        &self,
+        filters: &[(&str, &[&str])],
+    ) -> Task<Option<PathBuf>> {
+        let dialog = file_dialog(filters);
+
+        Task::parallel(vec![dialog.open()]).map(|mut paths| paths.pop()?)
+    }
+
+    /// Creates a [`Task`] that shows a native dialog to open many files with
+    /// the given filters.
+    ///
+    /// It produces the chosen files, which are none if the dialog is
+    /// cancelled. Check out [`open_file_dialog`] for more details.
+    ///
+    /// This method is only available when the `dialogs` feature is enabled.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`open_file_dialog`]: #method.open_file_dialog
+    #[cfg(feature = "dialogs")]
+    pub fn open_files_dialog(
+        &self,
+        filters: &[(&str, &[&str])],
+    ) -> Task<Vec<PathBuf>> {
+        let dialog = file_dialog(filters);
+
+        Task::parallel(vec![dialog.open_multiple()])
+            .map(|mut paths| paths.pop().unwrap_or_default())
+    }
+
+    /// Creates a [`Task`] that shows a native dialog to save a file, with
+    /// the given default name and filters.
+    ///
+    /// It produces the chosen file, or `None` if the dialog is cancelled.
+    /// Check out [`open_file_dialog`] for more details.
+    ///
+    /// This method is only available when the `dialogs` feature is enabled.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`open_file_dialog`]: #method.open_file_dialog
+    #[cfg(feature = "dialogs")]
+    pub fn save_file_dialog(
+        &self,
+        default_name: &str,
+        filters: &[(&str, &[&str])],
+    ) -> Task<Option<PathBuf>> {
+        let dialog = file_dialog(filters).default_path(default_name);
+
+        Task::parallel(vec![dialog.save()]).map(|mut paths| paths.pop()?)
+    }
+
+    /// Rumbles the gamepad with the given [`Id`] for the given duration.
+    ///
+    /// `strong` and `weak` are the magnitudes of the strong and weak motors of
+    /// the gamepad, in the `[0.0, 1.0]` interval. A new rumble replaces the
+    /// current one of the gamepad, if any.
+    ///
+    /// Nothing happens if the gamepad is not connected or does not support
+    /// force feedback.
+    ///
+    /// [`Id`]: ../input/gamepad/struct.Id.html
+    pub fn rumble(
+        &mut self,
+        gamepad: gamepad::Id,
+        strong: f32,
+        weak: f32,
+        duration: Duration,
+    ) {
+        if let Some(gamepads) = &mut self.gamepads {
+            gamepads.rumble(gamepad, strong, weak, duration);
+        }
+    }
+
+    /// Stops the current rumble of the gamepad with the given [`Id`], if any.
+    ///
+    /// [`Id`]: ../input/gamepad/struct.Id.html
+    pub fn stop_rumble(&mut self, gamepad: gamepad::Id) {
+        if let Some(gamepads) = &mut self.gamepads {
+            gamepads.stop_rumble(gamepad);
+        }
+    }
+
+    /// Returns the [`VirtualCursor`] of the [`Window`], if enabled.
+    ///
+    /// [`VirtualCursor`]: ../input/struct.VirtualCursor.html
+    /// [`Window`]: struct.Window.html
+    pub fn virtual_cursor(&self) -> Option<&VirtualCursor> {
+        self.virtual_cursor.as_ref()
+    }
+
+    /// Enables or disables the [`VirtualCursor`] of the [`Window`].
+    ///
+    /// [`VirtualCursor`]: ../input/struct.VirtualCursor.html
+    /// [`Window`]: struct.Window.html
+    pub fn set_virtual_cursor(
+        &mut self,
+        virtual_cursor: Option<VirtualCursor>,
+    ) {
+        self.virtual_cursor = virtual_cursor;
     }
 
     /// Toggles the [`Window`]'s fullscreen state.
     ///
     /// [`Window`]: struct.Window.html
     pub fn toggle_fullscreen(&mut self) {
-        let window = self.surface.window();
+        let window = match &self.device {
+            Some(device) => device.surface.window(),
+            None => return,
+        };
 
         let monitor = if self.is_fullscreen {
             None
@@ -90,16 +426,195 @@ impl Window {
         self.height
     }
 
-    pub(crate) fn swap_buffers(&mut self) {
-        self.surface.swap_buffers(&mut self.gpu);
+    /// Sets the position of the input method editor of the [`Window`].
+    ///
+    /// Use this to place the candidate window of the IME next to the text
+    /// field the player is typing in. The candidate window shows the text
+    /// being composed until it is committed as [`TextEntered`] events.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`TextEntered`]: ../input/keyboard/enum.Event.html#variant.TextEntered
+    pub fn set_ime_position(&mut self, position: Point) {
+        if let Some(device) = &self.device {
+            device.surface.window().set_ime_position(
+                winit::dpi::PhysicalPosition::new(
+                    position.x as f64,
+                    position.y as f64,
+                ),
+            );
+        }
+    }
+
+    /// Returns the [`FrameStats`] of the last presented frame.
+    ///
+    /// [`FrameStats`]: struct.FrameStats.html
+    pub fn frame_stats(&self) -> FrameStats {
+        self.device
+            .as_ref()
+            .map(|device| device.surface.frame_stats())
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`GpuStats`] of the last presented frame.
+    ///
+    /// [`GpuStats`]: struct.GpuStats.html
+    pub fn gpu_stats(&self) -> GpuStats {
+        self.gpu_stats
+    }
+
+    pub(crate) fn gamepads(&mut self) -> Option<&mut gamepad::Tracker> {
+        self.gamepads.as_mut()
+    }
+
+    pub(crate) fn virtual_cursor_mut(&mut self) -> Option<&mut VirtualCursor> {
+        self.virtual_cursor.as_mut()
+    }
+
+    pub(crate) fn swap_buffers(&mut self) -> Result<()> {
+        let Device { gpu, surface } =
+            self.device.as_mut().ok_or(Error::GpuUnavailable)?;
+
+        self.gpu_stats = gpu.take_stats();
+        surface.swap_buffers(gpu)
+    }
+
+    pub(crate) fn restore(&mut self) {
+        let window = match &self.device {
+            Some(device) => device.surface.window(),
+            None => return,
+        };
+
+        if self.is_fullscreen {
+            window.set_fullscreen(None);
+            self.is_fullscreen = false;
+        }
+
+        window.set_cursor_visible(true);
+
+        // Not every platform supports cursor grabbing
+        let _ = window.set_cursor_grab(false);
+    }
+
+    pub(crate) fn resume(&mut self) {
+        if let Some(Device { gpu, surface }) = &mut self.device {
+            let size = surface.window().inner_size();
+
+            // The graphics context may have been torn down while suspended
+            surface.resize(gpu, size);
+            gpu.lose_contents();
+        }
+    }
+
+    /// Runs the given function and records the time it takes in a custom
+    /// section of the [`Debug`] view.
+    ///
+    /// It works like [`Debug::time`], but you can use it in
+    /// [`Game::update`] and [`Game::interact`]. Nothing is recorded when the
+    /// debug view is compiled out.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    /// [`Debug::time`]: ../struct.Debug.html#method.time
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    pub fn debug_time<T>(&self, label: &str, f: impl FnOnce() -> T) -> T {
+        self.debug_sections.borrow_mut().begin(label);
+        let value = f();
+        self.debug_sections.borrow_mut().end();
+
+        value
+    }
+
+    /// Shows a value with the given name in the "Watches" section of the
+    /// [`Debug`] view.
+    ///
+    /// It works like [`Debug::watch`], but you can use it in
+    /// [`Game::update`] and [`Game::interact`]. Nothing is recorded when the
+    /// debug view is compiled out.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    /// [`Debug::watch`]: ../struct.Debug.html#method.watch
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    pub fn debug_watch(&self, name: &str, value: impl std::fmt::Display) {
+        self.debug_sections.borrow_mut().watch(name, value);
+    }
+
+    pub(crate) fn debug_sections(&self) -> Rc<RefCell<debug::Sections>> {
+        self.debug_sections.clone()
+    }
+
+    /// Shows the [`Debug`] view.
+    ///
+    /// It has no effect if the [`Debug`] view is compiled out.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    pub fn show_debug(&mut self) {
+        let mut controls = self.debug_controls.get();
+        controls.is_visible = true;
+
+        self.debug_controls.set(controls);
+    }
+
+    /// Hides the [`Debug`] view.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    pub fn hide_debug(&mut self) {
+        let mut controls = self.debug_controls.get();
+        controls.is_visible = false;
+
+        self.debug_controls.set(controls);
+    }
+
+    /// Returns true if the [`Debug`] view is visible.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    pub fn is_debug_visible(&self) -> bool {
+        self.debug_controls.get().is_visible
+    }
+
+    /// Sets the key that toggles the [`Debug`] view. If `None`, the
+    /// [`Debug`] view can only be shown and hidden programmatically.
+    ///
+    /// See [`Debug::set_toggle_key`].
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    /// [`Debug::set_toggle_key`]: ../struct.Debug.html#method.set_toggle_key
+    pub fn set_debug_toggle_key(&mut self, toggle_key: Option<KeyCode>) {
+        let mut controls = self.debug_controls.get();
+        controls.toggle_key = toggle_key;
+
+        self.debug_controls.set(controls);
+    }
+
+    pub(crate) fn debug_controls(&self) -> Rc<Cell<debug::Controls>> {
+        self.debug_controls.clone()
+    }
+
+    pub(crate) fn scale_factor(&self) -> f64 {
+        self.device
+            .as_ref()
+            .map(|device| device.surface.window().scale_factor())
+            .unwrap_or(1.0)
+    }
+
+    pub(crate) fn id(&self) -> winit::window::WindowId {
+        self.native().id()
     }
 
     pub(crate) fn request_redraw(&mut self) {
-        self.surface.request_redraw();
+        if let Some(device) = &mut self.device {
+            device.surface.request_redraw();
+        }
     }
 
     pub(crate) fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
-        self.surface.resize(&mut self.gpu, new_size);
+        // A surface with no area cannot be created, we keep the old one
+        // around until the window grows again
+        if new_size.width > 0 && new_size.height > 0 {
+            if let Some(Device { gpu, surface }) = &mut self.device {
+                surface.resize(gpu, new_size);
+            }
+        }
 
         self.width = new_size.width as f32;
         self.height = new_size.height as f32;
@@ -110,15 +625,28 @@ impl Window {
         new_cursor: Option<winit::window::CursorIcon>,
     ) {
         if self.cursor_icon != new_cursor {
-            if let Some(cursor_icon) = new_cursor {
-                self.surface.window().set_cursor_icon(cursor_icon);
+            if let Some(device) = &self.device {
+                let window = device.surface.window();
+
+                if let Some(cursor_icon) = new_cursor {
+                    window.set_cursor_icon(cursor_icon);
+                }
+
+                window.set_cursor_visible(new_cursor.is_some());
             }
-            self.surface
-                .window()
-                .set_cursor_visible(new_cursor.is_some());
+
             self.cursor_icon = new_cursor;
         }
     }
+
+    fn native(&self) -> &winit::window::Window {
+        self.try_native()
+            .expect("Stub windows have no native window")
+    }
+
+    fn try_native(&self) -> Option<&winit::window::Window> {
+        self.device.as_ref().map(|device| device.surface.window())
+    }
 }
 
 impl std::fmt::Debug for Window {
@@ -130,3 +658,63 @@ impl std::fmt::Debug for Window {
         )
     }
 }
+
+#[cfg(feature = "dialogs")]
+fn file_dialog(filters: &[(&str, &[&str])]) -> FileDialog {
+    filters
+        .iter()
+        .fold(FileDialog::new(), |dialog, (name, extensions)| {
+            dialog.filter(name, extensions)
+        })
+}
+
+#[cfg(feature = "raw-window-handle")]
+impl Window {
+    /// Returns the native handle of the [`Window`], or `None` if it has no
+    /// native window, like in a headless [`Runner`].
+    ///
+    /// It is only available when the `raw-window-handle` feature is enabled.
+    /// The same caveats as for the `HasRawWindowHandle` implementation apply.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Runner`]: ../headless/struct.Runner.html
+    pub fn raw_window_handle(
+        &self,
+    ) -> Option<raw_window_handle::RawWindowHandle> {
+        self.try_native()
+            .map(raw_window_handle::HasRawWindowHandle::raw_window_handle)
+    }
+}
+
+/// Access the native handle of the [`Window`].
+///
+/// This is an escape hatch, only available when the `raw-window-handle`
+/// feature is enabled. Safe uses include anchoring native dialogs to the
+/// [`Window`], integrating with the operating system (like taskbar progress
+/// or window decorations), and attaching the handle to crash reports.
+///
+/// Rendering into the [`Window`] with another graphics library is generally
+/// __not__ supported. Coffee already owns a swapchain for the [`Window`], and
+/// two swapchains presenting to the same window do not mix on most
+/// platforms: expect flickering or one of them to never show up. Render into
+/// a [`Canvas`] or an [`Image`] instead, if possible.
+///
+/// # Panics
+/// It panics if the [`Window`] has no native window, which only happens in a
+/// headless [`Runner`]. Use [`Window::raw_window_handle`] if you need to
+/// handle that case.
+///
+/// [`Window`]: struct.Window.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Image`]: struct.Image.html
+/// [`Runner`]: ../headless/struct.Runner.html
+/// [`Window::raw_window_handle`]: struct.Window.html#method.raw_window_handle
+#[cfg(feature = "raw-window-handle")]
+#[allow(unsafe_code)]
+unsafe impl raw_window_handle::HasRawWindowHandle for Window {
+    fn raw_window_handle(&self) -> raw_window_handle::RawWindowHandle {
+        // The handle stays valid as long as the `Window` is alive, because
+        // the surface owns the native window
+        raw_window_handle::HasRawWindowHandle::raw_window_handle(self.native())
+    }
+}
@@ -0,0 +1,51 @@
+use clipboard::{ClipboardContext, ClipboardProvider};
+
+/// The clipboard of the platform.
+///
+/// You can obtain it using [`Window::clipboard`].
+///
+/// [`Window::clipboard`]: struct.Window.html#method.clipboard
+pub struct Clipboard {
+    context: Option<ClipboardContext>,
+}
+
+impl Clipboard {
+    pub(crate) fn new() -> Clipboard {
+        Clipboard {
+            context: ClipboardContext::new().ok(),
+        }
+    }
+
+    /// Reads the text contents of the [`Clipboard`].
+    ///
+    /// Returns `None` if the [`Clipboard`] is not available or does not
+    /// contain any text. This may block briefly.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn read_text(&mut self) -> Option<String> {
+        self.context
+            .as_mut()
+            .and_then(|context| context.get_contents().ok())
+    }
+
+    /// Writes the given text to the [`Clipboard`].
+    ///
+    /// Nothing happens if the [`Clipboard`] is not available.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn write_text(&mut self, text: &str) {
+        if let Some(context) = &mut self.context {
+            let _ = context.set_contents(String::from(text));
+        }
+    }
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Clipboard {{ is_available: {} }}",
+            self.context.is_some()
+        )
+    }
+}
@@ -48,17 +48,10 @@ impl<'a> Frame<'a> {
     ///
     /// [`Target`]: struct.Target.html
     pub fn as_target(&mut self) -> Target<'_> {
-        let Window {
-            surface,
-            gpu,
-            width,
-            height,
-            ..
-        } = &mut self.window;
+        let (width, height) = (self.window.width, self.window.height);
+        let (gpu, view) = self.window.target();
 
-        let view = surface.target();
-
-        Target::new(gpu, view, *width, *height)
+        Target::new(gpu, view, width, height)
     }
 
     /// Clear the frame with the given [`Color`].
@@ -0,0 +1,35 @@
+use std::time;
+
+/// Frame pacing statistics of a [`Window`].
+///
+/// They are updated every time a frame is presented and can help you find out
+/// whether stutter is caused by your game logic, the GPU, or presentation.
+///
+/// You can obtain them with [`Window::frame_stats`].
+///
+/// [`Window`]: struct.Window.html
+/// [`Window::frame_stats`]: struct.Window.html#method.frame_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameStats {
+    /// The time spent on the CPU during the last frame, excluding the time
+    /// blocked waiting on the GPU.
+    pub cpu_frame_time: time::Duration,
+
+    /// The time blocked waiting on the GPU during the last frame.
+    ///
+    /// This includes the time spent acquiring the next swapchain image and
+    /// presenting the frame (i.e. V-Sync).
+    pub gpu_wait_time: time::Duration,
+
+    /// The total amount of frames that could not be presented in time.
+    ///
+    /// _Note:_ The OpenGL backend is not able to detect missed presents yet.
+    pub presents_missed: u32,
+
+    /// The total amount of times the swapchain has been recreated.
+    ///
+    /// This normally happens when the [`Window`] is resized.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub swapchain_recreations: u32,
+}
@@ -1,4 +1,5 @@
 use super::winit;
+use crate::graphics::GpuSettings;
 
 /// A window configuration.
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -17,6 +18,9 @@ pub struct Settings {
 
     /// Defines whether or not the window should start maximized.
     pub maximized: bool,
+
+    /// The graphics adapter configuration.
+    pub gpu: GpuSettings,
 }
 
 impl Settings {
@@ -0,0 +1,517 @@
+//! Run your game logic without a game loop.
+//!
+//! A [`Runner`] loads your [`Game`] into a hidden window and lets you drive it
+//! step by step: feed it input events, run a specific amount of update ticks
+//! and inspect the resulting game state. This is useful to write integration
+//! tests for your game.
+//!
+//! The game loop is never started. Updates are not tied to the wall clock, so
+//! a [`Runner`] is deterministic as long as your [`Game`] is.
+//!
+//! A [`Runner`] created with [`Runner::without_gpu`] does not need a
+//! graphics device at all, which makes it a good fit for CI environments.
+//! Its [`Window`] is a stub with a fixed size, and loading or drawing
+//! graphics fails with [`Error::GpuUnavailable`]. If your tests need to draw,
+//! use [`Runner::new`] instead, which loads your game into a hidden window.
+//! On machines without a GPU, you can still use a software rasterizer for it
+//! (for instance, Mesa's `llvmpipe` on Linux).
+//!
+//! # Determinism
+//! Coffee never exposes the wall clock to [`Game::update`]. The only time
+//! source available there is the amount of ticks, exposed by the [`Window`]
+//! with [`Window::total_ticks`] and [`Window::total_time`]. Therefore, the
+//! same sequence of inputs produces the same sequence of updates on any
+//! machine, as long as your [`Game`] follows some rules:
+//!
+//! - Do not read the wall clock in [`Game::update`] (`Instant::now`,
+//!   `SystemTime::now`...). Input events carry the time they were received;
+//!   only use it to react in [`Game::interact`], never to simulate.
+//! - Seed any random number generator and keep it in your game state.
+//! - Do not rely on the iteration order of `HashMap` and `HashSet`, which is
+//!   randomized.
+//! - Floating point operations are deterministic on the same target, but
+//!   results may differ between targets, compilers and optimization levels.
+//!   Use fixed-point arithmetic if you need to synchronize different
+//!   platforms.
+//!
+//! You can use [`simulate`] to check that your game follows these rules by
+//! replaying the same inputs twice and comparing the results.
+//!
+//! [`Runner`]: struct.Runner.html
+//! [`Runner::without_gpu`]: struct.Runner.html#method.without_gpu
+//! [`Runner::new`]: struct.Runner.html#method.new
+//! [`Error::GpuUnavailable`]: ../enum.Error.html#variant.GpuUnavailable
+//! [`Game`]: ../trait.Game.html
+//! [`Game::update`]: ../trait.Game.html#method.update
+//! [`Game::interact`]: ../trait.Game.html#method.interact
+//! [`Window`]: ../graphics/struct.Window.html
+//! [`Window::total_ticks`]: ../graphics/struct.Window.html#method.total_ticks
+//! [`Window::total_time`]: ../graphics/struct.Window.html#method.total_time
+//! [`simulate`]: fn.simulate.html
+use crate::game::{guard, step};
+use crate::graphics::window::winit;
+use crate::graphics::{Window, WindowSettings};
+use crate::input::{self, keyboard, Input};
+use crate::load::{Progress, Task};
+use crate::{Game, Result, Timer};
+
+use std::time::Duration;
+
+/// A headless driver of a [`Game`].
+///
+/// ```no_run
+/// # use coffee::graphics::{Frame, Window};
+/// # use coffee::load::Task;
+/// # use coffee::{Game, Result, Timer};
+/// # use coffee::headless::Runner;
+/// struct Counter {
+///     ticks: u32,
+/// }
+///
+/// impl Game for Counter {
+///     type Input = ();
+///     type LoadingScreen = ();
+///
+///     fn load(_window: &Window) -> Task<Counter> {
+///         Task::succeed(|| Counter { ticks: 0 })
+///     }
+///
+///     fn update(&mut self, _window: &Window) -> Result<()> {
+///         self.ticks += 1;
+///
+///         Ok(())
+///     }
+///
+///     fn draw(
+///         &mut self,
+///         _frame: &mut Frame<'_>,
+///         _timer: &Timer,
+///     ) -> Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut runner = Runner::<Counter>::without_gpu((640, 480))?;
+///
+/// runner.run(60)?;
+///
+/// assert_eq!(runner.game().ticks, 60);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Game`]: ../trait.Game.html
+pub struct Runner<G: Game> {
+    game: G,
+    input: G::Input,
+    timer: Timer,
+    window: Window,
+    _event_loop: Option<winit::event_loop::EventLoop<()>>,
+    _hook: guard::Hook,
+}
+
+impl<G: Game> Runner<G> {
+    /// Creates a new [`Runner`] by loading the [`Game`] in a hidden window
+    /// with the given settings.
+    ///
+    /// The [`Window`] reports the size in the provided settings. The loading
+    /// screen of the [`Game`] is not used.
+    ///
+    /// [`Runner`]: struct.Runner.html
+    /// [`Game`]: ../trait.Game.html
+    /// [`Window`]: ../graphics/struct.Window.html
+    pub fn new(settings: WindowSettings) -> Result<Runner<G>>
+    where
+        G: 'static,
+    {
+        let hook = guard::Hook::install::<G>();
+        let event_loop = new_event_loop();
+        let mut window =
+            Window::with_attributes(settings, false, true, &event_loop)?;
+
+        let game = G::load(&window).run(window.gpu())?;
+
+        Ok(Runner {
+            game,
+            input: G::Input::new(),
+            timer: Timer::new(G::TICKS_PER_SECOND),
+            window,
+            _event_loop: Some(event_loop),
+            _hook: hook,
+        })
+    }
+
+    /// Creates a new [`Runner`] by loading the [`Game`] in a stub [`Window`]
+    /// of the given size, without a graphics device.
+    ///
+    /// No window is opened and no GPU is needed. However, [`Game::load`]
+    /// fails with [`Error::GpuUnavailable`] if it loads any graphics, and so
+    /// does [`draw`]. [`Window::gpu`] panics.
+    ///
+    /// [`Runner`]: struct.Runner.html
+    /// [`Game`]: ../trait.Game.html
+    /// [`Window`]: ../graphics/struct.Window.html
+    /// [`Game::load`]: ../trait.Game.html#tymethod.load
+    /// [`Error::GpuUnavailable`]: ../enum.Error.html#variant.GpuUnavailable
+    /// [`draw`]: #method.draw
+    /// [`Window::gpu`]: ../graphics/struct.Window.html#method.gpu
+    pub fn without_gpu(size: (u32, u32)) -> Result<Runner<G>>
+    where
+        G: 'static,
+    {
+        let hook = guard::Hook::install::<G>();
+        let window = Window::stub(size.0, size.1);
+
+        let game = G::load(&window).run_without_gpu()?;
+
+        Ok(Runner {
+            game,
+            input: G::Input::new(),
+            timer: Timer::new(G::TICKS_PER_SECOND),
+            window,
+            _event_loop: None,
+            _hook: hook,
+        })
+    }
+
+    /// Runs a [`Task`] using the graphics device of the [`Runner`], if any.
+    ///
+    /// Tasks that load graphics fail with [`Error::GpuUnavailable`] when the
+    /// [`Runner`] was created with [`without_gpu`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Runner`]: struct.Runner.html
+    /// [`Error::GpuUnavailable`]: ../enum.Error.html#variant.GpuUnavailable
+    /// [`without_gpu`]: #method.without_gpu
+    pub fn load<T>(&mut self, task: Task<T>) -> Result<T> {
+        match self.window.try_gpu() {
+            Ok(gpu) => task.run(gpu),
+            Err(_) => task.run_without_gpu(),
+        }
+    }
+
+    /// Runs a [`Task`] like [`load`], calling the given function every time
+    /// the [`Task`] reports [`Progress`].
+    ///
+    /// This is useful to test the progress reported to loading screens.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`load`]: #method.load
+    /// [`Progress`]: ../load/struct.Progress.html
+    pub fn load_with_progress<T, F>(
+        &mut self,
+        task: Task<T>,
+        mut on_progress: F,
+    ) -> Result<T>
+    where
+        F: FnMut(&Progress),
+    {
+        task.run_with_window(&mut self.window, |progress, _| {
+            on_progress(progress)
+        })
+    }
+
+    /// Feeds an input event to the [`Game::Input`].
+    ///
+    /// The event will be consumed in the next [`tick`]. Keyboard events teach
+    /// the keyboard layout, like in a real game loop. Thus, their key codes
+    /// may be corrected, and [`keyboard::localized_name`] works.
+    ///
+    /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+    /// [`tick`]: #method.tick
+    /// [`keyboard::localized_name`]: ../input/keyboard/fn.localized_name.html
+    pub fn feed(&mut self, event: input::Event) {
+        self.input.update(keyboard::translate(event));
+    }
+
+    /// Runs a single tick of the [`Game`].
+    ///
+    /// It calls [`Game::interact`] with the input fed so far, clears the
+    /// input and calls [`Game::update`] once. It also calls
+    /// [`Game::slow_update`] when a slow tick is due. Then, it clears the
+    /// edges of the input.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Game::slow_update`]: ../trait.Game.html#method.slow_update
+    pub fn tick(&mut self) -> Result<()> {
+        step::interact(&mut self.game, &mut self.input, &mut self.window)?;
+
+        self.timer.count_tick();
+        step::tick(
+            &mut self.game,
+            &mut self.input,
+            &mut self.window,
+            &self.timer,
+        )
+    }
+
+    /// Runs a frame of the [`Game`], as if the given time had passed since
+    /// the previous one.
+    ///
+    /// Like the game loop, it calls [`Game::interact`] once and then
+    /// [`Game::update`] as many times as ticks fit in the elapsed time, up to
+    /// [`Game::MAX_TICKS_PER_FRAME`]. Any remaining backlog is dropped. The
+    /// time is simulated, so frames are deterministic too.
+    ///
+    /// Returns the amount of ticks run.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Game::MAX_TICKS_PER_FRAME`]: ../trait.Game.html#associatedconstant.MAX_TICKS_PER_FRAME
+    pub fn frame(&mut self, elapsed: Duration) -> Result<u16> {
+        self.timer.advance(elapsed);
+
+        step::interact(&mut self.game, &mut self.input, &mut self.window)?;
+        step::update(
+            &mut self.game,
+            &mut self.input,
+            &mut self.window,
+            &mut self.timer,
+            None,
+        )
+    }
+
+    /// Runs the given amount of ticks of the [`Game`].
+    ///
+    /// It stops at the first tick that fails.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn run(&mut self, ticks: u32) -> Result<()> {
+        for _ in 0..ticks {
+            self.tick()?;
+        }
+
+        Ok(())
+    }
+
+    /// Feeds the given events and then runs the given amount of ticks.
+    ///
+    /// All the events are consumed in the first tick.
+    pub fn run_with_events<I>(&mut self, ticks: u32, events: I) -> Result<()>
+    where
+        I: IntoIterator<Item = input::Event>,
+    {
+        for event in events {
+            self.feed(event);
+        }
+
+        self.run(ticks)
+    }
+
+    /// Runs the given amount of ticks, feeding the given inputs one tick at
+    /// a time.
+    ///
+    /// The n-th item of `inputs` contains the events consumed in the n-th
+    /// tick. If there are fewer items than ticks, the remaining ticks run
+    /// without input.
+    pub fn replay<I, E>(&mut self, ticks: u32, inputs: I) -> Result<()>
+    where
+        I: IntoIterator<Item = E>,
+        E: IntoIterator<Item = input::Event>,
+    {
+        let mut inputs = inputs.into_iter();
+
+        for _ in 0..ticks {
+            if let Some(events) = inputs.next() {
+                for event in events {
+                    self.feed(event);
+                }
+            }
+
+            self.tick()?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws the [`Game`] into the hidden window.
+    ///
+    /// The [`Timer`] provided to [`Game::draw`] only accumulates the time
+    /// simulated by [`frame`]. If you want to check the pixels produced by
+    /// your drawing code, draw into a [`Canvas`] using [`window_mut`] and read
+    /// them with [`Canvas::read_pixels`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Timer`]: ../struct.Timer.html
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    /// [`frame`]: #method.frame
+    /// [`Canvas`]: ../graphics/struct.Canvas.html
+    /// [`window_mut`]: #method.window_mut
+    /// [`Canvas::read_pixels`]: ../graphics/struct.Canvas.html#method.read_pixels
+    pub fn draw(&mut self) -> Result<()> {
+        if self.window.is_minimized() {
+            return Ok(());
+        }
+
+        let (game, timer) = (&mut self.game, &self.timer);
+        guard::run::<G, _>(&mut self.window, |window| {
+            game.draw(&mut window.frame()?, timer)
+        })?;
+        self.window.swap_buffers()
+    }
+
+    /// Suspends the [`Game`], as if the application was sent to the
+    /// background.
+    ///
+    /// It calls [`Game::on_suspend`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::on_suspend`]: ../trait.Game.html#method.on_suspend
+    pub fn suspend(&mut self) {
+        self.game.on_suspend();
+    }
+
+    /// Resumes the [`Game`] after a [`suspend`].
+    ///
+    /// The swapchain of the hidden window is recreated and every [`Canvas`]
+    /// is reported as invalid before [`Game::on_resume`] is called.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`suspend`]: #method.suspend
+    /// [`Canvas`]: ../graphics/struct.Canvas.html
+    /// [`Game::on_resume`]: ../trait.Game.html#method.on_resume
+    pub fn resume(&mut self) -> Result<()> {
+        self.window.resume();
+        self.game.on_resume(&mut self.window)
+    }
+
+    /// Resizes the hidden window, like a player would.
+    ///
+    /// Nothing is drawn while the window has zero width or height.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.window
+            .resize(winit::dpi::PhysicalSize::new(width, height));
+    }
+
+    /// Returns the amount of ticks run so far.
+    pub fn ticks(&self) -> u64 {
+        self.timer.ticks()
+    }
+
+    /// Returns the [`Timer`] of the [`Game`], as it is passed to
+    /// [`Game::draw`].
+    ///
+    /// [`Timer`]: ../struct.Timer.html
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    pub fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Returns a reference to the [`Game`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the [`Game`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn game_mut(&mut self) -> &mut G {
+        &mut self.game
+    }
+
+    /// Returns a reference to the [`Game::Input`].
+    ///
+    /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+    pub fn input(&self) -> &G::Input {
+        &self.input
+    }
+
+    /// Returns a reference to the hidden [`Window`].
+    ///
+    /// [`Window`]: ../graphics/struct.Window.html
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
+    /// Returns a mutable reference to the hidden [`Window`].
+    ///
+    /// [`Window`]: ../graphics/struct.Window.html
+    pub fn window_mut(&mut self) -> &mut Window {
+        &mut self.window
+    }
+
+    /// Consumes the [`Runner`] and returns the [`Game`].
+    ///
+    /// [`Runner`]: struct.Runner.html
+    /// [`Game`]: ../trait.Game.html
+    pub fn into_game(self) -> G {
+        self.game
+    }
+}
+
+/// Loads a [`Game`] and runs the given amount of ticks as fast as possible,
+/// without drawing.
+///
+/// The [`Game`] is loaded with [`Runner::without_gpu`], using the size in the
+/// provided settings. The inputs are fed one tick at a time, like in
+/// [`Runner::replay`]. The [`Game`] is returned after the last tick.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Runner::without_gpu`]: struct.Runner.html#method.without_gpu
+/// [`Runner::replay`]: struct.Runner.html#method.replay
+pub fn simulate<G, I, E>(
+    settings: WindowSettings,
+    ticks: u32,
+    inputs: I,
+) -> Result<G>
+where
+    G: Game + 'static,
+    I: IntoIterator<Item = E>,
+    E: IntoIterator<Item = input::Event>,
+{
+    let mut runner = Runner::without_gpu(settings.size)?;
+    runner.replay(ticks, inputs)?;
+
+    Ok(runner.into_game())
+}
+
+impl<G: Game> std::fmt::Debug for Runner<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Runner {{ ticks: {}, window: {:?} }}",
+            self.timer.ticks(),
+            self.window
+        )
+    }
+}
+
+// Test harnesses run tests outside of the main thread
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn new_event_loop() -> winit::event_loop::EventLoop<()> {
+    use winit::platform::unix::EventLoopExtUnix;
+
+    winit::event_loop::EventLoop::new_any_thread()
+}
+
+#[cfg(target_os = "windows")]
+fn new_event_loop() -> winit::event_loop::EventLoop<()> {
+    use winit::platform::windows::EventLoopExtWindows;
+
+    winit::event_loop::EventLoop::new_any_thread()
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows"
+)))]
+fn new_event_loop() -> winit::event_loop::EventLoop<()> {
+    winit::event_loop::EventLoop::new()
+}
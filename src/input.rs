@@ -2,25 +2,44 @@
 
 pub mod gamepad;
 pub mod keyboard;
+pub mod mapping;
 pub mod mouse;
+pub mod shortcut;
 pub mod window;
 
+mod axis_filter;
 mod event;
 mod keyboard_and_mouse;
+mod text_buffer;
+mod tuple;
+mod virtual_cursor;
 
 pub use crate::graphics::window::winit::event::ElementState as ButtonState;
+pub use axis_filter::{AxisFilter, Curve};
 pub use event::Event;
+pub use gamepad::Gamepad;
 pub use keyboard::Keyboard;
 pub use keyboard_and_mouse::KeyboardAndMouse;
+pub use mapping::Mapping;
 pub use mouse::Mouse;
+pub use shortcut::{Shortcut, Shortcuts};
+pub use text_buffer::TextBuffer;
+pub use virtual_cursor::VirtualCursor;
 
 /// The input of your [`Game`].
 ///
 /// If you just want simple access to the keyboard and mouse, check out the
-/// built-in [`KeyboardAndMouse`] type.
+/// built-in [`KeyboardAndMouse`] type. Likewise, the [`Gamepad`] type tracks
+/// the state of connected gamepads.
+///
+/// Tuples of up to 8 [`Input`] types are also an [`Input`]. Every event is
+/// fed to each member of the tuple, which allows you to compose different
+/// input trackers. For instance, `(KeyboardAndMouse, Gamepad)`.
 ///
 /// [`Game`]: ../trait.Game.html
 /// [`KeyboardAndMouse`]: struct.KeyboardAndMouse.html
+/// [`Gamepad`]: gamepad/struct.Gamepad.html
+/// [`Input`]: trait.Input.html
 pub trait Input {
     /// Creates a new [`Input`].
     ///
@@ -38,10 +57,32 @@ pub trait Input {
     /// Clears any temporary state that should be consumed by [`Game::interact`]
     /// and could accumulate otherwise.
     ///
-    /// This method will be called after each [`Game::interact`].
+    /// This method will be called after each [`Game::interact`], which runs
+    /// once per frame. Use it for state that only makes sense during a frame,
+    /// like the text typed or the wheel scrolled since the previous one.
+    ///
+    /// Edges that must reach [`Game::update`] should be cleared in
+    /// [`clear_edges`] instead.
     ///
     /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`clear_edges`]: #method.clear_edges
     fn clear(&mut self);
+
+    /// Clears any edge (e.g. a key press) detected since the last update.
+    ///
+    /// This method will be called after each [`Game::update`], and after
+    /// every frame while the game is paused. Thus, an edge reaches exactly one
+    /// update, independently of the frame rate and
+    /// [`Game::TICKS_PER_SECOND`]. Every [`Game::interact`] call until then
+    /// observes it too.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    fn clear_edges(&mut self) {}
 }
 
 impl Input for () {
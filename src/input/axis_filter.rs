@@ -0,0 +1,90 @@
+use crate::graphics::Vector;
+
+/// A filter for the values of analog axes, like the ones of a gamepad stick.
+///
+/// It ignores small values inside a dead zone and maps the rest using a
+/// [`Curve`]. Values are rescaled so a full deflection still produces a value
+/// of `1.0`.
+///
+/// [`Curve`]: enum.Curve.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisFilter {
+    /// The values to ignore, in the `[0.0, 1.0)` interval
+    pub dead_zone: f32,
+
+    /// The response curve applied to the values outside of the dead zone
+    pub curve: Curve,
+}
+
+/// A response curve of an [`AxisFilter`].
+///
+/// [`AxisFilter`]: struct.AxisFilter.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    /// The value is left as it is.
+    Linear,
+
+    /// The value is squared, allowing more precision on small deflections.
+    Squared,
+
+    /// A custom curve, which should map the `[0.0, 1.0]` interval to itself.
+    Custom(fn(f32) -> f32),
+}
+
+impl AxisFilter {
+    /// An [`AxisFilter`] that leaves values untouched.
+    ///
+    /// [`AxisFilter`]: struct.AxisFilter.html
+    pub const NONE: AxisFilter = AxisFilter {
+        dead_zone: 0.0,
+        curve: Curve::Linear,
+    };
+
+    /// Filters the value of a single axis, in the `[-1.0, 1.0]` interval.
+    pub fn apply(&self, value: f32) -> f32 {
+        value.signum() * self.scale(value.abs())
+    }
+
+    /// Filters the values of a pair of axes, like the ones of a stick.
+    ///
+    /// The dead zone is radial. In other words, it is applied to the length of
+    /// the vector instead of to each axis separately, which would distort
+    /// diagonals.
+    pub fn apply_radial(&self, values: Vector) -> Vector {
+        let length = values.norm();
+
+        if length == 0.0 {
+            values
+        } else {
+            values * (self.scale(length) / length)
+        }
+    }
+
+    fn scale(&self, magnitude: f32) -> f32 {
+        let dead_zone = self.dead_zone.max(0.0).min(0.99);
+        let magnitude = magnitude.min(1.0);
+
+        if magnitude <= dead_zone {
+            return 0.0;
+        }
+
+        let value = (magnitude - dead_zone) / (1.0 - dead_zone);
+
+        let curved = match self.curve {
+            Curve::Linear => value,
+            Curve::Squared => value * value,
+            Curve::Custom(f) => f(value),
+        };
+
+        curved.max(0.0).min(1.0)
+    }
+}
+
+impl Default for AxisFilter {
+    fn default() -> AxisFilter {
+        AxisFilter {
+            dead_zone: 0.1,
+            curve: Curve::Linear,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::input::{gamepad, keyboard, mouse, window};
 
-use std::time::SystemTime;
+use std::time::Instant;
 
 /// An input event.
 ///
@@ -9,16 +9,31 @@ use std::time::SystemTime;
 ///
 /// You can use your own input handler by implementing the [`Input`] trait.
 ///
+/// Every event contains the time it was received by the engine. Events are
+/// always delivered in chronological order.
+///
 /// [`Game`]: ../trait.Game.html
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
 /// [`Input`]: trait.Input.html
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum Event {
     /// A keyboard event
-    Keyboard(keyboard::Event),
+    Keyboard {
+        /// The keyboard event
+        event: keyboard::Event,
+
+        /// The time of the event
+        time: Instant,
+    },
 
     /// A mouse event
-    Mouse(mouse::Event),
+    Mouse {
+        /// The mouse event
+        event: mouse::Event,
+
+        /// The time of the event
+        time: Instant,
+    },
 
     /// A gamepad event
     Gamepad {
@@ -29,9 +44,29 @@ pub enum Event {
         event: gamepad::Event,
 
         /// The time of the event
-        time: SystemTime,
+        time: Instant,
     },
 
     /// A window event
-    Window(window::Event),
+    Window {
+        /// The window event
+        event: window::Event,
+
+        /// The time of the event
+        time: Instant,
+    },
+}
+
+impl Event {
+    /// Returns the time of the [`Event`].
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn time(&self) -> Instant {
+        match self {
+            Event::Keyboard { time, .. }
+            | Event::Mouse { time, .. }
+            | Event::Gamepad { time, .. }
+            | Event::Window { time, .. } => *time,
+        }
+    }
 }
@@ -1,49 +1,252 @@
 //! Listen to gamepad events.
 
 mod event;
+mod state;
 
 pub use event::Event;
+pub use state::State;
 
 pub use gilrs::Axis;
 pub use gilrs::Button;
 
+use super::{AxisFilter, Event as InputEvent, Input};
+use crate::graphics::Vector;
+
+use gilrs::ff;
 use gilrs::Gilrs;
+use std::collections::HashMap;
 use std::convert::TryInto;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 
 /// A gamepad identifier.
+///
+/// Identifiers are stable across reconnections when the platform allows it.
+/// In other words, a gamepad that is disconnected and connected again will
+/// normally keep the same [`Id`].
+///
+/// [`Id`]: struct.Id.html
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct Id(gilrs::GamepadId);
 
+/// A simple gamepad input tracker.
+///
+/// It keeps track of the [`State`] of every connected gamepad. Additionally, it
+/// provides some helpers to query the state of the last used gamepad, which is
+/// convenient for single-player games.
+///
+/// Gamepads can be rumbled using [`Window::rumble`].
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`State`]: struct.State.html
+/// [`Window::rumble`]: ../../graphics/struct.Window.html#method.rumble
+/// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Gamepad {
+    states: HashMap<Id, State>,
+    last_used: Option<Id>,
+    axis_filter: AxisFilter,
+}
+
+impl Gamepad {
+    /// Sets the [`AxisFilter`] applied to the axes and sticks of every
+    /// gamepad.
+    ///
+    /// By default, [`AxisFilter::default`] is used, which ignores small
+    /// deflections caused by stick drift.
+    ///
+    /// [`AxisFilter`]: ../struct.AxisFilter.html
+    /// [`AxisFilter::default`]: ../struct.AxisFilter.html#impl-Default
+    pub fn set_axis_filter(&mut self, axis_filter: AxisFilter) {
+        self.axis_filter = axis_filter;
+
+        for state in self.states.values_mut() {
+            state.set_axis_filter(axis_filter);
+        }
+    }
+
+    /// Returns an iterator over the identifiers of the connected gamepads.
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.states.keys().cloned()
+    }
+
+    /// Returns the [`State`] of the gamepad with the given [`Id`], if it is
+    /// connected.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Id`]: struct.Id.html
+    pub fn state(&self, id: Id) -> Option<&State> {
+        self.states.get(&id)
+    }
+
+    /// Returns the [`Id`] of the last used gamepad, if there is one connected.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn last_used(&self) -> Option<Id> {
+        self.last_used
+    }
+
+    /// Returns true if the given button is currently pressed in the last used
+    /// gamepad.
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.last_used_state()
+            .map(|state| state.is_button_pressed(button))
+            .unwrap_or(false)
+    }
+
+    /// Returns for how long the given button has been held in the last used
+    /// gamepad, if it is currently pressed.
+    pub fn button_held_duration(&self, button: Button) -> Option<Duration> {
+        self.last_used_state()
+            .and_then(|state| state.button_held_duration(button))
+    }
+
+    /// Returns for how long the given button was held in the last used
+    /// gamepad, if it was released since the last update.
+    pub fn last_button_hold(&self, button: Button) -> Option<Duration> {
+        self.last_used_state()
+            .and_then(|state| state.last_button_hold(button))
+    }
+
+    /// Returns the position of the left stick of the last used gamepad.
+    ///
+    /// Check out [`State::left_stick`] for more details.
+    ///
+    /// [`State::left_stick`]: struct.State.html#method.left_stick
+    pub fn left_stick(&self) -> Vector {
+        self.last_used_state()
+            .map(State::left_stick)
+            .unwrap_or(Vector::new(0.0, 0.0))
+    }
+
+    /// Returns the position of the right stick of the last used gamepad.
+    ///
+    /// Check out [`State::right_stick`] for more details.
+    ///
+    /// [`State::right_stick`]: struct.State.html#method.right_stick
+    pub fn right_stick(&self) -> Vector {
+        self.last_used_state()
+            .map(State::right_stick)
+            .unwrap_or(Vector::new(0.0, 0.0))
+    }
+
+    /// Returns how much the left trigger of the last used gamepad is pressed,
+    /// in the `[0.0, 1.0]` interval.
+    pub fn left_trigger(&self) -> f32 {
+        self.last_used_state()
+            .map(State::left_trigger)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns how much the right trigger of the last used gamepad is pressed,
+    /// in the `[0.0, 1.0]` interval.
+    pub fn right_trigger(&self) -> f32 {
+        self.last_used_state()
+            .map(State::right_trigger)
+            .unwrap_or(0.0)
+    }
+
+    fn last_used_state(&self) -> Option<&State> {
+        self.last_used.and_then(|id| self.states.get(&id))
+    }
+}
+
+impl Input for Gamepad {
+    fn new() -> Gamepad {
+        Gamepad {
+            states: HashMap::new(),
+            last_used: None,
+            axis_filter: AxisFilter::default(),
+        }
+    }
+
+    fn update(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Gamepad { id, event, .. } => match event {
+                Event::Connected => {
+                    let _ =
+                        self.states.insert(id, State::new(self.axis_filter));
+                }
+                Event::Disconnected => {
+                    let _ = self.states.remove(&id);
+
+                    if self.last_used == Some(id) {
+                        self.last_used = self.states.keys().next().cloned();
+                    }
+                }
+                _ => {
+                    let axis_filter = self.axis_filter;
+
+                    self.states
+                        .entry(id)
+                        .or_insert_with(|| State::new(axis_filter))
+                        .update(event);
+                    self.last_used = Some(id);
+                }
+            },
+            InputEvent::Keyboard { .. } => {
+                // Ignore keyboard events...
+            }
+            InputEvent::Mouse { .. } => {
+                // Ignore mouse events...
+            }
+            InputEvent::Window { .. } => {
+                // Ignore window events...
+            }
+        }
+    }
+
+    fn clear(&mut self) {}
+
+    fn clear_edges(&mut self) {
+        self.states.values_mut().for_each(State::clear_edges);
+    }
+}
+
 pub(crate) struct Tracker {
     context: Gilrs,
+    rumbles: HashMap<Id, ff::Effect>,
 }
 
 impl Tracker {
     pub fn new() -> Option<Tracker> {
         match Gilrs::new() {
-            Ok(context) => Some(Tracker { context }),
+            Ok(context) => Some(Tracker {
+                context,
+                rumbles: HashMap::new(),
+            }),
             Err(gilrs::Error::NotImplemented(dummy_context)) => {
                 // Use the dummy context as a fallback on unsupported platforms
+                #[cfg(feature = "log")]
+                log::warn!("Gamepads are not supported on this platform");
+
                 Some(Tracker {
                     context: dummy_context,
+                    rumbles: HashMap::new(),
                 })
             }
-            _ => {
+            Err(_error) => {
                 // Either `gilrs::error::InvalidAxisToBtn` has occured, or a
                 // platform specific error has occured.
+                #[cfg(feature = "log")]
+                log::warn!("Gamepads are disabled: {}", _error);
+
                 None
             }
         }
     }
 
-    pub fn next_event(&mut self) -> Option<(Id, Event, SystemTime)> {
+    pub fn next_event(&mut self) -> Option<(Id, Event, Instant)> {
         while let Some(gilrs::Event { id, event, time }) =
             self.context.next_event()
         {
             match event.try_into() {
                 Ok(gamepad_event) => {
-                    return Some((Id(id), gamepad_event, time));
+                    if gamepad_event == Event::Disconnected {
+                        let _ = self.rumbles.remove(&Id(id));
+                    }
+
+                    return Some((Id(id), gamepad_event, to_instant(time)));
                 }
                 Err(_) => {}
             }
@@ -51,4 +254,72 @@ impl Tracker {
 
         None
     }
+
+    pub fn rumble(
+        &mut self,
+        id: Id,
+        strong: f32,
+        weak: f32,
+        duration: Duration,
+    ) {
+        self.stop_rumble(id);
+
+        let is_supported = self
+            .context
+            .connected_gamepad(id.0)
+            .map(|gamepad| gamepad.is_ff_supported())
+            .unwrap_or(false);
+
+        if !is_supported {
+            return;
+        }
+
+        let scheduling = ff::Replay {
+            play_for: ff::Ticks::from_ms(
+                duration.as_millis().min(u32::max_value() as u128) as u32,
+            ),
+            ..ff::Replay::default()
+        };
+
+        let effect = ff::EffectBuilder::new()
+            .add_effect(ff::BaseEffect {
+                kind: ff::BaseEffectType::Strong {
+                    magnitude: magnitude(strong),
+                },
+                scheduling,
+                ..ff::BaseEffect::default()
+            })
+            .add_effect(ff::BaseEffect {
+                kind: ff::BaseEffectType::Weak {
+                    magnitude: magnitude(weak),
+                },
+                scheduling,
+                ..ff::BaseEffect::default()
+            })
+            .gamepads(&[id.0])
+            .finish(&mut self.context);
+
+        if let Ok(effect) = effect {
+            if effect.play().is_ok() {
+                let _ = self.rumbles.insert(id, effect);
+            }
+        }
+    }
+
+    pub fn stop_rumble(&mut self, id: Id) {
+        if let Some(effect) = self.rumbles.remove(&id) {
+            let _ = effect.stop();
+        }
+    }
+}
+
+fn magnitude(value: f32) -> u16 {
+    (value.max(0.0).min(1.0) * f32::from(u16::max_value())) as u16
+}
+
+fn to_instant(time: SystemTime) -> Instant {
+    let now = Instant::now();
+    let elapsed = SystemTime::now().duration_since(time).unwrap_or_default();
+
+    now.checked_sub(elapsed).unwrap_or(now)
 }
@@ -0,0 +1,157 @@
+use super::{Axis, Button, Event};
+use crate::graphics::Vector;
+use crate::input::AxisFilter;
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The current state of a connected gamepad.
+///
+/// You can obtain it from a [`Gamepad`] tracker.
+///
+/// [`Gamepad`]: struct.Gamepad.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    pressed_buttons: HashMap<Button, Instant>,
+    button_holds: HashMap<Button, Duration>,
+    button_values: HashMap<Button, f32>,
+    axes: HashMap<Axis, f32>,
+    axis_filter: AxisFilter,
+}
+
+impl State {
+    pub(super) fn new(axis_filter: AxisFilter) -> State {
+        State {
+            axis_filter,
+            ..State::default()
+        }
+    }
+
+    /// Returns true if the given button is currently pressed.
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.pressed_buttons.contains_key(&button)
+    }
+
+    /// Returns for how long the given button has been held, if it is
+    /// currently pressed.
+    ///
+    /// Hold durations are measured in wall time, from the moment the tracker
+    /// processes the press event.
+    pub fn button_held_duration(&self, button: Button) -> Option<Duration> {
+        self.pressed_buttons.get(&button).map(Instant::elapsed)
+    }
+
+    /// Returns for how long the given button was held, if it was released
+    /// since the last update.
+    pub fn last_button_hold(&self, button: Button) -> Option<Duration> {
+        self.button_holds.get(&button).cloned()
+    }
+
+    /// Returns the current value of the given button, in the `[0.0, 1.0]`
+    /// interval.
+    ///
+    /// This is mostly useful for analog buttons, like triggers.
+    pub fn button_value(&self, button: Button) -> f32 {
+        self.button_values.get(&button).cloned().unwrap_or(0.0)
+    }
+
+    /// Returns the current value of the given axis, in the `[-1.0, 1.0]`
+    /// interval, filtered by the [`AxisFilter`] of the tracker.
+    ///
+    /// [`AxisFilter`]: ../struct.AxisFilter.html
+    pub fn axis(&self, axis: Axis) -> f32 {
+        self.axis_filter.apply(self.raw_axis(axis))
+    }
+
+    /// Returns the current value of the given axis, in the `[-1.0, 1.0]`
+    /// interval, as reported by the gamepad.
+    pub fn raw_axis(&self, axis: Axis) -> f32 {
+        self.axes.get(&axis).cloned().unwrap_or(0.0)
+    }
+
+    /// Returns the position of the left stick, filtered by the
+    /// [`AxisFilter`] of the tracker using a radial dead zone.
+    ///
+    /// The returned [`Vector`] uses the same coordinate system as a
+    /// [`Target`]. Therefore, pushing the stick down produces a positive `y`.
+    ///
+    /// [`AxisFilter`]: ../struct.AxisFilter.html
+    /// [`Vector`]: ../../graphics/type.Vector.html
+    /// [`Target`]: ../../graphics/struct.Target.html
+    pub fn left_stick(&self) -> Vector {
+        self.axis_filter.apply_radial(self.raw_left_stick())
+    }
+
+    /// Returns the position of the right stick, filtered by the
+    /// [`AxisFilter`] of the tracker using a radial dead zone.
+    ///
+    /// The returned [`Vector`] uses the same coordinate system as a
+    /// [`Target`]. Therefore, pushing the stick down produces a positive `y`.
+    ///
+    /// [`AxisFilter`]: ../struct.AxisFilter.html
+    /// [`Vector`]: ../../graphics/type.Vector.html
+    /// [`Target`]: ../../graphics/struct.Target.html
+    pub fn right_stick(&self) -> Vector {
+        self.axis_filter.apply_radial(self.raw_right_stick())
+    }
+
+    /// Returns the position of the left stick, as reported by the gamepad.
+    pub fn raw_left_stick(&self) -> Vector {
+        Vector::new(
+            self.raw_axis(Axis::LeftStickX),
+            -self.raw_axis(Axis::LeftStickY),
+        )
+    }
+
+    /// Returns the position of the right stick, as reported by the gamepad.
+    pub fn raw_right_stick(&self) -> Vector {
+        Vector::new(
+            self.raw_axis(Axis::RightStickX),
+            -self.raw_axis(Axis::RightStickY),
+        )
+    }
+
+    /// Returns how much the left trigger is pressed, in the `[0.0, 1.0]`
+    /// interval.
+    pub fn left_trigger(&self) -> f32 {
+        self.button_value(Button::LeftTrigger2)
+    }
+
+    /// Returns how much the right trigger is pressed, in the `[0.0, 1.0]`
+    /// interval.
+    pub fn right_trigger(&self) -> f32 {
+        self.button_value(Button::RightTrigger2)
+    }
+
+    pub(super) fn update(&mut self, event: Event) {
+        match event {
+            Event::ButtonPressed(button) => {
+                let _ = self
+                    .pressed_buttons
+                    .entry(button)
+                    .or_insert_with(Instant::now);
+            }
+            Event::ButtonReleased(button) => {
+                if let Some(pressed_at) = self.pressed_buttons.remove(&button) {
+                    let _ =
+                        self.button_holds.insert(button, pressed_at.elapsed());
+                }
+            }
+            Event::ButtonChanged(button, value) => {
+                let _ = self.button_values.insert(button, value);
+            }
+            Event::AxisChanged(axis, value) => {
+                let _ = self.axes.insert(axis, value.max(-1.0).min(1.0));
+            }
+            Event::Connected | Event::Disconnected => {}
+        }
+    }
+
+    pub(super) fn clear_edges(&mut self) {
+        self.button_holds.clear();
+    }
+
+    pub(super) fn set_axis_filter(&mut self, axis_filter: AxisFilter) {
+        self.axis_filter = axis_filter;
+    }
+}
@@ -1,42 +1,127 @@
 //! Listen to keyboard events.
 
 mod event;
+mod key;
+mod layout;
+mod modifiers;
 
+pub use crate::graphics::window::winit::event::ScanCode;
 pub use crate::graphics::window::winit::event::VirtualKeyCode as KeyCode;
 pub use event::Event;
+pub use key::Key;
+pub use layout::{key_name, localized_name};
+pub use modifiers::Modifiers;
+
+pub(crate) use layout::name as key_code_name;
+pub(crate) use layout::translate;
 
 use super::{ButtonState, Event as InputEvent, Input};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// A simple keyboard input tracker.
 ///
 /// You can use this as your [`Game::Input`] directly!
 ///
+/// Hold durations are measured in wall time, from the moment the tracker
+/// processes the press event. Thus, they do not depend on
+/// [`Game::TICKS_PER_SECOND`].
+///
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+/// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
 #[derive(Debug, Clone)]
 pub struct Keyboard {
-    pressed_keys: HashSet<KeyCode>,
-    released_keys: HashSet<KeyCode>,
+    pressed_keys: HashMap<Key, Instant>,
+    just_pressed_keys: HashSet<Key>,
+    released_keys: HashMap<Key, Duration>,
+    modifiers: Modifiers,
 }
 
 impl Keyboard {
     /// Returns true if the given key is currently pressed.
     pub fn is_key_pressed(&self, key_code: KeyCode) -> bool {
-        self.pressed_keys.contains(&key_code)
+        self.is_pressed(Key::Logical(key_code))
+    }
+
+    /// Returns true if the given key was pressed since the last update.
+    ///
+    /// Repeats produced by holding the key down are ignored.
+    pub fn was_key_pressed(&self, key_code: KeyCode) -> bool {
+        self.was_pressed(Key::Logical(key_code))
     }
 
-    /// Returns true if the given key was released during the last interaction.
+    /// Returns true if the given key was released since the last update.
     pub fn was_key_released(&self, key_code: KeyCode) -> bool {
-        self.released_keys.contains(&key_code)
+        self.was_released(Key::Logical(key_code))
+    }
+
+    /// Returns true if the given [`Key`] is currently pressed.
+    ///
+    /// [`Key`]: enum.Key.html
+    pub fn is_pressed(&self, key: Key) -> bool {
+        self.pressed_keys.contains_key(&key)
+    }
+
+    /// Returns true if the given [`Key`] was pressed since the last update.
+    ///
+    /// Repeats produced by holding the key down are ignored.
+    ///
+    /// [`Key`]: enum.Key.html
+    pub fn was_pressed(&self, key: Key) -> bool {
+        self.just_pressed_keys.contains(&key)
+    }
+
+    /// Returns true if the given [`Key`] was released since the last update.
+    ///
+    /// [`Key`]: enum.Key.html
+    pub fn was_released(&self, key: Key) -> bool {
+        self.released_keys.contains_key(&key)
+    }
+
+    /// Returns for how long the given key has been held, if it is currently
+    /// pressed.
+    pub fn key_held_duration(&self, key_code: KeyCode) -> Option<Duration> {
+        self.held_duration(Key::Logical(key_code))
+    }
+
+    /// Returns for how long the given key was held, if it was released since
+    /// the last update.
+    ///
+    /// This is useful to distinguish a tap from a long hold.
+    pub fn last_key_hold(&self, key_code: KeyCode) -> Option<Duration> {
+        self.last_hold(Key::Logical(key_code))
+    }
+
+    /// Returns for how long the given [`Key`] has been held, if it is
+    /// currently pressed.
+    ///
+    /// [`Key`]: enum.Key.html
+    pub fn held_duration(&self, key: Key) -> Option<Duration> {
+        self.pressed_keys.get(&key).map(Instant::elapsed)
+    }
+
+    /// Returns for how long the given [`Key`] was held, if it was released
+    /// since the last update.
+    ///
+    /// [`Key`]: enum.Key.html
+    pub fn last_hold(&self, key: Key) -> Option<Duration> {
+        self.released_keys.get(&key).cloned()
+    }
+
+    /// Returns the current state of the keyboard modifiers.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
     }
 }
 
 impl Input for Keyboard {
     fn new() -> Keyboard {
         Keyboard {
-            pressed_keys: HashSet::new(),
-            released_keys: HashSet::new(),
+            pressed_keys: HashMap::new(),
+            just_pressed_keys: HashSet::new(),
+            released_keys: HashMap::new(),
+            modifiers: Modifiers::default(),
         }
     }
 
@@ -45,17 +130,50 @@ impl Input for Keyboard {
             InputEvent::Mouse { .. } => {
                 // Ignore mouse events...
             }
-            InputEvent::Keyboard(keyboard_event) => match keyboard_event {
-                Event::Input { key_code, state } => {
-                    match state {
-                        ButtonState::Pressed => {
-                            let _ = self.pressed_keys.insert(key_code);
-                        }
-                        ButtonState::Released => {
-                            let _ = self.pressed_keys.remove(&key_code);
-                            let _ = self.released_keys.insert(key_code);
-                        }
-                    };
+            InputEvent::Keyboard {
+                event: keyboard_event,
+                ..
+            } => match keyboard_event {
+                Event::Input {
+                    key_code,
+                    scan_code,
+                    state,
+                    modifiers,
+                    repeat,
+                } => {
+                    self.modifiers = modifiers;
+
+                    let keys = key_code
+                        .map(Key::Logical)
+                        .into_iter()
+                        .chain(Some(Key::Physical(scan_code)));
+
+                    for key in keys {
+                        match state {
+                            ButtonState::Pressed => {
+                                let _ = self
+                                    .pressed_keys
+                                    .entry(key)
+                                    .or_insert_with(Instant::now);
+
+                                if !repeat {
+                                    let _ = self.just_pressed_keys.insert(key);
+                                }
+                            }
+                            ButtonState::Released => {
+                                let hold = self
+                                    .pressed_keys
+                                    .remove(&key)
+                                    .map(|pressed_at| pressed_at.elapsed())
+                                    .unwrap_or_default();
+
+                                let _ = self.released_keys.insert(key, hold);
+                            }
+                        };
+                    }
+                }
+                Event::ModifiersChanged { modifiers } => {
+                    self.modifiers = modifiers;
                 }
                 Event::TextEntered { .. } => {}
             },
@@ -68,7 +186,10 @@ impl Input for Keyboard {
         }
     }
 
-    fn clear(&mut self) {
+    fn clear(&mut self) {}
+
+    fn clear_edges(&mut self) {
+        self.just_pressed_keys.clear();
         self.released_keys.clear();
     }
 }
@@ -1,4 +1,4 @@
-use super::KeyCode;
+use super::{KeyCode, Modifiers, ScanCode};
 use crate::input::ButtonState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -9,11 +9,46 @@ pub enum Event {
         /// The state of the key
         state: ButtonState,
 
-        /// The key identifier
-        key_code: KeyCode,
+        /// The key identifier in the current keyboard layout, if known
+        key_code: Option<KeyCode>,
+
+        /// The physical key identifier
+        scan_code: ScanCode,
+
+        /// Whether the event was produced by the key being held down
+        ///
+        /// Repeats are always delivered as [`ButtonState::Pressed`] events.
+        ///
+        /// [`ButtonState::Pressed`]: ../enum.ButtonState.html#variant.Pressed
+        repeat: bool,
+
+        /// The state of the modifiers when the key was pressed or released
+        modifiers: Modifiers,
+    },
+
+    /// The state of the modifiers changed.
+    ///
+    /// The modifiers are reset when the window gains or loses focus, as the
+    /// keys may have been pressed or released while the window was not focused.
+    ModifiersChanged {
+        /// The new state of the modifiers
+        modifiers: Modifiers,
     },
 
     /// Text was entered.
+    ///
+    /// Text committed by an input method editor is delivered as one event per
+    /// character, in order. The text being composed is not reported.
+    ///
+    /// Control characters and characters produced while the control or logo
+    /// keys are held are not considered text. Use the [`Input`] events of keys
+    /// like [`KeyCode::Back`] or [`KeyCode::Delete`] to edit text instead, or
+    /// simply use a [`TextBuffer`].
+    ///
+    /// [`Input`]: #variant.Input
+    /// [`KeyCode::Back`]: enum.KeyCode.html#variant.Back
+    /// [`KeyCode::Delete`]: enum.KeyCode.html#variant.Delete
+    /// [`TextBuffer`]: ../struct.TextBuffer.html
     TextEntered {
         /// The character entered
         character: char,
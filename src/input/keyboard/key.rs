@@ -0,0 +1,29 @@
+use super::{KeyCode, ScanCode};
+
+/// A keyboard key, identified either by its physical position or by its
+/// meaning in the current keyboard layout.
+///
+/// Bind to [`Physical`] keys when the position of a key matters more than its
+/// label (e.g. WASD movement), and to [`Logical`] keys for text and shortcuts.
+///
+/// [`Physical`]: #variant.Physical
+/// [`Logical`]: #variant.Logical
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Key {
+    /// A key identified by its scancode, independently of the keyboard layout.
+    Physical(ScanCode),
+
+    /// A key identified by its virtual key code, as mapped by the keyboard
+    /// layout.
+    Logical(KeyCode),
+}
+
+impl From<KeyCode> for Key {
+    fn from(key_code: KeyCode) -> Key {
+        Key::Logical(key_code)
+    }
+}
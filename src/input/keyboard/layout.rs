@@ -0,0 +1,369 @@
+use super::{ButtonState, Event, KeyCode, Modifiers, ScanCode};
+use crate::input;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+struct Layout {
+    keys: HashMap<ScanCode, KeyCode>,
+    characters: HashMap<ScanCode, char>,
+    pressed: HashMap<ScanCode, Option<KeyCode>>,
+    last_pressed: Option<(ScanCode, Modifiers)>,
+}
+
+thread_local! {
+    static LAYOUT: RefCell<Layout> = RefCell::new(Layout::default());
+}
+
+/// Returns the name of the key at the given physical position in the current
+/// keyboard layout.
+///
+/// The layout is learned from the keyboard events received by the game.
+/// Therefore, `None` is returned when a key with the given scancode has not
+/// been pressed yet.
+///
+/// This is useful to show physical bindings to your users. For instance, the
+/// physical position of the `W` key in a QWERTY layout will be named `Z` for
+/// users of an AZERTY layout.
+pub fn key_name(scan_code: ScanCode) -> Option<String> {
+    LAYOUT.with(|layout| {
+        let layout = layout.borrow();
+
+        layout
+            .characters
+            .get(&scan_code)
+            .map(|character| display(*character))
+            .or_else(|| layout.keys.get(&scan_code).map(name))
+    })
+}
+
+/// Returns the name of the given key as printed in the current keyboard
+/// layout.
+///
+/// The name is the character produced by the key, which is learned from the
+/// text entered by the player. Therefore, `None` is returned when the key has
+/// not been used to enter text yet, or when it does not produce any text.
+///
+/// For instance, in a German layout, the key producing `ß` has a [`KeyCode`]
+/// of `Minus` but a localized name of `ß`.
+///
+/// [`KeyCode`]: enum.KeyCode.html
+pub fn localized_name(key_code: KeyCode) -> Option<String> {
+    LAYOUT.with(|layout| {
+        let layout = layout.borrow();
+
+        layout
+            .keys
+            .iter()
+            .filter(|(_, key)| **key == key_code)
+            .find_map(|(scan_code, _)| layout.characters.get(scan_code))
+            .map(|character| display(*character))
+    })
+}
+
+/// Fills in the key code of a keyboard event using the learned layout, and
+/// learns from it.
+///
+/// Windowing backends do not agree on key codes for character keys: some
+/// report the key at a physical position (a German `Z` becomes `Y`), and some
+/// report nothing for punctuation. Once a key has produced a character, the
+/// character decides its key code. Until then, the reported key code is used,
+/// falling back to the physical position of the key.
+pub(crate) fn translate(event: input::Event) -> input::Event {
+    match event {
+        input::Event::Keyboard {
+            event:
+                Event::Input {
+                    state,
+                    key_code,
+                    scan_code,
+                    modifiers,
+                    repeat,
+                },
+            time,
+        } => {
+            let key_code = LAYOUT.with(|layout| {
+                let mut layout = layout.borrow_mut();
+
+                match state {
+                    ButtonState::Pressed => {
+                        let key_code = layout.key_code(scan_code, key_code);
+
+                        let _ = layout.pressed.insert(scan_code, key_code);
+                        layout.last_pressed = Some((scan_code, modifiers));
+
+                        key_code
+                    }
+                    // Releases keep the key code of their press, even if
+                    // the layout learned something in between
+                    ButtonState::Released => {
+                        layout.pressed.remove(&scan_code).unwrap_or_else(|| {
+                            layout.key_code(scan_code, key_code)
+                        })
+                    }
+                }
+            });
+
+            input::Event::Keyboard {
+                event: Event::Input {
+                    state,
+                    key_code,
+                    scan_code,
+                    modifiers,
+                    repeat,
+                },
+                time,
+            }
+        }
+        input::Event::Keyboard {
+            event: Event::TextEntered { character },
+            ..
+        } => {
+            LAYOUT.with(|layout| {
+                let mut layout = layout.borrow_mut();
+
+                if let Some((scan_code, modifiers)) = layout.last_pressed.take()
+                {
+                    // Modifiers change the produced character
+                    if modifiers.is_empty() && !character.is_control() {
+                        layout.learn(scan_code, character);
+                    }
+                }
+            });
+
+            event
+        }
+        _ => event,
+    }
+}
+
+impl Layout {
+    fn key_code(
+        &mut self,
+        scan_code: ScanCode,
+        reported: Option<KeyCode>,
+    ) -> Option<KeyCode> {
+        let learned = self
+            .characters
+            .get(&scan_code)
+            .and_then(|character| character_key_code(*character));
+
+        let key_code = match reported {
+            // Keys outside of the character block, like the numpad, keep
+            // their own key code even if they produce text
+            Some(reported) if !is_character_key(reported) => Some(reported),
+            _ => learned
+                .or(reported)
+                .or_else(|| physical_key_code(scan_code)),
+        };
+
+        if let Some(key_code) = key_code {
+            let _ = self.keys.insert(scan_code, key_code);
+        }
+
+        key_code
+    }
+
+    fn learn(&mut self, scan_code: ScanCode, character: char) {
+        let _ = self.characters.insert(scan_code, character);
+
+        let is_character_key = self
+            .keys
+            .get(&scan_code)
+            .map(|key_code| is_character_key(*key_code))
+            .unwrap_or(true);
+
+        if is_character_key {
+            if let Some(key_code) = character_key_code(character) {
+                let _ = self.keys.insert(scan_code, key_code);
+            }
+        }
+    }
+}
+
+// Uppercase, unless it changes the character (`ß` is not `SS`)
+fn display(character: char) -> String {
+    let mut uppercase = character.to_uppercase();
+
+    match (uppercase.next(), uppercase.next()) {
+        (Some(uppercase), None) => uppercase.to_string(),
+        _ => character.to_string(),
+    }
+}
+
+fn character_key_code(character: char) -> Option<KeyCode> {
+    let key_code = match character.to_ascii_lowercase() {
+        'a' => KeyCode::A,
+        'b' => KeyCode::B,
+        'c' => KeyCode::C,
+        'd' => KeyCode::D,
+        'e' => KeyCode::E,
+        'f' => KeyCode::F,
+        'g' => KeyCode::G,
+        'h' => KeyCode::H,
+        'i' => KeyCode::I,
+        'j' => KeyCode::J,
+        'k' => KeyCode::K,
+        'l' => KeyCode::L,
+        'm' => KeyCode::M,
+        'n' => KeyCode::N,
+        'o' => KeyCode::O,
+        'p' => KeyCode::P,
+        'q' => KeyCode::Q,
+        'r' => KeyCode::R,
+        's' => KeyCode::S,
+        't' => KeyCode::T,
+        'u' => KeyCode::U,
+        'v' => KeyCode::V,
+        'w' => KeyCode::W,
+        'x' => KeyCode::X,
+        'y' => KeyCode::Y,
+        'z' => KeyCode::Z,
+        '0' => KeyCode::Key0,
+        '1' => KeyCode::Key1,
+        '2' => KeyCode::Key2,
+        '3' => KeyCode::Key3,
+        '4' => KeyCode::Key4,
+        '5' => KeyCode::Key5,
+        '6' => KeyCode::Key6,
+        '7' => KeyCode::Key7,
+        '8' => KeyCode::Key8,
+        '9' => KeyCode::Key9,
+        '-' => KeyCode::Minus,
+        '=' => KeyCode::Equals,
+        ',' => KeyCode::Comma,
+        '.' => KeyCode::Period,
+        '/' => KeyCode::Slash,
+        '\\' => KeyCode::Backslash,
+        ';' => KeyCode::Semicolon,
+        ':' => KeyCode::Colon,
+        '\'' => KeyCode::Apostrophe,
+        '`' => KeyCode::Grave,
+        '[' => KeyCode::LBracket,
+        ']' => KeyCode::RBracket,
+        '@' => KeyCode::At,
+        _ => return None,
+    };
+
+    Some(key_code)
+}
+
+fn is_character_key(key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::A
+        | KeyCode::B
+        | KeyCode::C
+        | KeyCode::D
+        | KeyCode::E
+        | KeyCode::F
+        | KeyCode::G
+        | KeyCode::H
+        | KeyCode::I
+        | KeyCode::J
+        | KeyCode::K
+        | KeyCode::L
+        | KeyCode::M
+        | KeyCode::N
+        | KeyCode::O
+        | KeyCode::P
+        | KeyCode::Q
+        | KeyCode::R
+        | KeyCode::S
+        | KeyCode::T
+        | KeyCode::U
+        | KeyCode::V
+        | KeyCode::W
+        | KeyCode::X
+        | KeyCode::Y
+        | KeyCode::Z
+        | KeyCode::Key0
+        | KeyCode::Key1
+        | KeyCode::Key2
+        | KeyCode::Key3
+        | KeyCode::Key4
+        | KeyCode::Key5
+        | KeyCode::Key6
+        | KeyCode::Key7
+        | KeyCode::Key8
+        | KeyCode::Key9
+        | KeyCode::Minus
+        | KeyCode::Equals
+        | KeyCode::Comma
+        | KeyCode::Period
+        | KeyCode::Slash
+        | KeyCode::Backslash
+        | KeyCode::Semicolon
+        | KeyCode::Colon
+        | KeyCode::Apostrophe
+        | KeyCode::Grave
+        | KeyCode::LBracket
+        | KeyCode::RBracket
+        | KeyCode::At
+        | KeyCode::OEM102 => true,
+        _ => false,
+    }
+}
+
+// Scancodes of the punctuation keys, which some backends report without a
+// key code. Windows and Linux share the PC scancodes for these keys.
+#[cfg(not(target_os = "macos"))]
+fn physical_key_code(scan_code: ScanCode) -> Option<KeyCode> {
+    let key_code = match scan_code {
+        12 => KeyCode::Minus,
+        13 => KeyCode::Equals,
+        26 => KeyCode::LBracket,
+        27 => KeyCode::RBracket,
+        39 => KeyCode::Semicolon,
+        40 => KeyCode::Apostrophe,
+        41 => KeyCode::Grave,
+        43 => KeyCode::Backslash,
+        51 => KeyCode::Comma,
+        52 => KeyCode::Period,
+        53 => KeyCode::Slash,
+        86 => KeyCode::OEM102,
+        _ => return None,
+    };
+
+    Some(key_code)
+}
+
+#[cfg(target_os = "macos")]
+fn physical_key_code(scan_code: ScanCode) -> Option<KeyCode> {
+    let key_code = match scan_code {
+        0x1B => KeyCode::Minus,
+        0x18 => KeyCode::Equals,
+        0x21 => KeyCode::LBracket,
+        0x1E => KeyCode::RBracket,
+        0x29 => KeyCode::Semicolon,
+        0x27 => KeyCode::Apostrophe,
+        0x32 => KeyCode::Grave,
+        0x2A => KeyCode::Backslash,
+        0x2B => KeyCode::Comma,
+        0x2F => KeyCode::Period,
+        0x2C => KeyCode::Slash,
+        0x0A => KeyCode::OEM102,
+        _ => return None,
+    };
+
+    Some(key_code)
+}
+
+pub(crate) fn name(key_code: &KeyCode) -> String {
+    let name = format!("{:?}", key_code);
+
+    match key_code {
+        KeyCode::Key1
+        | KeyCode::Key2
+        | KeyCode::Key3
+        | KeyCode::Key4
+        | KeyCode::Key5
+        | KeyCode::Key6
+        | KeyCode::Key7
+        | KeyCode::Key8
+        | KeyCode::Key9
+        | KeyCode::Key0 => name.trim_start_matches("Key").to_string(),
+        KeyCode::OEM102 => String::from("IntlBackslash"),
+        _ => name,
+    }
+}
@@ -0,0 +1,22 @@
+/// The state of the keyboard modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    /// Whether a shift key is pressed
+    pub shift: bool,
+
+    /// Whether a control key is pressed
+    pub ctrl: bool,
+
+    /// Whether an alt key is pressed
+    pub alt: bool,
+
+    /// Whether a logo key is pressed (e.g. windows or command key)
+    pub logo: bool,
+}
+
+impl Modifiers {
+    /// Returns true if no modifier is pressed.
+    pub fn is_empty(&self) -> bool {
+        !(self.shift || self.ctrl || self.alt || self.logo)
+    }
+}
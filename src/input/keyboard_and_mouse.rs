@@ -1,12 +1,21 @@
-use super::keyboard::Keyboard;
-use super::mouse::Mouse;
+use super::keyboard::{KeyCode, Keyboard};
+use super::mouse::{Button, Mouse};
 use super::{Event, Input};
 
 /// A simple keyboard and mouse input tracker.
 ///
 /// You can use this as your [`Game::Input`] directly!
 ///
+/// The `was_*` methods detect edges: they return true if something happened
+/// since the last update. The engine clears them after each [`Game::update`],
+/// so every edge reaches exactly one update, independently of the frame rate
+/// and [`Game::TICKS_PER_SECOND`]. Until then, every [`Game::interact`] call
+/// observes it.
+///
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+/// [`Game::interact`]: ../trait.Game.html#method.interact
+/// [`Game::update`]: ../trait.Game.html#method.update
+/// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
 #[derive(Debug, Clone)]
 pub struct KeyboardAndMouse {
     mouse: Mouse,
@@ -27,6 +36,34 @@ impl KeyboardAndMouse {
     pub fn keyboard(&self) -> &Keyboard {
         &self.keyboard
     }
+
+    /// Returns true if the given key is currently pressed.
+    pub fn is_key_pressed(&self, key_code: KeyCode) -> bool {
+        self.keyboard.is_key_pressed(key_code)
+    }
+
+    /// Returns true if the given key was pressed since the last update.
+    ///
+    /// Repeats produced by holding the key down are ignored.
+    pub fn was_key_pressed(&self, key_code: KeyCode) -> bool {
+        self.keyboard.was_key_pressed(key_code)
+    }
+
+    /// Returns true if the given key was released since the last update.
+    pub fn was_key_released(&self, key_code: KeyCode) -> bool {
+        self.keyboard.was_key_released(key_code)
+    }
+
+    /// Returns true if the given mouse button is currently pressed.
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.mouse.is_button_pressed(button)
+    }
+
+    /// Returns true if the given mouse button was clicked since the last
+    /// update.
+    pub fn was_button_clicked(&self, button: Button) -> bool {
+        self.mouse.was_button_clicked(button)
+    }
 }
 
 impl Input for KeyboardAndMouse {
@@ -46,4 +83,9 @@ impl Input for KeyboardAndMouse {
         self.mouse.clear();
         self.keyboard.clear();
     }
+
+    fn clear_edges(&mut self) {
+        self.mouse.clear_edges();
+        self.keyboard.clear_edges();
+    }
 }
@@ -0,0 +1,250 @@
+//! Map input to the actions of your game.
+
+mod binding;
+
+pub use binding::Binding;
+
+use super::{gamepad, keyboard, mouse, window, ButtonState, Event, Input};
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// An input tracker that maps different input sources to actions.
+///
+/// `Action` is normally an `enum` defined by your game. This allows your game
+/// logic to stay independent of the actual keys or buttons used, making
+/// rebinding easy.
+///
+/// ```
+/// use coffee::input::{keyboard::KeyCode, mapping::Mapping, gamepad};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Action {
+///     Jump,
+///     Move,
+/// }
+///
+/// let mut mapping = Mapping::new();
+///
+/// mapping.bind(Action::Jump, KeyCode::Space);
+/// mapping.bind(Action::Jump, gamepad::Button::South);
+/// mapping.bind(Action::Move, gamepad::Axis::LeftStickX);
+/// ```
+///
+/// An input source can be bound to multiple actions. In that case, the last
+/// action it was bound to wins. Use [`conflicts`] to find these sources.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`conflicts`]: #method.conflicts
+/// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Mapping<Action: Copy + Eq + Hash> {
+    bindings: Vec<(Action, Binding)>,
+
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    values: HashMap<Binding, f32>,
+
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    triggered: HashSet<Action>,
+}
+
+impl<Action: Copy + Eq + Hash> Mapping<Action> {
+    /// The value an input source needs to reach to activate its action.
+    pub const ACTIVATION_THRESHOLD: f32 = 0.5;
+
+    /// Creates a new [`Mapping`] without any bindings.
+    ///
+    /// [`Mapping`]: struct.Mapping.html
+    pub fn new() -> Mapping<Action> {
+        Mapping {
+            bindings: Vec::new(),
+            values: HashMap::new(),
+            triggered: HashSet::new(),
+        }
+    }
+
+    /// Binds the given input source to an action.
+    ///
+    /// An action can have multiple bindings.
+    pub fn bind(&mut self, action: Action, binding: impl Into<Binding>) {
+        let binding = binding.into();
+
+        self.bindings
+            .retain(|&(a, b)| !(a == action && b == binding));
+
+        self.bindings.push((action, binding));
+    }
+
+    /// Removes all the bindings of the given action.
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|(a, _)| *a != action);
+    }
+
+    /// Returns the bindings of the given action.
+    pub fn bindings(&self, action: Action) -> Vec<Binding> {
+        self.bindings
+            .iter()
+            .filter(|(a, _)| *a == action)
+            .map(|(_, binding)| *binding)
+            .collect()
+    }
+
+    /// Returns the input sources that are bound to more than one action,
+    /// together with the actions they are bound to.
+    ///
+    /// The last action of each list is the one that wins.
+    pub fn conflicts(&self) -> Vec<(Binding, Vec<Action>)> {
+        let mut conflicts: Vec<(Binding, Vec<Action>)> = Vec::new();
+
+        for (action, binding) in &self.bindings {
+            match conflicts.iter_mut().find(|(b, _)| b == binding) {
+                Some((_, actions)) => actions.push(*action),
+                None => conflicts.push((*binding, vec![*action])),
+            }
+        }
+
+        conflicts.retain(|(_, actions)| actions.len() > 1);
+        conflicts
+    }
+
+    /// Returns true if any input source bound to the given action is
+    /// currently active.
+    pub fn is_action_active(&self, action: Action) -> bool {
+        self.action_axis(action).abs() >= Self::ACTIVATION_THRESHOLD
+    }
+
+    /// Returns true if the given action was activated since the last update.
+    ///
+    /// Key repeats do not trigger actions.
+    pub fn was_action_triggered(&self, action: Action) -> bool {
+        self.triggered.contains(&action)
+    }
+
+    /// Returns the current value of the given action.
+    ///
+    /// Buttons and keys have a value of `1.0` when pressed and `0.0`
+    /// otherwise. Gamepad axes have values between `-1.0` and `1.0`. When
+    /// multiple input sources are bound to the action, the value with the
+    /// largest magnitude is returned.
+    pub fn action_axis(&self, action: Action) -> f32 {
+        self.values
+            .iter()
+            .filter(|(binding, _)| self.action(**binding) == Some(action))
+            .map(|(_, value)| *value)
+            .fold(
+                0.0,
+                |max, value| {
+                    if value.abs() > max.abs() {
+                        value
+                    } else {
+                        max
+                    }
+                },
+            )
+    }
+
+    fn action(&self, binding: Binding) -> Option<Action> {
+        self.bindings
+            .iter()
+            .rev()
+            .find(|(_, b)| *b == binding)
+            .map(|(action, _)| *action)
+    }
+
+    fn set(&mut self, binding: Binding, value: f32) {
+        let previous = self.values.insert(binding, value).unwrap_or(0.0);
+
+        if previous.abs() < Self::ACTIVATION_THRESHOLD
+            && value.abs() >= Self::ACTIVATION_THRESHOLD
+        {
+            if let Some(action) = self.action(binding) {
+                let _ = self.triggered.insert(action);
+            }
+        }
+    }
+}
+
+impl<Action: Copy + Eq + Hash> Default for Mapping<Action> {
+    fn default() -> Mapping<Action> {
+        Mapping::new()
+    }
+}
+
+fn button_value(state: ButtonState) -> f32 {
+    match state {
+        ButtonState::Pressed => 1.0,
+        ButtonState::Released => 0.0,
+    }
+}
+
+impl<Action: Copy + Eq + Hash> Input for Mapping<Action> {
+    fn new() -> Mapping<Action> {
+        Mapping::new()
+    }
+
+    fn update(&mut self, event: Event) {
+        match event {
+            Event::Keyboard {
+                event:
+                    keyboard::Event::Input {
+                        state,
+                        key_code,
+                        scan_code,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => {
+                let value = button_value(state);
+
+                if let Some(key_code) = key_code {
+                    self.set(Binding::from(key_code), value);
+                }
+
+                self.set(
+                    Binding::Key(keyboard::Key::Physical(scan_code)),
+                    value,
+                );
+            }
+            Event::Mouse {
+                event: mouse::Event::Input { state, button, .. },
+                ..
+            } => {
+                self.set(Binding::MouseButton(button), button_value(state));
+            }
+            Event::Gamepad { event, .. } => match event {
+                gamepad::Event::ButtonPressed(button) => {
+                    self.set(Binding::GamepadButton(button), 1.0);
+                }
+                gamepad::Event::ButtonReleased(button) => {
+                    self.set(Binding::GamepadButton(button), 0.0);
+                }
+                gamepad::Event::ButtonChanged(button, value) => {
+                    self.set(Binding::GamepadButton(button), value);
+                }
+                gamepad::Event::AxisChanged(axis, value) => {
+                    self.set(Binding::GamepadAxis(axis), value);
+                }
+                _ => {}
+            },
+            Event::Window {
+                event: window::Event::Unfocused,
+                ..
+            } => {
+                self.values.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {}
+
+    fn clear_edges(&mut self) {
+        self.triggered.clear();
+    }
+}
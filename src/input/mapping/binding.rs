@@ -0,0 +1,53 @@
+use crate::input::{gamepad, keyboard, mouse};
+
+/// An input source that can be bound to an action in a [`Mapping`].
+///
+/// [`Mapping`]: struct.Mapping.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Binding {
+    /// A keyboard key
+    Key(keyboard::Key),
+
+    /// A mouse button
+    MouseButton(mouse::Button),
+
+    /// A gamepad button, from any gamepad
+    GamepadButton(gamepad::Button),
+
+    /// A gamepad axis, from any gamepad
+    GamepadAxis(gamepad::Axis),
+}
+
+impl From<keyboard::Key> for Binding {
+    fn from(key: keyboard::Key) -> Binding {
+        Binding::Key(key)
+    }
+}
+
+impl From<keyboard::KeyCode> for Binding {
+    fn from(key_code: keyboard::KeyCode) -> Binding {
+        Binding::Key(keyboard::Key::Logical(key_code))
+    }
+}
+
+impl From<mouse::Button> for Binding {
+    fn from(button: mouse::Button) -> Binding {
+        Binding::MouseButton(button)
+    }
+}
+
+impl From<gamepad::Button> for Binding {
+    fn from(button: gamepad::Button) -> Binding {
+        Binding::GamepadButton(button)
+    }
+}
+
+impl From<gamepad::Axis> for Binding {
+    fn from(axis: gamepad::Axis) -> Binding {
+        Binding::GamepadAxis(axis)
+    }
+}
@@ -1,16 +1,21 @@
 //! Listen to mouse events.
 
+mod drag;
 mod event;
+mod scroll_delta;
 mod wheel_movement;
 
 pub use crate::graphics::window::winit::event::MouseButton as Button;
+pub use drag::{Drag, DragStatus};
 pub use event::Event;
+pub use scroll_delta::ScrollDelta;
 pub use wheel_movement::WheelMovement;
 
-use super::{ButtonState, Event as InputEvent, Input};
-use crate::graphics::Point;
+use super::{window, ButtonState, Event as InputEvent, Input};
+use crate::graphics::{Point, Vector};
 
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// A simple mouse input tracker.
 ///
@@ -24,16 +29,73 @@ pub struct Mouse {
     is_cursor_taken: bool,
     is_cursor_within_window: bool,
     button_clicks: HashMap<Button, Vec<Point>>,
-    pressed_buttons: HashSet<Button>,
+    pressed_buttons: HashMap<Button, Press>,
+    button_holds: HashMap<Button, Duration>,
+    last_clicks: HashMap<Button, Click>,
+    multiple_clicks: HashSet<(Button, u32)>,
+    drags: HashMap<Button, Drag>,
+    double_click_interval: Duration,
+    click_distance: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    time: Instant,
+    position: Point,
+    is_cancelled: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Click {
+    time: Instant,
+    position: Point,
+    count: u32,
 }
 
 impl Mouse {
+    /// The amount of pixels that are considered a line when tracking
+    /// [`WheelMovement`].
+    ///
+    /// [`WheelMovement`]: struct.WheelMovement.html
+    pub const PIXELS_PER_LINE: f32 = 20.0;
+
+    /// The default maximum time between two clicks of a double click.
+    pub const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration =
+        Duration::from_millis(500);
+
+    /// The default maximum distance, in pixels, that the cursor can move
+    /// during a click or between the clicks of a double click.
+    pub const DEFAULT_CLICK_DISTANCE: f32 = 4.0;
+
+    /// Sets the maximum time between two clicks of a double click.
+    ///
+    /// By default, it is [`DEFAULT_DOUBLE_CLICK_INTERVAL`].
+    ///
+    /// [`DEFAULT_DOUBLE_CLICK_INTERVAL`]: #associatedconstant.DEFAULT_DOUBLE_CLICK_INTERVAL
+    pub fn set_double_click_interval(&mut self, interval: Duration) {
+        self.double_click_interval = interval;
+    }
+
+    /// Sets the maximum distance, in pixels, that the cursor can move during a
+    /// click or between the clicks of a double click.
+    ///
+    /// By default, it is [`DEFAULT_CLICK_DISTANCE`].
+    ///
+    /// [`DEFAULT_CLICK_DISTANCE`]: #associatedconstant.DEFAULT_CLICK_DISTANCE
+    pub fn set_click_distance(&mut self, distance: f32) {
+        self.click_distance = distance;
+    }
+
     /// Returns the current cursor position.
     pub fn cursor_position(&self) -> Point {
         self.cursor_position
     }
 
     /// Returns the wheel movements during the last interaction.
+    ///
+    /// Scrolls measured in pixels, like the ones produced by touchpads, are
+    /// converted to lines assuming a line height of
+    /// [`PIXELS_PER_LINE`](#associatedconstant.PIXELS_PER_LINE).
     pub fn wheel_movement(&self) -> WheelMovement {
         self.wheel_movement
     }
@@ -57,11 +119,61 @@ impl Mouse {
 
     /// Returns true if the given button is currently pressed.
     pub fn is_button_pressed(&self, button: Button) -> bool {
-        self.pressed_buttons.contains(&button)
+        self.pressed_buttons.contains_key(&button)
+    }
+
+    /// Returns true if the given button was clicked since the last update.
+    ///
+    /// Clicks performed while the cursor is not available are automatically
+    /// ignored.
+    pub fn was_button_clicked(&self, button: Button) -> bool {
+        !self.button_clicks(button).is_empty()
     }
 
-    /// Returns the positions of the clicks during the last interaction.
+    /// Returns for how long the given button has been held, if it is
+    /// currently pressed.
     ///
+    /// Hold durations are measured in wall time, from the moment the tracker
+    /// processes the press event.
+    pub fn button_held_duration(&self, button: Button) -> Option<Duration> {
+        self.pressed_buttons
+            .get(&button)
+            .map(|press| press.time.elapsed())
+    }
+
+    /// Returns for how long the given button was held, if it was released
+    /// since the last update.
+    pub fn last_button_hold(&self, button: Button) -> Option<Duration> {
+        self.button_holds.get(&button).cloned()
+    }
+
+    /// Returns true if the given button was double clicked since the last
+    /// update.
+    pub fn was_button_double_clicked(&self, button: Button) -> bool {
+        self.multiple_clicks.contains(&(button, 2))
+    }
+
+    /// Returns true if the given button was triple clicked since the last
+    /// update.
+    pub fn was_button_triple_clicked(&self, button: Button) -> bool {
+        self.multiple_clicks.contains(&(button, 3))
+    }
+
+    /// Returns the current [`Drag`] performed with the given button, if any.
+    ///
+    /// A drag only begins once the cursor moves further than the
+    /// [click distance] while the button is pressed. Finished and cancelled
+    /// drags are returned during the interaction where they ended.
+    ///
+    /// [`Drag`]: struct.Drag.html
+    /// [click distance]: #method.set_click_distance
+    pub fn drag(&self, button: Button) -> Option<Drag> {
+        self.drags.get(&button).cloned()
+    }
+
+    /// Returns the positions of the clicks since the last update.
+    ///
+    /// A click is a press and a release of a button in the same position.
     /// Clicks performed while the cursor is not available are automatically
     /// ignored.
     pub fn button_clicks(&self, button: Button) -> &[Point] {
@@ -72,6 +184,91 @@ impl Mouse {
     }
 }
 
+impl Mouse {
+    fn is_close(&self, point: Point) -> bool {
+        nalgebra::distance(&point, &self.cursor_position) <= self.click_distance
+    }
+
+    fn move_cursor(&mut self, position: Point) {
+        let delta = position - self.cursor_position;
+        self.cursor_position = position;
+
+        if self.is_cursor_taken {
+            return;
+        }
+
+        for (button, press) in self.pressed_buttons.iter() {
+            if press.is_cancelled {
+                continue;
+            }
+
+            let origin = &press.position;
+
+            if let Some(drag) = self.drags.get_mut(button) {
+                drag.current = position;
+                drag.delta += delta;
+            } else if nalgebra::distance(origin, &position)
+                > self.click_distance
+            {
+                let _ = self.drags.insert(
+                    *button,
+                    Drag {
+                        origin: *origin,
+                        current: position,
+                        delta: position - origin,
+                        status: DragStatus::InProgress,
+                    },
+                );
+            }
+        }
+    }
+
+    // Buttons stay pressed, but their presses produce no drags nor clicks
+    fn cancel_drags(&mut self) {
+        for (button, press) in self.pressed_buttons.iter_mut() {
+            press.is_cancelled = true;
+
+            if let Some(drag) = self.drags.get_mut(button) {
+                drag.status = DragStatus::Cancelled;
+            }
+        }
+    }
+
+    fn click(&mut self, button: Button) {
+        let now = Instant::now();
+        let position = self.cursor_position;
+
+        let count = match self.last_clicks.get(&button) {
+            Some(last)
+                if now.duration_since(last.time)
+                    <= self.double_click_interval
+                    && self.is_close(last.position) =>
+            {
+                last.count + 1
+            }
+            _ => 1,
+        };
+
+        let _ = self.last_clicks.insert(
+            button,
+            Click {
+                time: now,
+                position,
+                count,
+            },
+        );
+
+        if count > 1 {
+            let _ = self.multiple_clicks.insert((button, count));
+        }
+
+        self.button_clicks
+            .entry(button)
+            .or_insert_with(Vec::new)
+            .push(position);
+    }
+}
+
 impl Input for Mouse {
     fn new() -> Mouse {
         Mouse {
@@ -80,15 +277,23 @@ impl Input for Mouse {
             is_cursor_taken: false,
             is_cursor_within_window: false,
             button_clicks: HashMap::new(),
-            pressed_buttons: HashSet::new(),
+            pressed_buttons: HashMap::new(),
+            button_holds: HashMap::new(),
+            last_clicks: HashMap::new(),
+            multiple_clicks: HashSet::new(),
+            drags: HashMap::new(),
+            double_click_interval: Self::DEFAULT_DOUBLE_CLICK_INTERVAL,
+            click_distance: Self::DEFAULT_CLICK_DISTANCE,
         }
     }
 
     fn update(&mut self, event: InputEvent) {
         match event {
-            InputEvent::Mouse(mouse_event) => match mouse_event {
+            InputEvent::Mouse {
+                event: mouse_event, ..
+            } => match mouse_event {
                 Event::CursorMoved { x, y } => {
-                    self.cursor_position = Point::new(x, y);
+                    self.move_cursor(Point::new(x, y));
                 }
                 Event::CursorTaken => {
                     self.is_cursor_taken = true;
@@ -96,24 +301,40 @@ impl Input for Mouse {
                 Event::CursorReturned => {
                     self.is_cursor_taken = false;
                 }
-                Event::Input { state, button } => {
+                Event::Input { state, button, .. } => {
                     match state {
                         ButtonState::Pressed => {
                             if !self.is_cursor_taken {
-                                let _ = self.pressed_buttons.insert(button);
+                                let _ = self.pressed_buttons.insert(
+                                    button,
+                                    Press {
+                                        time: Instant::now(),
+                                        position: self.cursor_position,
+                                        is_cancelled: false,
+                                    },
+                                );
                             }
                         }
                         ButtonState::Released => {
-                            if !self.is_cursor_taken
-                                && self.is_button_pressed(button)
+                            if let Some(press) =
+                                self.pressed_buttons.remove(&button)
                             {
-                                self.button_clicks
-                                    .entry(button)
-                                    .or_insert_with(Vec::new)
-                                    .push(self.cursor_position);
-                            }
+                                let _ = self
+                                    .button_holds
+                                    .insert(button, press.time.elapsed());
 
-                            let _ = self.pressed_buttons.remove(&button);
+                                if press.is_cancelled {
+                                    // Cancelled presses are neither drags nor clicks
+                                } else if let Some(drag) =
+                                    self.drags.get_mut(&button)
+                                {
+                                    drag.status = DragStatus::Finished;
+                                } else if !self.is_cursor_taken
+                                    && self.is_close(press.position)
+                                {
+                                    self.click(button);
+                                }
+                            }
                         }
                     };
                 }
@@ -122,10 +343,19 @@ impl Input for Mouse {
                 }
                 Event::CursorLeft => {
                     self.is_cursor_within_window = false;
+                    self.cancel_drags();
                 }
-                Event::WheelScrolled { delta_x, delta_y } => {
-                    self.wheel_movement.horizontal += delta_x;
-                    self.wheel_movement.vertical += delta_y;
+                Event::WheelScrolled { delta } => {
+                    let (lines_x, lines_y) = match delta {
+                        ScrollDelta::Lines { x, y } => (x, y),
+                        ScrollDelta::Pixels { x, y } => (
+                            x / Self::PIXELS_PER_LINE,
+                            y / Self::PIXELS_PER_LINE,
+                        ),
+                    };
+
+                    self.wheel_movement.horizontal += lines_x;
+                    self.wheel_movement.vertical += lines_y;
                 }
             },
             InputEvent::Keyboard { .. } => {
@@ -134,15 +364,32 @@ impl Input for Mouse {
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
+            InputEvent::Window {
+                event: window::Event::Unfocused,
+                ..
+            } => {
+                self.cancel_drags();
+            }
             InputEvent::Window { .. } => {
-                // Ignore window events...
+                // Ignore other window events...
             }
         }
     }
 
     fn clear(&mut self) {
-        self.button_clicks.values_mut().for_each(Vec::clear);
+        self.drags
+            .retain(|_, drag| drag.status == DragStatus::InProgress);
+
+        for drag in self.drags.values_mut() {
+            drag.delta = Vector::new(0.0, 0.0);
+        }
         self.wheel_movement.horizontal = 0.0;
         self.wheel_movement.vertical = 0.0;
     }
+
+    fn clear_edges(&mut self) {
+        self.button_clicks.values_mut().for_each(Vec::clear);
+        self.multiple_clicks.clear();
+        self.button_holds.clear();
+    }
 }
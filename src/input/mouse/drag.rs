@@ -0,0 +1,33 @@
+use crate::graphics::{Point, Vector};
+
+/// A drag gesture performed with a mouse button.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    /// The position where the button was pressed
+    pub origin: Point,
+
+    /// The current position of the cursor
+    pub current: Point,
+
+    /// The movement of the cursor during the last interaction
+    pub delta: Vector,
+
+    /// The status of the drag
+    pub status: DragStatus,
+}
+
+/// The status of a [`Drag`].
+///
+/// [`Drag`]: struct.Drag.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragStatus {
+    /// The button is still pressed.
+    InProgress,
+
+    /// The button was released during the last interaction.
+    Finished,
+
+    /// The drag was interrupted during the last interaction, because the
+    /// cursor left the window or the window lost focus.
+    Cancelled,
+}
@@ -1,5 +1,5 @@
-use super::Button;
-use crate::input::ButtonState;
+use super::{Button, ScrollDelta};
+use crate::input::{keyboard, ButtonState};
 
 /// A mouse event.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -37,14 +37,18 @@ pub enum Event {
 
         /// The button identifier
         button: Button,
+
+        /// The state of the keyboard modifiers when the button was pressed or
+        /// released
+        modifiers: keyboard::Modifiers,
     },
 
     /// The mouse wheel was scrolled.
+    ///
+    /// Every scroll is delivered, even when multiple of them happen during the
+    /// same frame.
     WheelScrolled {
-        /// The number of horizontal lines scrolled
-        delta_x: f32,
-
-        /// The number of vertical lines scrolled
-        delta_y: f32,
+        /// The amount scrolled
+        delta: ScrollDelta,
     },
 }
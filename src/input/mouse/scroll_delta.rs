@@ -0,0 +1,28 @@
+/// The amount scrolled by a mouse wheel or a touchpad.
+///
+/// Positive values indicate movement forward (away from the user) or
+/// rightwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollDelta {
+    /// A scroll measured in lines.
+    ///
+    /// Mouse wheels normally produce this kind of scroll.
+    Lines {
+        /// The number of horizontal lines scrolled
+        x: f32,
+
+        /// The number of vertical lines scrolled
+        y: f32,
+    },
+
+    /// A scroll measured in pixels.
+    ///
+    /// Touchpads normally produce this kind of scroll.
+    Pixels {
+        /// The number of horizontal pixels scrolled
+        x: f32,
+
+        /// The number of vertical pixels scrolled
+        y: f32,
+    },
+}
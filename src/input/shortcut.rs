@@ -0,0 +1,88 @@
+//! Match keyboard shortcuts, like `Ctrl+Shift+S`.
+
+mod chord;
+mod error;
+mod shortcuts;
+
+pub use chord::Chord;
+pub use error::ParseError;
+pub use shortcuts::Shortcuts;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A keyboard shortcut.
+///
+/// A [`Shortcut`] is a sequence of one or more [`Chord`]s. It can be parsed
+/// from a string where chords are separated by spaces:
+///
+/// ```
+/// use coffee::input::Shortcut;
+///
+/// let save: Shortcut = "Ctrl+Shift+S".parse().expect("Parse shortcut");
+/// let delete_line: Shortcut = "G X".parse().expect("Parse shortcut");
+/// ```
+///
+/// [`Shortcut`]: struct.Shortcut.html
+/// [`Chord`]: struct.Chord.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    chords: Vec<Chord>,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] from a sequence of [`Chord`]s.
+    ///
+    /// # Panics
+    /// It panics if the sequence is empty.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    /// [`Chord`]: struct.Chord.html
+    pub fn new(chords: Vec<Chord>) -> Shortcut {
+        assert!(!chords.is_empty(), "A shortcut needs at least one chord");
+
+        Shortcut { chords }
+    }
+
+    /// Returns the [`Chord`]s of the [`Shortcut`].
+    ///
+    /// [`Chord`]: struct.Chord.html
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+}
+
+impl From<Chord> for Shortcut {
+    fn from(chord: Chord) -> Shortcut {
+        Shortcut {
+            chords: vec![chord],
+        }
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Shortcut, ParseError> {
+        let chords = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Chord>, _>>()?;
+
+        if chords.is_empty() {
+            Err(ParseError::Empty)
+        } else {
+            Ok(Shortcut { chords })
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chords: Vec<String> =
+            self.chords.iter().map(Chord::to_string).collect();
+
+        write!(f, "{}", chords.join(" "))
+    }
+}
@@ -0,0 +1,182 @@
+use super::ParseError;
+use crate::input::keyboard::{self, KeyCode, Modifiers};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A key pressed together with an exact set of modifiers, like `Ctrl+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// The modifiers that must be held
+    pub modifiers: Modifiers,
+
+    /// The key that must be pressed
+    pub key: KeyCode,
+}
+
+impl Chord {
+    /// Creates a new [`Chord`].
+    ///
+    /// [`Chord`]: struct.Chord.html
+    pub fn new(modifiers: Modifiers, key: KeyCode) -> Chord {
+        Chord { modifiers, key }
+    }
+}
+
+impl From<KeyCode> for Chord {
+    fn from(key: KeyCode) -> Chord {
+        Chord::new(Modifiers::default(), key)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ParseError;
+
+    fn from_str(chord: &str) -> Result<Chord, ParseError> {
+        let mut parts: Vec<&str> = chord.split('+').map(str::trim).collect();
+
+        let key = match parts.pop() {
+            Some(key) if !key.is_empty() => key,
+            _ => {
+                return Err(ParseError::MissingKey {
+                    chord: String::from(chord),
+                });
+            }
+        };
+
+        let mut modifiers = Modifiers::default();
+
+        for modifier in parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "logo" | "super" | "cmd" | "command" | "win" | "meta" => {
+                    modifiers.logo = true
+                }
+                _ => {
+                    return Err(ParseError::UnknownModifier {
+                        chord: String::from(chord),
+                        modifier: String::from(modifier),
+                    });
+                }
+            }
+        }
+
+        let key = parse_key(&key.to_lowercase()).ok_or_else(|| {
+            ParseError::UnknownKey {
+                chord: String::from(chord),
+                key: String::from(key),
+            }
+        })?;
+
+        Ok(Chord::new(modifiers, key))
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+
+        if self.modifiers.logo {
+            write!(f, "Logo+")?;
+        }
+
+        write!(f, "{}", keyboard::key_code_name(&self.key))
+    }
+}
+
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let key = match name {
+        "a" => KeyCode::A,
+        "b" => KeyCode::B,
+        "c" => KeyCode::C,
+        "d" => KeyCode::D,
+        "e" => KeyCode::E,
+        "f" => KeyCode::F,
+        "g" => KeyCode::G,
+        "h" => KeyCode::H,
+        "i" => KeyCode::I,
+        "j" => KeyCode::J,
+        "k" => KeyCode::K,
+        "l" => KeyCode::L,
+        "m" => KeyCode::M,
+        "n" => KeyCode::N,
+        "o" => KeyCode::O,
+        "p" => KeyCode::P,
+        "q" => KeyCode::Q,
+        "r" => KeyCode::R,
+        "s" => KeyCode::S,
+        "t" => KeyCode::T,
+        "u" => KeyCode::U,
+        "v" => KeyCode::V,
+        "w" => KeyCode::W,
+        "x" => KeyCode::X,
+        "y" => KeyCode::Y,
+        "z" => KeyCode::Z,
+        "0" => KeyCode::Key0,
+        "1" => KeyCode::Key1,
+        "2" => KeyCode::Key2,
+        "3" => KeyCode::Key3,
+        "4" => KeyCode::Key4,
+        "5" => KeyCode::Key5,
+        "6" => KeyCode::Key6,
+        "7" => KeyCode::Key7,
+        "8" => KeyCode::Key8,
+        "9" => KeyCode::Key9,
+        "f1" => KeyCode::F1,
+        "f2" => KeyCode::F2,
+        "f3" => KeyCode::F3,
+        "f4" => KeyCode::F4,
+        "f5" => KeyCode::F5,
+        "f6" => KeyCode::F6,
+        "f7" => KeyCode::F7,
+        "f8" => KeyCode::F8,
+        "f9" => KeyCode::F9,
+        "f10" => KeyCode::F10,
+        "f11" => KeyCode::F11,
+        "f12" => KeyCode::F12,
+        "escape" | "esc" => KeyCode::Escape,
+        "enter" | "return" => KeyCode::Return,
+        "space" => KeyCode::Space,
+        "tab" => KeyCode::Tab,
+        "backspace" | "back" => KeyCode::Back,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "minus" | "-" => KeyCode::Minus,
+        "equals" | "=" => KeyCode::Equals,
+        "comma" | "," => KeyCode::Comma,
+        "period" | "." => KeyCode::Period,
+        "slash" | "/" => KeyCode::Slash,
+        "backslash" | "\\" => KeyCode::Backslash,
+        "semicolon" | ";" => KeyCode::Semicolon,
+        "colon" | ":" => KeyCode::Colon,
+        "apostrophe" | "'" => KeyCode::Apostrophe,
+        "grave" | "`" => KeyCode::Grave,
+        "lbracket" | "[" => KeyCode::LBracket,
+        "rbracket" | "]" => KeyCode::RBracket,
+        "at" | "@" => KeyCode::At,
+        "intlbackslash" | "oem102" => KeyCode::OEM102,
+        _ => return None,
+    };
+
+    Some(key)
+}
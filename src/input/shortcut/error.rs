@@ -0,0 +1,57 @@
+use std::error;
+use std::fmt;
+
+/// An error produced when parsing a [`Shortcut`].
+///
+/// [`Shortcut`]: struct.Shortcut.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The shortcut does not contain any chord.
+    Empty,
+
+    /// A chord is missing its key, like in `Ctrl+`.
+    MissingKey {
+        /// The chord missing the key
+        chord: String,
+    },
+
+    /// A modifier is not recognized.
+    UnknownModifier {
+        /// The chord containing the modifier
+        chord: String,
+
+        /// The unknown modifier
+        modifier: String,
+    },
+
+    /// A key is not recognized.
+    UnknownKey {
+        /// The chord containing the key
+        chord: String,
+
+        /// The unknown key
+        key: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "The shortcut is empty"),
+            ParseError::MissingKey { chord } => {
+                write!(f, "The chord \"{}\" is missing a key", chord)
+            }
+            ParseError::UnknownModifier { chord, modifier } => write!(
+                f,
+                "Unknown modifier \"{}\" in chord \"{}\" \
+                 (expected Ctrl, Shift, Alt, or Logo)",
+                modifier, chord
+            ),
+            ParseError::UnknownKey { chord, key } => {
+                write!(f, "Unknown key \"{}\" in chord \"{}\"", key, chord)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
@@ -0,0 +1,146 @@
+use super::{Chord, Shortcut};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{ButtonState, Event, Input};
+
+use std::time::{Duration, Instant};
+
+/// An input tracker that matches [`Shortcut`]s and produces commands.
+///
+/// A [`Shortcut`] triggers when the key of its last [`Chord`] is pressed,
+/// while holding exactly the modifiers of the [`Chord`]. For instance, `Ctrl+S`
+/// does not trigger when `Ctrl+Shift+S` is pressed. Holding the key does not
+/// trigger the [`Shortcut`] again.
+///
+/// The [`Chord`]s of a sequence, like `G X`, need to be pressed less than
+/// [`SEQUENCE_TIMEOUT`] apart.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`Shortcut`]: struct.Shortcut.html
+/// [`Chord`]: struct.Chord.html
+/// [`SEQUENCE_TIMEOUT`]: #associatedconstant.SEQUENCE_TIMEOUT
+/// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Shortcuts<Command> {
+    shortcuts: Vec<(Shortcut, Command)>,
+    pressed: Vec<Chord>,
+    last_pressed_at: Option<Instant>,
+    triggered: Vec<Command>,
+}
+
+impl<Command: Clone> Shortcuts<Command> {
+    /// The maximum time between the [`Chord`]s of a sequence.
+    ///
+    /// [`Chord`]: struct.Chord.html
+    pub const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Creates an empty set of [`Shortcuts`].
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    pub fn new() -> Shortcuts<Command> {
+        Shortcuts {
+            shortcuts: Vec::new(),
+            pressed: Vec::new(),
+            last_pressed_at: None,
+            triggered: Vec::new(),
+        }
+    }
+
+    /// Registers a [`Shortcut`] that produces the given command.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn register(
+        &mut self,
+        shortcut: impl Into<Shortcut>,
+        command: Command,
+    ) {
+        self.shortcuts.push((shortcut.into(), command));
+    }
+
+    /// Returns the commands triggered during the last interaction, in order.
+    pub fn triggered(&self) -> &[Command] {
+        &self.triggered
+    }
+
+    fn press(&mut self, chord: Chord) {
+        let now = Instant::now();
+
+        let is_sequence_expired = self
+            .last_pressed_at
+            .map(|last| now.duration_since(last) > Self::SEQUENCE_TIMEOUT)
+            .unwrap_or(true);
+
+        if is_sequence_expired {
+            self.pressed.clear();
+        }
+
+        self.pressed.push(chord);
+        self.last_pressed_at = Some(now);
+
+        let pressed = &self.pressed;
+
+        let matched = self
+            .shortcuts
+            .iter()
+            .find(|(shortcut, _)| pressed.ends_with(shortcut.chords()));
+
+        if let Some((_, command)) = matched {
+            self.triggered.push(command.clone());
+            self.pressed.clear();
+        } else {
+            let longest = self
+                .shortcuts
+                .iter()
+                .map(|(shortcut, _)| shortcut.chords().len())
+                .max()
+                .unwrap_or(0);
+
+            if self.pressed.len() > longest {
+                let _ = self.pressed.remove(0);
+            }
+        }
+    }
+}
+
+fn is_modifier(key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::LShift
+        | KeyCode::RShift
+        | KeyCode::LControl
+        | KeyCode::RControl
+        | KeyCode::LAlt
+        | KeyCode::RAlt
+        | KeyCode::LWin
+        | KeyCode::RWin => true,
+        _ => false,
+    }
+}
+
+impl<Command: Clone> Input for Shortcuts<Command> {
+    fn new() -> Shortcuts<Command> {
+        Shortcuts::new()
+    }
+
+    fn update(&mut self, event: Event) {
+        match event {
+            Event::Keyboard {
+                event:
+                    keyboard::Event::Input {
+                        state: ButtonState::Pressed,
+                        key_code: Some(key_code),
+                        modifiers,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } if !is_modifier(key_code) => {
+                self.press(Chord::new(modifiers, key_code));
+            }
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        self.triggered.clear();
+    }
+}
@@ -0,0 +1,152 @@
+use super::keyboard::{self, KeyCode};
+use super::{ButtonState, Event, Input};
+
+/// A text input tracker that applies text editing events to a `String`.
+///
+/// It keeps a cursor and supports the `Backspace`, `Delete`, `Left`, `Right`,
+/// `Home`, and `End` keys.
+///
+/// Text committed by an input method editor, like the one used to type
+/// Chinese, Japanese, or Korean, is applied as it is committed. The text
+/// being composed is shown by the candidate window of the editor, which you
+/// can place with [`Window::set_ime_position`]. The windowing backend does not
+/// report composition yet, so the [`TextBuffer`] cannot show it inline.
+///
+/// You can use this as your [`Game::Input`] directly, or keep one in your own
+/// [`Input`] and only feed it events while a text field is focused.
+///
+/// [`Window::set_ime_position`]: ../graphics/struct.Window.html#method.set_ime_position
+/// [`TextBuffer`]: struct.TextBuffer.html
+/// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+/// [`Input`]: trait.Input.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TextBuffer {
+    text: String,
+    cursor: usize,
+}
+
+impl TextBuffer {
+    /// Returns the text of the [`TextBuffer`].
+    ///
+    /// [`TextBuffer`]: struct.TextBuffer.html
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the position of the cursor, as a byte index into the
+    /// [`text`].
+    ///
+    /// It always lies on a character boundary.
+    ///
+    /// [`text`]: #method.text
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Replaces the text of the [`TextBuffer`], placing the cursor at its end.
+    ///
+    /// [`TextBuffer`]: struct.TextBuffer.html
+    pub fn set_text(&mut self, text: &str) {
+        self.text = String::from(text);
+        self.cursor = self.text.len();
+    }
+
+    /// Inserts the given text at the cursor, moving the cursor to its end.
+    ///
+    /// Use it to paste the contents of the [`Clipboard`], for instance.
+    /// Control characters are skipped.
+    ///
+    /// [`Clipboard`]: ../graphics/struct.Clipboard.html
+    pub fn insert(&mut self, text: &str) {
+        for character in text.chars().filter(|c| !c.is_control()) {
+            self.text.insert(self.cursor, character);
+            self.cursor += character.len_utf8();
+        }
+    }
+
+    /// Removes all the text of the [`TextBuffer`].
+    ///
+    /// [`TextBuffer`]: struct.TextBuffer.html
+    pub fn reset(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    fn previous_boundary(&self) -> Option<usize> {
+        self.text[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+    }
+
+    fn next_boundary(&self) -> Option<usize> {
+        self.text[self.cursor..]
+            .chars()
+            .next()
+            .map(|c| self.cursor + c.len_utf8())
+    }
+
+    fn edit(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Back => {
+                if let Some(previous) = self.previous_boundary() {
+                    let _ = self.text.remove(previous);
+                    self.cursor = previous;
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor < self.text.len() {
+                    let _ = self.text.remove(self.cursor);
+                }
+            }
+            KeyCode::Left => {
+                if let Some(previous) = self.previous_boundary() {
+                    self.cursor = previous;
+                }
+            }
+            KeyCode::Right => {
+                if let Some(next) = self.next_boundary() {
+                    self.cursor = next;
+                }
+            }
+            KeyCode::Home => {
+                self.cursor = 0;
+            }
+            KeyCode::End => {
+                self.cursor = self.text.len();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Input for TextBuffer {
+    fn new() -> TextBuffer {
+        TextBuffer::default()
+    }
+
+    fn update(&mut self, event: Event) {
+        match event {
+            Event::Keyboard {
+                event: keyboard::Event::TextEntered { character },
+                ..
+            } => {
+                self.insert(character.encode_utf8(&mut [0; 4]));
+            }
+            Event::Keyboard {
+                event:
+                    keyboard::Event::Input {
+                        key_code: Some(key_code),
+                        state: ButtonState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.edit(key_code);
+            }
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {}
+}
@@ -0,0 +1,249 @@
+use super::{Event, Input};
+
+// Tuples of inputs fan out every event to each of their members. This allows
+// composing built-in trackers with custom input types, like:
+//
+//     type Input = (KeyboardAndMouse, Recorder);
+
+impl<A: Input, B: Input> Input for (A, B) {
+    fn new() -> (A, B) {
+        (A::new(), B::new())
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+    }
+}
+
+impl<A: Input, B: Input, C: Input> Input for (A, B, C) {
+    fn new() -> (A, B, C) {
+        (A::new(), B::new(), C::new())
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+    }
+}
+
+impl<A: Input, B: Input, C: Input, D: Input> Input for (A, B, C, D) {
+    fn new() -> (A, B, C, D) {
+        (A::new(), B::new(), C::new(), D::new())
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+        self.3.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+        self.3.clear_edges();
+    }
+}
+
+impl<A: Input, B: Input, C: Input, D: Input, E: Input> Input
+    for (A, B, C, D, E)
+{
+    fn new() -> (A, B, C, D, E) {
+        (A::new(), B::new(), C::new(), D::new(), E::new())
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+        self.3.update(event);
+        self.4.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+        self.4.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+        self.3.clear_edges();
+        self.4.clear_edges();
+    }
+}
+
+impl<A: Input, B: Input, C: Input, D: Input, E: Input, F: Input> Input
+    for (A, B, C, D, E, F)
+{
+    fn new() -> (A, B, C, D, E, F) {
+        (A::new(), B::new(), C::new(), D::new(), E::new(), F::new())
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+        self.3.update(event);
+        self.4.update(event);
+        self.5.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+        self.4.clear();
+        self.5.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+        self.3.clear_edges();
+        self.4.clear_edges();
+        self.5.clear_edges();
+    }
+}
+
+impl<A: Input, B: Input, C: Input, D: Input, E: Input, F: Input, G: Input> Input
+    for (A, B, C, D, E, F, G)
+{
+    fn new() -> (A, B, C, D, E, F, G) {
+        (
+            A::new(),
+            B::new(),
+            C::new(),
+            D::new(),
+            E::new(),
+            F::new(),
+            G::new(),
+        )
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+        self.3.update(event);
+        self.4.update(event);
+        self.5.update(event);
+        self.6.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+        self.4.clear();
+        self.5.clear();
+        self.6.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+        self.3.clear_edges();
+        self.4.clear_edges();
+        self.5.clear_edges();
+        self.6.clear_edges();
+    }
+}
+
+impl<
+        A: Input,
+        B: Input,
+        C: Input,
+        D: Input,
+        E: Input,
+        F: Input,
+        G: Input,
+        H: Input,
+    > Input for (A, B, C, D, E, F, G, H)
+{
+    fn new() -> (A, B, C, D, E, F, G, H) {
+        (
+            A::new(),
+            B::new(),
+            C::new(),
+            D::new(),
+            E::new(),
+            F::new(),
+            G::new(),
+            H::new(),
+        )
+    }
+
+    fn update(&mut self, event: Event) {
+        self.0.update(event);
+        self.1.update(event);
+        self.2.update(event);
+        self.3.update(event);
+        self.4.update(event);
+        self.5.update(event);
+        self.6.update(event);
+        self.7.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+        self.4.clear();
+        self.5.clear();
+        self.6.clear();
+        self.7.clear();
+    }
+
+    fn clear_edges(&mut self) {
+        self.0.clear_edges();
+        self.1.clear_edges();
+        self.2.clear_edges();
+        self.3.clear_edges();
+        self.4.clear_edges();
+        self.5.clear_edges();
+        self.6.clear_edges();
+        self.7.clear_edges();
+    }
+}
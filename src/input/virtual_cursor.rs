@@ -0,0 +1,205 @@
+use super::{gamepad, keyboard, mouse, ButtonState, Event};
+use crate::graphics::{Point, Vector};
+
+use std::time::Instant;
+
+/// A cursor moved by the left stick of a gamepad.
+///
+/// Once enabled with [`Window::set_virtual_cursor`], the engine produces
+/// synthetic mouse events for it. Therefore, any pointer-based logic, like the
+/// built-in [`UserInterface`], works with a gamepad without any changes.
+///
+/// Pressing the [`button`] of the [`VirtualCursor`] produces a left click. As
+/// soon as the real mouse moves, the [`VirtualCursor`] yields until the stick
+/// is used again.
+///
+/// Keep in mind that the engine does not draw the [`VirtualCursor`]. Use
+/// [`Window::virtual_cursor`] to obtain its [`position`] and draw it yourself.
+///
+/// [`Window::set_virtual_cursor`]: ../graphics/struct.Window.html#method.set_virtual_cursor
+/// [`Window::virtual_cursor`]: ../graphics/struct.Window.html#method.virtual_cursor
+/// [`UserInterface`]: ../ui/trait.UserInterface.html
+/// [`button`]: #method.button
+/// [`VirtualCursor`]: struct.VirtualCursor.html
+/// [`position`]: #method.position
+#[derive(Debug, Clone)]
+pub struct VirtualCursor {
+    speed: f32,
+    acceleration: f32,
+    dead_zone: f32,
+    button: gamepad::Button,
+    position: Point,
+    stick: Vector,
+    is_active: bool,
+    is_button_pressed: bool,
+    last_tick: Option<Instant>,
+}
+
+impl VirtualCursor {
+    /// Creates a new [`VirtualCursor`] with default settings.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub fn new() -> VirtualCursor {
+        VirtualCursor {
+            speed: 800.0,
+            acceleration: 2.0,
+            dead_zone: 0.2,
+            button: gamepad::Button::South,
+            position: Point::new(0.0, 0.0),
+            stick: Vector::new(0.0, 0.0),
+            is_active: false,
+            is_button_pressed: false,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the speed of the [`VirtualCursor`], in pixels per second, when the
+    /// stick is fully tilted.
+    ///
+    /// By default, it is set to `800.0`.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the exponent of the acceleration curve of the [`VirtualCursor`].
+    ///
+    /// A value of `1.0` makes the speed proportional to the stick tilt, while
+    /// higher values allow more precise movements with small tilts.
+    ///
+    /// By default, it is set to `2.0`.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub fn acceleration(mut self, acceleration: f32) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Sets the dead zone of the stick, in the `[0.0, 1.0)` interval.
+    ///
+    /// By default, it is set to `0.2`.
+    pub fn dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone.max(0.0).min(0.99);
+        self
+    }
+
+    /// Sets the gamepad button that clicks with the [`VirtualCursor`].
+    ///
+    /// By default, it is set to [`gamepad::Button::South`].
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    /// [`gamepad::Button::South`]: gamepad/enum.Button.html#variant.South
+    pub fn button(mut self, button: gamepad::Button) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Returns the current position of the [`VirtualCursor`].
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Returns true if the [`VirtualCursor`] is in use.
+    ///
+    /// It becomes inactive when the real mouse moves.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub(crate) fn process(&mut self, event: Event) -> Option<Event> {
+        match event {
+            Event::Mouse {
+                event: mouse::Event::CursorMoved { x, y },
+                ..
+            } => {
+                self.position = Point::new(x, y);
+                self.is_active = false;
+
+                None
+            }
+            Event::Gamepad { event, .. } => match event {
+                gamepad::Event::AxisChanged(gamepad::Axis::LeftStickX, x) => {
+                    self.stick.x = x;
+                    None
+                }
+                gamepad::Event::AxisChanged(gamepad::Axis::LeftStickY, y) => {
+                    self.stick.y = -y;
+                    None
+                }
+                gamepad::Event::ButtonPressed(button)
+                    if button == self.button && self.is_active =>
+                {
+                    self.is_button_pressed = true;
+                    Some(Self::click(ButtonState::Pressed))
+                }
+                gamepad::Event::ButtonReleased(button)
+                    if button == self.button && self.is_button_pressed =>
+                {
+                    self.is_button_pressed = false;
+                    Some(Self::click(ButtonState::Released))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub(crate) fn tick(&mut self, width: f32, height: f32) -> Option<Event> {
+        let now = Instant::now();
+        let elapsed = self
+            .last_tick
+            .map(|last_tick| now.duration_since(last_tick).as_secs_f32())
+            .unwrap_or(0.0);
+
+        self.last_tick = Some(now);
+
+        let tilt = self.stick.norm().min(1.0);
+
+        if tilt <= self.dead_zone {
+            return None;
+        }
+
+        let tilt = (tilt - self.dead_zone) / (1.0 - self.dead_zone);
+        let velocity =
+            self.stick.normalize() * self.speed * tilt.powf(self.acceleration);
+
+        let position = self.position + velocity * elapsed;
+
+        self.position = Point::new(
+            position.x.max(0.0).min(width),
+            position.y.max(0.0).min(height),
+        );
+        self.is_active = true;
+
+        Some(Event::Mouse {
+            event: mouse::Event::CursorMoved {
+                x: self.position.x,
+                y: self.position.y,
+            },
+            time: Instant::now(),
+        })
+    }
+
+    fn click(state: ButtonState) -> Event {
+        Event::Mouse {
+            event: mouse::Event::Input {
+                state,
+                button: mouse::Button::Left,
+                modifiers: keyboard::Modifiers::default(),
+            },
+            time: Instant::now(),
+        }
+    }
+}
+
+impl Default for VirtualCursor {
+    fn default() -> VirtualCursor {
+        VirtualCursor::new()
+    }
+}
@@ -31,7 +31,7 @@
 //! Here is a minimal example that will open a window:
 //!
 //! ```no_run
-//! use coffee::graphics::{Color, Frame, Window, WindowSettings};
+//! use coffee::graphics::{Color, Frame, GpuSettings, Window, WindowSettings};
 //! use coffee::load::Task;
 //! use coffee::{Game, Result, Timer};
 //!
@@ -42,6 +42,7 @@
 //!         resizable: true,
 //!         fullscreen: false,
 //!         maximized: false,
+//!         gpu: GpuSettings::default(),
 //!     })
 //! }
 //!
@@ -58,11 +59,13 @@
 //!         Task::succeed(|| MyGame { /* ... */ })
 //!     }
 //!
-//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
 //!         // Clear the current frame
 //!         frame.clear(Color::BLACK);
 //!
 //!         // Draw your game here. Check out the `graphics` module!
+//!
+//!         Ok(())
 //!     }
 //! }
 //! ```
@@ -76,17 +79,68 @@
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
 
+mod bench;
+mod clock;
 mod debug;
 mod game;
+#[cfg(feature = "log")]
+mod logging;
 mod result;
 mod timer;
 
+#[cfg(feature = "audio")]
+pub mod audio;
+#[cfg(feature = "dialogs")]
+pub mod dialog;
 pub mod graphics;
+pub mod headless;
 pub mod input;
 pub mod load;
+pub mod particles;
+pub mod runner;
+pub mod scene;
+#[cfg(any(feature = "data-json", feature = "data-ron"))]
+pub mod storage;
+#[cfg(feature = "tiles")]
+pub mod tiles;
+pub mod time;
 pub mod ui;
 
-pub use debug::Debug;
+/// The exact version of [`nalgebra`] used by Coffee.
+///
+/// [`Point`], [`Vector`], and [`Transformation`] are built on top of it. Use
+/// this re-export instead of depending on [`nalgebra`] directly, so your
+/// math types are always compatible with Coffee. Otherwise, a different
+/// version of [`nalgebra`] would define different types that cannot be mixed
+/// with the ones in Coffee.
+///
+/// [`nalgebra`]: https://nalgebra.org
+/// [`Point`]: graphics/type.Point.html
+/// [`Vector`]: graphics/type.Vector.html
+/// [`Transformation`]: graphics/struct.Transformation.html
+pub use nalgebra;
+
+/// The exact version of the [`image` crate] used by Coffee.
+///
+/// Use it to build the images given to [`Image::from_image`] or to process
+/// the result of [`Canvas::to_image`]. It is only available when the `image`
+/// feature is enabled, so the `image` crate does not become part of your
+/// dependencies unless you ask for it.
+///
+/// [`image` crate]: https://docs.rs/image
+/// [`Image::from_image`]: graphics/struct.Image.html#method.from_image
+/// [`Canvas::to_image`]: graphics/struct.Canvas.html#method.to_image
+#[cfg(feature = "image")]
+pub extern crate image_rs as image;
+
+#[cfg(not(feature = "image"))]
+extern crate image_rs as image;
+
+pub use bench::BenchReport;
+pub use clock::Clock;
+pub use debug::{Debug, DebugCorner, DebugSection, LogLevel};
 pub use game::Game;
+#[cfg(feature = "log")]
+pub use logging::{init_logging, LEVEL_VARIABLE};
 pub use result::{Error, Result};
-pub use timer::Timer;
+pub use timer::{FrameTimeStats, Timer};
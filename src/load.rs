@@ -18,9 +18,25 @@
 //! [`Task`]: struct.Task.html
 //! [`LoadingScreen`]: loading_screen/trait.LoadingScreen.html
 //! [`ProgressBar`]: loading_screen/struct.ProgressBar.html
+mod bundle;
+mod cache;
+mod directory;
+mod handle;
+mod job;
 mod task;
 
 pub mod loading_screen;
 
+#[cfg(any(feature = "data-json", feature = "data-ron"))]
+pub mod data;
+
+pub use bundle::{read, Bundle};
+pub use cache::Cache;
+pub use directory::from_dir;
+pub use handle::Handle;
+pub use job::Job;
 pub use loading_screen::LoadingScreen;
-pub use task::{Join, Progress, Task};
+pub use task::{CancelHandle, Join, Progress, Reporter, Task};
+
+pub(crate) use bundle::read_static;
+pub(crate) use task::Worker;
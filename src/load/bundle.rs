@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+static INSTALLED: AtomicPtr<Bundle> = AtomicPtr::new(ptr::null_mut());
+
+/// A set of assets embedded in the executable.
+///
+/// A [`Bundle`] maps virtual paths to bytes. Once installed, loaders that
+/// read files, like [`Image::load`] or [`Font::load_from_path`], look for
+/// their path in the [`Bundle`] first and only fall back to the filesystem
+/// when it is missing.
+///
+/// This allows you to ship your game as a single executable, while still
+/// being able to edit your assets during development.
+///
+/// ```
+/// use coffee::load::Bundle;
+///
+/// let mut bundle = Bundle::new();
+/// bundle.insert("resources/font/Inconsolata-Regular.ttf", &[]);
+///
+/// bundle.install();
+/// ```
+///
+/// The [`bundle!`] macro can embed a list of files at compile time for you.
+///
+/// [`Bundle`]: struct.Bundle.html
+/// [`Image::load`]: ../graphics/struct.Image.html#method.load
+/// [`Font::load_from_path`]: ../graphics/struct.Font.html#method.load_from_path
+/// [`bundle!`]: ../macro.bundle.html
+#[derive(Debug, Clone, Default)]
+pub struct Bundle {
+    assets: HashMap<PathBuf, &'static [u8]>,
+}
+
+impl Bundle {
+    /// Creates an empty [`Bundle`].
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    pub fn new() -> Bundle {
+        Bundle::default()
+    }
+
+    /// Adds an asset to the [`Bundle`] under the given virtual path.
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    pub fn insert<P: Into<PathBuf>>(&mut self, path: P, bytes: &'static [u8]) {
+        let _ = self.assets.insert(path.into(), bytes);
+    }
+
+    /// Returns the asset stored under the given virtual path, if any.
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&'static [u8]> {
+        self.assets.get(path.as_ref()).cloned()
+    }
+
+    /// Returns the amount of assets in the [`Bundle`].
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns true if the [`Bundle`] contains no assets.
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Installs the [`Bundle`] globally, making loaders use it.
+    ///
+    /// You should install your [`Bundle`] once, before running your game.
+    /// Installing a new [`Bundle`] replaces the previous one, but the memory
+    /// of the previous one is not reclaimed.
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    pub fn install(self) {
+        let _ = INSTALLED.swap(Box::into_raw(Box::new(self)), Ordering::SeqCst);
+    }
+
+    /// Returns the installed [`Bundle`], if any.
+    ///
+    /// [`Bundle`]: struct.Bundle.html
+    #[allow(unsafe_code)]
+    pub fn installed() -> Option<&'static Bundle> {
+        // An installed bundle is never freed, so it lives as long as the
+        // program does.
+        unsafe { INSTALLED.load(Ordering::SeqCst).as_ref() }
+    }
+}
+
+/// Reads the asset in the given path.
+///
+/// The installed [`Bundle`] is checked first. If the asset is not bundled, it
+/// is read from the filesystem.
+///
+/// [`Bundle`]: struct.Bundle.html
+pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    let bundle = Bundle::installed();
+
+    match bundle.and_then(|bundle| bundle.get(&path)) {
+        Some(bytes) => Ok(bytes.to_vec()),
+        None => {
+            #[cfg(feature = "log")]
+            let started_at = std::time::Instant::now();
+
+            let bytes = fs::read(&path);
+
+            #[cfg(feature = "log")]
+            match &bytes {
+                Ok(bytes) => log::debug!(
+                    "Read {} ({} bytes){} in {:.2?}",
+                    path.as_ref().display(),
+                    bytes.len(),
+                    if bundle.is_some() {
+                        " from disk, as it is not bundled,"
+                    } else {
+                        ""
+                    },
+                    started_at.elapsed()
+                ),
+                Err(error) => log::warn!(
+                    "Failed to read {}: {}",
+                    path.as_ref().display(),
+                    error
+                ),
+            }
+
+            bytes
+        }
+    }
+}
+
+/// Reads the asset in the given path and keeps it in memory for the rest of
+/// the program.
+///
+/// Like [`read`], but bundled assets are not copied.
+///
+/// [`read`]: fn.read.html
+pub(crate) fn read_static<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<&'static [u8]> {
+    match Bundle::installed().and_then(|bundle| bundle.get(&path)) {
+        Some(bytes) => Ok(bytes),
+        None => Ok(Box::leak(fs::read(path)?.into_boxed_slice())),
+    }
+}
+
+/// Creates a [`Bundle`] that embeds the given files at compile time.
+///
+/// Paths are relative to the root of your crate and are used as the virtual
+/// paths of the assets.
+///
+/// ```ignore
+/// let bundle = coffee::bundle![
+///     "resources/sprites/ball.png",
+///     "resources/fonts/Roboto.ttf",
+/// ];
+///
+/// bundle.install();
+/// ```
+///
+/// [`Bundle`]: load/struct.Bundle.html
+#[macro_export]
+macro_rules! bundle {
+    ($($path:literal),* $(,)?) => {{
+        let mut bundle = $crate::load::Bundle::new();
+
+        $(
+            bundle.insert(
+                $path,
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)),
+            );
+        )*
+
+        bundle
+    }};
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::graphics::{Font, Image};
+
+/// The cache of loaded assets of a [`Gpu`], keyed by path.
+///
+/// Loaders like [`Image::load`] and [`Font::load_from_path`] look for their
+/// path in the [`Cache`] before loading anything. This way, loading the same
+/// file twice produces a cheap clone of the same asset instead of a duplicate
+/// in GPU memory.
+///
+/// Every [`Gpu`] has its own [`Cache`], which you can obtain with
+/// [`Gpu::cache`]. An asset is dropped from the [`Cache`] at the end of the
+/// first frame where nothing else is using it. You can also [`evict`] assets
+/// or [`clear`] the whole [`Cache`] to stop sharing them, on level
+/// transitions for instance.
+///
+/// [`Gpu`]: ../graphics/struct.Gpu.html
+/// [`Image::load`]: ../graphics/struct.Image.html#method.load
+/// [`Font::load_from_path`]: ../graphics/struct.Font.html#method.load_from_path
+/// [`Cache`]: struct.Cache.html
+/// [`Gpu::cache`]: ../graphics/struct.Gpu.html#method.cache
+/// [`evict`]: #method.evict
+/// [`clear`]: #method.clear
+#[derive(Default)]
+pub struct Cache {
+    images: HashMap<PathBuf, Image>,
+    fonts: HashMap<PathBuf, Font>,
+}
+
+impl Cache {
+    /// Removes the assets loaded from the given path from the [`Cache`].
+    ///
+    /// Returns true if there was such an asset. Any clone of the asset that
+    /// is still in use remains valid.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn evict<P: AsRef<Path>>(&mut self, path: P) -> bool {
+        let image = self.images.remove(path.as_ref()).is_some();
+        let font = self.fonts.remove(path.as_ref()).is_some();
+
+        image || font
+    }
+
+    /// Removes every asset from the [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn clear(&mut self) {
+        self.images.clear();
+        self.fonts.clear();
+    }
+
+    /// Returns the amount of assets in the [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn len(&self) -> usize {
+        self.images.len() + self.fonts.len()
+    }
+
+    /// Returns true if the [`Cache`] contains no assets.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn image(&self, path: &Path) -> Option<Image> {
+        self.images.get(path).cloned()
+    }
+
+    pub(crate) fn insert_image(&mut self, path: PathBuf, image: Image) {
+        let _ = self.images.insert(path, image);
+    }
+
+    pub(crate) fn font(&self, path: &Path) -> Option<Font> {
+        self.fonts.get(path).cloned()
+    }
+
+    pub(crate) fn insert_font(&mut self, path: PathBuf, font: Font) {
+        let _ = self.fonts.insert(path, font);
+    }
+
+    // Drops the assets that are only kept alive by the cache
+    pub(crate) fn prune(&mut self) {
+        self.images.retain(|_, image| image.is_shared());
+        self.fonts.retain(|_, font| font.is_shared());
+    }
+}
+
+impl std::fmt::Debug for Cache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cache {{ images: {}, fonts: {} }}",
+            self.images.len(),
+            self.fonts.len()
+        )
+    }
+}
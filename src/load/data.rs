@@ -0,0 +1,106 @@
+//! Load data files, like levels or settings, using [`serde`].
+//!
+//! Data files are read like any other asset, so they can be embedded using a
+//! [`Bundle`]. Errors contain the path of the file and the position where
+//! deserialization failed.
+//!
+//! # Versioning
+//! Use `#[serde(default)]` on fields you add to your data formats. This way,
+//! files written before the field existed still load:
+//!
+//! ```ignore
+//! use coffee::graphics::Image;
+//! use coffee::load::{data, Join, Task};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct LevelDef {
+//!     name: String,
+//!     tiles: Vec<u16>,
+//!
+//!     // Added in version 2, older levels have no music
+//!     #[serde(default)]
+//!     music: Option<String>,
+//! }
+//!
+//! let load_level = (
+//!     Image::load("resources/tiles.png"),
+//!     data::from_json::<LevelDef, _>("resources/levels/1.json"),
+//! )
+//!     .join();
+//! ```
+//!
+//! [`serde`]: https://docs.rs/serde
+//! [`Bundle`]: ../struct.Bundle.html
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+
+use crate::load::{self, Task};
+use crate::Error;
+
+/// Creates a [`Task`] that deserializes a JSON file.
+///
+/// This function is only available when the `data-json` feature is enabled.
+///
+/// [`Task`]: ../struct.Task.html
+#[cfg(feature = "data-json")]
+pub fn from_json<T, P>(path: P) -> Task<T>
+where
+    T: 'static + DeserializeOwned,
+    P: Into<PathBuf>,
+{
+    from_file(path.into(), |bytes| {
+        serde_json::from_slice(bytes).map_err(|error| Error::Data {
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        })
+    })
+}
+
+/// Creates a [`Task`] that deserializes a [RON] file.
+///
+/// This function is only available when the `data-ron` feature is enabled.
+///
+/// [`Task`]: ../struct.Task.html
+/// [RON]: https://github.com/ron-rs/ron
+#[cfg(feature = "data-ron")]
+pub fn from_ron<T, P>(path: P) -> Task<T>
+where
+    T: 'static + DeserializeOwned,
+    P: Into<PathBuf>,
+{
+    from_file(path.into(), |bytes| {
+        ron::de::from_bytes(bytes).map_err(|error| {
+            let (line, column) = match &error {
+                ron::de::Error::Parser(_, position) => {
+                    (position.line, position.col)
+                }
+                _ => (0, 0),
+            };
+
+            Error::Data {
+                line,
+                column,
+                message: error.to_string(),
+            }
+        })
+    })
+}
+
+fn from_file<T, F>(path: PathBuf, deserialize: F) -> Task<T>
+where
+    T: 'static,
+    F: 'static + FnOnce(&[u8]) -> Result<T, Error>,
+{
+    Task::new(move || {
+        load::read(&path)
+            .map_err(Error::from)
+            .and_then(|bytes| deserialize(&bytes))
+            .map_err(|error| Error::Asset {
+                path,
+                error: Box::new(error),
+            })
+    })
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// Creates a [`Task`] that loads every file in a directory.
+///
+/// The given function is called with the path of each file in the directory.
+/// It should return the [`Task`] that loads the file, or `None` if the file
+/// should be skipped.
+///
+/// The resulting `HashMap` is keyed by the path of each file relative to the
+/// directory, without extension and using `/` as a separator. Files are
+/// loaded in lexicographical order of their paths; if two files share the
+/// same key, the last one loaded is kept.
+///
+/// If `recursive` is `true`, files in subdirectories are loaded too.
+///
+/// The directory is read when this function is called, so the total work of
+/// the resulting [`Task`] is known upfront. If the directory cannot be read or
+/// any file fails to load, the [`Task`] fails with an [`Error::Asset`]
+/// containing the offending path.
+///
+/// ```
+/// # use coffee::load::{self, Task};
+/// # use coffee::graphics::Font;
+/// #
+/// let load_fonts = load::from_dir("resources/fonts", false, |path| {
+///     match path.extension()?.to_str()? {
+///         "ttf" => {
+///             let path = path.to_path_buf();
+///
+///             Some(Task::new(move || Ok(std::fs::read(path)?)))
+///         }
+///         _ => None,
+///     }
+/// });
+/// ```
+///
+/// [`Task`]: struct.Task.html
+/// [`Error::Asset`]: ../enum.Error.html#variant.Asset
+pub fn from_dir<T, P, F>(
+    path: P,
+    recursive: bool,
+    f: F,
+) -> Task<HashMap<String, T>>
+where
+    T: 'static,
+    P: Into<PathBuf>,
+    F: Fn(&Path) -> Option<Task<T>>,
+{
+    let root = path.into();
+    let mut files = Vec::new();
+
+    if let Err(error) = list_files(&root, recursive, &mut files) {
+        return Task::new(move || {
+            Err(Error::Asset {
+                path: root,
+                error: Box::new(error.into()),
+            })
+        });
+    }
+
+    files.sort();
+
+    let tasks: Vec<(PathBuf, String, Task<T>)> = files
+        .into_iter()
+        .filter_map(|file| {
+            let task = f(&file)?;
+            let key = key(&root, &file);
+
+            Some((file, key, task))
+        })
+        .collect();
+
+    let total_work = tasks.iter().map(|(_, _, task)| task.total_work()).sum();
+    let total_stages =
+        tasks.iter().map(|(_, _, task)| task.total_stages()).sum();
+
+    Task::sequence(total_work, move |worker| {
+        let mut assets = HashMap::with_capacity(tasks.len());
+
+        for (path, key, task) in tasks {
+            let asset =
+                task.run_with_worker(worker).map_err(|error| match error {
+                    Error::Asset { .. } => error,
+                    _ => Error::Asset {
+                        path,
+                        error: Box::new(error),
+                    },
+                })?;
+
+            let _ = assets.insert(key, asset);
+        }
+
+        Ok(assets)
+    })
+    .with_total_stages(total_stages)
+}
+
+fn list_files(
+    directory: &Path,
+    recursive: bool,
+    files: &mut Vec<PathBuf>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            if recursive {
+                list_files(&path, recursive, files)?;
+            }
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn key(root: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file).with_extension("");
+
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
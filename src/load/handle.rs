@@ -0,0 +1,189 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use super::job::{Job, Upload};
+use crate::graphics;
+use crate::Result;
+
+/// A handle to jobs running in the background while the game is running.
+///
+/// A [`Handle`] lets you load assets without blocking your game loop. The
+/// CPU-bound part of every [`Job`] runs on a thread pool, while the GPU-bound
+/// part runs in small time slices every time you [`poll`] the [`Handle`].
+///
+/// For instance, you can load the next level while the current one is being
+/// played:
+///
+/// ```
+/// # use coffee::graphics::{Image, Window};
+/// # use coffee::load::Handle;
+/// # use std::task::Poll;
+/// # struct Level { tiles: Vec<Image> }
+/// # struct MyGame { level: Level, next_level: Option<Handle<Image>> }
+/// # impl MyGame {
+/// fn interact(&mut self, window: &mut Window) {
+///     if let Some(next_level) = &mut self.next_level {
+///         match next_level.poll(window.gpu()) {
+///             Poll::Ready(Ok(tiles)) => {
+///                 self.level = Level { tiles };
+///                 self.next_level = None;
+///             }
+///             Poll::Ready(Err(_error)) => {
+///                 // Handle the error...
+///                 self.next_level = None;
+///             }
+///             Poll::Pending => {}
+///         }
+///     }
+/// }
+/// # }
+/// ```
+///
+/// Dropping a [`Handle`] cancels any [`Job`] that has not started yet.
+///
+/// Only jobs can run in the background. A [`Task`] may use the [`Gpu`] at any
+/// point, so it can only run to completion.
+///
+/// [`Handle`]: struct.Handle.html
+/// [`Task`]: struct.Task.html
+/// [`Gpu`]: ../graphics/struct.Gpu.html
+/// [`Job`]: struct.Job.html
+/// [`poll`]: #method.poll
+pub struct Handle<T> {
+    receiver: mpsc::Receiver<(usize, Result<Upload<T>>)>,
+    results: Vec<Option<T>>,
+    completed: usize,
+    budget: Duration,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<T: 'static> Handle<T> {
+    /// The default amount of time a [`Handle`] can spend on GPU work every
+    /// time it is polled.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub const DEFAULT_BUDGET: Duration = Duration::from_millis(4);
+
+    /// Starts running the given jobs in the background.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn spawn(jobs: Vec<Job<T>>) -> Handle<T> {
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let results = jobs.iter().map(|_| None).collect();
+
+        for (i, job) in jobs.into_iter().enumerate() {
+            let sender = sender.clone();
+            let cancelled = cancelled.clone();
+
+            rayon::spawn(move || {
+                if !cancelled.load(Ordering::Relaxed) {
+                    let _ = sender.send((i, (job.prepare)()));
+                }
+            });
+        }
+
+        Handle {
+            receiver,
+            results,
+            completed: 0,
+            budget: Self::DEFAULT_BUDGET,
+            cancelled,
+        }
+    }
+}
+
+impl<T> Handle<T> {
+    /// Sets the amount of time the [`Handle`] can spend on GPU work every
+    /// time it is polled.
+    ///
+    /// At least one [`Job`] is always uploaded per poll, if ready.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    /// [`Job`]: struct.Job.html
+    pub fn set_budget(&mut self, budget: Duration) {
+        self.budget = budget;
+    }
+
+    /// Returns the amount of progress of the [`Handle`] as a percentage.
+    ///
+    /// You can use this to show an in-game loading indicator.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn percentage(&self) -> f32 {
+        if self.results.is_empty() {
+            100.0
+        } else {
+            self.completed as f32 / self.results.len() as f32 * 100.0
+        }
+    }
+
+    /// Makes progress on the background jobs and returns their results once
+    /// all of them have finished.
+    ///
+    /// Any [`Job`] that has finished its CPU-bound part is uploaded using the
+    /// given [`Gpu`], until the time budget of the [`Handle`] is exhausted.
+    ///
+    /// Once it has returned `Poll::Ready`, the [`Handle`] should not be
+    /// polled again.
+    ///
+    /// [`Job`]: struct.Job.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`Handle`]: struct.Handle.html
+    pub fn poll(&mut self, gpu: &mut graphics::Gpu) -> Poll<Result<Vec<T>>> {
+        let start = Instant::now();
+
+        while self.completed < self.results.len() {
+            let (i, prepared) = match self.receiver.try_recv() {
+                Ok(prepared) => prepared,
+                Err(_) => return Poll::Pending,
+            };
+
+            let upload = match prepared {
+                Ok(upload) => upload,
+                Err(error) => return Poll::Ready(Err(error)),
+            };
+
+            match upload(gpu) {
+                Ok(result) => {
+                    self.results[i] = Some(result);
+                    self.completed += 1;
+                }
+                Err(error) => return Poll::Ready(Err(error)),
+            }
+
+            if start.elapsed() >= self.budget {
+                break;
+            }
+        }
+
+        if self.completed < self.results.len() {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(Ok(self
+            .results
+            .drain(..)
+            .map(|result| result.expect("Job result"))
+            .collect()))
+    }
+}
+
+impl<T> Drop for Handle<T> {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<T> std::fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Handle {{ completed: {}, total: {} }}",
+            self.completed,
+            self.results.len()
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use crate::graphics;
+use crate::Result;
+
+/// A loading operation split in a CPU-bound part and a GPU-bound part.
+///
+/// The CPU-bound part of a [`Job`] can run on any thread, while the GPU-bound
+/// part always runs on the thread that owns the [`Gpu`]. This allows many
+/// jobs to run in parallel using [`Task::parallel`].
+///
+/// For instance, decoding an image is expensive and can be done on any
+/// thread, but uploading it to the GPU cannot:
+///
+/// ```
+/// # use coffee::load::Job;
+/// # use coffee::graphics::Image;
+/// #
+/// let load_image = Job::new(
+///     || Ok(image::open("my-image.png")?),
+///     |gpu, image| Image::from_image(gpu, &image),
+/// );
+/// ```
+///
+/// This is how [`Image::job`] works, you should use that instead!
+///
+/// [`Job`]: struct.Job.html
+/// [`Gpu`]: ../graphics/struct.Gpu.html
+/// [`Task::parallel`]: struct.Task.html#method.parallel
+/// [`Image::job`]: ../graphics/struct.Image.html#method.job
+pub struct Job<T> {
+    pub(super) prepare: Box<dyn FnOnce() -> Result<Upload<T>> + Send>,
+}
+
+pub(super) type Upload<T> =
+    Box<dyn FnOnce(&mut graphics::Gpu) -> Result<T> + Send>;
+
+impl<T> Job<T> {
+    /// Creates a new [`Job`] from a CPU-bound operation and a GPU-bound one.
+    ///
+    /// The output of `prepare` will be fed to `upload`.
+    ///
+    /// [`Job`]: struct.Job.html
+    pub fn new<D, P, U>(prepare: P, upload: U) -> Job<T>
+    where
+        D: 'static + Send,
+        P: 'static + Send + FnOnce() -> Result<D>,
+        U: 'static + Send + FnOnce(&mut graphics::Gpu, D) -> Result<T>,
+    {
+        Job {
+            prepare: Box::new(move || {
+                let data = prepare()?;
+                let upload: Upload<T> = Box::new(move |gpu| upload(gpu, data));
+
+                Ok(upload)
+            }),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Job<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Job")
+    }
+}
@@ -8,14 +8,18 @@
 //! [`LoadingScreen`] trait.
 //!
 //! If you want a simple placeholder, you can try out the built-in
-//! [`ProgressBar`] loading screen.
+//! [`ProgressBar`] loading screen. The [`Splash`] loading screen is a
+//! configurable alternative suitable for release builds.
 //!
 //! [`Task`]: ../struct.Task.html
 //! [`LoadingScreen`]: trait.LoadingScreen.html
 //! [`ProgressBar`]: struct.ProgressBar.html
+//! [`Splash`]: struct.Splash.html
 mod progress_bar;
+mod splash;
 
 pub use progress_bar::ProgressBar;
+pub use splash::{Splash, SplashSettings};
 
 use crate::graphics;
 use crate::load::{Progress, Task};
@@ -63,21 +67,50 @@ pub trait LoadingScreen {
     /// [`Game::draw`]: ../../trait.Game.html#tymethod.draw
     fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>);
 
+    /// Draws a frame of the [`LoadingScreen`] after its task has finished
+    /// and returns whether the [`LoadingScreen`] is done.
+    ///
+    /// This is polled every frame until it returns true, which allows you to
+    /// implement transitions or a minimum display time. The frame of the last
+    /// call, which returns true, is not shown.
+    ///
+    /// By default, it returns true right away.
+    ///
+    /// [`LoadingScreen`]: trait.LoadingScreen.html
+    fn finish(&mut self, _frame: &mut graphics::Frame<'_>) -> bool {
+        true
+    }
+
     /// Runs the [`LoadingScreen`] with a task and obtain its result.
     ///
     /// By default, it runs the task and refreshes the window when there is
-    /// progress.
+    /// progress. Then, it polls [`finish`] until it returns true.
     ///
     /// [`LoadingScreen`]: trait.LoadingScreen.html
+    /// [`finish`]: #method.finish
     fn run<T>(
         &mut self,
         task: Task<T>,
         window: &mut graphics::Window,
     ) -> Result<T> {
-        task.run_with_window(window, |progress, window| {
-            self.draw(progress, &mut window.frame());
-            window.swap_buffers();
-        })
+        let mut presented = Ok(());
+
+        let result = task.run_with_window(window, |progress, window| {
+            if presented.is_ok() {
+                presented = window
+                    .frame()
+                    .map(|mut frame| self.draw(progress, &mut frame))
+                    .and_then(|_| window.swap_buffers());
+            }
+        })?;
+
+        presented?;
+
+        while !self.finish(&mut window.frame()?) {
+            window.swap_buffers()?;
+        }
+
+        Ok(result)
     }
 }
 
@@ -50,8 +50,18 @@ impl LoadingScreen for ProgressBar {
         );
 
         if let Some(stage) = progress.stage() {
+            let content = match progress.current_stage_index() {
+                Some(index) if progress.total_stages() > 1 => format!(
+                    "{} ({}/{})",
+                    stage,
+                    index + 1,
+                    progress.total_stages()
+                ),
+                _ => stage.clone(),
+            };
+
             self.font.add(graphics::Text {
-                content: stage,
+                content: &content,
                 position: graphics::Point::new(
                     50.0,
                     frame.height() / 2.0 - 80.0,
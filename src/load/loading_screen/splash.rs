@@ -0,0 +1,242 @@
+use std::time::{Duration, Instant};
+
+use super::{LoadingScreen, Progress};
+use crate::graphics::{self, Color, Mesh, Rectangle, Shape};
+use crate::Result;
+
+/// A loading screen showing an optional logo, a progress bar and a fade out
+/// transition.
+///
+/// # Usage
+/// Set [`Splash`] as your [`Game::LoadingScreen`] associated type to use the
+/// default [`SplashSettings`].
+///
+/// If you want to customize it, wrap it in your own [`LoadingScreen`] and
+/// use [`Splash::with_settings`]:
+///
+/// ```
+/// use coffee::graphics::{Color, Frame, Gpu};
+/// use coffee::load::loading_screen::{LoadingScreen, Splash, SplashSettings};
+/// use coffee::load::Progress;
+/// use coffee::Result;
+/// use std::time::Duration;
+///
+/// struct MySplash(Splash);
+///
+/// impl LoadingScreen for MySplash {
+///     fn new(gpu: &mut Gpu) -> Result<Self> {
+///         Ok(MySplash(Splash::with_settings(
+///             gpu,
+///             SplashSettings {
+///                 background: Color::WHITE,
+///                 minimum_duration: Duration::from_millis(1500),
+///                 ..SplashSettings::default()
+///             },
+///         )?))
+///     }
+///
+///     fn draw(&mut self, progress: &Progress, frame: &mut Frame<'_>) {
+///         self.0.draw(progress, frame)
+///     }
+///
+///     fn finish(&mut self, frame: &mut Frame<'_>) -> bool {
+///         self.0.finish(frame)
+///     }
+/// }
+/// ```
+///
+/// [`Splash`]: struct.Splash.html
+/// [`Splash::with_settings`]: struct.Splash.html#method.with_settings
+/// [`SplashSettings`]: struct.SplashSettings.html
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+#[allow(missing_debug_implementations)]
+pub struct Splash {
+    settings: SplashSettings,
+    logo: Option<graphics::Image>,
+    started_at: Option<Instant>,
+    finished_at: Option<Instant>,
+    percentage: f32,
+}
+
+impl Splash {
+    /// Creates a [`Splash`] loading screen with the given [`SplashSettings`].
+    ///
+    /// [`Splash`]: struct.Splash.html
+    /// [`SplashSettings`]: struct.SplashSettings.html
+    pub fn with_settings(
+        gpu: &mut graphics::Gpu,
+        settings: SplashSettings,
+    ) -> Result<Splash> {
+        let logo = match settings.logo {
+            Some(bytes) => Some(graphics::Image::from_image(
+                gpu,
+                &image::load_from_memory(bytes)?,
+            )?),
+            None => None,
+        };
+
+        Ok(Splash {
+            settings,
+            logo,
+            started_at: None,
+            finished_at: None,
+            percentage: 0.0,
+        })
+    }
+
+    fn draw_splash(&self, frame: &mut graphics::Frame<'_>, opacity: f32) {
+        let frame_width = frame.width();
+        let frame_height = frame.height();
+        let bar_y = frame_height / 2.0 + 50.0;
+
+        frame.clear(self.settings.background);
+
+        let mut target = frame.as_target();
+
+        if let Some(logo) = &self.logo {
+            logo.draw(
+                graphics::Quad {
+                    position: graphics::Point::new(
+                        ((frame_width - logo.width() as f32) / 2.0).round(),
+                        (bar_y - logo.height() as f32 - 50.0).round(),
+                    ),
+                    size: (logo.width() as f32, logo.height() as f32),
+                    ..Default::default()
+                },
+                &mut target,
+            );
+        }
+
+        let width = frame_width - 200.0;
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: 100.0,
+                y: bar_y,
+                width,
+                height: 10.0,
+            }),
+            self.settings.bar_background,
+        );
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: 100.0,
+                y: bar_y,
+                width: width * self.percentage / 100.0,
+                height: 10.0,
+            }),
+            self.settings.bar,
+        );
+
+        if opacity < 1.0 {
+            mesh.fill(
+                Shape::Rectangle(Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: frame_width,
+                    height: frame_height,
+                }),
+                Color {
+                    a: 1.0 - opacity,
+                    ..Color::BLACK
+                },
+            );
+        }
+
+        mesh.draw(&mut target);
+    }
+}
+
+impl LoadingScreen for Splash {
+    fn new(gpu: &mut graphics::Gpu) -> Result<Self> {
+        Splash::with_settings(gpu, SplashSettings::default())
+    }
+
+    fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>) {
+        if self.started_at.is_none() {
+            self.started_at = Some(Instant::now());
+        }
+
+        self.percentage = self.percentage.max(progress.percentage());
+        self.draw_splash(frame, 1.0);
+    }
+
+    fn finish(&mut self, frame: &mut graphics::Frame<'_>) -> bool {
+        let now = Instant::now();
+        let started_at = *self.started_at.get_or_insert(now);
+
+        self.percentage = 100.0;
+
+        if now - started_at < self.settings.minimum_duration {
+            self.draw_splash(frame, 1.0);
+
+            return false;
+        }
+
+        let finished_at = *self.finished_at.get_or_insert(now);
+        let fading = now - finished_at;
+
+        if fading >= self.settings.fade_duration {
+            frame.clear(Color::BLACK);
+
+            return true;
+        }
+
+        let opacity = 1.0
+            - fading.as_secs_f32() / self.settings.fade_duration.as_secs_f32();
+
+        self.draw_splash(frame, opacity);
+
+        false
+    }
+}
+
+/// The settings of a [`Splash`] loading screen.
+///
+/// [`Splash`]: struct.Splash.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplashSettings {
+    /// The encoded bytes of an image to show above the progress bar, if any.
+    ///
+    /// You can use `include_bytes!` to embed it in your executable.
+    pub logo: Option<&'static [u8]>,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The color of the completed part of the progress bar.
+    pub bar: Color,
+
+    /// The color of the remaining part of the progress bar.
+    pub bar_background: Color,
+
+    /// The minimum amount of time the [`Splash`] is shown, even if loading
+    /// finishes earlier.
+    ///
+    /// [`Splash`]: struct.Splash.html
+    pub minimum_duration: Duration,
+
+    /// The duration of the fade out transition after loading finishes.
+    pub fade_duration: Duration,
+}
+
+impl Default for SplashSettings {
+    fn default() -> SplashSettings {
+        SplashSettings {
+            logo: None,
+            background: Color::BLACK,
+            bar: Color::WHITE,
+            bar_background: Color {
+                r: 0.2,
+                g: 0.2,
+                b: 0.2,
+                a: 1.0,
+            },
+            minimum_duration: Duration::from_secs(0),
+            fade_duration: Duration::from_millis(300),
+        }
+    }
+}
@@ -1,5 +1,16 @@
+use super::job::{Job, Upload};
 use crate::graphics;
-use crate::Result;
+use crate::{Error, Result};
+
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Loading screens are drawn at least this often while a task waits on another
+// thread, around 30 times per second
+const REDRAW_INTERVAL: Duration = Duration::from_millis(33);
 
 /// A `Task<T>` represents an operation that produces a value of type `T`.
 ///
@@ -69,7 +80,8 @@ use crate::Result;
 /// [`Image::load`]: ../graphics/struct.Image.html#method.load
 /// [`map`]: #method.map
 pub struct Task<T> {
-    total_work: u32,
+    total_work: f32,
+    total_stages: u32,
     function: Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>,
 }
 
@@ -93,23 +105,74 @@ impl<T> Task<T> {
     /// let generate_map = Task::new(|| Ok(Map::generate()));
     /// ```
     ///
+    /// The operation blocks the loading screen until it finishes. Consider
+    /// using [`on_thread`] for long operations.
+    ///
     /// [`Task`]: struct.Task.html
+    /// [`on_thread`]: #method.on_thread
     pub fn new<F>(f: F) -> Task<T>
     where
         F: 'static + FnOnce() -> Result<T>,
     {
         Task {
-            total_work: 1,
+            total_work: 1.0,
+            total_stages: 0,
             function: Box::new(move |worker| {
+                worker.check()?;
+
                 let result = f();
 
-                worker.notify_progress(1);
+                worker.notify_progress(1.0);
 
                 result
             }),
         }
     }
 
+    /// Creates a new [`Task`] from a lazy operation that reports its own
+    /// progress.
+    ///
+    /// The operation receives a [`Reporter`], which can be used to notify how
+    /// much of the operation has been completed. This is useful for long
+    /// operations that would otherwise leave a loading screen stuck in the
+    /// same position.
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # fn load_chunk(_chunk: usize) {}
+    /// let load_world = Task::with_progress(|reporter| {
+    ///     for chunk in 0..10 {
+    ///         load_chunk(chunk);
+    ///
+    ///         reporter.set((chunk + 1) as f32 / 10.0);
+    ///     }
+    ///
+    ///     Ok(())
+    /// });
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Reporter`]: struct.Reporter.html
+    pub fn with_progress<F>(f: F) -> Task<T>
+    where
+        F: 'static + FnOnce(&mut Reporter<'_, '_>) -> Result<T>,
+    {
+        Task::sequence(1.0, move |worker| {
+            worker.check()?;
+
+            let mut reporter = Reporter {
+                worker,
+                completed: 0.0,
+            };
+
+            let result = f(&mut reporter);
+
+            reporter.set(1.0);
+
+            result
+        })
+    }
+
     /// Creates a new [`Task`] from a lazy operation that cannot fail.
     ///
     /// ```rust
@@ -135,6 +198,66 @@ impl<T> Task<T> {
         Task::new(move || Ok(f()))
     }
 
+    /// Creates a new [`Task`] from a lazy operation that runs on its own
+    /// thread.
+    ///
+    /// While the operation runs, the loading screen keeps being drawn at a
+    /// steady rate. Use this for long operations that do not report progress,
+    /// like generating a world or decompressing a big file.
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # struct World;
+    /// # impl World {
+    /// # fn generate(_seed: u64) -> World { World }
+    /// # }
+    /// let generate_world = Task::on_thread(|| Ok(World::generate(42)));
+    /// ```
+    ///
+    /// If the [`Task`] is cancelled or times out, it stops waiting for the
+    /// operation, but the thread keeps running until the operation finishes.
+    /// If the operation panics, the panic is propagated to the thread running
+    /// the [`Task`].
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn on_thread<F>(f: F) -> Task<T>
+    where
+        T: 'static + Send,
+        F: 'static + Send + FnOnce() -> Result<T>,
+    {
+        Task::sequence(1.0, move |worker| {
+            worker.check()?;
+
+            let (sender, receiver) = mpsc::channel();
+
+            let handle = thread::spawn(move || {
+                let _ = sender.send(f());
+            });
+
+            loop {
+                match receiver.recv_timeout(REDRAW_INTERVAL) {
+                    Ok(result) => {
+                        worker.notify_progress(1.0);
+
+                        return result;
+                    }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        worker.check()?;
+
+                        // Keep the loading screen alive
+                        worker.notify_progress(0.0);
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => {
+                        match handle.join() {
+                            Ok(_) => unreachable!("Task thread sent nothing"),
+                            Err(panic) => panic::resume_unwind(panic),
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     /// Creates a new [`Task`] that uses a [`Gpu`].
     ///
     /// You can use this to load and prepare graphical assets.
@@ -154,25 +277,168 @@ impl<T> Task<T> {
     where
         F: 'static + FnOnce(&mut graphics::Gpu) -> Result<T>,
     {
-        Task::sequence(1, move |worker| {
-            let result = f(worker.gpu());
+        Task::sequence(1.0, move |worker| {
+            worker.check()?;
+
+            let result = worker.gpu().and_then(f);
 
-            worker.notify_progress(1);
+            worker.notify_progress(1.0);
 
             result
         })
     }
 
-    pub(crate) fn sequence<F>(total_work: u32, f: F) -> Task<T>
+    pub(crate) fn sequence<F>(total_work: f32, f: F) -> Task<T>
     where
         F: 'static + FnOnce(&mut Worker<'_>) -> Result<T>,
     {
         Task {
             total_work,
+            total_stages: 0,
             function: Box::new(f),
         }
     }
 
+    /// Creates a new [`Task`] that drives a future to completion.
+    ///
+    /// The future is polled on the thread running the [`Task`], while the
+    /// loading screen keeps being drawn. If the future relies on a specific
+    /// runtime, like `tokio`, make sure the runtime is running on another
+    /// thread and use it to spawn the future.
+    ///
+    /// Futures cannot use the [`Gpu`]. Chain a [`Task`] with [`and_then`]
+    /// instead:
+    ///
+    /// ```ignore
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// # async fn fetch(_url: &str) -> coffee::Result<Vec<u8>> {
+    /// #     Ok(Vec::new())
+    /// # }
+    /// let load_avatar = Task::from_future(fetch("https://example.com/avatar.png"))
+    ///     .and_then(|bytes| {
+    ///         Task::using_gpu(move |gpu| {
+    ///             Image::from_image(gpu, &image::load_from_memory(&bytes)?)
+    ///         })
+    ///     });
+    /// ```
+    ///
+    /// If the [`Task`] is cancelled or times out, the future is dropped.
+    ///
+    /// This function is only available when the `async` feature is enabled.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`and_then`]: #method.and_then
+    #[cfg(feature = "async")]
+    pub fn from_future<F>(future: F) -> Task<T>
+    where
+        F: 'static + std::future::Future<Output = Result<T>>,
+    {
+        use futures::task::{self, ArcWake};
+
+        struct Unpark(thread::Thread);
+
+        impl ArcWake for Unpark {
+            fn wake_by_ref(arc_self: &Arc<Self>) {
+                arc_self.0.unpark();
+            }
+        }
+
+        Task::sequence(1.0, move |worker| {
+            let waker = task::waker(Arc::new(Unpark(thread::current())));
+            let mut context = std::task::Context::from_waker(&waker);
+            let mut future = Box::pin(future);
+
+            loop {
+                worker.check()?;
+
+                match future.as_mut().poll(&mut context) {
+                    std::task::Poll::Ready(result) => {
+                        worker.notify_progress(1.0);
+
+                        return result;
+                    }
+                    std::task::Poll::Pending => {
+                        thread::park_timeout(REDRAW_INTERVAL);
+
+                        // Keep the loading screen alive
+                        worker.notify_progress(0.0);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Creates a new [`Task`] that runs multiple jobs in parallel.
+    ///
+    /// The CPU-bound part of every [`Job`] runs on a thread pool, while the
+    /// GPU-bound part runs on the thread that owns the [`Gpu`] as soon as
+    /// each [`Job`] is prepared.
+    ///
+    /// Jobs may finish in any order, but the resulting `Vec` keeps the order
+    /// of the given jobs. Progress is reported every time a [`Job`] finishes.
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// #
+    /// let load_tiles = Task::parallel(
+    ///     (0..300)
+    ///         .map(|i| Image::job(format!("tiles/{}.png", i)))
+    ///         .collect(),
+    /// );
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Job`]: struct.Job.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    pub fn parallel(jobs: Vec<Job<T>>) -> Task<Vec<T>>
+    where
+        T: 'static,
+    {
+        Task::sequence(jobs.len() as f32, move |worker| {
+            let (sender, receiver) =
+                mpsc::channel::<(usize, Result<Upload<T>>)>();
+            let mut results: Vec<Option<T>> =
+                jobs.iter().map(|_| None).collect();
+
+            for (i, job) in jobs.into_iter().enumerate() {
+                let sender = sender.clone();
+
+                rayon::spawn(move || {
+                    let _ = sender.send((i, (job.prepare)()));
+                });
+            }
+
+            drop(sender);
+
+            loop {
+                worker.check()?;
+
+                match receiver.recv_timeout(REDRAW_INTERVAL) {
+                    Ok((i, prepared)) => {
+                        let upload = prepared?;
+
+                        results[i] = Some(upload(worker.gpu()?)?);
+
+                        worker.notify_progress(1.0);
+                    }
+                    // Keep the loading screen alive while jobs are prepared
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        worker.notify_progress(0.0);
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+            }
+
+            Ok(results
+                .into_iter()
+                .map(|result| result.expect("Job result"))
+                .collect())
+        })
+    }
+
     /// Adds a title to the [`Task`].
     ///
     /// The title will be used when reporting progress once the [`Task`] is run.
@@ -215,16 +481,88 @@ impl<T> Task<T> {
 
         Task {
             total_work: task.total_work,
+            total_stages: task.total_stages + 1,
+            function: Box::new(move |worker| {
+                worker
+                    .with_stage(title.clone(), 1.0, task.function)
+                    .map_err(|error| Error::Stage {
+                        title,
+                        error: Box::new(error),
+                    })
+            }),
+        }
+    }
+
+    /// Adds a title and a relative weight to the [`Task`].
+    ///
+    /// Like [`stage`], but the [`Task`] will count as `weight` units of work
+    /// when reporting progress, independently of its actual amount of work.
+    ///
+    /// This allows you to tell a loading screen how long each stage takes in
+    /// relation to the others. For instance, a stage that takes 5 times longer
+    /// than the rest:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # let load_world = Task::succeed(|| ());
+    /// # let load_sounds = Task::succeed(|| ());
+    /// use coffee::load::Join;
+    ///
+    /// let load_game = (
+    ///     Task::stage_weighted("Loading world...", 5.0, load_world),
+    ///     Task::stage_weighted("Loading sounds...", 1.0, load_sounds),
+    /// )
+    ///     .join();
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`stage`]: #method.stage
+    pub fn stage_weighted<S: Into<String>>(
+        title: S,
+        weight: f32,
+        task: Task<T>,
+    ) -> Task<T>
+    where
+        T: 'static,
+    {
+        let title = title.into();
+        let weight = weight.max(0.0);
+        let scale = if task.total_work > 0.0 {
+            weight / task.total_work
+        } else {
+            0.0
+        };
+
+        Task {
+            total_work: weight,
+            total_stages: task.total_stages + 1,
             function: Box::new(move |worker| {
-                worker.with_stage(title.clone(), task.function)
+                worker
+                    .with_stage(title.clone(), scale, task.function)
+                    .map_err(|error| Error::Stage {
+                        title,
+                        error: Box::new(error),
+                    })
             }),
         }
     }
 
+    /// Returns the total amount of stages of the [`Task`].
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn total_stages(&self) -> u32 {
+        self.total_stages
+    }
+
+    pub(crate) fn with_total_stages(mut self, total_stages: u32) -> Task<T> {
+        self.total_stages = total_stages;
+        self
+    }
+
     /// Returns the total units of work of the [`Task`].
     ///
     /// [`Task`]: struct.Task.html
-    pub fn total_work(&self) -> u32 {
+    pub fn total_work(&self) -> f32 {
         self.total_work
     }
 
@@ -242,6 +580,7 @@ impl<T> Task<T> {
     {
         Task {
             total_work: self.total_work,
+            total_stages: self.total_stages,
             function: Box::new(move |worker| match (self.function)(worker) {
                 Ok(value) => Ok(f(value)),
                 Err(error) => Err(error),
@@ -249,11 +588,219 @@ impl<T> Task<T> {
         }
     }
 
+    /// Transforms the error of a [`Task`].
+    ///
+    /// You can use this to add your own context to errors.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn map_err<F>(self, f: F) -> Task<T>
+    where
+        T: 'static,
+        F: 'static + FnOnce(Error) -> Error,
+    {
+        Task {
+            total_work: self.total_work,
+            total_stages: self.total_stages,
+            function: Box::new(move |worker| {
+                (self.function)(worker).map_err(f)
+            }),
+        }
+    }
+
+    /// Runs a fallback [`Task`] if this one fails.
+    ///
+    /// This is useful to load optional assets, like a translation file, without
+    /// aborting the whole loading process:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # fn load_translation(_language: &str) -> Task<String> {
+    /// #     Task::succeed(String::new)
+    /// # }
+    /// let load_translation =
+    ///     load_translation("ca").or_else(load_translation("en"));
+    /// ```
+    ///
+    /// The total work of the resulting [`Task`] includes the work of the
+    /// fallback. If this [`Task`] succeeds, the work of the fallback is
+    /// reported as completed right away.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn or_else(self, fallback: Task<T>) -> Task<T>
+    where
+        T: 'static,
+    {
+        Task {
+            total_work: self.total_work + fallback.total_work,
+            total_stages: self.total_stages + fallback.total_stages,
+            function: Box::new(move |worker| match (self.function)(worker) {
+                Ok(value) => {
+                    worker.skip_stages(fallback.total_stages);
+                    worker.notify_progress(fallback.total_work);
+
+                    Ok(value)
+                }
+                Err(_error) => {
+                    #[cfg(feature = "log")]
+                    log::warn!("Task failed, running its fallback: {}", _error);
+
+                    (fallback.function)(worker)
+                }
+            }),
+        }
+    }
+
+    /// Produces the given value if the [`Task`] fails.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn unwrap_or(self, default: T) -> Task<T>
+    where
+        T: 'static,
+    {
+        Task {
+            total_work: self.total_work,
+            total_stages: self.total_stages,
+            function: Box::new(move |worker| {
+                Ok((self.function)(worker).unwrap_or_else(|_error| {
+                    #[cfg(feature = "log")]
+                    log::warn!(
+                        "Task failed, using its default value: {}",
+                        _error
+                    );
+
+                    default
+                }))
+            }),
+        }
+    }
+
+    /// Makes the [`Task`] fail with [`Error::Timeout`] if it takes longer than
+    /// the given duration.
+    ///
+    /// The deadline is checked cooperatively before any inner [`Task`] starts
+    /// and whenever a [`Reporter`] is checked. Operations that have already
+    /// started, like GPU uploads, always complete; only pending work is
+    /// skipped.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Error::Timeout`]: ../enum.Error.html#variant.Timeout
+    /// [`Reporter`]: struct.Reporter.html
+    pub fn timeout(self, duration: Duration) -> Task<T>
+    where
+        T: 'static,
+    {
+        Task {
+            total_work: self.total_work,
+            total_stages: self.total_stages,
+            function: Box::new(move |worker| {
+                let deadline = Instant::now() + duration;
+
+                worker.with_guard(Guard::Deadline(deadline), self.function)
+            }),
+        }
+    }
+
+    /// Makes the [`Task`] cancellable, returning a [`CancelHandle`] that can
+    /// be used to cancel it.
+    ///
+    /// A cancelled [`Task`] fails with [`Error::Cancelled`]. Like
+    /// [`timeout`], cancellation is cooperative: only pending work is
+    /// skipped.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`CancelHandle`]: struct.CancelHandle.html
+    /// [`Error::Cancelled`]: ../enum.Error.html#variant.Cancelled
+    /// [`timeout`]: #method.timeout
+    pub fn cancellable(self) -> (Task<T>, CancelHandle)
+    where
+        T: 'static,
+    {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let handle = CancelHandle {
+            cancelled: cancelled.clone(),
+        };
+
+        let task = Task {
+            total_work: self.total_work,
+            total_stages: self.total_stages,
+            function: Box::new(move |worker| {
+                worker.with_guard(Guard::Cancel(cancelled), self.function)
+            }),
+        };
+
+        (task, handle)
+    }
+
+    /// Chains a [`Task`] that depends on the output of this one.
+    ///
+    /// This is useful when you need to load something to know what else to
+    /// load. For example, a manifest file listing some images:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// # fn read_manifest() -> Vec<String> { Vec::new() }
+    /// # fn load_images(paths: Vec<String>) -> Task<Vec<Image>> {
+    /// #     Task::succeed(|| Vec::new())
+    /// # }
+    /// let load_images = Task::succeed(read_manifest).and_then(load_images);
+    /// ```
+    ///
+    /// # Progress
+    /// The amount of work of the chained [`Task`] cannot be known until this
+    /// one finishes. Therefore, progress is tracked in two phases of equal
+    /// weight: the first one covers this [`Task`] and the second one covers
+    /// the chained [`Task`], whatever its actual amount of work is.
+    ///
+    /// If the phases take very different amounts of time, you can use
+    /// [`stage_weighted`] on both sides to balance them.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`stage_weighted`]: #method.stage_weighted
+    pub fn and_then<F, A>(self, f: F) -> Task<A>
+    where
+        T: 'static,
+        F: 'static + FnOnce(T) -> Task<A>,
+    {
+        let weight = self.total_work.max(1.0);
+
+        Task {
+            total_work: self.total_work + weight,
+            total_stages: self.total_stages,
+            function: Box::new(move |worker| {
+                let value = (self.function)(worker)?;
+                let next = f(value);
+
+                worker.add_stages(next.total_stages);
+
+                if next.total_work > 0.0 {
+                    worker.with_weight(weight / next.total_work, next.function)
+                } else {
+                    let result = (next.function)(worker);
+
+                    worker.notify_progress(weight);
+
+                    result
+                }
+            }),
+        }
+    }
+
+    pub(crate) fn run_with_worker(self, worker: &mut Worker<'_>) -> Result<T> {
+        (self.function)(worker)
+    }
+
     /// Runs a [`Task`] and obtains the produced value.
     ///
     /// [`Task`]: struct.Task.html
     pub fn run(self, gpu: &mut graphics::Gpu) -> Result<T> {
-        let mut worker = Worker::Headless(gpu);
+        let mut worker = Worker::new(Target::Headless(gpu));
+
+        (self.function)(&mut worker)
+    }
+
+    pub(crate) fn run_without_gpu(self) -> Result<T> {
+        let mut worker = Worker::new(Target::Null);
 
         (self.function)(&mut worker)
     }
@@ -274,19 +821,52 @@ impl<T> Task<T> {
     where
         F: FnMut(&Progress, &mut graphics::Window) -> (),
     {
-        let mut worker = Worker::Windowed {
+        let mut worker = Worker::new(Target::Windowed {
             window,
             listener: &mut on_progress,
             progress: Progress {
                 total_work: self.total_work,
-                work_completed: 0,
+                work_completed: 0.0,
                 stages: Vec::new(),
+                total_stages: self.total_stages,
+                started_stages: 0,
+                completed_stages: 0,
+                started_at: Instant::now(),
+                last_report: Instant::now(),
+                delta: Duration::from_secs(0),
             },
-        };
+        });
 
-        worker.notify_progress(0);
+        worker.notify_progress(0.0);
 
-        (self.function)(&mut worker)
+        #[cfg(feature = "log")]
+        let started_at = Instant::now();
+
+        let result = (self.function)(&mut worker);
+
+        #[cfg(feature = "log")]
+        match &result {
+            Ok(_) => {
+                log::info!("Loading finished in {:.2?}", started_at.elapsed())
+            }
+            Err(error) => log::error!(
+                "Loading failed after {:.2?}: {}",
+                started_at.elapsed(),
+                error
+            ),
+        }
+
+        result
+    }
+}
+
+impl<T: 'static> From<Job<T>> for Task<T> {
+    fn from(job: Job<T>) -> Task<T> {
+        Task::using_gpu(move |gpu| {
+            let upload = (job.prepare)()?;
+
+            upload(gpu)
+        })
     }
 }
 
@@ -296,8 +876,16 @@ impl<T> std::fmt::Debug for Task<T> {
     }
 }
 
-pub(crate) enum Worker<'a> {
+pub(crate) struct Worker<'a> {
+    target: Target<'a>,
+    scale: f32,
+    guards: Vec<Guard>,
+}
+
+enum Target<'a> {
     Headless(&'a mut graphics::Gpu),
+    // Used by headless runners without a graphics device
+    Null,
     Windowed {
         window: &'a mut graphics::Window,
         listener: &'a mut dyn FnMut(&Progress, &mut graphics::Window) -> (),
@@ -305,90 +893,250 @@ pub(crate) enum Worker<'a> {
     },
 }
 
+enum Guard {
+    Deadline(Instant),
+    Cancel(Arc<AtomicBool>),
+}
+
 impl<'a> Worker<'a> {
-    pub fn gpu(&mut self) -> &mut graphics::Gpu {
-        match self {
-            Worker::Headless(gpu) => gpu,
-            Worker::Windowed { window, .. } => window.gpu(),
+    fn new(target: Target<'a>) -> Worker<'a> {
+        Worker {
+            target,
+            scale: 1.0,
+            guards: Vec::new(),
+        }
+    }
+
+    pub fn gpu(&mut self) -> Result<&mut graphics::Gpu> {
+        match &mut self.target {
+            Target::Headless(gpu) => Ok(&mut **gpu),
+            Target::Null => Err(Error::GpuUnavailable),
+            Target::Windowed { window, .. } => window.try_gpu(),
         }
     }
 
-    pub fn notify_progress(&mut self, work: u32) {
-        match self {
-            Worker::Headless(_) => {}
-            Worker::Windowed {
+    pub fn notify_progress(&mut self, work: f32) {
+        match &mut self.target {
+            Target::Headless(_) | Target::Null => {}
+            Target::Windowed {
                 progress,
                 window,
                 listener,
-                ..
             } => {
-                progress.work_completed += work;
+                let now = Instant::now();
+
+                progress.work_completed += work * self.scale;
+                progress.delta = now - progress.last_report;
+                progress.last_report = now;
 
                 listener(&progress, window);
             }
         };
     }
 
+    pub fn check(&self) -> Result<()> {
+        for guard in &self.guards {
+            match guard {
+                Guard::Deadline(deadline) => {
+                    if Instant::now() >= *deadline {
+                        return Err(Error::Timeout);
+                    }
+                }
+                Guard::Cancel(cancelled) => {
+                    if cancelled.load(Ordering::Relaxed) {
+                        return Err(Error::Cancelled);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn with_stage<T>(
         &mut self,
         title: String,
+        weight: f32,
         f: Box<dyn FnOnce(&mut Worker<'_>) -> T>,
     ) -> T {
-        match self {
-            Worker::Headless(_) => f(self),
-            Worker::Windowed { .. } => {
-                if let Worker::Windowed { progress, .. } = self {
-                    progress.stages.push(title);
-                }
+        if let Target::Windowed { progress, .. } = &mut self.target {
+            progress.stages.push((progress.started_stages, title));
+            progress.started_stages += 1;
+        }
 
-                self.notify_progress(0);
+        self.notify_progress(0.0);
 
-                let result = f(self);
+        let result = self.with_weight(weight, f);
 
-                if let Worker::Windowed { progress, .. } = self {
-                    let _ = progress.stages.pop();
-                }
+        if let Target::Windowed { progress, .. } = &mut self.target {
+            let _ = progress.stages.pop();
+            progress.completed_stages += 1;
+        }
 
-                result
-            }
+        result
+    }
+
+    pub fn add_stages(&mut self, stages: u32) {
+        if let Target::Windowed { progress, .. } = &mut self.target {
+            progress.total_stages += stages;
+        }
+    }
+
+    pub fn skip_stages(&mut self, stages: u32) {
+        if let Target::Windowed { progress, .. } = &mut self.target {
+            progress.started_stages += stages;
+            progress.completed_stages += stages;
         }
     }
+
+    pub fn with_weight<T>(
+        &mut self,
+        weight: f32,
+        f: Box<dyn FnOnce(&mut Worker<'_>) -> T>,
+    ) -> T {
+        let parent_scale = self.scale;
+        self.scale *= weight;
+
+        let result = f(self);
+
+        self.scale = parent_scale;
+
+        result
+    }
+
+    fn with_guard<T>(
+        &mut self,
+        guard: Guard,
+        f: Box<dyn FnOnce(&mut Worker<'_>) -> T>,
+    ) -> T {
+        self.guards.push(guard);
+
+        let result = f(self);
+
+        let _ = self.guards.pop();
+
+        result
+    }
+}
+
+/// A handle to report the progress of a [`Task::with_progress`].
+///
+/// [`Task::with_progress`]: struct.Task.html#method.with_progress
+#[allow(missing_debug_implementations)]
+pub struct Reporter<'a, 'b> {
+    worker: &'a mut Worker<'b>,
+    completed: f32,
+}
+
+impl<'a, 'b> Reporter<'a, 'b> {
+    /// Sets the completed fraction of the current [`Task`].
+    ///
+    /// The `fraction` is clamped to [0, 1]. Progress never goes backwards:
+    /// setting a fraction lower than the last one reported does nothing.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn set(&mut self, fraction: f32) {
+        let fraction = fraction.max(0.0).min(1.0);
+
+        if fraction > self.completed {
+            self.worker.notify_progress(fraction - self.completed);
+            self.completed = fraction;
+        }
+    }
+
+    /// Returns an error if the current [`Task`] has been cancelled or has timed
+    /// out.
+    ///
+    /// Long-running operations should call this periodically and stop early
+    /// when it fails, propagating the error.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn check(&self) -> Result<()> {
+        self.worker.check()
+    }
+
+    /// Returns the completed fraction of the current [`Task`] reported so far.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn get(&self) -> f32 {
+        self.completed
+    }
+}
+
+/// A handle to cancel a [`Task`].
+///
+/// You can obtain one using [`Task::cancellable`].
+///
+/// [`Task`]: struct.Task.html
+/// [`Task::cancellable`]: struct.Task.html#method.cancellable
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelHandle {
+    /// Cancels the related [`Task`].
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the related [`Task`] has been cancelled.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
 }
 
 /// The progress of a [`Task`].
 ///
 /// [`Task`]: struct.Task.html
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct Progress {
-    total_work: u32,
-    work_completed: u32,
-    stages: Vec<String>,
+    total_work: f32,
+    work_completed: f32,
+    stages: Vec<(u32, String)>,
+    total_stages: u32,
+    started_stages: u32,
+    completed_stages: u32,
+    started_at: Instant,
+    last_report: Instant,
+    delta: Duration,
 }
 
 impl Progress {
     /// Returns the total amount of work of the related [`Task`].
     ///
     /// [`Task`]: struct.Task.html
-    pub fn total_work(&self) -> u32 {
+    pub fn total_work(&self) -> f32 {
         self.total_work
     }
 
     /// Returns the amount of completed work of the related [`Task`].
     ///
-    /// The returned value is guaranteed to be in [0, total_work].
+    /// The returned value is guaranteed to be in [0, total_work]. It may be
+    /// fractional when a task reports its own progress or when stages are
+    /// weighted.
     ///
     /// [`Task`]: struct.Task.html
-    pub fn completed_work(&self) -> u32 {
+    pub fn completed_work(&self) -> f32 {
         self.work_completed.min(self.total_work)
     }
 
     /// Returns the amount of progress of the related [`Task`] as a percentage.
     ///
-    /// You can use this value directly in your loading screen.
+    /// You can use this value directly in your loading screen. It never
+    /// decreases while a [`Task`] runs, even when a new stage starts.
     ///
     /// [`Task`]: struct.Task.html
     pub fn percentage(&self) -> f32 {
-        self.completed_work() as f32 / self.total_work.max(1) as f32 * 100.0
+        if self.total_work > 0.0 {
+            self.completed_work() / self.total_work * 100.0
+        } else {
+            0.0
+        }
     }
 
     /// Returns the title of the current [`Task::stage`], if there is one.
@@ -397,7 +1145,51 @@ impl Progress {
     ///
     /// [`Task::state`]: struct.Task.html#method.stage
     pub fn stage(&self) -> Option<&String> {
-        self.stages.last()
+        self.stages.last().map(|(_, title)| title)
+    }
+
+    /// Returns the index of the current [`Task::stage`], if there is one.
+    ///
+    /// Stages are numbered in the order they start, beginning at 0.
+    ///
+    /// [`Task::stage`]: struct.Task.html#method.stage
+    pub fn current_stage_index(&self) -> Option<u32> {
+        self.stages.last().map(|(index, _)| *index)
+    }
+
+    /// Returns the amount of stages that have finished.
+    pub fn completed_stages(&self) -> u32 {
+        self.completed_stages
+    }
+
+    /// Returns the total amount of stages of the related [`Task`].
+    ///
+    /// The amount of stages of a [`Task::and_then`] is only known once it
+    /// starts, so this value may increase in that case.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Task::and_then`]: struct.Task.html#method.and_then
+    pub fn total_stages(&self) -> u32 {
+        self.total_stages
+    }
+
+    /// Returns the time elapsed since the related [`Task`] started running.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn elapsed(&self) -> Duration {
+        self.last_report - self.started_at
+    }
+
+    /// Returns the time elapsed since the previous progress report.
+    ///
+    /// Loading screens are drawn every time progress is reported, and around
+    /// 30 times per second while a [`Task`] waits on another thread. Use this
+    /// value to animate your loading screen independently of how often that
+    /// happens.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn delta(&self) -> Duration {
+        self.delta
     }
 }
 
@@ -421,6 +1213,7 @@ impl<A: 'static, B: 'static> Join for (Task<A>, Task<B>) {
 
     fn join(self) -> Task<(A, B)> {
         let (loader_a, loader_b) = self;
+        let total_stages = loader_a.total_stages + loader_b.total_stages;
 
         Task::sequence(
             loader_a.total_work() + loader_b.total_work(),
@@ -429,6 +1222,7 @@ impl<A: 'static, B: 'static> Join for (Task<A>, Task<B>) {
                     .and_then(|a| (loader_b.function)(task).map(|b| (a, b)))
             },
         )
+        .with_total_stages(total_stages)
     }
 }
 
@@ -0,0 +1,208 @@
+use std::cell::RefCell;
+use std::env;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// The environment variable that sets the maximum level logged by
+/// [`init_logging`].
+///
+/// It accepts `off`, `error`, `warn`, `info`, `debug`, and `trace`. By
+/// default, `info` records and above are logged.
+///
+/// [`init_logging`]: fn.init_logging.html
+pub const LEVEL_VARIABLE: &str = "COFFEE_LOG";
+
+/// The size after which the log file is rotated.
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// The amount of rotated log files kept next to the current one.
+const ROTATED_FILES: usize = 3;
+
+type Subscribers = Arc<Mutex<Vec<Box<dyn log::Log>>>>;
+
+thread_local! {
+    // The subscribers of the logger installed by `init_logging`, if any
+    static SUBSCRIBERS: RefCell<Option<Subscribers>> = RefCell::new(None);
+}
+
+/// Installs a logger that writes the records of Coffee and your game to the
+/// standard error and to a log file next to the executable.
+///
+/// The log file is named after the executable, like `my_game.log`, so your
+/// players can attach it to their bug reports. Older logs are rotated to
+/// `my_game.log.1`, `my_game.log.2`, and so on, whenever the game starts or
+/// the current file grows larger than 1 MB.
+///
+/// The maximum level logged can be changed with the [`LEVEL_VARIABLE`]. The
+/// console of the [`Debug`] view shows the same records.
+///
+/// Call it once, at the beginning of your `main` function. If another logger
+/// is installed already, this does nothing, so your own logger always takes
+/// precedence.
+///
+/// ```no_run
+/// coffee::init_logging();
+///
+/// // Run your game here!
+/// ```
+///
+/// [`LEVEL_VARIABLE`]: constant.LEVEL_VARIABLE.html
+/// [`Debug`]: struct.Debug.html
+pub fn init_logging() {
+    let level = env::var(LEVEL_VARIABLE)
+        .ok()
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(log::LevelFilter::Info);
+
+    let subscribers = Subscribers::default();
+    let file = Arc::new(Mutex::new(None));
+
+    let logger = Logger {
+        started_at: Instant::now(),
+        file: file.clone(),
+        subscribers: subscribers.clone(),
+    };
+
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        // The file is only touched once the logger is installed, so the logs
+        // of a previous run are kept if another logger is in use
+        if let Ok(mut file) = file.lock() {
+            *file = LogFile::open();
+        }
+
+        log::set_max_level(level);
+
+        SUBSCRIBERS.with(|current| *current.borrow_mut() = Some(subscribers));
+    }
+}
+
+/// Forwards the records of the logger installed by [`init_logging`] to the
+/// given subscriber.
+///
+/// The subscriber is given back if [`init_logging`] has not installed a
+/// logger.
+///
+/// [`init_logging`]: fn.init_logging.html
+#[cfg(any(debug_assertions, feature = "debug"))]
+pub(crate) fn subscribe(
+    subscriber: Box<dyn log::Log>,
+) -> Result<(), Box<dyn log::Log>> {
+    SUBSCRIBERS.with(|current| match &*current.borrow() {
+        Some(subscribers) => {
+            if let Ok(mut subscribers) = subscribers.lock() {
+                subscribers.push(subscriber);
+            }
+
+            Ok(())
+        }
+        None => Err(subscriber),
+    })
+}
+
+struct Logger {
+    started_at: Instant,
+    file: Arc<Mutex<Option<LogFile>>>,
+    subscribers: Subscribers,
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!(
+            "[{:>10.3}s {:<5} {}] {}\n",
+            self.started_at.elapsed().as_secs_f32(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        let _ = io::stderr().write_all(line.as_bytes());
+
+        if let Ok(mut file) = self.file.lock() {
+            // Logging should never stop the game, so the file is simply
+            // abandoned if it cannot be written
+            if let Some(Err(_)) = file.as_mut().map(|file| file.write(&line)) {
+                *file = None;
+            }
+        }
+
+        if let Ok(subscribers) = self.subscribers.lock() {
+            for subscriber in subscribers.iter() {
+                subscriber.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        let _ = io::stderr().flush();
+    }
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: Option<File>,
+    size: u64,
+}
+
+impl LogFile {
+    fn open() -> Option<LogFile> {
+        let path = env::current_exe().ok()?.with_extension("log");
+
+        let mut log_file = LogFile {
+            path,
+            file: None,
+            size: 0,
+        };
+
+        log_file.rotate().ok()?;
+
+        Some(log_file)
+    }
+
+    fn write(&mut self, line: &str) -> io::Result<()> {
+        if self.size + line.len() as u64 > MAX_FILE_SIZE {
+            self.rotate()?;
+        }
+
+        if let Some(file) = &mut self.file {
+            file.write_all(line.as_bytes())?;
+            self.size += line.len() as u64;
+        }
+
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        // The current file needs to be closed before renaming it on Windows
+        self.file = None;
+
+        for n in (1..ROTATED_FILES).rev() {
+            let _ = fs::rename(self.rotated(n), self.rotated(n + 1));
+        }
+
+        let _ = fs::rename(&self.path, self.rotated(1));
+
+        self.file = Some(File::create(&self.path)?);
+        self.size = 0;
+
+        Ok(())
+    }
+
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut path = OsString::from(self.path.as_os_str());
+        path.push(format!(".{}", n));
+
+        PathBuf::from(path)
+    }
+}
@@ -0,0 +1,588 @@
+//! Emit particles for explosions, smoke, sparkles, and other effects.
+//!
+//! An [`Emitter`] spawns particles at a steady rate or in bursts, and moves
+//! them every tick. Given the same seed and the same ticks, an [`Emitter`]
+//! always produces the same particles, so effects stay deterministic like the
+//! rest of your game.
+//!
+//! ```
+//! use coffee::graphics::{Color, Point, Vector};
+//! use coffee::particles::{Curve, Emitter};
+//! use std::f32::consts::PI;
+//! use std::time::Duration;
+//!
+//! let mut smoke = Emitter::new()
+//!     .position(Point::new(400.0, 500.0))
+//!     .rate(60.0)
+//!     .lifetime(Duration::from_secs(1), Duration::from_secs(3))
+//!     .direction(-PI / 2.0, PI / 6.0)
+//!     .speed(20.0, 60.0)
+//!     .acceleration(Vector::new(10.0, 0.0))
+//!     .size(Curve::linear(4.0, 24.0))
+//!     .color(Curve::linear(
+//!         Color::new(0.5, 0.5, 0.5, 0.8),
+//!         Color::new(0.5, 0.5, 0.5, 0.0),
+//!     ));
+//!
+//! // Called once per tick in `Game::update`
+//! smoke.update(1);
+//! ```
+//!
+//! [`Emitter`]: struct.Emitter.html
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::graphics::{
+    Batch, Color, Mesh, Point, Rectangle, Shape, Sprite, Vector,
+};
+
+/// A source of particles.
+///
+/// The amount of live particles is bounded by its [`capacity`]. When it is
+/// full, new particles replace the oldest ones. Therefore, an [`Emitter`]
+/// never allocates more memory than its [`capacity`] allows, even if it is
+/// never stopped.
+///
+/// [`capacity`]: #method.capacity
+/// [`Emitter`]: struct.Emitter.html
+#[derive(Debug, Clone)]
+pub struct Emitter {
+    position: Point,
+    source: Rectangle<u16>,
+    rate: f32,
+    lifetime: (f32, f32),
+    direction: f32,
+    spread: f32,
+    speed: (f32, f32),
+    acceleration: Vector,
+    size: Curve<f32>,
+    color: Curve<Color>,
+    capacity: usize,
+    ticks_per_second: f32,
+    is_spawning: bool,
+    pending: f32,
+    random: Random,
+    particles: VecDeque<Particle>,
+}
+
+impl Emitter {
+    /// The default capacity of an [`Emitter`].
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Creates a new [`Emitter`] at the origin.
+    ///
+    /// By default, it spawns 10 particles per second: white squares of 8
+    /// pixels that live for 1 second and do not move. It expects 60 ticks per
+    /// second, the default of [`Game::TICKS_PER_SECOND`].
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn new() -> Emitter {
+        Emitter {
+            position: Point::new(0.0, 0.0),
+            source: Rectangle {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            },
+            rate: 10.0,
+            lifetime: (1.0, 1.0),
+            direction: 0.0,
+            spread: 0.0,
+            speed: (0.0, 0.0),
+            acceleration: Vector::new(0.0, 0.0),
+            size: Curve::constant(8.0),
+            color: Curve::constant(Color::WHITE),
+            capacity: Self::DEFAULT_CAPACITY,
+            ticks_per_second: 60.0,
+            is_spawning: true,
+            pending: 0.0,
+            random: Random::new(0),
+            particles: VecDeque::new(),
+        }
+    }
+
+    /// Sets the position where the particles of the [`Emitter`] spawn.
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn position(mut self, position: Point) -> Emitter {
+        self.position = position;
+        self
+    }
+
+    /// Sets the region of the [`Image`] of a [`Batch`] used to draw the
+    /// particles, in absolute coordinates.
+    ///
+    /// [`Image`]: ../graphics/struct.Image.html
+    /// [`Batch`]: ../graphics/struct.Batch.html
+    pub fn source(mut self, source: Rectangle<u16>) -> Emitter {
+        self.source = source;
+        self
+    }
+
+    /// Sets the amount of particles spawned per second.
+    pub fn rate(mut self, particles_per_second: f32) -> Emitter {
+        self.rate = particles_per_second.max(0.0);
+        self
+    }
+
+    /// Sets the range of the lifetime of the particles.
+    ///
+    /// The lifetime of each particle is chosen randomly in the range.
+    pub fn lifetime(mut self, min: Duration, max: Duration) -> Emitter {
+        let min = min.as_secs_f32();
+
+        self.lifetime = (min, max.as_secs_f32().max(min));
+        self
+    }
+
+    /// Sets the cone where the particles are launched.
+    ///
+    /// The `direction` is an angle in radians, where `0` points right and
+    /// `PI / 2` points down. Each particle is launched with a random angle
+    /// up to half the `spread` away from the `direction`.
+    pub fn direction(mut self, direction: f32, spread: f32) -> Emitter {
+        self.direction = direction;
+        self.spread = spread.abs();
+        self
+    }
+
+    /// Sets the range of the initial speed of the particles, in pixels per
+    /// second.
+    pub fn speed(mut self, min: f32, max: f32) -> Emitter {
+        self.speed = (min, max.max(min));
+        self
+    }
+
+    /// Sets the acceleration of the particles, in pixels per second squared.
+    ///
+    /// Use it for gravity, wind, or buoyancy.
+    pub fn acceleration(mut self, acceleration: Vector) -> Emitter {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Sets the width of the particles in pixels over their life.
+    ///
+    /// Textured particles keep the aspect ratio of their [`source`].
+    ///
+    /// [`source`]: #method.source
+    pub fn size(mut self, size: Curve<f32>) -> Emitter {
+        self.size = size;
+        self
+    }
+
+    /// Sets the color of the particles over their life.
+    ///
+    /// The color is only applied when drawing on a [`Mesh`]. Textured quads
+    /// cannot be tinted yet, so particles drawn on a [`Batch`] keep the
+    /// colors of their [`source`].
+    ///
+    /// [`Mesh`]: ../graphics/struct.Mesh.html
+    /// [`Batch`]: ../graphics/struct.Batch.html
+    /// [`source`]: #method.source
+    pub fn color(mut self, color: Curve<Color>) -> Emitter {
+        self.color = color;
+        self
+    }
+
+    /// Sets the maximum amount of live particles.
+    ///
+    /// When the [`Emitter`] is full, new particles replace the oldest ones.
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn capacity(mut self, capacity: usize) -> Emitter {
+        let excess = self.particles.len().saturating_sub(capacity);
+        let _ = self.particles.drain(..excess);
+
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets the amount of ticks per second of your game.
+    ///
+    /// It should match your [`Game::TICKS_PER_SECOND`].
+    ///
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn ticks_per_second(mut self, ticks_per_second: u16) -> Emitter {
+        self.ticks_per_second = f32::from(ticks_per_second.max(1));
+        self
+    }
+
+    /// Sets the seed used to randomize the particles.
+    ///
+    /// Emitters with the same settings and seed produce the same particles.
+    pub fn seed(mut self, seed: u64) -> Emitter {
+        self.random = Random::new(seed);
+        self
+    }
+
+    /// Moves the spawn position of the [`Emitter`].
+    ///
+    /// Particles that are already alive are not moved.
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn set_position(&mut self, position: Point) {
+        self.position = position;
+    }
+
+    /// Starts or stops spawning particles at the configured [`rate`].
+    ///
+    /// Live particles keep moving until they die.
+    ///
+    /// [`rate`]: #method.rate
+    pub fn set_spawning(&mut self, is_spawning: bool) {
+        self.is_spawning = is_spawning;
+    }
+
+    /// Returns true if the [`Emitter`] is spawning particles at its rate.
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn is_spawning(&self) -> bool {
+        self.is_spawning
+    }
+
+    /// Spawns the given amount of particles at once.
+    ///
+    /// This is useful for explosions. Combine it with a [`rate`] of `0`.
+    ///
+    /// [`rate`]: #method.rate
+    pub fn burst(&mut self, amount: usize) {
+        for _ in 0..amount.min(self.capacity) {
+            self.spawn();
+        }
+    }
+
+    /// Advances the particles of the [`Emitter`] by the given amount of
+    /// ticks.
+    ///
+    /// Usually, you will call `update(1)` in [`Game::update`].
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    pub fn update(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    /// Returns the amount of live particles.
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Returns true if there are no live particles.
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Returns the live particles, from oldest to newest.
+    pub fn particles(&self) -> impl Iterator<Item = &Particle> {
+        self.particles.iter()
+    }
+
+    /// Removes all the live particles.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+        self.pending = 0.0;
+    }
+
+    /// Adds the live particles to the given [`Batch`], as quads of the
+    /// [`source`] region of its [`Image`].
+    ///
+    /// [`Batch`]: ../graphics/struct.Batch.html
+    /// [`source`]: #method.source
+    /// [`Image`]: ../graphics/struct.Image.html
+    pub fn draw(&self, batch: &mut Batch) {
+        let source = self.source;
+        let aspect_ratio =
+            f32::from(source.height) / f32::from(source.width.max(1));
+
+        batch.extend(self.particles.iter().map(|particle| {
+            let width = self.size.at(particle.progress());
+            let height = width * aspect_ratio;
+            let scale = width / f32::from(source.width.max(1));
+
+            Sprite {
+                source,
+                position: particle.position
+                    - Vector::new(width / 2.0, height / 2.0),
+                scale: (scale, scale),
+            }
+        }));
+    }
+
+    /// Adds the live particles to the given [`Mesh`], as squares colored by
+    /// the [`color`] curve.
+    ///
+    /// [`Mesh`]: ../graphics/struct.Mesh.html
+    /// [`color`]: #method.color
+    pub fn draw_mesh(&self, mesh: &mut Mesh) {
+        for particle in &self.particles {
+            let progress = particle.progress();
+            let size = self.size.at(progress);
+
+            mesh.fill(
+                Shape::Rectangle(Rectangle {
+                    x: particle.position.x - size / 2.0,
+                    y: particle.position.y - size / 2.0,
+                    width: size,
+                    height: size,
+                }),
+                self.color.at(progress),
+            );
+        }
+    }
+
+    fn tick(&mut self) {
+        let delta = 1.0 / self.ticks_per_second;
+        let acceleration = self.acceleration * delta;
+
+        for particle in self.particles.iter_mut() {
+            particle.ticks += 1;
+            particle.velocity += acceleration;
+            particle.position += particle.velocity * delta;
+        }
+
+        self.particles
+            .retain(|particle| particle.ticks < particle.lifetime);
+
+        if self.is_spawning {
+            // Ages and spawns are counted in ticks, so they stay exact
+            self.pending += self.rate / self.ticks_per_second;
+
+            let amount = self.pending.floor();
+            self.pending -= amount;
+
+            self.burst(amount as usize);
+        }
+    }
+
+    fn spawn(&mut self) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.particles.len() >= self.capacity {
+            let _ = self.particles.pop_front();
+        }
+
+        let angle = self.direction
+            + self.random.between(-self.spread / 2.0, self.spread / 2.0);
+        let speed = self.random.between(self.speed.0, self.speed.1);
+
+        let lifetime = self.random.between(self.lifetime.0, self.lifetime.1);
+
+        self.particles.push_back(Particle {
+            position: self.position,
+            velocity: Vector::new(angle.cos(), angle.sin()) * speed,
+            ticks: 0,
+            lifetime: (lifetime * self.ticks_per_second).round() as u32,
+            ticks_per_second: self.ticks_per_second,
+        });
+    }
+}
+
+impl Default for Emitter {
+    fn default() -> Emitter {
+        Emitter::new()
+    }
+}
+
+/// A live particle of an [`Emitter`].
+///
+/// [`Emitter`]: struct.Emitter.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    position: Point,
+    velocity: Vector,
+    ticks: u32,
+    lifetime: u32,
+    ticks_per_second: f32,
+}
+
+impl Particle {
+    /// Returns the position of the [`Particle`].
+    ///
+    /// [`Particle`]: struct.Particle.html
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Returns the velocity of the [`Particle`], in pixels per second.
+    ///
+    /// [`Particle`]: struct.Particle.html
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    /// Returns the time the [`Particle`] has been alive, in seconds.
+    ///
+    /// [`Particle`]: struct.Particle.html
+    pub fn age(&self) -> f32 {
+        self.ticks as f32 / self.ticks_per_second
+    }
+
+    /// Returns the total time the [`Particle`] will be alive, in seconds.
+    ///
+    /// [`Particle`]: struct.Particle.html
+    pub fn lifetime(&self) -> f32 {
+        self.lifetime as f32 / self.ticks_per_second
+    }
+
+    /// Returns how far the [`Particle`] is into its life, between `0.0` and
+    /// `1.0`.
+    ///
+    /// [`Particle`]: struct.Particle.html
+    pub fn progress(&self) -> f32 {
+        if self.lifetime > 0 {
+            (self.ticks as f32 / self.lifetime as f32).min(1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A value that changes over the life of a [`Particle`].
+///
+/// A [`Curve`] is made of keys, pairs of progress and value. Values between
+/// keys are interpolated linearly.
+///
+/// ```
+/// use coffee::particles::Curve;
+///
+/// // Grow quickly, then shrink slowly
+/// let size = Curve::new(vec![(0.0, 2.0), (0.25, 16.0), (1.0, 0.0)]);
+///
+/// assert_eq!(size.at(0.125), 9.0);
+/// assert_eq!(size.at(0.625), 8.0);
+/// ```
+///
+/// [`Particle`]: struct.Particle.html
+/// [`Curve`]: struct.Curve.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve<T> {
+    keys: Vec<(f32, T)>,
+}
+
+impl<T: Interpolate> Curve<T> {
+    /// Creates a [`Curve`] from keys of progress, between `0.0` and `1.0`,
+    /// and value.
+    ///
+    /// # Panics
+    /// It panics if no keys are given.
+    ///
+    /// [`Curve`]: struct.Curve.html
+    pub fn new(mut keys: Vec<(f32, T)>) -> Curve<T> {
+        assert!(!keys.is_empty(), "A curve needs at least one key");
+
+        keys.sort_by(|(a, _), (b, _)| {
+            a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Curve { keys }
+    }
+
+    /// Creates a [`Curve`] with a value that never changes.
+    ///
+    /// [`Curve`]: struct.Curve.html
+    pub fn constant(value: T) -> Curve<T> {
+        Curve {
+            keys: vec![(0.0, value)],
+        }
+    }
+
+    /// Creates a [`Curve`] that goes from one value to another linearly.
+    ///
+    /// [`Curve`]: struct.Curve.html
+    pub fn linear(from: T, to: T) -> Curve<T> {
+        Curve {
+            keys: vec![(0.0, from), (1.0, to)],
+        }
+    }
+
+    /// Returns the value of the [`Curve`] at the given progress.
+    ///
+    /// [`Curve`]: struct.Curve.html
+    pub fn at(&self, progress: f32) -> T {
+        let next = self.keys.iter().position(|(key, _)| *key > progress);
+
+        match next {
+            Some(0) => self.keys[0].1,
+            Some(i) => {
+                let (start, from) = self.keys[i - 1];
+                let (end, to) = self.keys[i];
+
+                from.interpolate(to, (progress - start) / (end - start))
+            }
+            None => self.keys[self.keys.len() - 1].1,
+        }
+    }
+}
+
+/// A value that can be interpolated linearly, used by a [`Curve`].
+///
+/// [`Curve`]: struct.Curve.html
+pub trait Interpolate: Copy {
+    /// Returns the value at the given fraction, between `0.0` and `1.0`, of
+    /// the way from this value to another.
+    fn interpolate(self, other: Self, fraction: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, other: f32, fraction: f32) -> f32 {
+        self + (other - self) * fraction
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, other: Color, fraction: f32) -> Color {
+        Color {
+            r: self.r.interpolate(other.r, fraction),
+            g: self.g.interpolate(other.g, fraction),
+            b: self.b.interpolate(other.b, fraction),
+            a: self.a.interpolate(other.a, fraction),
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(self, other: Vector, fraction: f32) -> Vector {
+        self + (other - self) * fraction
+    }
+}
+
+// A small xorshift generator, so particles are deterministic without
+// depending on `rand`
+#[derive(Debug, Clone)]
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        let state = seed ^ 0x9E37_79B9_7F4A_7C15;
+
+        // The state of xorshift must never be zero
+        Random {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn between(&mut self, min: f32, max: f32) -> f32 {
+        // The upper 24 bits fit exactly in the mantissa of an `f32`
+        let fraction = (self.next() >> 40) as f32 / (1u64 << 24) as f32;
+
+        min + (max - min) * fraction
+    }
+}
@@ -1,8 +1,9 @@
 use std::error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
 
-use crate::graphics::texture_array;
+use crate::graphics::{texture_array, AdapterInfo};
 
 /// A convenient result with a locked [`Error`] type.
 ///
@@ -26,6 +27,92 @@ pub enum Error {
 
     /// An image failed to load.
     Image(image::ImageError),
+
+    /// No graphics adapter matched the [`GpuSettings`].
+    ///
+    /// [`GpuSettings`]: graphics/struct.GpuSettings.html
+    AdapterSelection {
+        /// The reason why the selection failed.
+        reason: String,
+
+        /// The adapters that were found.
+        adapters: Vec<AdapterInfo>,
+    },
+
+    /// The graphics device was lost.
+    DeviceLost,
+
+    /// A graphics device was needed, but there is none.
+    ///
+    /// This happens when a [`headless::Runner`] created without a graphics
+    /// device loads or draws graphics.
+    ///
+    /// [`headless::Runner`]: headless/struct.Runner.html
+    GpuUnavailable,
+
+    /// The graphics backend failed.
+    Graphics(String),
+
+    /// A sound failed to decode.
+    Audio(String),
+
+    /// A task took longer than its timeout.
+    Timeout,
+
+    /// A task was cancelled.
+    Cancelled,
+
+    /// A data file failed to deserialize.
+    Data {
+        /// The line where deserialization failed, starting at 1.
+        ///
+        /// It is 0 if the position is unknown.
+        line: usize,
+
+        /// The column where deserialization failed, starting at 1.
+        ///
+        /// It is 0 if the position is unknown.
+        column: usize,
+
+        /// The message of the deserializer.
+        message: String,
+    },
+
+    /// An asset failed to load.
+    Asset {
+        /// The path of the asset.
+        path: PathBuf,
+
+        /// The reason why the asset failed to load.
+        error: Box<Error>,
+    },
+
+    /// A stage of a task failed.
+    Stage {
+        /// The title of the stage.
+        title: String,
+
+        /// The reason why the stage failed.
+        error: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns the innermost [`Error`], skipping any context added by stages
+    /// and asset loaders.
+    ///
+    /// This is useful to find out whether a task failed because of a timeout
+    /// or a cancellation, for instance.
+    ///
+    /// [`Error`]: enum.Error.html
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Asset { error, .. } | Error::Stage { error, .. } => {
+                error.root()
+            }
+            _ => self,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -39,6 +126,40 @@ impl fmt::Display for Error {
             }
             Error::IO(error) => write!(f, "IO error: {}", error),
             Error::Image(error) => write!(f, "Image error: {}", error),
+            Error::AdapterSelection { reason, adapters } => {
+                write!(f, "Graphics adapter selection error: {}", reason)?;
+
+                if adapters.is_empty() {
+                    write!(f, ". No adapters were found")
+                } else {
+                    write!(f, ". Adapters found:")?;
+
+                    for adapter in adapters {
+                        write!(f, "\n  - {}", adapter)?;
+                    }
+
+                    Ok(())
+                }
+            }
+            Error::DeviceLost => write!(f, "Graphics device lost"),
+            Error::GpuUnavailable => {
+                write!(f, "Graphics device unavailable in a headless runner")
+            }
+            Error::Graphics(error) => write!(f, "Graphics error: {}", error),
+            Error::Audio(error) => write!(f, "Audio error: {}", error),
+            Error::Timeout => write!(f, "Task timed out"),
+            Error::Cancelled => write!(f, "Task cancelled"),
+            Error::Data {
+                line,
+                column,
+                message,
+            } => write!(f, "Data error at {}:{}: {}", line, column, message),
+            Error::Asset { path, error } => {
+                write!(f, "Asset error in {}: {}", path.display(), error)
+            }
+            Error::Stage { title, error } => {
+                write!(f, "Stage error in \"{}\": {}", title, error)
+            }
         }
     }
 }
@@ -48,6 +169,8 @@ impl error::Error for Error {
         match self {
             Error::IO(error) => Some(error),
             Error::Image(error) => Some(error),
+            Error::Asset { error, .. } => Some(error.as_ref()),
+            Error::Stage { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
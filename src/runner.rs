@@ -0,0 +1,311 @@
+//! Embed your game in an application that owns the event loop.
+//!
+//! [`Game::run`] takes control of the event loop and never gives it back until
+//! the game ends. If you need to run a [`Game`] inside a larger application,
+//! like an editor with a game preview, you can use a [`Runner`] instead.
+//!
+//! A [`Runner`] creates the game window using your event loop. Then, you feed
+//! it the window events you receive and tell it when to run a frame.
+//!
+//! [`Game::run`]: ../trait.Game.html#method.run
+//! [`Game`]: ../trait.Game.html
+//! [`Runner`]: struct.Runner.html
+use crate::debug::Debug;
+use crate::game::{guard, step};
+use crate::graphics::window::winit;
+use crate::graphics::{Window, WindowSettings};
+use crate::input::Input;
+use crate::load::LoadingScreen;
+use crate::{Game, Result, Timer};
+
+use std::convert::TryInto;
+
+/// A [`Game`] running in an event loop owned by you.
+///
+/// ```no_run
+/// # use coffee::graphics::{Frame, GpuSettings, Window, WindowSettings};
+/// # use coffee::load::Task;
+/// # use coffee::{Game, Result, Timer};
+/// use coffee::runner::Runner;
+/// use winit::event::Event;
+/// use winit::event_loop::{ControlFlow, EventLoop};
+///
+/// # struct MyGame;
+/// #
+/// # impl Game for MyGame {
+/// #     type Input = ();
+/// #     type LoadingScreen = ();
+/// #
+/// #     fn load(_window: &Window) -> Task<MyGame> {
+/// #         Task::succeed(|| MyGame)
+/// #     }
+/// #
+/// #     fn draw(&mut self, _frame: &mut Frame, _timer: &Timer) -> Result<()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # fn main() -> Result<()> {
+/// let event_loop = EventLoop::new();
+///
+/// let mut runner = Runner::<MyGame>::new(
+///     WindowSettings {
+///         title: String::from("Game preview"),
+///         size: (640, 480),
+///         resizable: true,
+///         fullscreen: false,
+///         maximized: false,
+///         gpu: GpuSettings::default(),
+///     },
+///     &event_loop,
+/// )?;
+///
+/// event_loop.run(move |event, _, control_flow| match event {
+///     Event::WindowEvent { window_id, event }
+///         if window_id == runner.window_id() =>
+///     {
+///         runner.handle_event(event);
+///     }
+///     Event::MainEventsCleared => {
+///         let frame = runner.tick().and_then(|_| runner.render());
+///
+///         if frame.is_err() || runner.is_finished() {
+///             *control_flow = ControlFlow::Exit;
+///         }
+///     }
+///     _ => {}
+/// });
+/// # }
+/// ```
+///
+/// When the [`Runner`] is dropped, [`Game::on_exit`] is called, unless it is
+/// dropped because of a panic.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Runner`]: struct.Runner.html
+/// [`Game::on_exit`]: ../trait.Game.html#method.on_exit
+pub struct Runner<G: Game> {
+    game: G,
+    input: G::Input,
+    window: Window,
+    timer: Timer,
+    debug: Debug,
+    events: step::Events,
+    _hook: guard::Hook,
+}
+
+impl<G: Game> Runner<G> {
+    /// Creates a new [`Runner`] by opening a window with the given settings
+    /// in the provided event loop and loading the [`Game`].
+    ///
+    /// The loading screen of the [`Game`] is shown while loading.
+    ///
+    /// [`Runner`]: struct.Runner.html
+    /// [`Game`]: ../trait.Game.html
+    pub fn new(
+        settings: WindowSettings,
+        event_loop: &winit::event_loop::EventLoop<()>,
+    ) -> Result<Runner<G>>
+    where
+        G: 'static,
+    {
+        let hook = guard::Hook::install::<G>();
+        let mut window = Window::new(settings, event_loop)?;
+        let mut debug = Debug::new(&mut window);
+        debug.set_toggle_key(G::DEBUG_KEY);
+
+        debug.loading_started();
+        let game = {
+            let mut loading_screen = G::LoadingScreen::new(window.gpu())?;
+
+            loading_screen.run(G::load(&window), &mut window)?
+        };
+        debug.loading_finished();
+
+        let mut timer = Timer::new(G::TICKS_PER_SECOND);
+        timer.update();
+
+        Ok(Runner {
+            game,
+            input: G::Input::new(),
+            window,
+            timer,
+            debug,
+            events: step::Events::default(),
+            _hook: hook,
+        })
+    }
+
+    /// Returns the identifier of the window of the [`Runner`].
+    ///
+    /// Use it to find out which window events should be handled by the
+    /// [`Runner`].
+    ///
+    /// [`Runner`]: struct.Runner.html
+    pub fn window_id(&self) -> winit::window::WindowId {
+        self.window.id()
+    }
+
+    /// Handles an event of the window of the [`Runner`].
+    ///
+    /// Input events are queued and delivered in the next [`tick`]. Like in
+    /// [`Game::run`], the [`Game`] is paused while the window is unfocused,
+    /// if [`Game::PAUSE_WHEN_UNFOCUSED`] is set, and the [`Debug`] view is
+    /// toggled with [`Game::DEBUG_KEY`].
+    ///
+    /// [`Runner`]: struct.Runner.html
+    /// [`tick`]: #method.tick
+    /// [`Game::run`]: ../trait.Game.html#method.run
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::PAUSE_WHEN_UNFOCUSED`]: ../trait.Game.html#associatedconstant.PAUSE_WHEN_UNFOCUSED
+    /// [`Debug`]: ../struct.Debug.html
+    /// [`Game::DEBUG_KEY`]: ../trait.Game.html#associatedconstant.DEBUG_KEY
+    pub fn handle_event(&mut self, event: winit::event::WindowEvent<'_>) {
+        match event {
+            winit::event::WindowEvent::CloseRequested => {
+                if self.game.on_close_request() {
+                    self.window.close();
+                }
+            }
+            winit::event::WindowEvent::Resized(size) => {
+                self.window.resize(size);
+            }
+            event => {
+                let _ = self.events.handle::<G>(event, &mut self.debug, true);
+            }
+        }
+    }
+
+    /// Delivers the queued input events and updates the [`Game`] as many
+    /// times as necessary, up to [`Game::MAX_TICKS_PER_FRAME`].
+    ///
+    /// You should call it once per frame, before [`render`]. Nothing is
+    /// updated while the [`Game`] is paused.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Game::MAX_TICKS_PER_FRAME`]: ../trait.Game.html#associatedconstant.MAX_TICKS_PER_FRAME
+    /// [`render`]: #method.render
+    pub fn tick(&mut self) -> Result<()> {
+        self.debug.interact_started();
+
+        let input = &mut self.input;
+        self.events
+            .deliver(&mut self.window, |event| input.update(event));
+
+        if self.events.is_idle::<G>(&self.window) {
+            self.input.clear();
+            self.input.clear_edges();
+            self.debug.interact_finished();
+
+            return Ok(());
+        }
+
+        let interaction =
+            step::interact(&mut self.game, &mut self.input, &mut self.window);
+        self.debug.interact_finished();
+        interaction?;
+
+        let _ = step::update(
+            &mut self.game,
+            &mut self.input,
+            &mut self.window,
+            &mut self.timer,
+            Some(&mut self.debug),
+        )?;
+
+        Ok(())
+    }
+
+    /// Draws the [`Game`] and presents the frame.
+    ///
+    /// Nothing is drawn while the window is minimized.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn render(&mut self) -> Result<()> {
+        let is_idle = self.events.is_idle::<G>(&self.window);
+
+        if self.window.is_minimized() {
+            if is_idle {
+                self.timer.skip();
+            } else {
+                self.timer.update();
+            }
+
+            return Ok(());
+        }
+
+        self.debug.draw_started();
+        let (game, timer) = (&mut self.game, &self.timer);
+        let drawing = guard::run::<G, _>(&mut self.window, |window| {
+            game.draw(&mut window.frame()?, timer)
+        });
+        self.debug.draw_finished();
+        drawing?;
+
+        self.window
+            .update_cursor(self.game.cursor_icon().try_into().ok());
+
+        step::draw_debug(
+            &mut self.game,
+            &self.input,
+            &mut self.window,
+            &mut self.debug,
+        )?;
+        step::present(&mut self.window, &mut self.debug)?;
+
+        if is_idle {
+            // The paused period must not be caught up on resume
+            self.timer.skip();
+        } else {
+            self.timer.update();
+        }
+
+        self.debug.frame_finished(&self.timer);
+
+        Ok(())
+    }
+
+    /// Returns true if the [`Game`] is finished or its window has been
+    /// closed.
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn is_finished(&self) -> bool {
+        self.game.is_finished() || self.window.is_closed()
+    }
+
+    /// Returns a reference to the [`Game`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the [`Game`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    pub fn game_mut(&mut self) -> &mut G {
+        &mut self.game
+    }
+
+    /// Returns a mutable reference to the [`Window`] of the [`Game`].
+    ///
+    /// [`Window`]: ../graphics/struct.Window.html
+    /// [`Game`]: ../trait.Game.html
+    pub fn window(&mut self) -> &mut Window {
+        &mut self.window
+    }
+}
+
+impl<G: Game> Drop for Runner<G> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.game.on_exit();
+        }
+    }
+}
+
+impl<G: Game> std::fmt::Debug for Runner<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Runner {{ window: {:?} }}", self.window)
+    }
+}
@@ -0,0 +1,386 @@
+//! Split your game into scenes and switch between them.
+//!
+//! Most games are made of different screens: a title screen, the gameplay
+//! itself, a pause menu, a game over screen... A [`Scene`] represents one of
+//! these screens. It is updated, drawn and interacted with just like a
+//! [`Game`].
+//!
+//! A [`Director`] manages a stack of scenes and implements [`Game`]. Only the
+//! scene on top of the stack is updated and receives input. Scenes can ask the
+//! [`Director`] to change the stack by returning a [`Transition`].
+//!
+//! The first scene of the [`Director`] is defined by a [`Script`].
+//!
+//! [`Scene`]: trait.Scene.html
+//! [`Game`]: ../trait.Game.html
+//! [`Director`]: struct.Director.html
+//! [`Transition`]: enum.Transition.html
+//! [`Script`]: trait.Script.html
+use crate::graphics::{CursorIcon, Frame, Window};
+use crate::input::Input;
+use crate::load::{LoadingScreen, Task};
+use crate::{Error, Game, Result, Timer};
+
+/// A screen of your game.
+///
+/// It mirrors the [`Game`] trait. Scenes are managed by a [`Director`].
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Director`]: struct.Director.html
+pub trait Scene<I: Input> {
+    /// Consumes [`Input`] to let users interact with the [`Scene`].
+    ///
+    /// It is only called when the [`Scene`] is on top of the stack.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Input`]: ../input/trait.Input.html
+    /// [`Scene`]: trait.Scene.html
+    fn interact(&mut self, _input: &mut I, _window: &mut Window) -> Result<()> {
+        Ok(())
+    }
+
+    /// Updates the [`Scene`].
+    ///
+    /// It is only called when the [`Scene`] is on top of the stack.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        Ok(())
+    }
+
+    /// Draws the [`Scene`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) -> Result<()>;
+
+    /// Returns the [`Transition`] that the [`Director`] should perform, if
+    /// any.
+    ///
+    /// It is called once per frame, right before [`interact`], when the
+    /// [`Scene`] is on top of the stack.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Transition`]: enum.Transition.html
+    /// [`Director`]: struct.Director.html
+    /// [`interact`]: #method.interact
+    /// [`Scene`]: trait.Scene.html
+    fn transition(&mut self) -> Option<Transition<I>> {
+        None
+    }
+
+    /// Returns whether the scenes below this [`Scene`] should be drawn.
+    ///
+    /// Scenes below are drawn first, from the bottom of the stack. This is
+    /// useful for translucent overlays, like a pause menu.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn is_translucent(&self) -> bool {
+        false
+    }
+
+    /// Defines the cursor icon of the window while the [`Scene`] is on top of
+    /// the stack.
+    ///
+    /// By default, it returns platform-dependent default cursor.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn cursor_icon(&self) -> CursorIcon {
+        CursorIcon::Default
+    }
+
+    /// Handles an error produced while loading the [`Scene`] of a
+    /// [`Transition`] returned by this [`Scene`].
+    ///
+    /// The stack is left untouched when a [`Scene`] fails to load.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`Transition`]: enum.Transition.html
+    fn on_load_error(&mut self, _error: Error) {}
+}
+
+/// A change in the stack of scenes of a [`Director`].
+///
+/// The new scenes are loaded using the [`Task`] provided. The
+/// [`Script::LoadingScreen`] is shown while loading.
+///
+/// [`Director`]: struct.Director.html
+/// [`Task`]: ../load/struct.Task.html
+/// [`Script::LoadingScreen`]: trait.Script.html#associatedtype.LoadingScreen
+pub enum Transition<I: Input> {
+    /// Loads a new scene and puts it on top of the stack.
+    Push(Task<Box<dyn Scene<I>>>),
+
+    /// Removes the scene on top of the stack.
+    ///
+    /// The [`Director`] finishes when the stack is empty.
+    ///
+    /// [`Director`]: struct.Director.html
+    Pop,
+
+    /// Loads a new scene and replaces the scene on top of the stack with it.
+    Replace(Task<Box<dyn Scene<I>>>),
+}
+
+impl<I: Input> Transition<I> {
+    /// Creates a [`Transition::Push`] from a [`Task`] that loads a [`Scene`].
+    ///
+    /// [`Transition::Push`]: enum.Transition.html#variant.Push
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Scene`]: trait.Scene.html
+    pub fn push<S>(task: Task<S>) -> Transition<I>
+    where
+        S: 'static + Scene<I>,
+    {
+        Transition::Push(task.map(boxed))
+    }
+
+    /// Creates a [`Transition::Replace`] from a [`Task`] that loads a
+    /// [`Scene`].
+    ///
+    /// [`Transition::Replace`]: enum.Transition.html#variant.Replace
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Scene`]: trait.Scene.html
+    pub fn replace<S>(task: Task<S>) -> Transition<I>
+    where
+        S: 'static + Scene<I>,
+    {
+        Transition::Replace(task.map(boxed))
+    }
+}
+
+impl<I: Input> std::fmt::Debug for Transition<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transition::Push(_) => write!(f, "Transition::Push"),
+            Transition::Pop => write!(f, "Transition::Pop"),
+            Transition::Replace(_) => write!(f, "Transition::Replace"),
+        }
+    }
+}
+
+/// The configuration of a [`Director`].
+///
+/// It defines the first [`Scene`] of your game.
+///
+/// [`Director`]: struct.Director.html
+/// [`Scene`]: trait.Scene.html
+pub trait Script {
+    /// The input data shared by all the scenes.
+    ///
+    /// It works like [`Game::Input`].
+    ///
+    /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+    type Input: Input;
+
+    /// The loading screen shown while loading scenes.
+    ///
+    /// It works like [`Game::LoadingScreen`].
+    ///
+    /// [`Game::LoadingScreen`]: ../trait.Game.html#associatedtype.LoadingScreen
+    type LoadingScreen: LoadingScreen;
+
+    /// Defines how many times the scene on top of the stack should be updated
+    /// per second.
+    ///
+    /// By default, it is set to `60`.
+    const TICKS_PER_SECOND: u16 = 60;
+
+    /// Loads the first [`Scene`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn first_scene(window: &Window) -> Task<Box<dyn Scene<Self::Input>>>;
+}
+
+/// A [`Game`] that manages a stack of scenes.
+///
+/// ```no_run
+/// # use coffee::graphics::{Frame, GpuSettings, Window, WindowSettings};
+/// # use coffee::load::Task;
+/// # use coffee::scene::{Director, Scene, Script};
+/// # use coffee::{Game, Result, Timer};
+/// struct Title;
+///
+/// impl Scene<()> for Title {
+///     fn draw(
+///         &mut self,
+///         _frame: &mut Frame<'_>,
+///         _timer: &Timer,
+///     ) -> Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct MyGame;
+///
+/// impl Script for MyGame {
+///     type Input = ();
+///     type LoadingScreen = ();
+///
+///     fn first_scene(_window: &Window) -> Task<Box<dyn Scene<()>>> {
+///         Task::succeed(|| Box::new(Title) as Box<dyn Scene<()>>)
+///     }
+/// }
+///
+/// fn main() -> Result<()> {
+///     Director::<MyGame>::run(WindowSettings {
+///         title: String::from("Scenes - Coffee"),
+///         size: (1280, 1024),
+///         resizable: false,
+///         fullscreen: false,
+///         maximized: false,
+///         gpu: GpuSettings::default(),
+///     })
+/// }
+/// ```
+///
+/// [`Game`]: ../trait.Game.html
+pub struct Director<S: Script> {
+    scenes: Vec<Box<dyn Scene<S::Input>>>,
+    loading_screen: Option<S::LoadingScreen>,
+}
+
+impl<S: Script> Director<S> {
+    /// Returns the amount of scenes in the stack.
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Returns true if the stack of scenes is empty.
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    // Returns true if the top scene asked for a transition
+    fn transition(&mut self, window: &mut Window) -> bool {
+        let transition = match self.scenes.last_mut() {
+            Some(scene) => scene.transition(),
+            None => None,
+        };
+
+        match transition {
+            Some(Transition::Push(task)) => match self.load(task, window) {
+                Ok(scene) => self.scenes.push(scene),
+                Err(error) => self.on_load_error(error),
+            },
+            Some(Transition::Pop) => {
+                let _ = self.scenes.pop();
+            }
+            Some(Transition::Replace(task)) => match self.load(task, window) {
+                Ok(scene) => {
+                    let _ = self.scenes.pop();
+                    self.scenes.push(scene);
+                }
+                Err(error) => self.on_load_error(error),
+            },
+            None => return false,
+        }
+
+        true
+    }
+
+    fn load(
+        &mut self,
+        task: Task<Box<dyn Scene<S::Input>>>,
+        window: &mut Window,
+    ) -> Result<Box<dyn Scene<S::Input>>> {
+        let mut loading_screen = match self.loading_screen.take() {
+            Some(loading_screen) => loading_screen,
+            None => S::LoadingScreen::new(window.gpu())?,
+        };
+
+        let result = loading_screen.run(task, window);
+        self.loading_screen = Some(loading_screen);
+
+        result
+    }
+
+    fn on_load_error(&mut self, error: Error) {
+        if let Some(scene) = self.scenes.last_mut() {
+            scene.on_load_error(error);
+        }
+    }
+}
+
+impl<S: Script> Game for Director<S> {
+    type Input = S::Input;
+    type LoadingScreen = S::LoadingScreen;
+
+    const TICKS_PER_SECOND: u16 = S::TICKS_PER_SECOND;
+
+    fn load(window: &Window) -> Task<Director<S>> {
+        S::first_scene(window).map(|scene| Director {
+            scenes: vec![scene],
+            loading_screen: None,
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) -> Result<()> {
+        let visible = self
+            .scenes
+            .iter()
+            .rposition(|scene| !scene.is_translucent())
+            .unwrap_or(0);
+
+        for scene in &mut self.scenes[visible..] {
+            scene.draw(frame, timer)?;
+        }
+
+        Ok(())
+    }
+
+    fn interact(
+        &mut self,
+        input: &mut S::Input,
+        window: &mut Window,
+    ) -> Result<()> {
+        if self.transition(window) {
+            // Edges that caused a transition must not reach the next scene
+            input.clear_edges();
+        }
+
+        match self.scenes.last_mut() {
+            Some(scene) => scene.interact(input, window),
+            None => Ok(()),
+        }
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        match self.scenes.last_mut() {
+            Some(scene) => scene.update(window),
+            None => Ok(()),
+        }
+    }
+
+    fn cursor_icon(&self) -> CursorIcon {
+        self.scenes
+            .last()
+            .map(|scene| scene.cursor_icon())
+            .unwrap_or(CursorIcon::Default)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.scenes.is_empty()
+    }
+}
+
+impl<S: Script> std::fmt::Debug for Director<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Director {{ scenes: {} }}", self.scenes.len())
+    }
+}
+
+fn boxed<I, S>(scene: S) -> Box<dyn Scene<I>>
+where
+    I: Input,
+    S: 'static + Scene<I>,
+{
+    Box::new(scene)
+}
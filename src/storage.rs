@@ -0,0 +1,454 @@
+//! Save settings and progress to the right place on every platform.
+//!
+//! This module is only available when the `data-json` or the `data-ron`
+//! feature is enabled.
+//!
+//! A [`Storage`] is a directory for your game in the data directory of the
+//! player:
+//!
+//!   * `%APPDATA%` on Windows,
+//!   * `~/Library/Application Support` on macOS,
+//!   * `$XDG_DATA_HOME` or `~/.local/share` on other platforms.
+//!
+//! Values are saved under a key, each one in its own file. Saving writes a
+//! temporary file first and then renames it, so a crash while saving never
+//! corrupts a previous save.
+//!
+//! ```no_run
+//! use coffee::storage::Storage;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Default)]
+//! struct Settings {
+//!     volume: f32,
+//!     fullscreen: bool,
+//! }
+//!
+//! # fn main() -> coffee::Result<()> {
+//! let storage = Storage::new("my-game")?;
+//!
+//! let mut settings: Settings = storage.load("settings")?.unwrap_or_default();
+//! settings.volume = 0.5;
+//!
+//! storage.save("settings", &settings)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Versioning
+//! Every value is saved along with the version of its [`Storage`], which is
+//! `0` unless you change it with [`Storage::version`]. Once you change the
+//! format of your saves, increase the version and use [`Storage::load_with`]
+//! to upgrade the values saved by older versions of your game.
+//!
+//! [`Storage`]: struct.Storage.html
+//! [`Storage::version`]: struct.Storage.html#method.version
+//! [`Storage::load_with`]: struct.Storage.html#method.load_with
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// A directory where your game can save values.
+///
+/// Check out the [module documentation] to learn more.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Storage {
+    directory: PathBuf,
+    format: Format,
+    version: u32,
+}
+
+impl Storage {
+    /// Creates a [`Storage`] for the game with the given name, in the data
+    /// directory of the player.
+    ///
+    /// The name is used as the name of the directory, so it should be unique
+    /// and a valid file name.
+    ///
+    /// It fails if the data directory of the player cannot be found.
+    ///
+    /// [`Storage`]: struct.Storage.html
+    pub fn new(app_name: &str) -> Result<Storage> {
+        let directory = data_directory().ok_or_else(|| {
+            Error::IO(io::Error::new(
+                io::ErrorKind::NotFound,
+                "the data directory of the player could not be found",
+            ))
+        })?;
+
+        Ok(Storage::at(directory.join(app_name)))
+    }
+
+    /// Creates a [`Storage`] in the given directory.
+    ///
+    /// The directory is created when the first value is saved.
+    ///
+    /// [`Storage`]: struct.Storage.html
+    pub fn at<P: Into<PathBuf>>(directory: P) -> Storage {
+        Storage {
+            directory: directory.into(),
+            format: Format::default(),
+            version: 0,
+        }
+    }
+
+    /// Sets the [`Format`] of the saved files.
+    ///
+    /// By default, RON is used if the `data-ron` feature is enabled, and JSON
+    /// otherwise.
+    ///
+    /// [`Format`]: enum.Format.html
+    pub fn format(mut self, format: Format) -> Storage {
+        self.format = format;
+        self
+    }
+
+    /// Sets the version of the format of your saves.
+    ///
+    /// Values saved with an older version need to be upgraded with
+    /// [`Storage::load_with`].
+    ///
+    /// [`Storage::load_with`]: #method.load_with
+    pub fn version(mut self, version: u32) -> Storage {
+        self.version = version;
+        self
+    }
+
+    /// Returns the directory of the [`Storage`].
+    ///
+    /// [`Storage`]: struct.Storage.html
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Saves a value under the given key, replacing the previous one.
+    ///
+    /// Keys must be valid file names.
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let path = self.path(key)?;
+
+        let bytes = self
+            .format
+            .serialize(&Saved {
+                version: self.version,
+                data: value,
+            })
+            .map_err(|error| in_file(&path, error))?;
+
+        fs::create_dir_all(&self.directory)?;
+
+        let temporary =
+            path.with_extension(format!("{}.tmp", self.format.extension()));
+
+        let mut file = File::create(&temporary)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+
+        // Renaming is atomic, so the previous save stays intact until the new
+        // one is complete
+        fs::rename(&temporary, &path)?;
+
+        Ok(())
+    }
+
+    /// Loads the value saved under the given key, if any.
+    ///
+    /// It fails if the value was saved with a different version. Use
+    /// [`Storage::load_with`] to upgrade old values.
+    ///
+    /// [`Storage::load_with`]: #method.load_with
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let version = self.version;
+
+        self.load_with(key, |saved| {
+            Err(Error::Data {
+                line: 0,
+                column: 0,
+                message: format!(
+                    "the value was saved with version {}, expected {}",
+                    saved.version(),
+                    version
+                ),
+            })
+        })
+    }
+
+    /// Loads the value saved under the given key, if any, upgrading it with
+    /// the given function if it was saved with an older version.
+    ///
+    /// The function receives the [`OldValue`], which can be deserialized as
+    /// the type used by the version that saved it. Values saved by a newer
+    /// version always fail to load.
+    ///
+    /// ```no_run
+    /// use coffee::storage::Storage;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct SettingsV0 {
+    ///     volume: u8,
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Settings {
+    ///     volume: f32,
+    /// }
+    ///
+    /// # fn main() -> coffee::Result<()> {
+    /// let storage = Storage::new("my-game")?.version(1);
+    ///
+    /// let settings = storage.load_with("settings", |old| {
+    ///     let settings: SettingsV0 = old.deserialize()?;
+    ///
+    ///     Ok(Settings {
+    ///         volume: f32::from(settings.volume) / 100.0,
+    ///     })
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OldValue`]: struct.OldValue.html
+    pub fn load_with<T, F>(&self, key: &str, migrate: F) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(OldValue) -> Result<T>,
+    {
+        let path = self.path(key)?;
+
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(error) => return Err(in_file(&path, Error::IO(error))),
+        };
+
+        let old = OldValue {
+            format: self.format,
+            bytes,
+        };
+
+        let result = match old.version() {
+            Ok(version) if version == self.version => old.deserialize(),
+            Ok(version) if version < self.version => migrate(old),
+            Ok(version) => Err(Error::Data {
+                line: 0,
+                column: 0,
+                message: format!(
+                    "the value was saved with version {}, which is newer \
+                     than {}",
+                    version, self.version
+                ),
+            }),
+            Err(error) => Err(error),
+        };
+
+        result.map(Some).map_err(|error| in_file(&path, error))
+    }
+
+    /// Creates a [`Task`] that loads the value saved under the given key, if
+    /// any.
+    ///
+    /// Use it to load saves while showing a loading screen.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    pub fn load_task<T>(&self, key: &str) -> Task<Option<T>>
+    where
+        T: 'static + DeserializeOwned,
+    {
+        let storage = self.clone();
+        let key = String::from(key);
+
+        Task::new(move || storage.load(&key))
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        let is_valid = !key.is_empty()
+            && key != "."
+            && key != ".."
+            && !key.contains(|c| c == '/' || c == '\\');
+
+        if !is_valid {
+            return Err(Error::IO(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid storage key \"{}\"", key),
+            )));
+        }
+
+        Ok(self
+            .directory
+            .join(format!("{}.{}", key, self.format.extension())))
+    }
+}
+
+/// The format of the files of a [`Storage`].
+///
+/// [`Storage`]: struct.Storage.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// JSON, only available when the `data-json` feature is enabled.
+    #[cfg(feature = "data-json")]
+    Json,
+
+    /// [RON], only available when the `data-ron` feature is enabled.
+    ///
+    /// [RON]: https://github.com/ron-rs/ron
+    #[cfg(feature = "data-ron")]
+    Ron,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            #[cfg(feature = "data-json")]
+            Format::Json => "json",
+            #[cfg(feature = "data-ron")]
+            Format::Ron => "ron",
+        }
+    }
+
+    fn serialize<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+        let message = match self {
+            #[cfg(feature = "data-json")]
+            Format::Json => match serde_json::to_vec_pretty(value) {
+                Ok(bytes) => return Ok(bytes),
+                Err(error) => error.to_string(),
+            },
+            #[cfg(feature = "data-ron")]
+            Format::Ron => match ron::ser::to_string_pretty(
+                value,
+                ron::ser::PrettyConfig::default(),
+            ) {
+                Ok(string) => return Ok(string.into_bytes()),
+                Err(error) => error.to_string(),
+            },
+        };
+
+        Err(Error::Data {
+            line: 0,
+            column: 0,
+            message,
+        })
+    }
+
+    fn deserialize<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T> {
+        match self {
+            #[cfg(feature = "data-json")]
+            Format::Json => {
+                serde_json::from_slice(bytes).map_err(|error| Error::Data {
+                    line: error.line(),
+                    column: error.column(),
+                    message: error.to_string(),
+                })
+            }
+            #[cfg(feature = "data-ron")]
+            Format::Ron => ron::de::from_bytes(bytes).map_err(|error| {
+                let (line, column) = match &error {
+                    ron::de::Error::Parser(_, position) => {
+                        (position.line, position.col)
+                    }
+                    _ => (0, 0),
+                };
+
+                Error::Data {
+                    line,
+                    column,
+                    message: error.to_string(),
+                }
+            }),
+        }
+    }
+}
+
+impl Default for Format {
+    #[cfg(feature = "data-ron")]
+    fn default() -> Format {
+        Format::Ron
+    }
+
+    #[cfg(not(feature = "data-ron"))]
+    fn default() -> Format {
+        Format::Json
+    }
+}
+
+/// A value saved by an older version of your game.
+///
+/// It is given to the migration function of [`Storage::load_with`].
+///
+/// [`Storage::load_with`]: struct.Storage.html#method.load_with
+#[derive(Debug, Clone)]
+pub struct OldValue {
+    format: Format,
+    bytes: Vec<u8>,
+}
+
+impl OldValue {
+    /// Returns the version of the [`Storage`] that saved the value.
+    ///
+    /// [`Storage`]: struct.Storage.html
+    pub fn version(&self) -> Result<u32> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+
+        let saved: Version = self.format.deserialize(&self.bytes)?;
+
+        Ok(saved.version)
+    }
+
+    /// Deserializes the value as the given type.
+    ///
+    /// Use the type that the version that saved the value used.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        let saved: Saved<T> = self.format.deserialize(&self.bytes)?;
+
+        Ok(saved.data)
+    }
+}
+
+// A saved value, along with the version of its format
+#[derive(Serialize, Deserialize)]
+struct Saved<T> {
+    version: u32,
+    data: T,
+}
+
+fn in_file(path: &Path, error: Error) -> Error {
+    Error::Asset {
+        path: path.to_path_buf(),
+        error: Box::new(error),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn data_directory() -> Option<PathBuf> {
+    std::env::var_os("APPDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn data_directory() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join("Library/Application Support"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn data_directory() -> Option<PathBuf> {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(".local/share"))
+        })
+}
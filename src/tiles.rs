@@ -0,0 +1,956 @@
+//! Load and draw tile maps made with [Tiled].
+//!
+//! A [`TileMap`] is loaded from the JSON format of [Tiled]. Its tile layers
+//! are drawn with a [`Batch`] per tileset, emitting quads only for the tiles
+//! that are visible. Its object layers are not drawn; instead, you can use
+//! their shapes and properties to spawn the entities of your game.
+//!
+//! ```no_run
+//! use coffee::graphics::{Frame, Rectangle};
+//! use coffee::load::Task;
+//! use coffee::tiles::TileMap;
+//! use coffee::Timer;
+//!
+//! struct Level {
+//!     map: TileMap,
+//! }
+//!
+//! impl Level {
+//!     fn load() -> Task<Level> {
+//!         TileMap::load_tiled("resources/levels/1.json")
+//!             .map(|map| Level { map })
+//!     }
+//!
+//!     fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) {
+//!         let camera = Rectangle {
+//!             x: 0.0,
+//!             y: 0.0,
+//!             width: frame.width(),
+//!             height: frame.height(),
+//!         };
+//!
+//!         self.map.update(timer);
+//!         self.map.draw(camera, &mut frame.as_target());
+//!     }
+//! }
+//! ```
+//!
+//! This module is only available when the `tiles` feature is enabled.
+//!
+//! [Tiled]: https://www.mapeditor.org
+//! [`TileMap`]: struct.TileMap.html
+//! [`Batch`]: ../graphics/struct.Batch.html
+mod tiled;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::graphics::{
+    Batch, Color, Image, Point, Quad, Rectangle, Target, Vector,
+};
+use crate::load::{self, Task};
+use crate::{Error, Result, Timer};
+
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+const FLAGS: u32 =
+    FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY;
+
+/// The custom properties of a map, layer, tile, or object.
+pub type Properties = HashMap<String, Property>;
+
+/// A custom property defined in [Tiled].
+///
+/// [Tiled]: https://www.mapeditor.org
+#[derive(Debug, Clone, PartialEq)]
+pub enum Property {
+    /// A boolean property.
+    Bool(bool),
+
+    /// An integer property.
+    Int(i64),
+
+    /// A floating point property.
+    Float(f32),
+
+    /// A string property.
+    String(String),
+
+    /// A color property.
+    Color(Color),
+
+    /// A file property, relative to the map or tileset that defines it.
+    File(PathBuf),
+}
+
+/// A map of tiles loaded from [Tiled].
+///
+/// Only orthogonal, finite maps with tile layers in the CSV format are
+/// supported. Tilesets may be embedded in the map or exported as separate
+/// JSON files. Flipped tiles are supported, but rotated tiles are drawn
+/// without their rotation.
+///
+/// [Tiled]: https://www.mapeditor.org
+#[derive(Debug)]
+pub struct TileMap {
+    width: u32,
+    height: u32,
+    tile_width: u32,
+    tile_height: u32,
+    overdraw: (u32, u32),
+    tilesets: Vec<Tileset>,
+    tile_layers: Vec<TileLayer>,
+    object_layers: Vec<ObjectLayer>,
+    properties: Properties,
+    time: Duration,
+    batches: RefCell<Vec<Batch>>,
+}
+
+impl TileMap {
+    /// Creates a [`Task`] that loads a [`TileMap`] from a JSON file exported
+    /// by [Tiled].
+    ///
+    /// The images of the tilesets are loaded in parallel. Their paths are
+    /// relative to the file that defines the tileset, like [Tiled] expects.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`TileMap`]: struct.TileMap.html
+    /// [Tiled]: https://www.mapeditor.org
+    pub fn load_tiled<P: Into<PathBuf>>(path: P) -> Task<TileMap> {
+        let path = path.into();
+
+        Task::new(move || {
+            parse(&path).map_err(|error| Error::Asset {
+                path,
+                error: Box::new(error),
+            })
+        })
+        .and_then(|(map, images)| {
+            Task::parallel(images.into_iter().map(Image::job).collect())
+                .map(|images| map.with_images(images))
+        })
+    }
+
+    /// Returns the width of the [`TileMap`], in tiles.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`TileMap`], in tiles.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the width and height of a tile of the [`TileMap`], in pixels.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn tile_size(&self) -> (u32, u32) {
+        (self.tile_width, self.tile_height)
+    }
+
+    /// Returns the custom properties of the [`TileMap`].
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+
+    /// Returns the custom properties of the tile with the given id, if any.
+    ///
+    /// Tile ids are obtained with [`TileLayer::tile`].
+    ///
+    /// [`TileLayer::tile`]: struct.TileLayer.html#method.tile
+    pub fn tile_properties(&self, tile: u32) -> Option<&Properties> {
+        let (tileset, id) = self.tileset(tile)?;
+
+        tileset.properties.get(&id)
+    }
+
+    /// Returns the tile layers of the [`TileMap`], from bottom to top.
+    ///
+    /// Layers inside groups are flattened.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn tile_layers(&self) -> &[TileLayer] {
+        &self.tile_layers
+    }
+
+    /// Returns the tile layers of the [`TileMap`] mutably, so they can be
+    /// hidden or shown.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn tile_layers_mut(&mut self) -> &mut [TileLayer] {
+        &mut self.tile_layers
+    }
+
+    /// Returns the object layers of the [`TileMap`], from bottom to top.
+    ///
+    /// Layers inside groups are flattened.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    pub fn object_layers(&self) -> &[ObjectLayer] {
+        &self.object_layers
+    }
+
+    /// Returns the first object layer with the given name, if any.
+    pub fn object_layer(&self, name: &str) -> Option<&ObjectLayer> {
+        self.object_layers.iter().find(|layer| layer.name == name)
+    }
+
+    /// Advances the animated tiles of the [`TileMap`] using the given
+    /// [`Timer`].
+    ///
+    /// Animations follow the [`total_time`] of the [`Timer`], so they stop
+    /// when the game is paused.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    /// [`Timer`]: ../struct.Timer.html
+    /// [`total_time`]: ../struct.Timer.html#method.total_time
+    pub fn update(&mut self, timer: &Timer) {
+        self.time = timer.total_time();
+    }
+
+    /// Draws the visible tile layers of the [`TileMap`] on the given
+    /// [`Target`].
+    ///
+    /// Only the tiles that overlap the given region, in map coordinates, are
+    /// drawn. Usually, it is the region that your camera is looking at.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    /// [`Target`]: ../graphics/struct.Target.html
+    pub fn draw(&self, region: Rectangle<f32>, target: &mut Target<'_>) {
+        for layer in 0..self.tile_layers.len() {
+            self.draw_layer(layer, region, target);
+        }
+    }
+
+    /// Draws the tile layer with the given index on the given [`Target`], if
+    /// it is visible.
+    ///
+    /// This is useful to draw your entities between layers. Read [`draw`] to
+    /// learn more.
+    ///
+    /// # Panics
+    /// It panics if the index is out of bounds.
+    ///
+    /// [`Target`]: ../graphics/struct.Target.html
+    /// [`draw`]: #method.draw
+    pub fn draw_layer(
+        &self,
+        index: usize,
+        region: Rectangle<f32>,
+        target: &mut Target<'_>,
+    ) {
+        let layer = &self.tile_layers[index];
+
+        if !layer.is_visible {
+            return;
+        }
+
+        let tile_width = self.tile_width as f32;
+        let tile_height = self.tile_height as f32;
+        let left = (region.x - layer.offset.x) / tile_width;
+        let top = (region.y - layer.offset.y) / tile_height;
+        let right = left + region.width / tile_width;
+        let bottom = top + region.height / tile_height;
+
+        // Tiles larger than the grid are anchored to the bottom left corner
+        // of their cell, so they may be visible from outside the region
+        let first_column = tile_index(left).saturating_sub(self.overdraw.0);
+        let last_column = tile_index(right.ceil()).min(layer.width);
+        let first_row = tile_index(top);
+        let last_row =
+            (tile_index(bottom.ceil()) + self.overdraw.1).min(layer.height);
+
+        let mut batches = self.batches.borrow_mut();
+
+        for row in first_row..last_row {
+            for column in first_column..last_column {
+                let tile = layer.tiles[(row * layer.width + column) as usize];
+
+                let (batch, tileset, id) = match self.tileset_index(tile) {
+                    Some(tileset) => tileset,
+                    None => continue,
+                };
+
+                let mut source = tileset.source(tileset.frame(id, self.time));
+
+                if tile & FLIPPED_HORIZONTALLY != 0 {
+                    source.x += source.width;
+                    source.width = -source.width;
+                }
+
+                if tile & FLIPPED_VERTICALLY != 0 {
+                    source.y += source.height;
+                    source.height = -source.height;
+                }
+
+                batches[batch].add(Quad {
+                    source,
+                    position: Point::new(
+                        layer.offset.x + column as f32 * tile_width,
+                        layer.offset.y + (row + 1) as f32 * tile_height
+                            - tileset.tile_height as f32,
+                    ),
+                    size: (
+                        tileset.tile_width as f32,
+                        tileset.tile_height as f32,
+                    ),
+                });
+            }
+        }
+
+        for batch in batches.iter_mut() {
+            if !batch.is_empty() {
+                batch.draw(target);
+                batch.clear();
+            }
+        }
+    }
+
+    fn tileset(&self, tile: u32) -> Option<(&Tileset, u32)> {
+        self.tileset_index(tile)
+            .map(|(_, tileset, id)| (tileset, id))
+    }
+
+    fn tileset_index(&self, tile: u32) -> Option<(usize, &Tileset, u32)> {
+        let gid = tile & !FLAGS;
+
+        if gid == 0 {
+            return None;
+        }
+
+        let (index, tileset) = self
+            .tilesets
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, tileset)| tileset.first_gid <= gid)?;
+
+        let id = gid - tileset.first_gid;
+
+        if id < tileset.tile_count {
+            Some((index, tileset, id))
+        } else {
+            None
+        }
+    }
+
+    fn with_images(mut self, images: Vec<Image>) -> TileMap {
+        for (tileset, image) in self.tilesets.iter_mut().zip(images.iter()) {
+            tileset.x_unit = 1.0 / image.width() as f32;
+            tileset.y_unit = 1.0 / image.height() as f32;
+        }
+
+        self.batches =
+            RefCell::new(images.into_iter().map(Batch::new).collect());
+        self
+    }
+}
+
+/// A layer of tiles of a [`TileMap`].
+///
+/// [`TileMap`]: struct.TileMap.html
+#[derive(Debug, Clone)]
+pub struct TileLayer {
+    name: String,
+    width: u32,
+    height: u32,
+    offset: Vector,
+    is_visible: bool,
+    tiles: Vec<u32>,
+    properties: Properties,
+}
+
+impl TileLayer {
+    /// Returns the name of the [`TileLayer`].
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the width of the [`TileLayer`], in tiles.
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`TileLayer`], in tiles.
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the offset of the [`TileLayer`], in pixels.
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Returns the id of the tile in the given column and row, if there is
+    /// one.
+    ///
+    /// The id identifies the tile in the whole [`TileMap`]. It can be used to
+    /// obtain the [`tile_properties`], which are useful to describe things
+    /// like collisions.
+    ///
+    /// [`TileMap`]: struct.TileMap.html
+    /// [`tile_properties`]: struct.TileMap.html#method.tile_properties
+    pub fn tile(&self, column: u32, row: u32) -> Option<u32> {
+        if column >= self.width || row >= self.height {
+            return None;
+        }
+
+        let tile = self.tiles[(row * self.width + column) as usize] & !FLAGS;
+
+        if tile == 0 {
+            None
+        } else {
+            Some(tile)
+        }
+    }
+
+    /// Returns true if the [`TileLayer`] is drawn.
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Shows or hides the [`TileLayer`].
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn set_visible(&mut self, is_visible: bool) {
+        self.is_visible = is_visible;
+    }
+
+    /// Returns the custom properties of the [`TileLayer`].
+    ///
+    /// [`TileLayer`]: struct.TileLayer.html
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+}
+
+/// A layer of objects of a [`TileMap`].
+///
+/// Object layers are not drawn. Use their objects to spawn the entities of
+/// your game.
+///
+/// [`TileMap`]: struct.TileMap.html
+#[derive(Debug, Clone)]
+pub struct ObjectLayer {
+    name: String,
+    is_visible: bool,
+    objects: Vec<Object>,
+    properties: Properties,
+}
+
+impl ObjectLayer {
+    /// Returns the name of the [`ObjectLayer`].
+    ///
+    /// [`ObjectLayer`]: struct.ObjectLayer.html
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns true if the [`ObjectLayer`] is visible in [Tiled].
+    ///
+    /// [`ObjectLayer`]: struct.ObjectLayer.html
+    /// [Tiled]: https://www.mapeditor.org
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Returns the objects of the [`ObjectLayer`].
+    ///
+    /// [`ObjectLayer`]: struct.ObjectLayer.html
+    pub fn objects(&self) -> &[Object] {
+        &self.objects
+    }
+
+    /// Returns the custom properties of the [`ObjectLayer`].
+    ///
+    /// [`ObjectLayer`]: struct.ObjectLayer.html
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+}
+
+/// An object placed in an [`ObjectLayer`].
+///
+/// [`ObjectLayer`]: struct.ObjectLayer.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Object {
+    /// The unique id of the object in the map.
+    pub id: u32,
+
+    /// The name of the object.
+    pub name: String,
+
+    /// The type of the object.
+    pub kind: String,
+
+    /// The shape of the object, in map coordinates.
+    pub shape: Shape,
+
+    /// The rotation of the object around its position, in degrees.
+    pub rotation: f32,
+
+    /// Whether the object is visible in [Tiled].
+    ///
+    /// [Tiled]: https://www.mapeditor.org
+    pub is_visible: bool,
+
+    /// The id of the tile of the object, if it is a tile object.
+    pub tile: Option<u32>,
+
+    /// The custom properties of the object.
+    pub properties: Properties,
+}
+
+/// The shape of an [`Object`].
+///
+/// [`Object`]: struct.Object.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// A rectangle.
+    ///
+    /// Tile objects are rectangles too. Like in [Tiled], their position is
+    /// the bottom left corner of the tile.
+    ///
+    /// [Tiled]: https://www.mapeditor.org
+    Rectangle(Rectangle<f32>),
+
+    /// An ellipse inscribed in the given rectangle.
+    Ellipse(Rectangle<f32>),
+
+    /// A single point.
+    Point(Point),
+
+    /// A closed polygon.
+    Polygon(Vec<Point>),
+
+    /// An open line made of segments.
+    Polyline(Vec<Point>),
+}
+
+#[derive(Debug)]
+struct Tileset {
+    first_gid: u32,
+    tile_width: u32,
+    tile_height: u32,
+    margin: u32,
+    spacing: u32,
+    columns: u32,
+    tile_count: u32,
+    x_unit: f32,
+    y_unit: f32,
+    animations: HashMap<u32, Animation>,
+    properties: HashMap<u32, Properties>,
+}
+
+impl Tileset {
+    fn from_tiled(
+        first_gid: u32,
+        tileset: tiled::Tileset,
+        directory: &Path,
+    ) -> Tileset {
+        let mut animations = HashMap::new();
+        let mut tile_properties = HashMap::new();
+
+        for tile in tileset.tiles {
+            if !tile.animation.is_empty() {
+                let frames: Vec<_> = tile
+                    .animation
+                    .iter()
+                    .map(|frame| (frame.tile_id, frame.duration))
+                    .collect();
+
+                let duration =
+                    frames.iter().map(|(_, duration)| duration).sum();
+
+                let _ =
+                    animations.insert(tile.id, Animation { frames, duration });
+            }
+
+            if !tile.properties.is_empty() {
+                let _ = tile_properties
+                    .insert(tile.id, properties(tile.properties, directory));
+            }
+        }
+
+        Tileset {
+            first_gid,
+            tile_width: tileset.tile_width,
+            tile_height: tileset.tile_height,
+            margin: tileset.margin,
+            spacing: tileset.spacing,
+            columns: tileset.columns,
+            tile_count: tileset.tile_count,
+            x_unit: 0.0,
+            y_unit: 0.0,
+            animations,
+            properties: tile_properties,
+        }
+    }
+
+    fn source(&self, id: u32) -> Rectangle<f32> {
+        let column = id % self.columns.max(1);
+        let row = id / self.columns.max(1);
+
+        Rectangle {
+            x: (self.margin + column * (self.tile_width + self.spacing)) as f32
+                * self.x_unit,
+            y: (self.margin + row * (self.tile_height + self.spacing)) as f32
+                * self.y_unit,
+            width: self.tile_width as f32 * self.x_unit,
+            height: self.tile_height as f32 * self.y_unit,
+        }
+    }
+
+    fn frame(&self, id: u32, time: Duration) -> u32 {
+        match self.animations.get(&id) {
+            Some(animation) => animation.frame(time).unwrap_or(id),
+            None => id,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Animation {
+    frames: Vec<(u32, u64)>,
+    duration: u64,
+}
+
+impl Animation {
+    fn frame(&self, time: Duration) -> Option<u32> {
+        if self.duration == 0 {
+            return None;
+        }
+
+        let mut elapsed = time.as_millis() as u64 % self.duration;
+
+        for (id, duration) in &self.frames {
+            if elapsed < *duration {
+                return Some(*id);
+            }
+
+            elapsed -= duration;
+        }
+
+        None
+    }
+}
+
+fn tile_index(position: f32) -> u32 {
+    position.floor().max(0.0) as u32
+}
+
+fn parse(path: &Path) -> Result<(TileMap, Vec<PathBuf>)> {
+    let map: tiled::Map = from_json(&load::read(path)?)?;
+
+    if map.orientation != "orthogonal" {
+        return Err(unsupported(format!(
+            "{} maps are not supported, only orthogonal ones",
+            map.orientation
+        )));
+    }
+
+    if map.infinite {
+        return Err(unsupported("infinite maps are not supported"));
+    }
+
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut tilesets = Vec::with_capacity(map.tilesets.len());
+    let mut images = Vec::with_capacity(map.tilesets.len());
+
+    for reference in map.tilesets {
+        let (tileset, directory) = match reference.source {
+            Some(source) => {
+                let path = directory.join(source);
+
+                if path.extension() == Some(OsStr::new("tsx")) {
+                    return Err(unsupported(
+                        "TSX tilesets are not supported, export the tileset \
+                         as JSON instead",
+                    ));
+                }
+
+                let tileset: tiled::Tileset = from_json(&load::read(&path)?)?;
+
+                let directory =
+                    path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+                (tileset, directory)
+            }
+            None => (
+                serde_json::from_value(serde_json::Value::Object(
+                    reference.embedded,
+                ))
+                .map_err(|error| unsupported(error.to_string()))?,
+                directory.to_path_buf(),
+            ),
+        };
+
+        images.push(directory.join(&tileset.image));
+        tilesets.push(Tileset::from_tiled(
+            reference.first_gid,
+            tileset,
+            &directory,
+        ));
+    }
+
+    let (tile_width, tile_height) = (map.tile_width, map.tile_height);
+    let overdraw = tilesets.iter().fold((0, 0), |(x, y), tileset| {
+        (
+            x.max(overflow(tileset.tile_width, tile_width)),
+            y.max(overflow(tileset.tile_height, tile_height)),
+        )
+    });
+
+    let mut tile_layers = Vec::new();
+    let mut object_layers = Vec::new();
+
+    flatten(
+        map.layers,
+        Vector::new(0.0, 0.0),
+        true,
+        directory,
+        &mut tile_layers,
+        &mut object_layers,
+    )?;
+
+    Ok((
+        TileMap {
+            width: map.width,
+            height: map.height,
+            tile_width: map.tile_width,
+            tile_height: map.tile_height,
+            overdraw,
+            tilesets,
+            tile_layers,
+            object_layers,
+            properties: properties(map.properties, directory),
+            time: Duration::from_secs(0),
+            batches: RefCell::new(Vec::new()),
+        },
+        images,
+    ))
+}
+
+// The amount of extra cells covered by a tile of the given size
+fn overflow(tile_size: u32, grid_size: u32) -> u32 {
+    tile_size.saturating_sub(1) / grid_size.max(1)
+}
+
+fn flatten(
+    layers: Vec<tiled::Layer>,
+    offset: Vector,
+    is_visible: bool,
+    directory: &Path,
+    tile_layers: &mut Vec<TileLayer>,
+    object_layers: &mut Vec<ObjectLayer>,
+) -> Result<()> {
+    for layer in layers {
+        match layer {
+            tiled::Layer::Tiles {
+                name,
+                width,
+                height,
+                data,
+                encoding,
+                offset_x,
+                offset_y,
+                visible,
+                properties: layer_properties,
+            } => {
+                let tiles = match (data, encoding.as_deref()) {
+                    (Some(tiled::Data::Csv(tiles)), None)
+                    | (Some(tiled::Data::Csv(tiles)), Some("csv")) => tiles,
+                    _ => {
+                        return Err(unsupported(format!(
+                            "the data of layer \"{}\" is not supported, \
+                             use the CSV tile layer format",
+                            name
+                        )))
+                    }
+                };
+
+                if tiles.len() != (width * height) as usize {
+                    return Err(unsupported(format!(
+                        "layer \"{}\" has {} tiles, but it should have {}",
+                        name,
+                        tiles.len(),
+                        width * height
+                    )));
+                }
+
+                tile_layers.push(TileLayer {
+                    name,
+                    width,
+                    height,
+                    offset: offset + Vector::new(offset_x, offset_y),
+                    is_visible: is_visible && visible,
+                    tiles,
+                    properties: properties(layer_properties, directory),
+                });
+            }
+            tiled::Layer::Objects {
+                name,
+                objects,
+                offset_x,
+                offset_y,
+                visible,
+                properties: layer_properties,
+            } => {
+                let offset = offset + Vector::new(offset_x, offset_y);
+
+                object_layers.push(ObjectLayer {
+                    name,
+                    is_visible: is_visible && visible,
+                    objects: objects
+                        .into_iter()
+                        .map(|object| {
+                            Object::from_tiled(object, offset, directory)
+                        })
+                        .collect(),
+                    properties: properties(layer_properties, directory),
+                });
+            }
+            tiled::Layer::Group {
+                layers,
+                offset_x,
+                offset_y,
+                visible,
+            } => {
+                flatten(
+                    layers,
+                    offset + Vector::new(offset_x, offset_y),
+                    is_visible && visible,
+                    directory,
+                    tile_layers,
+                    object_layers,
+                )?;
+            }
+            tiled::Layer::Other => {}
+        }
+    }
+
+    Ok(())
+}
+
+impl Object {
+    fn from_tiled(
+        object: tiled::Object,
+        offset: Vector,
+        directory: &Path,
+    ) -> Object {
+        let position = Point::new(object.x, object.y) + offset;
+        let bounds = Rectangle {
+            x: position.x,
+            y: position.y,
+            width: object.width,
+            height: object.height,
+        };
+
+        let points = |coordinates: Vec<tiled::Coordinates>| {
+            coordinates
+                .iter()
+                .map(|point| position + Vector::new(point.x, point.y))
+                .collect()
+        };
+
+        let shape = if let Some(polygon) = object.polygon {
+            Shape::Polygon(points(polygon))
+        } else if let Some(polyline) = object.polyline {
+            Shape::Polyline(points(polyline))
+        } else if object.point {
+            Shape::Point(position)
+        } else if object.ellipse {
+            Shape::Ellipse(bounds)
+        } else {
+            Shape::Rectangle(bounds)
+        };
+
+        Object {
+            id: object.id,
+            name: object.name,
+            kind: object.kind,
+            shape,
+            rotation: object.rotation,
+            is_visible: object.visible,
+            tile: object.gid.map(|gid| gid & !FLAGS),
+            properties: properties(object.properties, directory),
+        }
+    }
+}
+
+fn properties(
+    properties: Vec<tiled::Property>,
+    directory: &Path,
+) -> Properties {
+    properties
+        .into_iter()
+        .filter_map(|property| {
+            let value = &property.value;
+
+            let value = match property.kind.as_str() {
+                "bool" => Property::Bool(value.as_bool()?),
+                "int" => Property::Int(value.as_i64()?),
+                "float" => Property::Float(value.as_f64()? as f32),
+                "color" => Property::Color(color(value.as_str()?)?),
+                "file" => Property::File(directory.join(value.as_str()?)),
+                _ => Property::String(value.as_str()?.to_owned()),
+            };
+
+            Some((property.name, value))
+        })
+        .collect()
+}
+
+// Tiled colors are formatted as `#AARRGGBB`, or empty when unset
+fn color(hex: &str) -> Option<Color> {
+    let hex = hex.trim_start_matches('#');
+
+    if hex.is_empty() {
+        return Some(Color::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    let argb = u32::from_str_radix(hex, 16).ok()?;
+    let alpha = if hex.len() == 8 { argb >> 24 } else { 0xFF };
+    let rgb = Color::from_rgb_u32(argb & 0x00FF_FFFF);
+
+    Some(Color {
+        a: alpha as f32 / 255.0,
+        ..rgb
+    })
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    serde_json::from_slice(bytes).map_err(|error| Error::Data {
+        line: error.line(),
+        column: error.column(),
+        message: error.to_string(),
+    })
+}
+
+fn unsupported<S: Into<String>>(message: S) -> Error {
+    Error::Data {
+        line: 0,
+        column: 0,
+        message: message.into(),
+    }
+}
@@ -0,0 +1,182 @@
+// The subset of the JSON format of Tiled supported by the tiles module.
+//
+// Reference: https://doc.mapeditor.org/en/stable/reference/json-map-format/
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Map {
+    pub width: u32,
+    pub height: u32,
+    #[serde(rename = "tilewidth")]
+    pub tile_width: u32,
+    #[serde(rename = "tileheight")]
+    pub tile_height: u32,
+    #[serde(default = "orthogonal")]
+    pub orientation: String,
+    #[serde(default)]
+    pub infinite: bool,
+    pub layers: Vec<Layer>,
+    pub tilesets: Vec<TilesetRef>,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+pub enum Layer {
+    #[serde(rename = "tilelayer")]
+    Tiles {
+        name: String,
+        width: u32,
+        height: u32,
+        #[serde(default)]
+        data: Option<Data>,
+        #[serde(default)]
+        encoding: Option<String>,
+        #[serde(default, rename = "offsetx")]
+        offset_x: f32,
+        #[serde(default, rename = "offsety")]
+        offset_y: f32,
+        #[serde(default = "visible")]
+        visible: bool,
+        #[serde(default)]
+        properties: Vec<Property>,
+    },
+    #[serde(rename = "objectgroup")]
+    Objects {
+        name: String,
+        objects: Vec<Object>,
+        #[serde(default, rename = "offsetx")]
+        offset_x: f32,
+        #[serde(default, rename = "offsety")]
+        offset_y: f32,
+        #[serde(default = "visible")]
+        visible: bool,
+        #[serde(default)]
+        properties: Vec<Property>,
+    },
+    #[serde(rename = "group")]
+    Group {
+        layers: Vec<Layer>,
+        #[serde(default, rename = "offsetx")]
+        offset_x: f32,
+        #[serde(default, rename = "offsety")]
+        offset_y: f32,
+        #[serde(default = "visible")]
+        visible: bool,
+    },
+    // Image layers are not supported and skipped
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Data {
+    Csv(Vec<u32>),
+    // Base64 data, which is not supported
+    Encoded(serde::de::IgnoredAny),
+}
+
+#[derive(Deserialize)]
+pub struct Object {
+    #[serde(default)]
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub kind: String,
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub width: f32,
+    #[serde(default)]
+    pub height: f32,
+    #[serde(default)]
+    pub rotation: f32,
+    #[serde(default = "visible")]
+    pub visible: bool,
+    #[serde(default)]
+    pub gid: Option<u32>,
+    #[serde(default)]
+    pub point: bool,
+    #[serde(default)]
+    pub ellipse: bool,
+    #[serde(default)]
+    pub polygon: Option<Vec<Coordinates>>,
+    #[serde(default)]
+    pub polyline: Option<Vec<Coordinates>>,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+}
+
+#[derive(Deserialize)]
+pub struct Coordinates {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Deserialize)]
+pub struct Property {
+    pub name: String,
+    #[serde(default = "string", rename = "type")]
+    pub kind: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+pub struct TilesetRef {
+    #[serde(rename = "firstgid")]
+    pub first_gid: u32,
+    #[serde(default)]
+    pub source: Option<String>,
+    // An embedded tileset, unless it has a source
+    #[serde(flatten)]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+pub struct Tileset {
+    pub image: String,
+    #[serde(rename = "tilewidth")]
+    pub tile_width: u32,
+    #[serde(rename = "tileheight")]
+    pub tile_height: u32,
+    #[serde(default)]
+    pub margin: u32,
+    #[serde(default)]
+    pub spacing: u32,
+    pub columns: u32,
+    #[serde(rename = "tilecount")]
+    pub tile_count: u32,
+    #[serde(default)]
+    pub tiles: Vec<Tile>,
+}
+
+#[derive(Deserialize)]
+pub struct Tile {
+    pub id: u32,
+    #[serde(default)]
+    pub animation: Vec<Frame>,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+}
+
+#[derive(Deserialize)]
+pub struct Frame {
+    #[serde(rename = "tileid")]
+    pub tile_id: u32,
+    pub duration: u64,
+}
+
+fn orthogonal() -> String {
+    String::from("orthogonal")
+}
+
+fn string() -> String {
+    String::from("string")
+}
+
+fn visible() -> bool {
+    true
+}
@@ -0,0 +1,172 @@
+//! Express gameplay timings in durations instead of ticks.
+//!
+//! The helpers in this module are driven by the game time of the [`Clock`]
+//! of a [`Window`]. Game time advances by a fixed amount every tick, so they
+//! are deterministic and keep working when you change
+//! [`Game::TICKS_PER_SECOND`]. They also stop while the [`Clock`] is paused.
+//!
+//! Use them in [`Game::update`]. When your game falls behind and runs
+//! multiple catch-up ticks in a single frame, [`Game::update`] is called once
+//! per tick, so the helpers see every tick.
+//!
+//! [`Clock`]: ../struct.Clock.html
+//! [`Window`]: ../graphics/struct.Window.html
+//! [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+//! [`Game::update`]: ../trait.Game.html#method.update
+use crate::graphics::Window;
+
+use std::time::Duration;
+
+/// A timer that fires periodically.
+///
+/// ```
+/// # use coffee::graphics::Window;
+/// # use coffee::time::Interval;
+/// # use coffee::Result;
+/// use std::time::Duration;
+///
+/// struct Spawner {
+///     enemies: u32,
+///     interval: Interval,
+/// }
+///
+/// impl Spawner {
+///     fn new() -> Spawner {
+///         Spawner {
+///             enemies: 0,
+///             interval: Interval::every(Duration::from_secs(2)),
+///         }
+///     }
+///
+///     // Called in `Game::update`
+///     fn update(&mut self, window: &Window) -> Result<()> {
+///         if self.interval.tick(window) {
+///             self.enemies += 1;
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// An [`Interval`] fires at most once per tick. If its period is shorter
+/// than a tick, the periods that elapse during the same tick are coalesced
+/// into a single firing.
+///
+/// [`Interval`]: struct.Interval.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interval {
+    period: Duration,
+    next: Option<Duration>,
+}
+
+impl Interval {
+    /// Creates a new [`Interval`] that fires every `period` of game time.
+    ///
+    /// The first firing happens one `period` after the first call to
+    /// [`tick`].
+    ///
+    /// [`Interval`]: struct.Interval.html
+    /// [`tick`]: #method.tick
+    pub fn every(period: Duration) -> Interval {
+        Interval { period, next: None }
+    }
+
+    /// Returns the period of the [`Interval`].
+    ///
+    /// [`Interval`]: struct.Interval.html
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns true if the [`Interval`] fires in the current tick.
+    ///
+    /// You should call it once per tick.
+    ///
+    /// [`Interval`]: struct.Interval.html
+    pub fn tick(&mut self, window: &Window) -> bool {
+        let now = window.clock().game_time();
+        let period = self.period;
+
+        if period == Duration::from_secs(0) {
+            return true;
+        }
+
+        let next = *self.next.get_or_insert(now + period);
+
+        if now < next {
+            return false;
+        }
+
+        let missed = (now - next).as_nanos() / period.as_nanos();
+        self.next = Some(next + period * (missed as u32 + 1));
+
+        true
+    }
+
+    /// Restarts the [`Interval`]. The next firing happens one period after
+    /// the next call to [`tick`].
+    ///
+    /// [`Interval`]: struct.Interval.html
+    /// [`tick`]: #method.tick
+    pub fn reset(&mut self) {
+        self.next = None;
+    }
+}
+
+/// A timer that tracks when an action can be performed again.
+///
+/// A [`Cooldown`] is ready until it is started. Then, it stays not ready
+/// until its duration of game time has elapsed.
+///
+/// [`Cooldown`]: struct.Cooldown.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cooldown {
+    duration: Duration,
+    ready_at: Option<Duration>,
+}
+
+impl Cooldown {
+    /// Creates a new [`Cooldown`] with the given duration.
+    ///
+    /// [`Cooldown`]: struct.Cooldown.html
+    pub fn new(duration: Duration) -> Cooldown {
+        Cooldown {
+            duration,
+            ready_at: None,
+        }
+    }
+
+    /// Returns the duration of the [`Cooldown`].
+    ///
+    /// [`Cooldown`]: struct.Cooldown.html
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Starts the [`Cooldown`], even if it is not ready yet.
+    ///
+    /// [`Cooldown`]: struct.Cooldown.html
+    pub fn start(&mut self, window: &Window) {
+        self.ready_at = Some(window.clock().game_time() + self.duration);
+    }
+
+    /// Returns true if the [`Cooldown`] is ready.
+    ///
+    /// [`Cooldown`]: struct.Cooldown.html
+    pub fn is_ready(&self, window: &Window) -> bool {
+        self.remaining(window) == Duration::from_secs(0)
+    }
+
+    /// Returns the game time left until the [`Cooldown`] is ready.
+    ///
+    /// [`Cooldown`]: struct.Cooldown.html
+    pub fn remaining(&self, window: &Window) -> Duration {
+        match self.ready_at {
+            Some(ready_at) => ready_at
+                .checked_sub(window.clock().game_time())
+                .unwrap_or_default(),
+            None => Duration::from_secs(0),
+        }
+    }
+}
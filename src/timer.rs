@@ -1,5 +1,8 @@
 use std::time;
 
+const FRAME_TIME_WINDOW: usize = 120;
+const SLOW_FRAMES_THRESHOLD: u32 = 3;
+
 /// The timer of your game state.
 ///
 /// A [`Timer`] is updated once per frame, and it ticks [`Game::TICKS_PER_SECOND`]
@@ -11,9 +14,21 @@ use std::time;
 pub struct Timer {
     target_ticks: u16,
     target_delta: time::Duration,
+    started: time::Instant,
     last_tick: time::Instant,
+    last_delta: time::Duration,
+    smoothed_delta: time::Duration,
+    updates: u64,
+    frame_times: Vec<time::Duration>,
+    frame_times_head: usize,
     accumulated_delta: time::Duration,
     has_ticked: bool,
+    frame_ticks: u16,
+    smoothed_ticks: f32,
+    is_dropping: bool,
+    lag_history: u8,
+    dropped_ticks: u64,
+    total_ticks: u64,
 }
 
 impl Timer {
@@ -24,28 +39,103 @@ impl Timer {
             _ => (0, ((1.0 / ticks_per_second as f64) * 1e9) as u32),
         };
 
+        let now = time::Instant::now();
+
         Timer {
             target_ticks: ticks_per_second,
             target_delta: time::Duration::new(target_seconds, target_nanos),
-            last_tick: time::Instant::now(),
+            started: now,
+            last_tick: now,
+            last_delta: time::Duration::from_secs(0),
+            smoothed_delta: time::Duration::from_secs(0),
+            updates: 0,
+            frame_times: Vec::with_capacity(FRAME_TIME_WINDOW),
+            frame_times_head: 0,
             accumulated_delta: time::Duration::from_secs(0),
             has_ticked: false,
+            frame_ticks: 0,
+            smoothed_ticks: 0.0,
+            is_dropping: false,
+            lag_history: 0,
+            dropped_ticks: 0,
+            total_ticks: 0,
         }
     }
 
     pub(crate) fn update(&mut self) {
-        let now = time::Instant::now();
+        let diff = self.record_frame(time::Instant::now());
+
+        self.accumulated_delta += diff;
+    }
+
+    // Updates the timer as if the given time had passed since the last update
+    pub(crate) fn advance(&mut self, elapsed: time::Duration) {
+        let diff = self.record_frame(self.last_tick + elapsed);
+
+        self.accumulated_delta += diff;
+    }
+
+    pub(crate) fn skip(&mut self) {
+        let _ = self.record_frame(time::Instant::now());
+    }
+
+    fn record_frame(&mut self, now: time::Instant) -> time::Duration {
+        const SMOOTHING: f64 = 0.1;
+
         let diff = now - self.last_tick;
 
+        // The first update starts the first frame, the second one ends it
+        self.smoothed_delta = match self.updates {
+            0 => time::Duration::from_secs(0),
+            1 => diff,
+            // An exponential moving average keeps the frame rate readable
+            _ => {
+                self.smoothed_delta.mul_f64(1.0 - SMOOTHING)
+                    + diff.mul_f64(SMOOTHING)
+            }
+        };
+
+        if self.updates == 0 {
+            self.started = now;
+        } else if self.frame_times.len() < FRAME_TIME_WINDOW {
+            self.frame_times.push(diff);
+        } else {
+            self.frame_times[self.frame_times_head] = diff;
+            self.frame_times_head =
+                (self.frame_times_head + 1) % FRAME_TIME_WINDOW;
+        }
+
+        // Each bit tells whether one of the last 8 frames fell behind, either
+        // dropping ticks or needing more of them than usual to catch up
+        let frame_ticks = f32::from(self.frame_ticks);
+        let is_lagging = self.is_dropping
+            || (self.updates > 1 && frame_ticks > self.smoothed_ticks + 1.0);
+
+        self.lag_history = (self.lag_history << 1) | is_lagging as u8;
+
+        self.smoothed_ticks = match self.updates {
+            0 => 0.0,
+            1 => frame_ticks,
+            _ => {
+                self.smoothed_ticks * (1.0 - SMOOTHING as f32)
+                    + frame_ticks * SMOOTHING as f32
+            }
+        };
+
+        self.updates += 1;
         self.last_tick = now;
-        self.accumulated_delta += diff;
+        self.last_delta = diff;
         self.has_ticked = false;
+        self.frame_ticks = 0;
+        self.is_dropping = false;
+
+        diff
     }
 
     pub(crate) fn tick(&mut self) -> bool {
         if self.accumulated_delta >= self.target_delta {
             self.accumulated_delta -= self.target_delta;
-            self.has_ticked = true;
+            self.count_tick();
 
             true
         } else {
@@ -53,6 +143,189 @@ impl Timer {
         }
     }
 
+    pub(crate) fn count_tick(&mut self) {
+        self.has_ticked = true;
+        self.frame_ticks = self.frame_ticks.saturating_add(1);
+        self.total_ticks += 1;
+    }
+
+    pub(crate) fn is_slow_tick(&self, slow_ticks_per_second: u16) -> bool {
+        if slow_ticks_per_second == 0 || self.target_ticks == 0 {
+            return false;
+        }
+
+        let interval = (f64::from(self.target_ticks)
+            / f64::from(slow_ticks_per_second))
+        .round()
+        .max(1.0) as u64;
+
+        self.total_ticks > 0 && self.total_ticks % interval == 0
+    }
+
+    pub(crate) fn drop_backlog(&mut self) -> u64 {
+        if self.accumulated_delta < self.target_delta {
+            return 0;
+        }
+
+        let target = self.target_delta.as_nanos();
+        let accumulated = self.accumulated_delta.as_nanos();
+        let dropped = (accumulated / target) as u64;
+
+        self.accumulated_delta =
+            time::Duration::from_nanos((accumulated % target) as u64);
+        self.dropped_ticks += dropped;
+        self.is_dropping = true;
+
+        dropped
+    }
+
+    /// Returns the total amount of ticks that have been dropped.
+    ///
+    /// When your game falls behind, it is updated up to
+    /// [`Game::MAX_TICKS_PER_FRAME`] times per frame to catch up. If that is
+    /// not enough, the remaining ticks are dropped and your game slows down
+    /// for a moment instead of falling further behind.
+    ///
+    /// [`Game::MAX_TICKS_PER_FRAME`]: trait.Game.html#associatedconstant.MAX_TICKS_PER_FRAME
+    pub fn dropped_ticks(&self) -> u64 {
+        self.dropped_ticks
+    }
+
+    /// Returns the total amount of ticks since the game started.
+    ///
+    /// It is increased right before every call to [`Game::update`].
+    ///
+    /// [`Game::update`]: trait.Game.html#method.update
+    pub fn ticks(&self) -> u64 {
+        self.total_ticks
+    }
+
+    /// Returns the duration of a tick, defined by
+    /// [`Game::TICKS_PER_SECOND`].
+    ///
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn tick_duration(&self) -> time::Duration {
+        self.target_delta
+    }
+
+    /// Returns the elapsed game time.
+    ///
+    /// It is derived from the [`ticks`] and the [`tick_duration`], not
+    /// from the wall clock. Therefore, it is deterministic and excludes pauses
+    /// and dropped ticks.
+    ///
+    /// [`ticks`]: #method.ticks
+    /// [`tick_duration`]: #method.tick_duration
+    pub fn total_time(&self) -> time::Duration {
+        game_time(self.target_delta, self.total_ticks)
+    }
+
+    /// Returns the wall time between the last two updates of the [`Timer`].
+    ///
+    /// In other words, it is the duration of the previous frame. Use it for
+    /// purely visual effects, like animating a shader uniform. Do not use it
+    /// to simulate your game; it is not deterministic.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn delta(&self) -> time::Duration {
+        self.last_delta
+    }
+
+    /// Returns the wall time since the game loop started until the last
+    /// update of the [`Timer`].
+    ///
+    /// Unlike [`total_time`], it includes pauses and dropped ticks.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`total_time`]: #method.total_time
+    pub fn elapsed(&self) -> time::Duration {
+        self.last_tick - self.started
+    }
+
+    /// Returns the amount of frames per second, smoothed over the last
+    /// frames.
+    ///
+    /// It is `0.0` until the first frame has been presented.
+    pub fn fps(&self) -> f32 {
+        let seconds = self.smoothed_delta.as_secs_f32();
+
+        if seconds > 0.0 {
+            1.0 / seconds
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns statistics of the frame times of the last 120 frames.
+    ///
+    /// Unlike [`delta`], these are stable enough to be displayed or to drive
+    /// adaptive quality settings. The [`Debug`] view shows the same
+    /// statistics.
+    ///
+    /// [`delta`]: #method.delta
+    /// [`Debug`]: struct.Debug.html
+    pub fn frame_time_stats(&self) -> FrameTimeStats {
+        let frames = self.frame_times.len();
+
+        if frames == 0 {
+            return FrameTimeStats::default();
+        }
+
+        let mut sorted = [time::Duration::from_secs(0); FRAME_TIME_WINDOW];
+        sorted[..frames].copy_from_slice(&self.frame_times);
+        sorted[..frames].sort_unstable();
+
+        let total: time::Duration = sorted[..frames].iter().sum();
+        let p95_rank = (frames * 95 + 99) / 100;
+
+        FrameTimeStats {
+            average: total / frames as u32,
+            p95: sorted[p95_rank.max(1) - 1],
+            worst: sorted[frames - 1],
+            last: self.last_delta,
+        }
+    }
+
+    /// Returns `true` if your game is struggling to keep up with
+    /// [`Game::TICKS_PER_SECOND`].
+    ///
+    /// A frame falls behind when it drops ticks (see [`dropped_ticks`]) or
+    /// when it needs more ticks to catch up than frames usually do. It is
+    /// `true` when at least 3 of the last 8 frames fell behind. Therefore, it
+    /// becomes `false` again at most 8 frames after performance recovers.
+    ///
+    /// Running many ticks per frame is not slow on its own. For instance,
+    /// a game with 240 [`Game::TICKS_PER_SECOND`] on a 60 Hz display runs
+    /// 4 ticks every frame, which is not considered slow.
+    ///
+    /// When it is `true`, consider skipping expensive visual effects in
+    /// [`Game::draw`] instead of letting the simulation fall behind.
+    ///
+    /// [`dropped_ticks`]: #method.dropped_ticks
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    /// [`Game::draw`]: trait.Game.html#tymethod.draw
+    pub fn is_running_slow(&self) -> bool {
+        self.lag_history.count_ones() >= SLOW_FRAMES_THRESHOLD
+    }
+
+    /// Returns the recommended amount of visual detail, in the `[0.0, 1.0]`
+    /// interval.
+    ///
+    /// It is the fraction of the last 8 frames that did not fall behind (see
+    /// [`is_running_slow`]). It stays at `1.0` while the game keeps up,
+    /// independently of the frame rate and [`Game::TICKS_PER_SECOND`], and
+    /// it goes down as more frames fall behind: `0.5` means that half of them
+    /// did.
+    ///
+    /// For instance, you could halve the amount of particles you draw when it
+    /// falls below `0.5`.
+    ///
+    /// [`is_running_slow`]: #method.is_running_slow
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn recommended_detail(&self) -> f32 {
+        1.0 - self.lag_history.count_ones() as f32 / 8.0
+    }
+
     /// Returns `true` if the [`Timer`] has ticked since its last update.
     ///
     /// This tells you whether your game has been updated or not during a frame.
@@ -66,19 +339,104 @@ impl Timer {
         self.has_ticked
     }
 
+    /// Returns the time of the last update of the [`Timer`], which happens
+    /// right before a frame starts.
+    ///
+    /// It uses the same monotonic clock as the time of input events. Thus, you
+    /// can use it to measure how long ago an [`input::Event`] happened.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`input::Event`]: input/enum.Event.html
+    pub fn last_update(&self) -> time::Instant {
+        self.last_tick
+    }
+
     /// Returns how close the next tick is.
     ///
-    /// The returned value is in the `[0.0, 1.0]` interval. You should use this
-    /// value in your [`Game::draw`] function to perform _graphics
-    /// interpolation_. You can read more about it in [this excellent article].
+    /// The returned value is guaranteed to be in the `[0.0, 1.0)` interval. It
+    /// represents the fraction of a tick that has elapsed since the last
+    /// update of your game. If [`Game::TICKS_PER_SECOND`] is 0, it is always
+    /// `0.0`.
+    ///
+    /// You should use this value in your [`Game::draw`] function to perform
+    /// _graphics interpolation_. Keep the state of your game before and after
+    /// the last update, and blend them when drawing:
+    ///
+    /// ```
+    /// # use coffee::graphics::{Point, Vector};
+    /// # use coffee::Timer;
+    /// struct Ball {
+    ///     previous_position: Point,
+    ///     position: Point,
+    ///     velocity: Vector,
+    /// }
+    ///
+    /// impl Ball {
+    ///     // Called in `Game::update`
+    ///     fn update(&mut self) {
+    ///         self.previous_position = self.position;
+    ///         self.position += self.velocity;
+    ///     }
+    ///
+    ///     // Called in `Game::draw`
+    ///     fn interpolated_position(&self, timer: &Timer) -> Point {
+    ///         let alpha = timer.next_tick_proximity();
+    ///
+    ///         self.previous_position
+    ///             + (self.position - self.previous_position) * alpha
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// You can read more about it in [this excellent article].
     ///
     /// [`Game::draw`]: trait.Game.html#tymethod.draw
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
     /// [this excellent article]: http://web.archive.org/web/20190506030345/https://gafferongames.com/post/fix_your_timestep/
     pub fn next_tick_proximity(&self) -> f32 {
-        let delta = self.accumulated_delta;
+        if self.target_ticks == 0 {
+            return 0.0;
+        }
+
+        let proximity = self.accumulated_delta.as_secs_f32()
+            / self.target_delta.as_secs_f32();
+
+        proximity.max(0.0).min(1.0 - std::f32::EPSILON)
+    }
+}
+
+/// Frame time statistics over a sliding window of frames.
+///
+/// You can obtain them with [`Timer::frame_time_stats`].
+///
+/// [`Timer::frame_time_stats`]: struct.Timer.html#method.frame_time_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameTimeStats {
+    /// The mean frame time.
+    pub average: time::Duration,
+
+    /// The 95th percentile of the frame times.
+    pub p95: time::Duration,
 
-        self.target_ticks as f32
-            * (delta.as_secs() as f32
-                + (delta.subsec_micros() as f32 / 1_000_000.0))
+    /// The longest frame time.
+    pub worst: time::Duration,
+
+    /// The frame time of the last frame.
+    pub last: time::Duration,
+}
+
+pub(crate) fn game_time(
+    tick_duration: time::Duration,
+    ticks: u64,
+) -> time::Duration {
+    if ticks == 0 {
+        return time::Duration::from_secs(0);
     }
+
+    let nanos = tick_duration.as_nanos() * u128::from(ticks);
+
+    time::Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
 }
@@ -54,8 +54,9 @@
 //! #         })
 //! #     }
 //! #
-//! #     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+//! #     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
 //! #         frame.clear(Color::BLACK);
+//! #         Ok(())
 //! #     }
 //! # }
 //! #
@@ -139,14 +140,18 @@
 //! [`core`]: core/index.html
 pub mod core;
 mod renderer;
+pub mod theme;
 pub mod widget;
 
 #[doc(no_inline)]
-pub use self::core::{Align, Justify};
+pub use self::core::{Align, Id, Justify};
 pub use renderer::{Configuration, Renderer};
+pub use theme::Theme;
 pub use widget::{
-    button, image, progress_bar, slider, Button, Checkbox, Image, ProgressBar,
-    Radio, Slider, Text,
+    button, grid, image, modal, panel, paragraph, progress_bar, slider,
+    spinner, stepper, text_input, toggle, tooltip, viewport, Button, Checkbox,
+    Image, Paragraph, ProgressBar, Radio, RadioGroup, Slider, Spinner, Stepper,
+    Text, TextInput, Toggle, Viewport,
 };
 
 /// A [`Column`] using the built-in [`Renderer`].
@@ -161,25 +166,67 @@ pub type Column<'a, Message> = widget::Column<'a, Message, Renderer>;
 /// [`Renderer`]: struct.Renderer.html
 pub type Row<'a, Message> = widget::Row<'a, Message, Renderer>;
 
+/// A [`Grid`] using the built-in [`Renderer`].
+///
+/// [`Grid`]: widget/grid/struct.Grid.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Grid<'a, Message> = widget::Grid<'a, Message, Renderer>;
+
+/// A [`Stack`] using the built-in [`Renderer`].
+///
+/// [`Stack`]: widget/struct.Stack.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Stack<'a, Message> = widget::Stack<'a, Message, Renderer>;
+
+/// A [`Modal`] using the built-in [`Renderer`].
+///
+/// [`Modal`]: widget/modal/struct.Modal.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Modal<'a, Message> = widget::Modal<'a, Message, Renderer>;
+
 /// A [`Panel`] using the built-in [`Renderer`].
 ///
 /// [`Panel`]: widget/panel/struct.Panel.html
 /// [`Renderer`]: struct.Renderer.html
 pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 
+/// A [`FloatingLayer`] using the built-in [`Renderer`].
+///
+/// [`FloatingLayer`]: widget/panel/struct.FloatingLayer.html
+/// [`Renderer`]: struct.Renderer.html
+pub type FloatingLayer<'a, Message> =
+    widget::FloatingLayer<'a, Message, Renderer>;
+
+/// A [`Tooltip`] using the built-in [`Renderer`].
+///
+/// [`Tooltip`]: widget/tooltip/struct.Tooltip.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Tooltip<'a, Message> = widget::Tooltip<'a, Message, Renderer>;
+
 /// An [`Element`] using the built-in [`Renderer`].
 ///
 /// [`Element`]: core/struct.Element.html
 /// [`Renderer`]: struct.Renderer.html
 pub type Element<'a, Message> = self::core::Element<'a, Message, Renderer>;
 
+use crate::bench;
 use crate::game::{self, Loop as _};
-use crate::graphics::{Point, Window, WindowSettings};
-use crate::input::{self, mouse, Input as _};
+use crate::graphics::{Point, Rectangle, Window, WindowSettings};
+use crate::input::{self, keyboard, mouse, Input as _};
 use crate::load::Task;
 use crate::ui::core::{Event, Interface, MouseCursor, Renderer as _};
-use crate::{Debug, Game, Result};
+use crate::{BenchReport, Debug, Game, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::convert::TryInto;
+use std::time::Instant;
+
+thread_local! {
+    // The bounds of the widgets with an `Id` in the last layout, which the
+    // runtime shares with `UserInterface::bounds`
+    static BOUNDS: RefCell<HashMap<Id, Rectangle<f32>>> =
+        RefCell::new(HashMap::new());
+}
 
 /// The user interface of your game.
 ///
@@ -202,15 +249,25 @@ pub trait UserInterface: Game {
     /// user interactions. For example:
     ///
     /// ```
+    /// #[derive(Debug, Clone)]
     /// enum Message {
     ///     ButtonPressed,
     ///     CheckboxToggled(bool),
     ///     SliderChanged(f32),
+    ///     NameChanged(String),
+    ///     ItemUsed(usize),
     ///     // ...
     /// }
     /// ```
     ///
+    /// Messages can carry any data, like the text of a [`TextInput`] or the
+    /// index of the item a [`Button`] belongs to. Widgets that produce the
+    /// same message many times, like a [`Button`], need it to implement
+    /// `Clone`.
+    ///
     /// [`react`]: #tymethod.react
+    /// [`TextInput`]: widget/text_input/struct.TextInput.html
+    /// [`Button`]: widget/button/struct.Button.html
     /// [`Message`]: #associatedtype.Message
     type Message;
 
@@ -252,6 +309,110 @@ pub trait UserInterface: Game {
         window: &Window,
     ) -> self::core::Element<'_, Self::Message, Self::Renderer>;
 
+    /// Returns true if the user interface should consume all the keyboard
+    /// input.
+    ///
+    /// While it returns true, key presses and text are only processed by the
+    /// user interface and never reach the [`Game::Input`]. Releases are still
+    /// delivered, so keys held before do not get stuck.
+    ///
+    /// Widgets can capture the keyboard input too. For instance, a focused
+    /// [`TextInput`] does.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+    /// [`TextInput`]: widget/text_input/struct.TextInput.html
+    fn captures_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the user interface should consume all the mouse input.
+    ///
+    /// Mouse button presses and wheel scrolls over a widget are always
+    /// consumed by the user interface. While this method returns true, they
+    /// are consumed everywhere, which is useful for modal menus.
+    ///
+    /// By default, it returns `false`.
+    fn captures_mouse(&self) -> bool {
+        false
+    }
+
+    /// Returns the [`Theme`] of the user interface.
+    ///
+    /// It is called on every frame, so you can switch themes at runtime by
+    /// returning a different one.
+    ///
+    /// By default, it returns `None` and the built-in [`Renderer`] uses its
+    /// spritesheet.
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    /// [`Renderer`]: struct.Renderer.html
+    fn theme(&self) -> Option<Theme> {
+        None
+    }
+
+    /// Returns the [`Id`] of the widget that should be focused, if any.
+    ///
+    /// It is called on every frame, right before [`layout`]. Return an [`Id`]
+    /// once to move the focus to the widget with that [`Id`] in the new
+    /// layout; for instance, to focus the first item of a menu when it opens.
+    ///
+    /// Besides, users can move the focus between widgets with `Tab` and
+    /// `Shift+Tab`, or with the d-pad and the left stick of a gamepad. While a
+    /// widget is focused, the user interface captures the keyboard input.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Id`]: core/struct.Id.html
+    /// [`layout`]: #tymethod.layout
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::Id;
+    ///
+    /// struct Menu {
+    ///     focus: Option<Id>,
+    /// }
+    ///
+    /// impl Menu {
+    ///     fn open(&mut self) {
+    ///         self.focus = Some(Id::new("resume"));
+    ///     }
+    ///
+    ///     // In `UserInterface::requested_focus`
+    ///     fn requested_focus(&mut self) -> Option<Id> {
+    ///         self.focus.take()
+    ///     }
+    /// }
+    /// ```
+    fn requested_focus(&mut self) -> Option<Id> {
+        None
+    }
+
+    /// Returns the bounds of the widget with the given [`Id`] in the window,
+    /// as laid out in the last frame.
+    ///
+    /// It returns `None` if no widget had that [`Id`]. Set the [`Id`] of a
+    /// widget using its `id` method, or [`Element::id`] for widgets that do
+    /// not have one. This is useful to point at a widget from your game; for
+    /// instance, to highlight a button during a tutorial.
+    ///
+    /// The user interface is laid out after [`Game::draw`] is called.
+    /// Therefore, in [`Game::draw`] you get the bounds of the previous frame,
+    /// while in [`react`] you get the ones of the current frame.
+    ///
+    /// You should not need to implement this method.
+    ///
+    /// [`Id`]: core/struct.Id.html
+    /// [`Element::id`]: core/struct.Element.html#method.id
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    /// [`react`]: #tymethod.react
+    fn bounds(&self, id: Id) -> Option<Rectangle<f32>> {
+        BOUNDS.with(|bounds| bounds.borrow().get(&id).cloned())
+    }
+
     /// Builds the renderer configuration for the user interface.
     ///
     /// By default, it returns `Default::default()`.
@@ -268,11 +429,51 @@ pub trait UserInterface: Game {
     /// [`UserInterface`]: trait.UserInterface.html
     /// [`Game::run`]: ../trait.Game.html#method.run
     fn run(window_settings: WindowSettings) -> Result<()>
+    where
+        Self: 'static + Sized,
+    {
+        Loop::<Self>::run(window_settings).map(|_| ())
+    }
+
+    /// Runs the [`Game`] with a user interface and returns it once the game
+    /// loop ends.
+    ///
+    /// Call this method instead of [`Game::run_returning`] once you have
+    /// implemented the [`UserInterface`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`UserInterface`]: trait.UserInterface.html
+    /// [`Game::run_returning`]: ../trait.Game.html#method.run_returning
+    fn run_returning(window_settings: WindowSettings) -> Result<Self>
     where
         Self: 'static + Sized,
     {
         Loop::<Self>::run(window_settings)
     }
+
+    /// Runs the [`Game`] with a user interface until the given amount of
+    /// frames has been rendered, and returns a [`BenchReport`].
+    ///
+    /// Call this method instead of [`Game::run_bench`] once you have
+    /// implemented the [`UserInterface`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`BenchReport`]: ../struct.BenchReport.html
+    /// [`Game::run_bench`]: ../trait.Game.html#method.run_bench
+    /// [`UserInterface`]: trait.UserInterface.html
+    fn run_bench(
+        window_settings: WindowSettings,
+        frames: u32,
+    ) -> Result<BenchReport>
+    where
+        Self: 'static + Sized,
+    {
+        let mut recorder = bench::Recorder::new(frames);
+
+        Loop::<Self>::run_with(window_settings, Some(&mut recorder))?;
+
+        Ok(recorder.into_report())
+    }
 }
 
 struct Loop<UI: UserInterface> {
@@ -282,6 +483,7 @@ struct Loop<UI: UserInterface> {
     cache: Option<core::Cache>,
     cursor_position: Point,
     events: Vec<Event>,
+    is_keyboard_captured: bool,
 }
 
 impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
@@ -296,6 +498,7 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             cache: Some(cache),
             cursor_position: Point::new(0.0, 0.0),
             events: Vec::new(),
+            is_keyboard_captured: false,
         }
     }
 
@@ -303,11 +506,52 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         UI::Renderer::load(UI::configuration())
     }
 
-    fn on_input(&mut self, input: &mut UI::Input, event: input::Event) {
-        input.update(event);
+    fn on_input(
+        &mut self,
+        ui: &mut UI,
+        input: &mut UI::Input,
+        event: input::Event,
+    ) {
+        let is_consumed = match event {
+            input::Event::Mouse {
+                event:
+                    mouse::Event::Input {
+                        state: input::ButtonState::Pressed,
+                        ..
+                    },
+                ..
+            }
+            | input::Event::Mouse {
+                event: mouse::Event::WheelScrolled { .. },
+                ..
+            } => {
+                self.mouse_cursor != MouseCursor::OutOfBounds
+                    || ui.captures_mouse()
+            }
+            input::Event::Keyboard {
+                event:
+                    keyboard::Event::Input {
+                        state: input::ButtonState::Pressed,
+                        ..
+                    },
+                ..
+            }
+            | input::Event::Keyboard {
+                event: keyboard::Event::TextEntered { .. },
+                ..
+            } => self.is_keyboard_captured || ui.captures_keyboard(),
+            _ => false,
+        };
+
+        if !is_consumed {
+            input.update(event);
+        }
 
         match event {
-            input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+            input::Event::Mouse {
+                event: mouse::Event::CursorMoved { x, y },
+                ..
+            } => {
                 self.cursor_position = Point::new(x, y);
             }
             _ => {}
@@ -324,14 +568,22 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         input: &mut UI::Input,
         window: &mut Window,
         debug: &mut Debug,
-    ) {
+    ) -> Result<()> {
         debug.ui_started();
+        self.renderer.set_theme(ui.theme());
+
+        let requested_focus = ui.requested_focus();
+
         let mut interface = Interface::compute_with_cache(
             ui.layout(window),
             &self.renderer,
             self.cache.take().unwrap(),
         );
 
+        if let Some(id) = requested_focus {
+            interface.focus(id);
+        }
+
         let cursor_position = self.cursor_position;
         let messages = &mut self.messages;
 
@@ -339,19 +591,31 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             interface.on_event(event, cursor_position, messages)
         });
 
+        interface.tick(cursor_position, messages);
+
+        BOUNDS
+            .with(|bounds| bounds.borrow_mut().clone_from(interface.bounds()));
+
         let new_cursor = interface.draw(
             &mut self.renderer,
-            &mut window.frame(),
+            &mut window.frame()?,
             cursor_position,
         );
 
+        self.is_keyboard_captured = interface.captures_keyboard();
         self.cache = Some(interface.cache());
 
         if new_cursor != self.mouse_cursor {
             if new_cursor == MouseCursor::OutOfBounds {
-                input.update(input::Event::Mouse(mouse::Event::CursorReturned));
+                input.update(input::Event::Mouse {
+                    event: mouse::Event::CursorReturned,
+                    time: Instant::now(),
+                });
             } else if self.mouse_cursor == MouseCursor::OutOfBounds {
-                input.update(input::Event::Mouse(mouse::Event::CursorTaken));
+                input.update(input::Event::Mouse {
+                    event: mouse::Event::CursorTaken,
+                    time: Instant::now(),
+                });
             }
 
             self.mouse_cursor = new_cursor;
@@ -368,5 +632,7 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             ui.react(message, window);
         }
         debug.ui_finished();
+
+        Ok(())
     }
 }
@@ -3,10 +3,48 @@
 //!   * The [`Widget`] trait allows you to build custom widgets.
 //!   * The [`Renderer`] trait can be used to build your own renderer.
 //!
+//! # Custom widgets
+//! A custom widget is any type that implements [`Widget`]. Implement it for
+//! the built-in [`ui::Renderer`] if you only need your widget to work with it,
+//! or for any `Renderer` that implements a trait of your own if you want it to
+//! be drawn by different renderers, like the built-in widgets do.
+//!
+//! The runtime calls the methods of a [`Widget`] on every frame:
+//!
+//!   1. [`Widget::node`] describes its layout requirements. Use [`Node::new`]
+//!   with a [`Style`] for a fixed or flexible size, or [`Node::with_measure`]
+//!   to compute its size given the space available.
+//!   2. [`Widget::on_event`] receives the user interactions. The [`Layout`] it
+//!   receives contains the bounds computed for the widget, which you can use
+//!   to hit-test the cursor position and decide which messages to produce.
+//!   3. [`Widget::draw`] draws the widget inside the bounds of its [`Layout`]
+//!   and returns the [`MouseCursor`] it wants. The built-in [`ui::Renderer`]
+//!   has methods to draw shapes, quads, text, images, and clipped groups that
+//!   are drawn consistently with the built-in widgets.
+//!
+//! Widgets are created again on every frame, so any state that needs to
+//! persist between frames, like whether the widget is being dragged, must
+//! live in your game. The convention is to keep it in a `State` type that the
+//! widget borrows mutably, like [`button::State`].
+//!
+//! The `examples` directory contains a color picker built this way.
+//!
 //! [`Widget`]: trait.Widget.html
 //! [`Renderer`]: trait.Renderer.html
+//! [`ui::Renderer`]: ../struct.Renderer.html
+//! [`Widget::node`]: trait.Widget.html#tymethod.node
+//! [`Node::new`]: struct.Node.html#method.new
+//! [`Style`]: struct.Style.html
+//! [`Node::with_measure`]: struct.Node.html#method.with_measure
+//! [`Widget::on_event`]: trait.Widget.html#method.on_event
+//! [`Layout`]: struct.Layout.html
+//! [`Widget::draw`]: trait.Widget.html#tymethod.draw
+//! [`MouseCursor`]: enum.MouseCursor.html
+//! [`button::State`]: ../widget/button/struct.State.html
+mod animation;
 mod element;
 mod event;
+mod focus;
 mod hasher;
 mod interface;
 mod layout;
@@ -19,8 +57,11 @@ mod widget;
 #[doc(no_inline)]
 pub use stretch::{geometry::Size, number::Number};
 
+pub use animation::{Animated, Easing, Interpolate, Transition};
 pub use element::Element;
 pub use event::Event;
+pub(crate) use focus::Navigation;
+pub use focus::{Action, Focus, Id};
 pub use hasher::Hasher;
 pub(crate) use interface::{Cache, Interface};
 pub use layout::Layout;
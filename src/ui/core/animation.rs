@@ -0,0 +1,255 @@
+use std::time::{Duration, Instant};
+
+use crate::graphics::{Color, Point, Vector};
+
+/// A value that moves smoothly towards a target.
+///
+/// Changing the target of an [`Animated`] value starts a [`Transition`] from
+/// its current value, even if a previous one has not finished yet. The
+/// current value is computed using the time of the frame being drawn, so
+/// animations do not depend on the update rate of your game.
+///
+/// Store an [`Animated`] value in the local state of your custom widgets to
+/// animate colors, offsets, scales, or anything else that implements
+/// [`Interpolate`].
+///
+/// [`Animated`]: struct.Animated.html
+/// [`Transition`]: struct.Transition.html
+/// [`Interpolate`]: trait.Interpolate.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::core::{Animated, Easing, Transition};
+/// use std::time::Duration;
+///
+/// let mut offset = Animated::new(
+///     0.0,
+///     Transition::new(Duration::from_millis(200), Easing::EaseOut),
+/// );
+///
+/// offset.set_target(100.0);
+///
+/// // Later, while drawing...
+/// let x = offset.value();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animated<T> {
+    start: T,
+    target: T,
+    started_at: Option<Instant>,
+    transition: Transition,
+}
+
+impl<T> Animated<T>
+where
+    T: Interpolate,
+{
+    /// Creates a new [`Animated`] value resting at the given value, which
+    /// will move using the given [`Transition`].
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn new(value: T, transition: Transition) -> Self {
+        Animated {
+            start: value,
+            target: value,
+            started_at: None,
+            transition,
+        }
+    }
+
+    /// Returns the current value of the [`Animated`] value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn value(&self) -> T {
+        self.value_at(Instant::now())
+    }
+
+    /// Returns the value of the [`Animated`] value at the given instant.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn value_at(&self, instant: Instant) -> T {
+        match self.started_at {
+            Some(started_at) => {
+                let progress = self.transition.progress(started_at, instant);
+
+                self.start.interpolate(self.target, progress)
+            }
+            None => self.target,
+        }
+    }
+
+    /// Returns the target of the [`Animated`] value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// Returns whether the [`Animated`] value is still moving towards its
+    /// target or not.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn is_animating(&self) -> bool {
+        self.started_at.map_or(false, |started_at| {
+            started_at.elapsed() < self.transition.duration
+        })
+    }
+
+    /// Sets the target of the [`Animated`] value.
+    ///
+    /// If the target changes, a new [`Transition`] starts from the current
+    /// value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn set_target(&mut self, target: T) {
+        if target == self.target {
+            return;
+        }
+
+        let now = Instant::now();
+
+        self.start = self.value_at(now);
+        self.target = target;
+        self.started_at = Some(now);
+    }
+
+    /// Sets the value of the [`Animated`] value immediately, stopping any
+    /// [`Transition`] in progress.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn set(&mut self, value: T) {
+        self.start = value;
+        self.target = value;
+        self.started_at = None;
+    }
+
+    /// Sets the [`Transition`] used by the next changes of the [`Animated`]
+    /// value.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Animated`]: struct.Animated.html
+    pub fn set_transition(&mut self, transition: Transition) {
+        self.transition = transition;
+    }
+}
+
+/// How an [`Animated`] value moves towards its target.
+///
+/// [`Animated`]: struct.Animated.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    /// How long it takes to reach the target
+    pub duration: Duration,
+
+    /// The [`Easing`] of the movement
+    ///
+    /// [`Easing`]: enum.Easing.html
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new [`Transition`] with the given duration and [`Easing`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Easing`]: enum.Easing.html
+    pub fn new(duration: Duration, easing: Easing) -> Transition {
+        Transition { duration, easing }
+    }
+
+    // The eased progress of a transition started at the given instant
+    fn progress(&self, started_at: Instant, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(started_at);
+
+        if elapsed >= self.duration {
+            return 1.0;
+        }
+
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+
+        self.easing.apply(t)
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Transition {
+        Transition::new(Duration::from_millis(120), Easing::EaseOut)
+    }
+}
+
+/// The rate of change of a [`Transition`] over time.
+///
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// A constant rate of change
+    Linear,
+
+    /// Fast at the beginning and slow at the end
+    EaseOut,
+
+    /// A damped spring, which overshoots the target slightly before settling
+    Spring,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the linear progress of a [`Transition`],
+    /// from `0.0` to `1.0`.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.max(0.0).min(1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::Spring => {
+                1.0 - (-6.0 * t).exp() * (3.0 * std::f32::consts::PI * t).cos()
+            }
+        }
+    }
+}
+
+/// A value that can be interpolated, and therefore [`Animated`].
+///
+/// [`Animated`]: struct.Animated.html
+pub trait Interpolate: Copy + PartialEq {
+    /// Interpolates between two values.
+    ///
+    /// The progress is `0.0` at `self` and `1.0` at `target`. It may be
+    /// slightly out of this range for easings that overshoot.
+    fn interpolate(self, target: Self, progress: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, target: f32, progress: f32) -> f32 {
+        self + (target - self) * progress
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, target: Color, progress: f32) -> Color {
+        Color {
+            r: self.r.interpolate(target.r, progress),
+            g: self.g.interpolate(target.g, progress),
+            b: self.b.interpolate(target.b, progress),
+            a: self.a.interpolate(target.a, progress),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(self, target: Point, progress: f32) -> Point {
+        self + (target - self) * progress
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(self, target: Vector, progress: f32) -> Vector {
+        self + (target - self) * progress
+    }
+}
@@ -1,7 +1,9 @@
 use stretch::{geometry, result};
 
-use crate::graphics::{Color, Point};
-use crate::ui::core::{self, Event, Hasher, Layout, MouseCursor, Node, Widget};
+use crate::graphics::{Color, Point, Rectangle};
+use crate::ui::core::{
+    self, Event, Focus, Hasher, Id, Layout, MouseCursor, Node, Widget,
+};
 
 /// A generic [`Widget`].
 ///
@@ -134,7 +136,7 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
     /// these modules to compose them together freely.
     pub fn map<F, B>(self, f: F) -> Element<'a, B, Renderer>
     where
-        Message: 'static + Copy,
+        Message: 'static,
         Renderer: 'a,
         B: 'static,
         F: 'static + Fn(Message) -> B,
@@ -161,6 +163,68 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
         }
     }
 
+    /// Sets the [`Id`] of the [`Element`], allowing you to find where it is
+    /// laid out with [`UserInterface::bounds`].
+    ///
+    /// This is useful for widgets that do not have an [`Id`] on their own,
+    /// like [`Text`] or a [`Column`]. Do not use the same [`Id`] for the
+    /// [`Element`] and the widget it contains.
+    ///
+    /// [`Id`]: struct.Id.html
+    /// [`Element`]: struct.Element.html
+    /// [`UserInterface::bounds`]: ../trait.UserInterface.html#method.bounds
+    /// [`Text`]: ../widget/text/struct.Text.html
+    /// [`Column`]: ../widget/struct.Column.html
+    pub fn id(self, id: Id) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Identified { element: self, id }),
+        }
+    }
+
+    /// Lays out the [`Element`] on its own and returns the bounds of the
+    /// widget with the given [`Id`], if any.
+    ///
+    /// It allows you to test your layouts without running a game. Keep in
+    /// mind that the root of a user interface normally fills the window, so
+    /// you may need to set its size explicitly.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Id`]: struct.Id.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::core::{Element, Id};
+    /// use coffee::ui::{Column, Renderer};
+    ///
+    /// # fn test(renderer: &Renderer, layout: Element<(), Renderer>) {
+    /// let menu = Column::new()
+    ///     .width(800)
+    ///     .push(layout.id(Id::new("play")));
+    ///
+    /// let bounds = Element::from(menu).bounds(renderer, Id::new("play"));
+    ///
+    /// assert_eq!(bounds.map(|bounds| bounds.x), Some(0.0));
+    /// # }
+    /// ```
+    pub fn bounds(
+        &mut self,
+        renderer: &Renderer,
+        id: Id,
+    ) -> Option<Rectangle<f32>> {
+        let layout = self.compute_layout(renderer);
+        let mut focus = Focus::new(None);
+
+        self.widget
+            .focus(Layout::new(&layout, Point::new(0.0, 0.0)), &mut focus);
+
+        focus.into_recorded().get(&id).cloned()
+    }
+
     pub(crate) fn compute_layout(&self, renderer: &Renderer) -> result::Layout {
         let node = self.widget.node(renderer);
 
@@ -198,10 +262,7 @@ impl<'a, A, B, Renderer> Map<'a, A, B, Renderer> {
     }
 }
 
-impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer>
-where
-    A: Copy,
-{
+impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer> {
     fn node(&self, renderer: &Renderer) -> Node {
         self.widget.node(renderer)
     }
@@ -223,8 +284,7 @@ where
         );
 
         original_messages
-            .iter()
-            .cloned()
+            .into_iter()
             .for_each(|message| messages.push((self.mapper)(message)));
     }
 
@@ -240,6 +300,30 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.widget.hash(state);
     }
+
+    fn captures_keyboard(&self) -> bool {
+        self.widget.captures_keyboard()
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.widget.focus(layout, focus)
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<B>,
+    ) {
+        let mut original_messages = Vec::new();
+
+        self.widget
+            .tick(layout, cursor_position, &mut original_messages);
+
+        original_messages
+            .into_iter()
+            .for_each(|message| messages.push((self.mapper)(message)));
+    }
 }
 
 struct Explain<'a, Message, Renderer> {
@@ -296,4 +380,89 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.element.widget.hash(state);
     }
+
+    fn captures_keyboard(&self) -> bool {
+        self.element.widget.captures_keyboard()
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.element.widget.focus(layout, focus)
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element.widget.tick(layout, cursor_position, messages)
+    }
+}
+
+struct Identified<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    id: Id,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for Identified<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Identified")
+            .field("element", &self.element)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Identified<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.element.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.element.widget.captures_keyboard()
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        focus.record(self.id, layout.bounds());
+
+        self.element.widget.focus(layout, focus)
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element.widget.tick(layout, cursor_position, messages)
+    }
 }
@@ -26,10 +26,10 @@ pub enum Event {
 impl Event {
     pub(crate) fn from_input(event: input::Event) -> Option<Event> {
         match event {
-            input::Event::Keyboard(keyboard_event) => {
-                Some(Event::Keyboard(keyboard_event))
+            input::Event::Keyboard { event, .. } => {
+                Some(Event::Keyboard(event))
             }
-            input::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
+            input::Event::Mouse { event, .. } => Some(Event::Mouse(event)),
             input::Event::Gamepad { id, event, .. } => {
                 Some(Event::Gamepad { id, event })
             }
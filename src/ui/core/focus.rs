@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::graphics::Rectangle;
+use crate::input::{gamepad, keyboard, ButtonState};
+use crate::ui::core::Event;
+
+/// The identifier of a widget.
+///
+/// You can use it to focus a widget programmatically with
+/// [`UserInterface::requested_focus`], or to find where a widget was laid out
+/// with [`UserInterface::bounds`].
+///
+/// An [`Id`] is just a hash, so it is cheap to create and compare every frame.
+/// The widgets of a user interface should have different ids; in debug builds,
+/// using the same [`Id`] twice in a layout causes a panic.
+///
+/// [`UserInterface::requested_focus`]: ../trait.UserInterface.html#method.requested_focus
+/// [`UserInterface::bounds`]: ../trait.UserInterface.html#method.bounds
+/// [`Id`]: struct.Id.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::core::Id;
+///
+/// let resume = Id::new("resume");
+///
+/// assert_eq!(resume, Id::new("resume"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a new [`Id`] from a hashable value.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn new<T: Hash>(value: T) -> Id {
+        let mut hasher = twox_hash::XxHash::default();
+        value.hash(&mut hasher);
+
+        Id(hasher.finish())
+    }
+}
+
+/// A traversal of the focusable widgets of a user interface.
+///
+/// Focusable widgets register themselves in layout order when
+/// [`Widget::focus`] is called, and learn whether they are focused or not.
+/// The traversal also records the bounds of every widget with an [`Id`].
+///
+/// [`Widget::focus`]: trait.Widget.html#method.focus
+/// [`Id`]: struct.Id.html
+#[derive(Debug)]
+pub struct Focus {
+    target: Option<usize>,
+    is_claimed: bool,
+    ids: Vec<Option<Id>>,
+    bounds: Option<Rectangle<f32>>,
+    recorded: HashMap<Id, Rectangle<f32>>,
+}
+
+impl Focus {
+    pub(crate) fn new(target: Option<usize>) -> Focus {
+        Focus {
+            target,
+            is_claimed: false,
+            ids: Vec::new(),
+            bounds: None,
+            recorded: HashMap::new(),
+        }
+    }
+
+    /// Registers a focusable widget and returns whether it is focused or not.
+    ///
+    /// It receives:
+    ///   * the [`Id`] of the widget, if it has one
+    ///   * the bounds of the widget
+    ///   * whether the widget has focused itself, like a [`TextInput`] that
+    ///   has just been clicked
+    ///
+    /// Disabled widgets should not register themselves, so they are skipped
+    /// when navigating. Use [`skip`] for them instead.
+    ///
+    /// [`Id`]: struct.Id.html
+    /// [`TextInput`]: ../widget/text_input/struct.TextInput.html
+    /// [`skip`]: #method.skip
+    pub fn register(
+        &mut self,
+        id: Option<Id>,
+        bounds: Rectangle<f32>,
+        claims_focus: bool,
+    ) -> bool {
+        let index = self.ids.len();
+
+        if self.target.is_none() && claims_focus {
+            self.target = Some(index);
+            self.is_claimed = true;
+        }
+
+        let is_focused = self.target == Some(index);
+
+        if is_focused {
+            self.bounds = Some(bounds);
+        }
+
+        self.ids.push(id);
+        self.skip(id, bounds);
+
+        is_focused
+    }
+
+    /// Records the bounds of a focusable widget that cannot be focused right
+    /// now, like a disabled button, so it can still be found by its [`Id`].
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn skip(&mut self, id: Option<Id>, bounds: Rectangle<f32>) {
+        if let Some(id) = id {
+            self.record(id, bounds);
+        }
+    }
+
+    /// Records the bounds of the widget with the given [`Id`].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, it panics if the [`Id`] has already been recorded
+    /// during the traversal.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn record(&mut self, id: Id, bounds: Rectangle<f32>) {
+        let previous = self.recorded.insert(id, bounds);
+
+        debug_assert!(
+            previous.is_none(),
+            "{:?} is used by more than one widget in the same layout",
+            id
+        );
+    }
+
+    pub(crate) fn target(&self) -> Option<usize> {
+        self.target
+    }
+
+    pub(crate) fn is_claimed(&self) -> bool {
+        self.is_claimed
+    }
+
+    pub(crate) fn ids(&self) -> &[Option<Id>] {
+        &self.ids
+    }
+
+    pub(crate) fn bounds(&self) -> Option<Rectangle<f32>> {
+        self.bounds
+    }
+
+    pub(crate) fn into_recorded(self) -> HashMap<Id, Rectangle<f32>> {
+        self.recorded
+    }
+}
+
+/// An action performed on the focused widget.
+///
+/// Focusable widgets can use [`Action::from_event`] in [`Widget::on_event`]
+/// to react to the keyboard and gamepads consistently.
+///
+/// [`Action::from_event`]: #method.from_event
+/// [`Widget::on_event`]: trait.Widget.html#method.on_event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Activates the widget, like pressing a button.
+    ///
+    /// Produced by `Enter`, `Space`, and the south button of a gamepad.
+    Activate,
+
+    /// Decreases the value of the widget.
+    ///
+    /// Produced by the `Left` arrow and the left button of a d-pad.
+    Decrease,
+
+    /// Increases the value of the widget.
+    ///
+    /// Produced by the `Right` arrow and the right button of a d-pad.
+    Increase,
+}
+
+impl Action {
+    /// Returns the [`Action`] produced by an [`Event`], if any.
+    ///
+    /// [`Action`]: enum.Action.html
+    /// [`Event`]: enum.Event.html
+    pub fn from_event(event: &Event) -> Option<Action> {
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: Some(key_code),
+                ..
+            }) => match key_code {
+                keyboard::KeyCode::Return
+                | keyboard::KeyCode::NumpadEnter
+                | keyboard::KeyCode::Space => Some(Action::Activate),
+                keyboard::KeyCode::Left => Some(Action::Decrease),
+                keyboard::KeyCode::Right => Some(Action::Increase),
+                _ => None,
+            },
+            Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(button),
+                ..
+            } => match button {
+                gamepad::Button::South => Some(Action::Activate),
+                gamepad::Button::DPadLeft => Some(Action::Decrease),
+                gamepad::Button::DPadRight => Some(Action::Increase),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+// A request to move the focus, produced by navigation events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Navigation {
+    Next,
+    Previous,
+}
+
+impl Navigation {
+    // Stick positions past this threshold move the focus once
+    const STICK_THRESHOLD: f32 = 0.5;
+
+    pub(crate) fn from_event(
+        event: &Event,
+        is_stick_tilted: &mut bool,
+    ) -> Option<Navigation> {
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: Some(keyboard::KeyCode::Tab),
+                modifiers,
+                ..
+            }) => Some(if modifiers.shift {
+                Navigation::Previous
+            } else {
+                Navigation::Next
+            }),
+            Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(button),
+                ..
+            } => match button {
+                gamepad::Button::DPadDown => Some(Navigation::Next),
+                gamepad::Button::DPadUp => Some(Navigation::Previous),
+                _ => None,
+            },
+            Event::Gamepad {
+                event:
+                    gamepad::Event::AxisChanged(gamepad::Axis::LeftStickY, value),
+                ..
+            } => {
+                if value.abs() < Self::STICK_THRESHOLD {
+                    *is_stick_tilted = false;
+                    None
+                } else if *is_stick_tilted {
+                    None
+                } else {
+                    *is_stick_tilted = true;
+
+                    // The Y axis of a stick points up
+                    Some(if *value > 0.0 {
+                        Navigation::Previous
+                    } else {
+                        Navigation::Next
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,18 +1,35 @@
+use std::collections::HashMap;
 use std::hash::Hasher;
 use stretch::result;
 
-use crate::graphics::{Frame, Point};
-use crate::ui::core::{self, Element, Event, Layout, MouseCursor};
+use crate::graphics::{Frame, Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    self, Element, Event, Focus, Id, Layout, MouseCursor, Navigation,
+};
 
 pub struct Interface<'a, Message, Renderer> {
     hash: u64,
     root: Element<'a, Message, Renderer>,
     layout: result::Layout,
+    focus: FocusState,
+    focus_count: usize,
+    focus_bounds: Option<Rectangle<f32>>,
+    bounds: HashMap<Id, Rectangle<f32>>,
 }
 
 pub struct Cache {
     hash: u64,
     layout: result::Layout,
+    focus: FocusState,
+}
+
+// The focus is kept between frames, as widgets are rebuilt every frame
+#[derive(Debug, Clone, Copy, Default)]
+struct FocusState {
+    index: Option<usize>,
+    id: Option<Id>,
+    is_stick_tilted: bool,
 }
 
 impl<'a, Message, Renderer> Interface<'a, Message, Renderer>
@@ -29,7 +46,7 @@ where
         let hash = hasher.finish();
         let layout = root.compute_layout(renderer);
 
-        Interface { hash, root, layout }
+        Self::new(hash, root, layout, FocusState::default())
     }
 
     pub fn compute_with_cache(
@@ -48,7 +65,27 @@ where
             root.compute_layout(renderer)
         };
 
-        Interface { hash, root, layout }
+        Self::new(hash, root, layout, cache.focus)
+    }
+
+    fn new(
+        hash: u64,
+        root: Element<'a, Message, Renderer>,
+        layout: result::Layout,
+        focus: FocusState,
+    ) -> Interface<'a, Message, Renderer> {
+        let mut interface = Interface {
+            hash,
+            root,
+            layout,
+            focus,
+            focus_count: 0,
+            focus_bounds: None,
+            bounds: HashMap::new(),
+        };
+
+        interface.update_focus();
+        interface
     }
 
     pub fn on_event(
@@ -57,6 +94,13 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if let Some(navigation) =
+            Navigation::from_event(&event, &mut self.focus.is_stick_tilted)
+        {
+            self.navigate(navigation);
+            return;
+        }
+
         let Interface { root, layout, .. } = self;
 
         root.widget.on_event(
@@ -65,6 +109,34 @@ where
             cursor_position,
             messages,
         );
+
+        if let Event::Mouse(mouse::Event::Input {
+            state: ButtonState::Pressed,
+            ..
+        }) = event
+        {
+            // Using the mouse clears the focus, unless a widget claims it
+            self.focus.index = None;
+            self.focus.id = None;
+            self.update_focus();
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let Interface { root, layout, .. } = self;
+
+        root.widget
+            .tick(Self::layout(layout), cursor_position, messages);
+    }
+
+    pub fn focus(&mut self, id: Id) {
+        self.focus.index = None;
+        self.focus.id = Some(id);
+        self.update_focus();
     }
 
     pub fn draw(
@@ -79,16 +151,91 @@ where
             root.widget
                 .draw(renderer, Self::layout(layout), cursor_position);
 
+        if let Some(bounds) = self.focus_bounds {
+            renderer.draw_focus(bounds);
+        }
+
         renderer.flush(frame);
 
         cursor
     }
 
+    pub fn bounds(&self) -> &HashMap<Id, Rectangle<f32>> {
+        &self.bounds
+    }
+
+    pub fn captures_keyboard(&self) -> bool {
+        self.root.widget.captures_keyboard() || self.focus.index.is_some()
+    }
+
     pub fn cache(self) -> Cache {
         Cache {
             hash: self.hash,
             layout: self.layout,
+            focus: self.focus,
+        }
+    }
+
+    fn navigate(&mut self, navigation: Navigation) {
+        let count = self.focus_count;
+
+        self.focus.index = if count == 0 {
+            None
+        } else {
+            Some(match (navigation, self.focus.index) {
+                (Navigation::Next, Some(index)) => (index + 1) % count,
+                (Navigation::Next, None) => 0,
+                (Navigation::Previous, Some(index)) => {
+                    (index + count - 1) % count
+                }
+                (Navigation::Previous, None) => count - 1,
+            })
+        };
+
+        self.focus.id = None;
+        self.update_focus();
+    }
+
+    // Finds the focused widget again, as the widget tree may have changed
+    fn update_focus(&mut self) {
+        let mut focus = self.traverse_focus(self.focus.index);
+        let count = focus.ids().len();
+
+        let position = self.focus.id.and_then(|id| {
+            focus.ids().iter().position(|other| *other == Some(id))
+        });
+
+        let index = match position {
+            Some(index) => Some(index),
+            None if focus.is_claimed() => focus.target(),
+            // The focused widget has disappeared, so we focus the widget that
+            // took its place, if any
+            None if self.focus.index.is_some() && count > 0 => {
+                self.focus.index.map(|index| index.min(count - 1))
+            }
+            None => None,
+        };
+
+        if index != focus.target() {
+            focus = self.traverse_focus(index);
         }
+
+        self.focus.index = focus.target();
+        self.focus.id = focus
+            .target()
+            .and_then(|index| focus.ids().get(index).cloned().flatten());
+        self.focus_count = focus.ids().len();
+        self.focus_bounds = focus.bounds();
+        self.bounds = focus.into_recorded();
+    }
+
+    fn traverse_focus(&mut self, target: Option<usize>) -> Focus {
+        let mut focus = Focus::new(target);
+        let Interface { root, layout, .. } = self;
+
+        root.widget.focus(Self::layout(layout), &mut focus);
+
+        focus
     }
 
     fn layout(layout: &result::Layout) -> Layout<'_> {
@@ -12,7 +12,7 @@ use crate::graphics::{Point, Rectangle, Vector};
 /// [`Widget::on_event`]: trait.Widget.html#method.on_event
 /// [`Widget::draw`]: trait.Widget.html#tymethod.draw
 /// [`Widget::node`]: trait.Widget.html#tymethod.node
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Layout<'a> {
     layout: &'a result::Layout,
     position: Point,
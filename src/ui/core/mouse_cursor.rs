@@ -20,6 +20,9 @@ pub enum MouseCursor {
 
     /// The cursor is grabbing a widget.
     Grabbing,
+
+    /// The cursor is over a text widget.
+    Text,
 }
 
 #[doc(hidden)]
@@ -32,6 +35,7 @@ impl From<MouseCursor> for winit::window::CursorIcon {
             MouseCursor::Working => winit::window::CursorIcon::Progress,
             MouseCursor::Grab => winit::window::CursorIcon::Grab,
             MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+            MouseCursor::Text => winit::window::CursorIcon::Text,
         }
     }
 }
@@ -1,6 +1,7 @@
-use crate::graphics::{Color, Frame};
+use crate::graphics::{Color, Frame, Rectangle};
 use crate::load::Task;
 use crate::ui::core::Layout;
+use crate::ui::Theme;
 
 /// The renderer of a user interface.
 ///
@@ -33,6 +34,39 @@ pub trait Renderer {
     /// [`Element::explain`]: struct.Element.html#method.explain
     fn explain(&mut self, layout: &Layout<'_>, color: Color);
 
+    /// Sets the [`Theme`] of the user interface.
+    ///
+    /// This will be called by the runtime before drawing every frame, using
+    /// the result of [`UserInterface::theme`].
+    ///
+    /// By default, it does nothing. Renderers that do not support themes can
+    /// ignore it.
+    ///
+    /// [`Theme`]: ../theme/struct.Theme.html
+    /// [`UserInterface::theme`]: ../trait.UserInterface.html#method.theme
+    fn set_theme(&mut self, _theme: Option<Theme>) {}
+
+    /// Draws the focus ring of the focused widget, given its bounds.
+    ///
+    /// This will be called by the runtime after calling [`Widget::draw`] for
+    /// all the widgets of the user interface, if a widget is focused.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget::draw`]: trait.Widget.html#tymethod.draw
+    fn draw_focus(&mut self, _bounds: Rectangle<f32>) {}
+
+    /// Starts a new layer.
+    ///
+    /// Everything drawn after starting a layer must be drawn on top of
+    /// everything drawn before, like the layers of a [`Stack`].
+    ///
+    /// By default, it does nothing. Renderers that draw in submission order
+    /// can ignore it.
+    ///
+    /// [`Stack`]: ../widget/struct.Stack.html
+    fn start_layer(&mut self) {}
+
     /// Flushes the renderer to draw on the given [`Frame`].
     ///
     /// This method will be called by the runtime after calling [`Widget::draw`]
@@ -217,6 +217,17 @@ impl From<Align> for style::AlignSelf {
     }
 }
 
+#[doc(hidden)]
+impl From<Align> for style::JustifyContent {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Start | Align::Stretch => style::JustifyContent::FlexStart,
+            Align::Center => style::JustifyContent::Center,
+            Align::End => style::JustifyContent::FlexEnd,
+        }
+    }
+}
+
 /// Distribution on the main axis of a container.
 ///
 ///   * On a [`Column`], it describes __vertical__ distribution.
@@ -1,5 +1,5 @@
 use crate::graphics::Point;
-use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node};
+use crate::ui::core::{Event, Focus, Hasher, Layout, MouseCursor, Node};
 
 /// A component that displays information or allows interaction.
 ///
@@ -71,4 +71,55 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
         _messages: &mut Vec<Message>,
     ) {
     }
+
+    /// Returns true if the [`Widget`], or any of its children, is capturing
+    /// the keyboard input.
+    ///
+    /// While a [`Widget`] of the user interface captures the keyboard input,
+    /// key presses and text are not delivered to the [`Game::Input`]. For
+    /// example, a focused [`TextInput`] captures the keyboard input.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+    /// [`TextInput`]: ../widget/text_input/struct.TextInput.html
+    fn captures_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Registers the focusable widgets of the [`Widget`] in the given
+    /// [`Focus`], in layout order.
+    ///
+    /// Focusable widgets should call [`Focus::register`] to learn whether
+    /// they are focused or not, and containers should forward the call to
+    /// their children.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Focus`]: struct.Focus.html
+    /// [`Focus::register`]: struct.Focus.html#method.register
+    fn focus(&mut self, _layout: Layout<'_>, _focus: &mut Focus) {}
+
+    /// Lets the [`Widget`] produce messages as time passes.
+    ///
+    /// It is called once per frame, after all the events of the frame have
+    /// been processed with [`on_event`]. For example, a [`Stepper`] uses it to
+    /// keep changing its value while one of its buttons is held down.
+    ///
+    /// Containers should forward the call to their children.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`on_event`]: #method.on_event
+    /// [`Stepper`]: ../widget/stepper/struct.Stepper.html
+    fn tick(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+    ) {
+    }
 }
@@ -1,19 +1,37 @@
 mod button;
 mod checkbox;
 mod image;
+mod modal;
 mod panel;
+mod paragraph;
 mod progress_bar;
 mod radio;
 mod slider;
+mod spinner;
+mod stepper;
 mod text;
+mod text_input;
+mod toggle;
+mod tooltip;
+mod viewport;
 
-use crate::graphics::{Batch, Color, Font, Frame, Image, Mesh, Shape};
+use crate::graphics::{
+    Batch, Canvas, Color, Font, FontStyle, Frame, HorizontalAlignment, Image,
+    Mesh, Point, Quad, Rectangle, RichText, Shape, Target, Text, TextSpan,
+    VerticalAlignment,
+};
 use crate::load::{Join, Task};
-use crate::ui::core;
+use crate::ui::core::{self, Animated, Transition};
+use crate::ui::theme::{Appearance, Theme};
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
+// The focus ring is drawn this far away from the focused widget
+const FOCUS_MARGIN: f32 = 3.0;
+const FOCUS_WIDTH: f32 = 2.0;
+
 /// A renderer capable of drawing all the [built-in widgets].
 ///
 /// It can be configured using [`Configuration`] and
@@ -23,10 +41,23 @@ use std::rc::Rc;
 /// [`Configuration`]: struct.Configuration.html
 /// [`UserInterface::configuration`]: trait.UserInterface.html#method.configuration
 pub struct Renderer {
+    pub(crate) backgrounds: Mesh,
     pub(crate) sprites: Batch,
     pub(crate) images: Vec<Batch>,
+    pub(crate) canvases: Vec<(Canvas, Rectangle<f32>)>,
     pub(crate) font: Rc<RefCell<Font>>,
+    pub(crate) shapes: Mesh,
+    pub(crate) tooltips: Vec<tooltip::Overlay>,
+    pub(crate) theme: Option<Theme>,
+    sprite_sheet: Image,
+    labels: Vec<Label>,
+    rich_labels: Vec<RichLabel>,
+    layers: Vec<Layer>,
     explain_mesh: Mesh,
+    transitions: HashMap<Key, Animated<Appearance>>,
+    last_transitions: HashMap<Key, Animated<Appearance>>,
+    clip: Option<Rectangle<f32>>,
+    clip_canvas: Option<Canvas>,
 }
 
 impl std::fmt::Debug for Renderer {
@@ -34,6 +65,7 @@ impl std::fmt::Debug for Renderer {
         f.debug_struct("Renderer")
             .field("sprites", &self.sprites)
             .field("images", &self.images)
+            .field("theme", &self.theme)
             .finish()
     }
 }
@@ -45,10 +77,23 @@ impl core::Renderer for Renderer {
         (config.sprites, config.font)
             .join()
             .map(|(sprites, font)| Renderer {
-                sprites: Batch::new(sprites),
+                backgrounds: Mesh::new(),
+                sprites: Batch::new(sprites.clone()),
                 images: Vec::new(),
+                canvases: Vec::new(),
                 font: Rc::new(RefCell::new(font)),
+                shapes: Mesh::new(),
+                tooltips: Vec::new(),
+                theme: None,
+                sprite_sheet: sprites,
+                labels: Vec::new(),
+                rich_labels: Vec::new(),
+                layers: Vec::new(),
                 explain_mesh: Mesh::new(),
+                transitions: HashMap::new(),
+                last_transitions: HashMap::new(),
+                clip: None,
+                clip_canvas: None,
             })
     }
 
@@ -61,27 +106,522 @@ impl core::Renderer for Renderer {
             .for_each(|layout| self.explain(&layout, color));
     }
 
+    fn set_theme(&mut self, theme: Option<Theme>) {
+        self.theme = theme;
+    }
+
+    fn draw_focus(&mut self, bounds: Rectangle<f32>) {
+        let color = self.theme.map(|theme| theme.focus).unwrap_or(Color {
+            r: 0.35,
+            g: 0.6,
+            b: 0.96,
+            a: 1.0,
+        });
+
+        let outline = rounded_rectangle(
+            Rectangle {
+                x: bounds.x - FOCUS_MARGIN,
+                y: bounds.y - FOCUS_MARGIN,
+                width: bounds.width + FOCUS_MARGIN * 2.0,
+                height: bounds.height + FOCUS_MARGIN * 2.0,
+            },
+            FOCUS_MARGIN * 2.0,
+        );
+
+        self.shapes.stroke(
+            Shape::Polyline { points: outline },
+            color,
+            FOCUS_WIDTH,
+        );
+    }
+
+    fn start_layer(&mut self) {
+        let layer = Layer {
+            backgrounds: std::mem::replace(&mut self.backgrounds, Mesh::new()),
+            canvases: std::mem::replace(&mut self.canvases, Vec::new()),
+            sprites: std::mem::replace(
+                &mut self.sprites,
+                Batch::new(self.sprite_sheet.clone()),
+            ),
+            shapes: std::mem::replace(&mut self.shapes, Mesh::new()),
+            images: std::mem::replace(&mut self.images, Vec::new()),
+            labels: std::mem::replace(&mut self.labels, Vec::new()),
+            rich_labels: std::mem::replace(&mut self.rich_labels, Vec::new()),
+            clip: self.clip,
+        };
+
+        // Empty layers would only waste draw calls
+        if !layer.is_empty() {
+            self.layers.push(layer);
+        }
+    }
+
     fn flush(&mut self, frame: &mut Frame<'_>) {
+        let window_size = (frame.width(), frame.height());
+
+        // The widgets drawn last form the topmost layer
+        core::Renderer::start_layer(self);
+
+        let layers = std::mem::replace(&mut self.layers, Vec::new());
+
+        for layer in layers {
+            match layer.clip {
+                Some(clip) => self.draw_clipped(layer, clip, frame),
+                None => layer
+                    .draw(&mut self.font.borrow_mut(), &mut frame.as_target()),
+            }
+        }
+
         let target = &mut frame.as_target();
 
-        self.sprites.draw(target);
-        self.sprites.clear();
+        if !self.explain_mesh.is_empty() {
+            self.explain_mesh.draw(target);
+            self.explain_mesh = Mesh::new();
+        }
+
+        // Tooltips are drawn last, on top of everything else
+        if !self.tooltips.is_empty() {
+            tooltip::Overlay::draw_all(
+                &self.tooltips,
+                &mut self.font.borrow_mut(),
+                target,
+                window_size,
+            );
+
+            self.tooltips.clear();
+        }
+
+        // Transitions of widgets that were not drawn this frame are dropped
+        self.last_transitions =
+            std::mem::replace(&mut self.transitions, HashMap::new());
+    }
+}
+
+impl Renderer {
+    /// Returns the current [`Theme`], if any.
+    ///
+    /// Custom widgets can use it to look like the built-in ones.
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    pub fn theme(&self) -> Option<Theme> {
+        self.theme
+    }
+
+    /// Fills a [`Shape`] with the given [`Color`].
+    ///
+    /// [`Shape`]: ../graphics/enum.Shape.html
+    /// [`Color`]: ../graphics/struct.Color.html
+    pub fn fill(&mut self, shape: Shape, color: Color) {
+        self.shapes.fill(shape, color);
+    }
+
+    /// Strokes a [`Shape`] with the given [`Color`] and line width.
+    ///
+    /// [`Shape`]: ../graphics/enum.Shape.html
+    /// [`Color`]: ../graphics/struct.Color.html
+    pub fn stroke(&mut self, shape: Shape, color: Color, width: f32) {
+        self.shapes.stroke(shape, color, width);
+    }
+
+    /// Draws a rectangle with the given [`Appearance`], like the built-in
+    /// widgets do when using a [`Theme`].
+    ///
+    /// The text color of the [`Appearance`] is ignored.
+    ///
+    /// [`Appearance`]: theme/struct.Appearance.html
+    /// [`Theme`]: theme/struct.Theme.html
+    pub fn draw_quad(
+        &mut self,
+        bounds: Rectangle<f32>,
+        appearance: &Appearance,
+    ) {
+        draw_box(&mut self.shapes, bounds, appearance);
+    }
+
+    /// Draws some [`Text`].
+    ///
+    /// [`Text`]: ../graphics/struct.Text.html
+    pub fn draw_text(&mut self, text: Text<'_>) {
+        self.add_text(text);
+    }
+
+    /// Draws the given portion of an [`Image`] inside some bounds, keeping its
+    /// aspect ratio.
+    ///
+    /// [`Image`]: ../graphics/struct.Image.html
+    pub fn draw_image(
+        &mut self,
+        bounds: Rectangle<f32>,
+        image: &Image,
+        source: Rectangle<u16>,
+    ) {
+        crate::ui::image::Renderer::draw(self, bounds, image.clone(), source);
+    }
+
+    /// Draws everything the given function draws only inside some bounds.
+    ///
+    /// Clipped groups can be nested, and they are drawn on top of everything
+    /// drawn before them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::graphics::{Color, Rectangle, Shape};
+    /// use coffee::ui::Renderer;
+    ///
+    /// fn draw_map(renderer: &mut Renderer, bounds: Rectangle<f32>) {
+    ///     renderer.clip(bounds, |renderer| {
+    ///         // This circle is cut at the edges of the bounds
+    ///         renderer.fill(
+    ///             Shape::Circle {
+    ///                 center: bounds.center(),
+    ///                 radius: bounds.width,
+    ///             },
+    ///             Color::BLUE,
+    ///         );
+    ///     });
+    /// }
+    /// ```
+    pub fn clip<F>(&mut self, bounds: Rectangle<f32>, draw: F)
+    where
+        F: FnOnce(&mut Renderer),
+    {
+        core::Renderer::start_layer(self);
+
+        let parent = self.clip;
+
+        self.clip = Some(match parent {
+            Some(parent) => intersection(parent, bounds),
+            None => bounds,
+        });
+
+        draw(self);
+
+        core::Renderer::start_layer(self);
+        self.clip = parent;
+    }
+
+    // Draws a layer only inside the given bounds, using an off-screen canvas
+    fn draw_clipped(
+        &mut self,
+        layer: Layer,
+        clip: Rectangle<f32>,
+        frame: &mut Frame<'_>,
+    ) {
+        let (width, height) = (frame.width(), frame.height());
+
+        let clip = intersection(
+            clip,
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width,
+                height,
+            },
+        );
+
+        if clip.width <= 0.0 || clip.height <= 0.0 {
+            return;
+        }
+
+        let size = (width.ceil() as u16, height.ceil() as u16);
+
+        let is_outdated = self
+            .clip_canvas
+            .as_ref()
+            .map_or(true, |canvas| (canvas.width(), canvas.height()) != size);
+
+        if is_outdated {
+            self.clip_canvas = Canvas::new(frame.gpu(), size.0, size.1).ok();
+        }
+
+        let canvas = match &mut self.clip_canvas {
+            Some(canvas) => canvas,
+            None => return,
+        };
+
+        {
+            let mut target = canvas.as_target(frame.gpu());
+
+            target.clear(Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            });
+
+            layer.draw(&mut self.font.borrow_mut(), &mut target);
+        }
+
+        canvas.draw(
+            Quad {
+                source: Rectangle {
+                    x: clip.x / f32::from(size.0),
+                    y: clip.y / f32::from(size.1),
+                    width: clip.width / f32::from(size.0),
+                    height: clip.height / f32::from(size.1),
+                },
+                position: Point::new(clip.x, clip.y),
+                size: (clip.width, clip.height),
+            },
+            &mut frame.as_target(),
+        );
+    }
+
+    // Queues some text to be drawn in the current layer
+    pub(crate) fn add_text(&mut self, text: Text<'_>) {
+        self.labels.push(Label {
+            content: String::from(text.content),
+            position: text.position,
+            bounds: text.bounds,
+            size: text.size,
+            color: text.color,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        });
+    }
+
+    // Queues a paragraph to be drawn in the current layer
+    pub(crate) fn add_rich_label(&mut self, label: RichLabel) {
+        self.rich_labels.push(label);
+    }
+
+    // Animates the appearance of the widget drawn in the given bounds towards
+    // the given one, which is returned directly if transitions are disabled.
+    //
+    // Widgets are recognized between frames by their bounds.
+    pub(crate) fn animate(
+        &mut self,
+        bounds: Rectangle<f32>,
+        appearance: Appearance,
+    ) -> Appearance {
+        let transition = match self.theme {
+            Some(theme) => theme.transition,
+            None => Some(Transition::default()),
+        };
+
+        let transition = match transition {
+            Some(transition) => transition,
+            None => return appearance,
+        };
+
+        let key = (
+            bounds.x.round() as i32,
+            bounds.y.round() as i32,
+            bounds.width.round() as i32,
+            bounds.height.round() as i32,
+        );
+
+        let mut animated = self
+            .last_transitions
+            .remove(&key)
+            .unwrap_or_else(|| Animated::new(appearance, transition));
+
+        animated.set_transition(transition);
+        animated.set_target(appearance);
+
+        let current = animated.value();
+        let _ = self.transitions.insert(key, animated);
+
+        current
+    }
+}
+
+// The position and size of a widget, used to recognize it between frames
+type Key = (i32, i32, i32, i32);
+
+// Everything drawn before a layer was started, which must stay below
+// everything drawn afterwards
+struct Layer {
+    backgrounds: Mesh,
+    canvases: Vec<(Canvas, Rectangle<f32>)>,
+    sprites: Batch,
+    shapes: Mesh,
+    images: Vec<Batch>,
+    labels: Vec<Label>,
+    rich_labels: Vec<RichLabel>,
+    clip: Option<Rectangle<f32>>,
+}
+
+impl Layer {
+    fn is_empty(&self) -> bool {
+        self.backgrounds.is_empty()
+            && self.canvases.is_empty()
+            && self.sprites.is_empty()
+            && self.shapes.is_empty()
+            && self.images.is_empty()
+            && self.labels.is_empty()
+            && self.rich_labels.is_empty()
+    }
+
+    fn draw(self, font: &mut Font, target: &mut Target<'_>) {
+        let mut backgrounds = self.backgrounds;
+        let mut shapes = self.shapes;
+
+        // When nothing is drawn between the backgrounds and the shapes, they
+        // can share a single draw call
+        if self.canvases.is_empty() && self.sprites.is_empty() {
+            backgrounds.append(std::mem::replace(&mut shapes, Mesh::new()));
+        }
+
+        if !backgrounds.is_empty() {
+            backgrounds.draw(target);
+        }
+
+        for (canvas, bounds) in &self.canvases {
+            canvas.draw(
+                Quad {
+                    position: Point::new(bounds.x, bounds.y),
+                    size: (bounds.width, bounds.height),
+                    ..Quad::default()
+                },
+                target,
+            );
+        }
+
+        if !self.sprites.is_empty() {
+            self.sprites.draw(target);
+        }
+
+        if !shapes.is_empty() {
+            shapes.draw(target);
+        }
 
         for image in &self.images {
             image.draw(target);
         }
 
-        self.images.clear();
+        if !self.labels.is_empty() || !self.rich_labels.is_empty() {
+            for label in &self.labels {
+                font.add(Text {
+                    content: &label.content,
+                    position: label.position,
+                    bounds: label.bounds,
+                    size: label.size,
+                    color: label.color,
+                    horizontal_alignment: label.horizontal_alignment,
+                    vertical_alignment: label.vertical_alignment,
+                });
+            }
 
-        self.font.borrow_mut().draw(target);
+            for label in &self.rich_labels {
+                let spans: Vec<TextSpan<'_>> = label
+                    .spans
+                    .iter()
+                    .map(|(content, color, style)| TextSpan {
+                        content,
+                        color: *color,
+                        style: *style,
+                    })
+                    .collect();
 
-        if !self.explain_mesh.is_empty() {
-            self.explain_mesh.draw(target);
-            self.explain_mesh = Mesh::new();
+                font.add_rich(RichText {
+                    spans: &spans,
+                    position: label.position,
+                    bounds: label.bounds,
+                    size: label.size,
+                    horizontal_alignment: label.horizontal_alignment,
+                    vertical_alignment: VerticalAlignment::Top,
+                });
+            }
+
+            font.draw(target);
         }
     }
 }
 
+// A text waiting for its layer to be drawn
+struct Label {
+    content: String,
+    position: Point,
+    bounds: (f32, f32),
+    size: f32,
+    color: Color,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+// A paragraph waiting for its layer to be drawn
+struct RichLabel {
+    spans: Vec<(String, Color, FontStyle)>,
+    position: Point,
+    bounds: (f32, f32),
+    size: f32,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+// Adds a box with the given appearance to a mesh
+pub(crate) fn draw_box(
+    mesh: &mut Mesh,
+    bounds: Rectangle<f32>,
+    appearance: &Appearance,
+) {
+    let outline = rounded_rectangle(bounds, appearance.border_radius);
+
+    mesh.fill(
+        Shape::Polyline {
+            points: outline.clone(),
+        },
+        appearance.background,
+    );
+
+    if appearance.border_width > 0.0 {
+        mesh.stroke(
+            Shape::Polyline { points: outline },
+            appearance.border_color,
+            appearance.border_width,
+        );
+    }
+}
+
+// The area covered by both rectangles, which may be empty
+fn intersection(a: Rectangle<f32>, b: Rectangle<f32>) -> Rectangle<f32> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: ((a.x + a.width).min(b.x + b.width) - x).max(0.0),
+        height: ((a.y + a.height).min(b.y + b.height) - y).max(0.0),
+    }
+}
+
+// The closed outline of a rectangle with rounded corners
+fn rounded_rectangle(bounds: Rectangle<f32>, radius: f32) -> Vec<Point> {
+    const SEGMENTS: usize = 6;
+
+    let radius = radius
+        .max(0.0)
+        .min(bounds.width / 2.0)
+        .min(bounds.height / 2.0);
+
+    let corners = [
+        (bounds.x + bounds.width - radius, bounds.y + radius, -90.0),
+        (
+            bounds.x + bounds.width - radius,
+            bounds.y + bounds.height - radius,
+            0.0,
+        ),
+        (bounds.x + radius, bounds.y + bounds.height - radius, 90.0),
+        (bounds.x + radius, bounds.y + radius, 180.0),
+    ];
+
+    let mut points = Vec::with_capacity(corners.len() * (SEGMENTS + 1) + 1);
+
+    for &(x, y, start) in corners.iter() {
+        for segment in 0..=SEGMENTS {
+            let angle: f32 = start + 90.0 * segment as f32 / SEGMENTS as f32;
+            let (sin, cos) = angle.to_radians().sin_cos();
+
+            points.push(Point::new(x + radius * cos, y + radius * sin));
+        }
+    }
+
+    points.push(points[0]);
+    points
+}
+
 /// The [`Renderer`] configuration.
 ///
 /// You can implement [`UserInterface::configuration`] and return your own
@@ -3,7 +3,8 @@ use crate::graphics::{
     VerticalAlignment,
 };
 use crate::ui::core::MouseCursor;
-use crate::ui::{button, Renderer};
+use crate::ui::renderer::draw_box;
+use crate::ui::{button, theme, Renderer};
 
 const LEFT: Rectangle<u16> = Rectangle {
     x: 0,
@@ -30,87 +31,158 @@ impl button::Renderer for Renderer {
     fn draw(
         &mut self,
         cursor_position: Point,
-        mut bounds: Rectangle<f32>,
+        bounds: Rectangle<f32>,
         state: &button::State,
         label: &str,
         class: button::Class,
+        style: Option<theme::Style>,
     ) -> MouseCursor {
         let mouse_over = bounds.contains(cursor_position);
 
-        let mut state_offset = 0;
-
-        if mouse_over {
-            if state.is_pressed() {
-                bounds.y += 4.0;
-                state_offset = RIGHT.x + RIGHT.width;
-            } else {
-                bounds.y -= 1.0;
-            }
-        }
-
-        let class_index = match class {
-            button::Class::Primary => 0,
-            button::Class::Secondary => 1,
-            button::Class::Positive => 2,
-        };
-
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: LEFT.x + state_offset,
-                y: LEFT.y + class_index * LEFT.height,
-                ..LEFT
-            },
-            position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
+        let style = style.or_else(|| {
+            self.theme.map(|theme| match class {
+                button::Class::Primary => theme.primary_button,
+                button::Class::Secondary => theme.secondary_button,
+                button::Class::Positive => theme.positive_button,
+            })
         });
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: BACKGROUND.x + state_offset,
-                y: BACKGROUND.y + class_index * BACKGROUND.height,
-                ..BACKGROUND
-            },
-            position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
-            scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
-        });
+        match style {
+            Some(style) => {
+                let appearance = self.animate(
+                    bounds,
+                    style.appearance(
+                        mouse_over,
+                        mouse_over && state.is_pressed(),
+                        state.is_disabled(),
+                    ),
+                );
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: RIGHT.x + state_offset,
-                y: RIGHT.y + class_index * RIGHT.height,
-                ..RIGHT
-            },
-            position: Point::new(
-                bounds.x + bounds.width - RIGHT.width as f32,
-                bounds.y,
-            ),
-            scale: (1.0, 1.0),
-        });
+                draw_box(&mut self.shapes, bounds, &appearance);
 
-        self.font.borrow_mut().add(Text {
-            content: label,
-            position: Point::new(bounds.x, bounds.y - 4.0),
-            bounds: (bounds.width, bounds.height),
-            color: if mouse_over {
-                Color::WHITE
-            } else {
-                Color {
-                    r: 0.9,
-                    g: 0.9,
-                    b: 0.9,
-                    a: 1.0,
-                }
-            },
-            size: 20.0,
-            horizontal_alignment: HorizontalAlignment::Center,
-            vertical_alignment: VerticalAlignment::Center,
-            ..Text::default()
-        });
+                let padding = f32::from(style.padding);
 
-        if mouse_over {
+                self.add_text(Text {
+                    content: label,
+                    position: Point::new(bounds.x + padding, bounds.y - 4.0),
+                    bounds: (bounds.width - padding * 2.0, bounds.height),
+                    color: appearance.text_color,
+                    size: 20.0,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                    ..Text::default()
+                });
+            }
+            None => {
+                draw_sprites(
+                    self,
+                    mouse_over,
+                    bounds,
+                    state.is_pressed(),
+                    state.is_disabled(),
+                    label,
+                    class,
+                );
+            }
+        }
+
+        if !mouse_over {
+            MouseCursor::OutOfBounds
+        } else if state.is_disabled() {
+            MouseCursor::Idle
+        } else {
             MouseCursor::Pointer
+        }
+    }
+}
+
+// Draws a button using the spritesheet, which is also used by other widgets
+// that contain buttons
+pub(crate) fn draw_sprites(
+    renderer: &mut Renderer,
+    mouse_over: bool,
+    mut bounds: Rectangle<f32>,
+    is_pressed: bool,
+    is_disabled: bool,
+    label: &str,
+    class: button::Class,
+) {
+    // Disabled buttons are drawn like idle ones, with a faded label
+    let mouse_over = mouse_over && !is_disabled;
+    let mut state_offset = 0;
+
+    if mouse_over {
+        if is_pressed {
+            bounds.y += 4.0;
+            state_offset = RIGHT.x + RIGHT.width;
         } else {
-            MouseCursor::OutOfBounds
+            bounds.y -= 1.0;
         }
     }
+
+    let class_index = match class {
+        button::Class::Primary => 0,
+        button::Class::Secondary => 1,
+        button::Class::Positive => 2,
+    };
+
+    renderer.sprites.add(Sprite {
+        source: Rectangle {
+            x: LEFT.x + state_offset,
+            y: LEFT.y + class_index * LEFT.height,
+            ..LEFT
+        },
+        position: Point::new(bounds.x, bounds.y),
+        scale: (1.0, 1.0),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: Rectangle {
+            x: BACKGROUND.x + state_offset,
+            y: BACKGROUND.y + class_index * BACKGROUND.height,
+            ..BACKGROUND
+        },
+        position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
+        scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: Rectangle {
+            x: RIGHT.x + state_offset,
+            y: RIGHT.y + class_index * RIGHT.height,
+            ..RIGHT
+        },
+        position: Point::new(
+            bounds.x + bounds.width - RIGHT.width as f32,
+            bounds.y,
+        ),
+        scale: (1.0, 1.0),
+    });
+
+    renderer.add_text(Text {
+        content: label,
+        position: Point::new(bounds.x, bounds.y - 4.0),
+        bounds: (bounds.width, bounds.height),
+        color: if is_disabled {
+            Color {
+                r: 0.9,
+                g: 0.9,
+                b: 0.9,
+                a: 0.5,
+            }
+        } else if mouse_over {
+            Color::WHITE
+        } else {
+            Color {
+                r: 0.9,
+                g: 0.9,
+                b: 0.9,
+                a: 1.0,
+            }
+        },
+        size: 20.0,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+        ..Text::default()
+    });
 }
@@ -1,7 +1,8 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Point, Rectangle, Shape, Sprite};
 use crate::ui::core::MouseCursor;
+use crate::ui::renderer::draw_box;
 use crate::ui::widget::checkbox;
-use crate::ui::Renderer;
+use crate::ui::{theme, Renderer};
 
 const SPRITE: Rectangle<u16> = Rectangle {
     x: 98,
@@ -10,6 +11,8 @@ const SPRITE: Rectangle<u16> = Rectangle {
     height: 28,
 };
 
+const MARK_MARGIN: f32 = 7.0;
+
 impl checkbox::Renderer for Renderer {
     fn draw(
         &mut self,
@@ -17,34 +20,63 @@ impl checkbox::Renderer for Renderer {
         bounds: Rectangle<f32>,
         text_bounds: Rectangle<f32>,
         is_checked: bool,
+        style: Option<theme::Style>,
+        is_disabled: bool,
     ) -> MouseCursor {
         let mouse_over = bounds.contains(cursor_position)
             || text_bounds.contains(cursor_position);
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
-                ..SPRITE
-            },
-            position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
+        let style = style.or_else(|| self.theme.map(|theme| theme.checkbox));
+
+        if let Some(style) = style {
+            let appearance = self.animate(
+                bounds,
+                style.appearance(mouse_over, false, is_disabled),
+            );
+
+            draw_box(&mut self.shapes, bounds, &appearance);
+
+            if is_checked {
+                self.shapes.fill(
+                    Shape::Rectangle(Rectangle {
+                        x: bounds.x + MARK_MARGIN,
+                        y: bounds.y + MARK_MARGIN,
+                        width: bounds.width - MARK_MARGIN * 2.0,
+                        height: bounds.height - MARK_MARGIN * 2.0,
+                    }),
+                    appearance.text_color,
+                );
+            }
+        } else {
+            let is_hovered = mouse_over && !is_disabled;
 
-        if is_checked {
             self.sprites.add(Sprite {
                 source: Rectangle {
-                    x: SPRITE.x + SPRITE.width * 2,
+                    x: SPRITE.x + (if is_hovered { SPRITE.width } else { 0 }),
                     ..SPRITE
                 },
                 position: Point::new(bounds.x, bounds.y),
                 scale: (1.0, 1.0),
             });
+
+            if is_checked {
+                self.sprites.add(Sprite {
+                    source: Rectangle {
+                        x: SPRITE.x + SPRITE.width * 2,
+                        ..SPRITE
+                    },
+                    position: Point::new(bounds.x, bounds.y),
+                    scale: (1.0, 1.0),
+                });
+            }
         }
 
-        if mouse_over {
-            MouseCursor::Pointer
-        } else {
+        if !mouse_over {
             MouseCursor::OutOfBounds
+        } else if is_disabled {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::Pointer
         }
     }
 }
@@ -1,5 +1,5 @@
-use crate::graphics::{Rectangle, Sprite, Point, Image, Batch};
-use crate::ui::{Renderer, image};
+use crate::graphics::{Batch, Image, Point, Rectangle, Sprite};
+use crate::ui::{image, Renderer};
 
 impl image::Renderer for Renderer {
     fn draw(
@@ -22,14 +22,21 @@ impl image::Renderer for Renderer {
             ((ratio_x, ratio_x), Point::new(position_x, position_y))
         };
 
-        let mut batch = Batch::new(image); 
-        batch.add(Sprite {
+        let sprite = Sprite {
             source,
             position,
             scale,
-        });
+        };
+
+        // Consecutive images sharing a texture are drawn at once
+        match self.images.last_mut() {
+            Some(batch) if batch.image().is_same(&image) => batch.add(sprite),
+            _ => {
+                let mut batch = Batch::new(image);
+                batch.add(sprite);
 
-        self.images.push(batch);
+                self.images.push(batch);
+            }
+        }
     }
 }
-
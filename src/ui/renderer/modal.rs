@@ -0,0 +1,15 @@
+use crate::graphics::{Color, Rectangle, Shape};
+use crate::ui::{modal, Renderer};
+
+const BACKDROP: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.6,
+};
+
+impl modal::Renderer for Renderer {
+    fn draw(&mut self, bounds: Rectangle<f32>) {
+        self.backgrounds.fill(Shape::Rectangle(bounds), BACKDROP);
+    }
+}
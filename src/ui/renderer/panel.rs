@@ -1,6 +1,11 @@
-use crate::graphics::{Point, Rectangle, Sprite};
-use crate::ui::widget::panel;
-use crate::ui::Renderer;
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Shape, Sprite, Text,
+    VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
+use crate::ui::renderer::draw_box;
+use crate::ui::widget::panel::{self, Part};
+use crate::ui::{theme, Renderer};
 
 const PANEL_WIDTH: u16 = 28;
 const PANEL_HEIGHT: u16 = 34;
@@ -69,91 +74,203 @@ const BOTTOM_RIGHT: Rectangle<u16> = Rectangle {
 };
 
 impl panel::Renderer for Renderer {
-    fn draw(&mut self, bounds: Rectangle<f32>) {
-        self.sprites.add(Sprite {
-            source: TOP_LEFT,
-            position: Point::new(bounds.x, bounds.y),
-            ..Sprite::default()
-        });
+    fn draw(&mut self, bounds: Rectangle<f32>, style: Option<theme::Style>) {
+        let style = style.or_else(|| self.theme.map(|theme| theme.panel));
 
-        self.sprites.add(Sprite {
-            source: TOP_BORDER,
-            position: Point::new(bounds.x + TOP_LEFT.width as f32, bounds.y),
-            scale: (
-                bounds.width - (TOP_LEFT.width + TOP_RIGHT.width) as f32,
-                1.0,
-            ),
-        });
+        match style {
+            Some(style) => {
+                draw_box(&mut self.backgrounds, bounds, &style.active);
+            }
+            None => draw_sprites(self, bounds),
+        }
+    }
 
-        self.sprites.add(Sprite {
-            source: TOP_RIGHT,
-            position: Point::new(
-                bounds.x + bounds.width - TOP_RIGHT.width as f32,
-                bounds.y,
-            ),
-            ..Sprite::default()
-        });
+    fn draw_floating(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        state: &panel::State,
+        style: Option<theme::Style>,
+    ) -> MouseCursor {
+        let style = style.or_else(|| self.theme.map(|theme| theme.panel));
 
-        self.sprites.add(Sprite {
-            source: CONTENT_BACKGROUND,
-            position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
-            scale: (
-                bounds.width,
-                bounds.height
-                    - (TOP_BORDER.height + BOTTOM_BORDER.height) as f32,
-            ),
-        });
+        let title_bar = Part::TitleBar.bounds(bounds);
+        let collapse_button = Part::CollapseButton.bounds(bounds);
+        let resize_handle = Part::ResizeHandle.bounds(bounds);
 
-        self.sprites.add(Sprite {
-            source: LEFT_BORDER,
-            position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
-            scale: (
-                1.0,
-                bounds.height - (TOP_BORDER.height + BOTTOM_LEFT.height) as f32,
-            ),
-        });
+        let color = match style {
+            Some(style) => {
+                draw_box(&mut self.backgrounds, bounds, &style.active);
 
-        self.sprites.add(Sprite {
-            source: RIGHT_BORDER,
-            position: Point::new(
-                bounds.x + bounds.width - RIGHT_BORDER.width as f32,
-                bounds.y + TOP_BORDER.height as f32,
-            ),
-            scale: (
-                1.0,
-                bounds.height
-                    - (TOP_BORDER.height + BOTTOM_RIGHT.height) as f32,
-            ),
+                if collapse_button.contains(cursor_position) {
+                    draw_box(&mut self.shapes, collapse_button, &style.hovered);
+                }
+
+                style.active.text_color
+            }
+            None => {
+                draw_sprites(self, bounds);
+
+                Color::WHITE
+            }
+        };
+
+        self.add_text(Text {
+            content: title,
+            position: Point::new(title_bar.x + 10.0, title_bar.y),
+            bounds: (title_bar.width - 10.0, title_bar.height),
+            color,
+            size: 20.0,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+            ..Text::default()
         });
 
-        self.sprites.add(Sprite {
-            source: BOTTOM_LEFT,
-            position: Point::new(
-                bounds.x,
-                bounds.y + bounds.height - BOTTOM_LEFT.height as f32,
-            ),
-            ..Sprite::default()
+        self.add_text(Text {
+            content: if state.is_collapsed() { "+" } else { "-" },
+            position: Point::new(collapse_button.x, collapse_button.y - 2.0),
+            bounds: (collapse_button.width, collapse_button.height),
+            color,
+            size: 20.0,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+            ..Text::default()
         });
 
-        self.sprites.add(Sprite {
-            source: BOTTOM_BORDER,
-            position: Point::new(
-                bounds.x + BOTTOM_LEFT.width as f32,
-                bounds.y + bounds.height - BOTTOM_BORDER.height as f32,
-            ),
-            scale: (
-                bounds.width - (BOTTOM_LEFT.width + BOTTOM_LEFT.width) as f32,
+        let faded = Color { a: 0.4, ..color };
+
+        if !state.is_collapsed() {
+            let separator = title_bar.y + title_bar.height;
+
+            self.shapes.stroke(
+                Shape::Polyline {
+                    points: vec![
+                        Point::new(bounds.x, separator),
+                        Point::new(bounds.x + bounds.width, separator),
+                    ],
+                },
+                faded,
                 1.0,
-            ),
-        });
+            );
 
-        self.sprites.add(Sprite {
-            source: BOTTOM_RIGHT,
-            position: Point::new(
-                bounds.x + bounds.width - BOTTOM_RIGHT.width as f32,
-                bounds.y + bounds.height - BOTTOM_RIGHT.height as f32,
-            ),
-            ..Sprite::default()
-        });
+            // Two diagonal lines in the corner
+            let right = resize_handle.x + resize_handle.width - 3.0;
+            let bottom = resize_handle.y + resize_handle.height - 3.0;
+
+            for &offset in [6.0, 11.0].iter() {
+                self.shapes.stroke(
+                    Shape::Polyline {
+                        points: vec![
+                            Point::new(right - offset, bottom),
+                            Point::new(right, bottom - offset),
+                        ],
+                    },
+                    faded,
+                    1.0,
+                );
+            }
+        }
+
+        if state.dragged().is_some() {
+            MouseCursor::Grabbing
+        } else if !bounds.contains(cursor_position) {
+            MouseCursor::OutOfBounds
+        } else if collapse_button.contains(cursor_position) {
+            MouseCursor::Pointer
+        } else if title_bar.contains(cursor_position)
+            || (!state.is_collapsed()
+                && resize_handle.contains(cursor_position))
+        {
+            MouseCursor::Grab
+        } else {
+            MouseCursor::Idle
+        }
     }
 }
+
+fn draw_sprites(renderer: &mut Renderer, bounds: Rectangle<f32>) {
+    renderer.sprites.add(Sprite {
+        source: TOP_LEFT,
+        position: Point::new(bounds.x, bounds.y),
+        ..Sprite::default()
+    });
+
+    renderer.sprites.add(Sprite {
+        source: TOP_BORDER,
+        position: Point::new(bounds.x + TOP_LEFT.width as f32, bounds.y),
+        scale: (
+            bounds.width - (TOP_LEFT.width + TOP_RIGHT.width) as f32,
+            1.0,
+        ),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: TOP_RIGHT,
+        position: Point::new(
+            bounds.x + bounds.width - TOP_RIGHT.width as f32,
+            bounds.y,
+        ),
+        ..Sprite::default()
+    });
+
+    renderer.sprites.add(Sprite {
+        source: CONTENT_BACKGROUND,
+        position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
+        scale: (
+            bounds.width,
+            bounds.height - (TOP_BORDER.height + BOTTOM_BORDER.height) as f32,
+        ),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: LEFT_BORDER,
+        position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
+        scale: (
+            1.0,
+            bounds.height - (TOP_BORDER.height + BOTTOM_LEFT.height) as f32,
+        ),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: RIGHT_BORDER,
+        position: Point::new(
+            bounds.x + bounds.width - RIGHT_BORDER.width as f32,
+            bounds.y + TOP_BORDER.height as f32,
+        ),
+        scale: (
+            1.0,
+            bounds.height - (TOP_BORDER.height + BOTTOM_RIGHT.height) as f32,
+        ),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: BOTTOM_LEFT,
+        position: Point::new(
+            bounds.x,
+            bounds.y + bounds.height - BOTTOM_LEFT.height as f32,
+        ),
+        ..Sprite::default()
+    });
+
+    renderer.sprites.add(Sprite {
+        source: BOTTOM_BORDER,
+        position: Point::new(
+            bounds.x + BOTTOM_LEFT.width as f32,
+            bounds.y + bounds.height - BOTTOM_BORDER.height as f32,
+        ),
+        scale: (
+            bounds.width - (BOTTOM_LEFT.width + BOTTOM_LEFT.width) as f32,
+            1.0,
+        ),
+    });
+
+    renderer.sprites.add(Sprite {
+        source: BOTTOM_RIGHT,
+        position: Point::new(
+            bounds.x + bounds.width - BOTTOM_RIGHT.width as f32,
+            bounds.y + bounds.height - BOTTOM_RIGHT.height as f32,
+        ),
+        ..Sprite::default()
+    });
+}
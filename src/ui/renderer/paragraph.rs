@@ -0,0 +1,92 @@
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, RichText, TextSpan,
+};
+use crate::ui::core::{Node, Number, Size, Style};
+use crate::ui::widget::paragraph;
+use crate::ui::Renderer;
+
+use super::RichLabel;
+
+use std::cell::RefCell;
+use std::f32;
+
+impl paragraph::Renderer for Renderer {
+    fn node(&self, style: Style, spans: &[paragraph::Span], size: f32) -> Node {
+        let font = self.font.clone();
+        let spans = spans.to_vec();
+
+        // The last measurement and the width it was made for
+        let measure: RefCell<Option<(f32, Size<f32>)>> = RefCell::new(None);
+
+        Node::with_measure(style, move |bounds| {
+            let width = match bounds.width {
+                Number::Undefined => f32::INFINITY,
+                Number::Defined(width) => width,
+            };
+
+            if let Some((measured_width, measured)) = *measure.borrow() {
+                if (measured_width - width).abs() < f32::EPSILON {
+                    return measured;
+                }
+            }
+
+            let text_spans: Vec<TextSpan<'_>> = spans
+                .iter()
+                .map(|span| TextSpan {
+                    content: &span.content,
+                    color: Color::WHITE,
+                    style: span.style,
+                })
+                .collect();
+
+            // The height is measured for the given width, so the paragraph
+            // grows with the lines it wraps into
+            let (text_width, text_height) =
+                font.borrow_mut().measure_rich(RichText {
+                    spans: &text_spans,
+                    size,
+                    bounds: (width, f32::INFINITY),
+                    ..RichText::default()
+                });
+
+            let result = Size {
+                width: if width.is_finite() { width } else { text_width },
+                height: text_height,
+            };
+
+            *measure.borrow_mut() = Some((width, result));
+
+            result
+        })
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        spans: &[paragraph::Span],
+        size: f32,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+    ) {
+        let default_color = color
+            .or_else(|| self.theme.map(|theme| theme.text))
+            .unwrap_or(Color::WHITE);
+
+        self.add_rich_label(RichLabel {
+            spans: spans
+                .iter()
+                .map(|span| {
+                    (
+                        span.content.clone(),
+                        span.color.unwrap_or(default_color),
+                        span.style,
+                    )
+                })
+                .collect(),
+            position: Point::new(bounds.x, bounds.y),
+            bounds: (bounds.width, bounds.height),
+            size,
+            horizontal_alignment,
+        });
+    }
+}
@@ -1,6 +1,23 @@
-use crate::graphics::{Rectangle, Sprite, Point};
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Shape, Sprite, Text,
+    VerticalAlignment,
+};
 use crate::ui::{progress_bar, Renderer};
 
+const FILL: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 1.0,
+};
+
+const BACKGROUND_COLOR: Color = Color {
+    r: 0.3,
+    g: 0.3,
+    b: 0.3,
+    a: 1.0,
+};
+
 const LEFT: Rectangle<u16> = Rectangle {
     x: 0,
     y: 34,
@@ -27,34 +44,92 @@ impl progress_bar::Renderer for Renderer {
         &mut self,
         bounds: Rectangle<f32>,
         progress: f32,
+        colors: progress_bar::Colors,
+        label: Option<&str>,
     ) {
-        let active_class = 0;
-        let background_class = 1;
-        let full = 1.0;
-        let left_width_f32 = LEFT.width as f32 / 100.0;
-        let background_width = 1.0 - 2.0 * left_width_f32;
-
-        self.sprites.add(left_sprite(bounds, background_class, full));
-        self.sprites.add(background_sprite(bounds, background_class, full));
-        self.sprites.add(right_sprite(bounds, background_class, full));
-
-        if progress > 0.0 {
-            let area = bound(progress / left_width_f32);
-            self.sprites.add(left_sprite(bounds, active_class, area));
-        }
+        let progress = progress.max(0.0).min(1.0);
+
+        if colors.fill.is_none() && colors.background.is_none() {
+            draw_sprites(self, bounds, progress);
+        } else {
+            // Custom colors are drawn with shapes. The default colors are
+            // approximated, so both parts stay in the same layer.
+            self.shapes.fill(
+                Shape::Rectangle(bounds),
+                colors.background.unwrap_or(BACKGROUND_COLOR),
+            );
 
-        if progress > left_width_f32 {
-            let area = bound((progress - left_width_f32) / background_width);
-            self.sprites.add(background_sprite(bounds, active_class, area));
+            if progress > 0.0 {
+                self.shapes.fill(
+                    Shape::Rectangle(Rectangle {
+                        width: bounds.width * progress,
+                        ..bounds
+                    }),
+                    colors.fill.unwrap_or(FILL),
+                );
+            }
         }
 
-        if progress > left_width_f32 + background_width {
-            let area = bound((progress - left_width_f32 - background_width) / left_width_f32);
-            self.sprites.add(right_sprite(bounds, active_class, area));
+        if let Some(label) = label {
+            self.add_text(Text {
+                content: label,
+                position: Point::new(bounds.x, bounds.y - 4.0),
+                bounds: (bounds.width, bounds.height),
+                color: Color::WHITE,
+                size: 20.0,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..Text::default()
+            });
         }
     }
 }
 
+fn draw_sprites(
+    renderer: &mut Renderer,
+    bounds: Rectangle<f32>,
+    progress: f32,
+) {
+    let active_class = 0;
+    let background_class = 1;
+    let full = 1.0;
+    let left_width_f32 = LEFT.width as f32 / 100.0;
+    let background_width = 1.0 - 2.0 * left_width_f32;
+
+    renderer
+        .sprites
+        .add(left_sprite(bounds, background_class, full));
+    renderer
+        .sprites
+        .add(background_sprite(bounds, background_class, full));
+    renderer
+        .sprites
+        .add(right_sprite(bounds, background_class, full));
+
+    if progress > 0.0 {
+        let area = bound(progress / left_width_f32);
+        renderer
+            .sprites
+            .add(left_sprite(bounds, active_class, area));
+    }
+
+    if progress > left_width_f32 {
+        let area = bound((progress - left_width_f32) / background_width);
+        renderer
+            .sprites
+            .add(background_sprite(bounds, active_class, area));
+    }
+
+    if progress > left_width_f32 + background_width {
+        let area = bound(
+            (progress - left_width_f32 - background_width) / left_width_f32,
+        );
+        renderer
+            .sprites
+            .add(right_sprite(bounds, active_class, area));
+    }
+}
+
 fn bound(v: f32) -> f32 {
     if v > 1.0 {
         1.0
@@ -76,7 +151,11 @@ fn left_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
     }
 }
 
-fn background_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
+fn background_sprite(
+    bounds: Rectangle<f32>,
+    class_index: u16,
+    area: f32,
+) -> Sprite {
     Sprite {
         source: Rectangle {
             x: BACKGROUND.x,
@@ -84,7 +163,10 @@ fn background_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Spr
             ..BACKGROUND
         },
         position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
-        scale: ((bounds.width - (LEFT.width + RIGHT.width) as f32) * area, 1.0),
+        scale: (
+            (bounds.width - (LEFT.width + RIGHT.width) as f32) * area,
+            1.0,
+        ),
     }
 }
 
@@ -1,7 +1,7 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Point, Rectangle, Shape, Sprite};
 use crate::ui::core::MouseCursor;
 use crate::ui::widget::radio;
-use crate::ui::Renderer;
+use crate::ui::{theme, Renderer};
 
 const SPRITE: Rectangle<u16> = Rectangle {
     x: 98,
@@ -17,33 +17,74 @@ impl radio::Renderer for Renderer {
         bounds: Rectangle<f32>,
         bounds_with_label: Rectangle<f32>,
         is_selected: bool,
+        style: Option<theme::Style>,
+        is_disabled: bool,
     ) -> MouseCursor {
         let mouse_over = bounds_with_label.contains(cursor_position);
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
-                ..SPRITE
-            },
-            position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
+        let style = style.or_else(|| self.theme.map(|theme| theme.radio));
+
+        if let Some(style) = style {
+            let appearance = self.animate(
+                bounds,
+                style.appearance(mouse_over, false, is_disabled),
+            );
+            let center = bounds.center();
+            let radius = bounds.width.min(bounds.height) / 2.0;
+
+            self.shapes
+                .fill(Shape::Circle { center, radius }, appearance.background);
+
+            if appearance.border_width > 0.0 {
+                self.shapes.stroke(
+                    Shape::Circle {
+                        center,
+                        radius: radius - appearance.border_width / 2.0,
+                    },
+                    appearance.border_color,
+                    appearance.border_width,
+                );
+            }
+
+            if is_selected {
+                self.shapes.fill(
+                    Shape::Circle {
+                        center,
+                        radius: radius / 2.0,
+                    },
+                    appearance.text_color,
+                );
+            }
+        } else {
+            let is_hovered = mouse_over && !is_disabled;
 
-        if is_selected {
             self.sprites.add(Sprite {
                 source: Rectangle {
-                    x: SPRITE.x + SPRITE.width * 2,
+                    x: SPRITE.x + (if is_hovered { SPRITE.width } else { 0 }),
                     ..SPRITE
                 },
                 position: Point::new(bounds.x, bounds.y),
                 scale: (1.0, 1.0),
             });
+
+            if is_selected {
+                self.sprites.add(Sprite {
+                    source: Rectangle {
+                        x: SPRITE.x + SPRITE.width * 2,
+                        ..SPRITE
+                    },
+                    position: Point::new(bounds.x, bounds.y),
+                    scale: (1.0, 1.0),
+                });
+            }
         }
 
-        if mouse_over {
-            MouseCursor::Pointer
-        } else {
+        if !mouse_over {
             MouseCursor::OutOfBounds
+        } else if is_disabled {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::Pointer
         }
     }
 }
@@ -1,6 +1,7 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Point, Rectangle, Shape, Sprite};
 use crate::ui::core::MouseCursor;
-use crate::ui::{slider, Renderer};
+use crate::ui::renderer::draw_box;
+use crate::ui::{slider, theme, Renderer};
 
 use std::ops::RangeInclusive;
 
@@ -26,38 +27,111 @@ impl slider::Renderer for Renderer {
         state: &slider::State,
         range: RangeInclusive<f32>,
         value: f32,
+        style: Option<theme::Style>,
     ) -> MouseCursor {
-        self.sprites.add(Sprite {
-            source: RAIL,
-            position: Point::new(
-                bounds.x + MARKER.width as f32 / 2.0,
-                bounds.y + 12.5,
-            ),
-            scale: (bounds.width - MARKER.width as f32, 1.0),
-        });
-
         let (range_start, range_end) = range.into_inner();
-
-        let marker_offset = (bounds.width - MARKER.width as f32)
-            * ((value - range_start) / (range_end - range_start).max(1.0));
+        let percent =
+            (value - range_start) / (range_end - range_start).max(1.0);
 
         let mouse_over = bounds.contains(cursor_position);
-        let is_active = state.is_dragging() || mouse_over;
-
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: MARKER.x + (if is_active { MARKER.width } else { 0 }),
-                ..MARKER
-            },
-            position: Point::new(
-                bounds.x + marker_offset.round(),
-                bounds.y + (if state.is_dragging() { 2.0 } else { 0.0 }),
-            ),
-            scale: (1.0, 1.0),
-        });
+        let is_active =
+            !state.is_disabled() && (state.is_dragging() || mouse_over);
+
+        let is_vertical = state.orientation() == slider::Orientation::Vertical;
+
+        // The rail and the handle are rotated when the slider is vertical
+        let (rail, handle) = if is_vertical {
+            let offset =
+                (bounds.height - MARKER.width as f32) * (1.0 - percent);
+
+            (
+                Rectangle {
+                    x: bounds.x + 12.5 - RAIL.height as f32 / 2.0,
+                    y: bounds.y + MARKER.width as f32 / 2.0,
+                    width: RAIL.height as f32,
+                    height: bounds.height - MARKER.width as f32,
+                },
+                Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + offset.round(),
+                    width: MARKER.height as f32,
+                    height: MARKER.width as f32,
+                },
+            )
+        } else {
+            let offset = (bounds.width - MARKER.width as f32) * percent;
+
+            (
+                Rectangle {
+                    x: bounds.x + MARKER.width as f32 / 2.0,
+                    y: bounds.y + 12.5,
+                    width: bounds.width - MARKER.width as f32,
+                    height: RAIL.height as f32,
+                },
+                Rectangle {
+                    x: bounds.x + offset.round(),
+                    y: bounds.y,
+                    width: MARKER.width as f32,
+                    height: MARKER.height as f32,
+                },
+            )
+        };
+
+        let style = style.or_else(|| self.theme.map(|theme| theme.slider));
+
+        if let Some(style) = style {
+            let appearance = self.animate(
+                bounds,
+                style.appearance(
+                    mouse_over,
+                    state.is_dragging(),
+                    state.is_disabled(),
+                ),
+            );
+
+            self.shapes
+                .fill(Shape::Rectangle(rail), appearance.border_color);
+
+            draw_box(&mut self.shapes, handle, &appearance);
+        } else {
+            self.sprites.add(Sprite {
+                source: RAIL,
+                position: Point::new(rail.x, rail.y),
+                scale: if is_vertical {
+                    (rail.width, rail.height / RAIL.height as f32)
+                } else {
+                    (rail.width, 1.0)
+                },
+            });
+
+            // The spritesheet only has an horizontal handle, which is centered
+            // in the rotated handle bounds
+            let (x, y) = if is_vertical {
+                (
+                    handle.x + (handle.width - MARKER.width as f32) / 2.0,
+                    handle.y + (handle.height - MARKER.height as f32) / 2.0,
+                )
+            } else {
+                (handle.x, handle.y)
+            };
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    x: MARKER.x + (if is_active { MARKER.width } else { 0 }),
+                    ..MARKER
+                },
+                position: Point::new(
+                    x,
+                    y + (if state.is_dragging() { 2.0 } else { 0.0 }),
+                ),
+                scale: (1.0, 1.0),
+            });
+        }
 
         if state.is_dragging() {
             MouseCursor::Grabbing
+        } else if mouse_over && state.is_disabled() {
+            MouseCursor::Idle
         } else if mouse_over {
             MouseCursor::Grab
         } else {
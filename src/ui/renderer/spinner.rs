@@ -0,0 +1,56 @@
+use crate::graphics::{Color, Point, Rectangle, Shape};
+use crate::ui::{spinner, Renderer};
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+const DOTS: u32 = 8;
+const PERIOD_MILLIS: u128 = 1000;
+
+const COLOR: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 1.0,
+};
+
+impl spinner::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        time: Duration,
+        color: Option<Color>,
+    ) {
+        let color = color.unwrap_or(COLOR);
+        let size = bounds.width.min(bounds.height);
+        let dot_radius = size / 10.0;
+        let radius = size / 2.0 - dot_radius;
+        let center = Point::new(
+            bounds.x + bounds.width / 2.0,
+            bounds.y + bounds.height / 2.0,
+        );
+
+        let phase =
+            (time.as_millis() % PERIOD_MILLIS) as f32 / PERIOD_MILLIS as f32;
+        let head = (phase * DOTS as f32) as u32;
+
+        for dot in 0..DOTS {
+            let angle = 2.0 * PI * dot as f32 / DOTS as f32 - PI / 2.0;
+            let distance = (head + DOTS - dot) % DOTS;
+
+            self.shapes.fill(
+                Shape::Circle {
+                    center: Point::new(
+                        center.x + radius * angle.cos(),
+                        center.y + radius * angle.sin(),
+                    ),
+                    radius: dot_radius,
+                },
+                Color {
+                    a: color.a * (1.0 - distance as f32 / DOTS as f32),
+                    ..color
+                },
+            );
+        }
+    }
+}
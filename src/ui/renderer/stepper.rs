@@ -0,0 +1,103 @@
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Text, VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
+use crate::ui::renderer::{button::draw_sprites, draw_box};
+use crate::ui::widget::stepper::Part;
+use crate::ui::{button, stepper, theme, Renderer};
+
+impl stepper::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &stepper::State,
+        value: &str,
+        style: Option<theme::Style>,
+    ) -> MouseCursor {
+        let style = style.or_else(|| self.theme.map(|theme| theme.stepper));
+        let is_disabled = state.is_disabled();
+
+        let buttons = [(Part::Decrement, "-"), (Part::Increment, "+")];
+
+        for &(part, label) in buttons.iter() {
+            let part_bounds = part.bounds(bounds);
+            let mouse_over = part_bounds.contains(cursor_position);
+            let is_pressed = state.pressed() == Some(part);
+
+            match style {
+                Some(style) => {
+                    let appearance = self.animate(
+                        part_bounds,
+                        style.appearance(
+                            mouse_over,
+                            mouse_over && is_pressed,
+                            is_disabled,
+                        ),
+                    );
+
+                    draw_box(&mut self.shapes, part_bounds, &appearance);
+
+                    self.add_text(Text {
+                        content: label,
+                        position: Point::new(
+                            part_bounds.x,
+                            part_bounds.y - 4.0,
+                        ),
+                        bounds: (part_bounds.width, part_bounds.height),
+                        color: appearance.text_color,
+                        size: 20.0,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                        ..Text::default()
+                    });
+                }
+                None => {
+                    draw_sprites(
+                        self,
+                        mouse_over,
+                        part_bounds,
+                        is_pressed,
+                        is_disabled,
+                        label,
+                        button::Class::Secondary,
+                    );
+                }
+            }
+        }
+
+        let value_bounds = Part::Value.bounds(bounds);
+
+        let color = match style {
+            Some(style) => {
+                style.appearance(false, false, is_disabled).text_color
+            }
+            None => Color::WHITE,
+        };
+
+        self.add_text(Text {
+            content: value,
+            position: Point::new(value_bounds.x, value_bounds.y - 4.0),
+            bounds: (value_bounds.width, value_bounds.height),
+            color: if is_disabled {
+                Color { a: 0.5, ..color }
+            } else {
+                color
+            },
+            size: 20.0,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+            ..Text::default()
+        });
+
+        if !bounds.contains(cursor_position) {
+            MouseCursor::OutOfBounds
+        } else if is_disabled {
+            MouseCursor::Idle
+        } else if value_bounds.contains(cursor_position) {
+            MouseCursor::Text
+        } else {
+            MouseCursor::Pointer
+        }
+    }
+}
@@ -65,15 +65,17 @@ impl text::Renderer for Renderer {
         bounds: Rectangle<f32>,
         content: &str,
         size: f32,
-        color: Color,
+        color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) {
-        self.font.borrow_mut().add(graphics::Text {
+        self.add_text(graphics::Text {
             content,
             position: Point::new(bounds.x, bounds.y),
             bounds: (bounds.width, bounds.height),
-            color,
+            color: color
+                .or_else(|| self.theme.map(|theme| theme.text))
+                .unwrap_or(Color::WHITE),
             size,
             horizontal_alignment,
             vertical_alignment,
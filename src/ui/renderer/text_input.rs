@@ -0,0 +1,174 @@
+use crate::graphics::{self, Color, Point, Rectangle, Shape};
+use crate::ui::core::MouseCursor;
+use crate::ui::widget::text_input;
+use crate::ui::Renderer;
+
+const BORDER: Color = Color {
+    r: 0.6,
+    g: 0.6,
+    b: 0.6,
+    a: 1.0,
+};
+
+const BORDER_HOVERED: Color = Color {
+    r: 0.4,
+    g: 0.4,
+    b: 0.4,
+    a: 1.0,
+};
+
+const BORDER_FOCUSED: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 1.0,
+};
+
+const SELECTION: Color = Color {
+    r: 0.7,
+    g: 0.8,
+    b: 1.0,
+    a: 1.0,
+};
+
+const DISABLED_BACKGROUND: Color = Color {
+    r: 0.9,
+    g: 0.9,
+    b: 0.9,
+    a: 1.0,
+};
+
+const PLACEHOLDER: Color = Color {
+    r: 0.6,
+    g: 0.6,
+    b: 0.6,
+    a: 1.0,
+};
+
+impl text_input::Renderer for Renderer {
+    fn measure(&self, content: &str, size: f32) -> f32 {
+        let (width, _) = self.font.borrow_mut().measure(graphics::Text {
+            content,
+            size,
+            ..graphics::Text::default()
+        });
+
+        width
+    }
+
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        text_bounds: Rectangle<f32>,
+        state: &text_input::State,
+        value: &str,
+        placeholder: &str,
+    ) -> MouseCursor {
+        let mouse_over = bounds.contains(cursor_position);
+        let scroll = state.scroll();
+        let start_x = text_bounds.x - scroll;
+        let end_x = text_bounds.x + text_bounds.width;
+
+        self.shapes.fill(
+            Shape::Rectangle(bounds),
+            if state.is_disabled() {
+                DISABLED_BACKGROUND
+            } else {
+                Color::WHITE
+            },
+        );
+        self.shapes.stroke(
+            Shape::Rectangle(bounds),
+            if state.is_focused() {
+                BORDER_FOCUSED
+            } else if mouse_over && !state.is_disabled() {
+                BORDER_HOVERED
+            } else {
+                BORDER
+            },
+            1.0,
+        );
+
+        if let Some((start, end)) = state.selection() {
+            let left = (start_x + state.offset(start)).max(text_bounds.x);
+            let right = (start_x + state.offset(end)).min(end_x);
+
+            if right > left {
+                self.shapes.fill(
+                    Shape::Rectangle(Rectangle {
+                        x: left,
+                        y: text_bounds.y,
+                        width: right - left,
+                        height: text_bounds.height,
+                    }),
+                    SELECTION,
+                );
+            }
+        }
+
+        if value.is_empty() {
+            self.add_text(graphics::Text {
+                content: placeholder,
+                position: Point::new(text_bounds.x, text_bounds.y),
+                size: text_bounds.height,
+                color: PLACEHOLDER,
+                ..graphics::Text::default()
+            });
+        } else {
+            // Only the characters that fit completely inside the text bounds
+            // are drawn
+            let visible: Vec<(usize, usize)> = value
+                .char_indices()
+                .enumerate()
+                .filter(|(index, _)| {
+                    start_x + state.offset(*index) >= text_bounds.x - 0.5
+                        && start_x + state.offset(index + 1) <= end_x + 0.5
+                })
+                .map(|(index, (byte, _))| (index, byte))
+                .collect();
+
+            if let (Some(&(first, start)), Some(&(_, last))) =
+                (visible.first(), visible.last())
+            {
+                let end = last
+                    + value[last..].chars().next().map_or(0, char::len_utf8);
+
+                self.add_text(graphics::Text {
+                    content: &value[start..end],
+                    position: Point::new(
+                        start_x + state.offset(first),
+                        text_bounds.y,
+                    ),
+                    size: text_bounds.height,
+                    color: if state.is_disabled() {
+                        PLACEHOLDER
+                    } else {
+                        Color::BLACK
+                    },
+                    ..graphics::Text::default()
+                });
+            }
+        }
+
+        if state.is_cursor_visible() {
+            self.shapes.fill(
+                Shape::Rectangle(Rectangle {
+                    x: (start_x + state.offset(state.cursor())).round(),
+                    y: text_bounds.y,
+                    width: 1.0,
+                    height: text_bounds.height,
+                }),
+                Color::BLACK,
+            );
+        }
+
+        if !mouse_over {
+            MouseCursor::OutOfBounds
+        } else if state.is_disabled() {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::Text
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::graphics::{Point, Rectangle, Shape};
+use crate::ui::core::{Interpolate, MouseCursor};
+use crate::ui::renderer::draw_box;
+use crate::ui::theme::{Appearance, Theme};
+use crate::ui::{theme, toggle, Renderer};
+
+const KNOB_MARGIN: f32 = 3.0;
+
+impl toggle::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &toggle::State,
+        style: Option<theme::Style>,
+    ) -> MouseCursor {
+        let mouse_over = bounds.contains(cursor_position);
+
+        // The spritesheet has no toggle, so the default theme is used instead
+        let style = style
+            .or_else(|| self.theme.map(|theme| theme.toggle))
+            .unwrap_or_else(|| Theme::default().toggle);
+
+        let appearance = self.animate(
+            bounds,
+            style.appearance(mouse_over, false, state.is_disabled()),
+        );
+
+        let position = state.knob_position();
+        let radius = bounds.height / 2.0;
+
+        draw_box(
+            &mut self.shapes,
+            bounds,
+            &Appearance {
+                background: appearance
+                    .background
+                    .interpolate(appearance.border_color, position),
+                border_width: 0.0,
+                border_radius: radius,
+                ..appearance
+            },
+        );
+
+        self.shapes.fill(
+            Shape::Circle {
+                center: Point::new(
+                    bounds.x
+                        + radius
+                        + (bounds.width - radius * 2.0) * position,
+                    bounds.y + radius,
+                ),
+                radius: radius - KNOB_MARGIN,
+            },
+            appearance.text_color,
+        );
+
+        if !mouse_over {
+            MouseCursor::OutOfBounds
+        } else if state.is_disabled() {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::Pointer
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::graphics::{
+    Batch, Color, Font, Mesh, Point, Rectangle, Shape, Sprite, Target, Text,
+};
+use crate::ui::{tooltip, Renderer};
+
+const PADDING: f32 = 6.0;
+const SPACING: f32 = 8.0;
+const CURSOR_OFFSET: f32 = 16.0;
+const MAX_WIDTH: f32 = 300.0;
+const TEXT_SIZE: f32 = 16.0;
+
+const BACKGROUND: Color = Color {
+    r: 0.1,
+    g: 0.1,
+    b: 0.1,
+    a: 0.9,
+};
+
+const BORDER: Color = Color {
+    r: 0.6,
+    g: 0.6,
+    b: 0.6,
+    a: 1.0,
+};
+
+// A tooltip waiting to be drawn on top of everything else
+pub(crate) struct Overlay {
+    bounds: Rectangle<f32>,
+    content: tooltip::Content,
+}
+
+impl tooltip::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        content: &tooltip::Content,
+        placement: tooltip::Placement,
+    ) {
+        let (width, height) = match content {
+            tooltip::Content::Text(text) => {
+                self.font.borrow_mut().measure(Text {
+                    content: text,
+                    bounds: (MAX_WIDTH, f32::INFINITY),
+                    size: TEXT_SIZE,
+                    ..Text::default()
+                })
+            }
+            tooltip::Content::Image(image) => {
+                (f32::from(image.width()), f32::from(image.height()))
+            }
+        };
+
+        let width = width + PADDING * 2.0;
+        let height = height + PADDING * 2.0;
+
+        let position = match placement {
+            tooltip::Placement::FollowCursor => Point::new(
+                cursor_position.x + CURSOR_OFFSET,
+                cursor_position.y + CURSOR_OFFSET,
+            ),
+            tooltip::Placement::Top => Point::new(
+                bounds.x + (bounds.width - width) / 2.0,
+                bounds.y - height - SPACING,
+            ),
+            tooltip::Placement::Bottom => Point::new(
+                bounds.x + (bounds.width - width) / 2.0,
+                bounds.y + bounds.height + SPACING,
+            ),
+            tooltip::Placement::Left => Point::new(
+                bounds.x - width - SPACING,
+                bounds.y + (bounds.height - height) / 2.0,
+            ),
+            tooltip::Placement::Right => Point::new(
+                bounds.x + bounds.width + SPACING,
+                bounds.y + (bounds.height - height) / 2.0,
+            ),
+        };
+
+        self.tooltips.push(Overlay {
+            bounds: Rectangle {
+                x: position.x,
+                y: position.y,
+                width,
+                height,
+            },
+            content: content.clone(),
+        });
+    }
+}
+
+impl Overlay {
+    // Draws the given tooltips, moving them inside the window when necessary
+    pub(crate) fn draw_all(
+        overlays: &[Overlay],
+        font: &mut Font,
+        target: &mut Target<'_>,
+        (window_width, window_height): (f32, f32),
+    ) {
+        let mut background = Mesh::new();
+        let mut images = Vec::new();
+
+        for overlay in overlays {
+            let bounds = overlay.clamped(window_width, window_height);
+
+            background.fill(Shape::Rectangle(bounds), BACKGROUND);
+            background.stroke(Shape::Rectangle(bounds), BORDER, 1.0);
+
+            let position = Point::new(bounds.x + PADDING, bounds.y + PADDING);
+
+            match &overlay.content {
+                tooltip::Content::Text(text) => {
+                    font.add(Text {
+                        content: text,
+                        position,
+                        bounds: (MAX_WIDTH, f32::INFINITY),
+                        color: Color::WHITE,
+                        size: TEXT_SIZE,
+                        ..Text::default()
+                    });
+                }
+                tooltip::Content::Image(image) => {
+                    let mut batch = Batch::new(image.clone());
+
+                    batch.add(Sprite {
+                        source: Rectangle {
+                            x: 0,
+                            y: 0,
+                            width: image.width(),
+                            height: image.height(),
+                        },
+                        position,
+                        scale: (1.0, 1.0),
+                    });
+
+                    images.push(batch);
+                }
+            }
+        }
+
+        background.draw(target);
+
+        for image in &images {
+            image.draw(target);
+        }
+
+        font.draw(target);
+    }
+
+    fn clamped(&self, window_width: f32, window_height: f32) -> Rectangle<f32> {
+        let max_x = (window_width - self.bounds.width).max(0.0);
+        let max_y = (window_height - self.bounds.height).max(0.0);
+
+        Rectangle {
+            x: self.bounds.x.max(0.0).min(max_x),
+            y: self.bounds.y.max(0.0).min(max_y),
+            ..self.bounds
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::graphics::{Canvas, Rectangle};
+use crate::ui::{viewport, Renderer};
+
+impl viewport::Renderer for Renderer {
+    fn draw(&mut self, bounds: Rectangle<f32>, canvas: &Canvas) {
+        self.canvases.push((canvas.clone(), bounds));
+    }
+}
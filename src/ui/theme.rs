@@ -0,0 +1,449 @@
+//! Restyle the built-in widgets.
+//!
+//! Every built-in widget that can be restyled has a `style` method that takes
+//! a [`Style`]. A [`Theme`] provides the default [`Style`] of all of them at
+//! once. You can choose the [`Theme`] of your user interface by implementing
+//! [`UserInterface::theme`].
+//!
+//! When there is neither a [`Theme`] nor a custom [`Style`], the built-in
+//! [`Renderer`] draws widgets using the spritesheet in its [`Configuration`].
+//!
+//! [`Style`]: struct.Style.html
+//! [`Theme`]: struct.Theme.html
+//! [`UserInterface::theme`]: ../trait.UserInterface.html#method.theme
+//! [`Renderer`]: ../struct.Renderer.html
+//! [`Configuration`]: ../struct.Configuration.html
+use crate::graphics::Color;
+use crate::ui::core::{Interpolate, Transition};
+
+/// The appearance of a widget in a particular state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The background color
+    pub background: Color,
+
+    /// The color of the border
+    pub border_color: Color,
+
+    /// The width of the border, in pixels
+    pub border_width: f32,
+
+    /// The radius of the corners, in pixels
+    pub border_radius: f32,
+
+    /// The color of the text and the marks of the widget, like the check mark
+    /// of a [`Checkbox`]
+    ///
+    /// [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
+    pub text_color: Color,
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(self, target: Appearance, progress: f32) -> Appearance {
+        Appearance {
+            background: self
+                .background
+                .interpolate(target.background, progress),
+            border_color: self
+                .border_color
+                .interpolate(target.border_color, progress),
+            border_width: self
+                .border_width
+                .interpolate(target.border_width, progress),
+            border_radius: self
+                .border_radius
+                .interpolate(target.border_radius, progress),
+            text_color: self
+                .text_color
+                .interpolate(target.text_color, progress),
+        }
+    }
+}
+
+/// The style of a widget.
+///
+/// It contains an [`Appearance`] for every state of a widget.
+///
+/// [`Appearance`]: struct.Appearance.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Appearance`] of a widget that is not being interacted with
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub active: Appearance,
+
+    /// The [`Appearance`] of a widget under the mouse cursor
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub hovered: Appearance,
+
+    /// The [`Appearance`] of a widget that is being pressed or dragged
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub pressed: Appearance,
+
+    /// The [`Appearance`] of a widget that cannot be interacted with
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub disabled: Appearance,
+
+    /// The space between the border and the content of a widget, in pixels
+    ///
+    /// The padding of a [`Panel`] changes its layout. Therefore, it is only
+    /// used when the [`Style`] is set directly on the [`Panel`].
+    ///
+    /// [`Panel`]: ../widget/panel/struct.Panel.html
+    /// [`Style`]: struct.Style.html
+    pub padding: u16,
+}
+
+impl Style {
+    /// Creates a new [`Style`] using the same [`Appearance`] for every state.
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Appearance`]: struct.Appearance.html
+    pub fn new(appearance: Appearance) -> Style {
+        Style {
+            active: appearance,
+            hovered: appearance,
+            pressed: appearance,
+            disabled: appearance,
+            padding: 0,
+        }
+    }
+
+    /// Sets the hovered [`Appearance`] of the [`Style`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Style`]: struct.Style.html
+    pub fn hovered(mut self, appearance: Appearance) -> Style {
+        self.hovered = appearance;
+        self
+    }
+
+    /// Sets the pressed [`Appearance`] of the [`Style`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Style`]: struct.Style.html
+    pub fn pressed(mut self, appearance: Appearance) -> Style {
+        self.pressed = appearance;
+        self
+    }
+
+    /// Sets the disabled [`Appearance`] of the [`Style`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Style`]: struct.Style.html
+    pub fn disabled(mut self, appearance: Appearance) -> Style {
+        self.disabled = appearance;
+        self
+    }
+
+    /// Sets the padding of the [`Style`], in pixels.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub fn padding(mut self, padding: u16) -> Style {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the [`Appearance`] for the given state.
+    ///
+    /// A disabled widget always uses the disabled [`Appearance`]. Otherwise, a
+    /// pressed widget takes precedence over a hovered one.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub fn appearance(
+        &self,
+        is_hovered: bool,
+        is_pressed: bool,
+        is_disabled: bool,
+    ) -> Appearance {
+        if is_disabled {
+            self.disabled
+        } else if is_pressed {
+            self.pressed
+        } else if is_hovered {
+            self.hovered
+        } else {
+            self.active
+        }
+    }
+}
+
+/// The default styles of the built-in widgets.
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::Theme;
+///
+/// # let dark_mode = true;
+/// let theme = if dark_mode { Theme::dark() } else { Theme::light() };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// The [`Color`] of [`Text`] and labels without a custom color
+    ///
+    /// [`Color`]: ../../graphics/struct.Color.html
+    /// [`Text`]: ../widget/text/struct.Text.html
+    pub text: Color,
+
+    /// The [`Style`] of a primary [`Button`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub primary_button: Style,
+
+    /// The [`Style`] of a secondary [`Button`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub secondary_button: Style,
+
+    /// The [`Style`] of a positive [`Button`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub positive_button: Style,
+
+    /// The [`Style`] of a [`Checkbox`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
+    pub checkbox: Style,
+
+    /// The [`Style`] of a [`Radio`] button
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Radio`]: ../widget/radio/struct.Radio.html
+    pub radio: Style,
+
+    /// The [`Style`] of a [`Slider`]
+    ///
+    /// The border color is used to draw the rail of the [`Slider`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Slider`]: ../widget/slider/struct.Slider.html
+    pub slider: Style,
+
+    /// The [`Style`] of a [`Panel`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Panel`]: ../widget/panel/struct.Panel.html
+    pub panel: Style,
+
+    /// The [`Style`] of a [`Toggle`]
+    ///
+    /// The background color fills the track of a [`Toggle`] that is off, the
+    /// border color fills the track of a [`Toggle`] that is on, and the text
+    /// color is used to draw the knob.
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Toggle`]: ../widget/toggle/struct.Toggle.html
+    pub toggle: Style,
+
+    /// The [`Style`] of the buttons of a [`Stepper`]
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Stepper`]: ../widget/stepper/struct.Stepper.html
+    pub stepper: Style,
+
+    /// The [`Color`] of the ring drawn around the focused widget
+    ///
+    /// [`Color`]: ../../graphics/struct.Color.html
+    pub focus: Color,
+
+    /// The [`Transition`] between the appearances of a widget, like when it
+    /// is hovered or pressed
+    ///
+    /// Set it to `None` to change appearances instantly.
+    ///
+    /// [`Transition`]: ../core/struct.Transition.html
+    pub transition: Option<Transition>,
+}
+
+impl Theme {
+    /// Creates a dark [`Theme`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn dark() -> Theme {
+        let text = Color::from_rgb(0xEE, 0xEE, 0xEE);
+        let control = control(Color::from_rgb(0x3A, 0x3A, 0x3A), text);
+        let secondary_button = button(Color::from_rgb(0x55, 0x55, 0x55), text);
+
+        Theme {
+            text,
+            primary_button: button(Color::from_rgb(0x2F, 0x6F, 0xD0), text),
+            secondary_button,
+            positive_button: button(Color::from_rgb(0x2E, 0x9E, 0x5B), text),
+            checkbox: control,
+            radio: control,
+            slider: control,
+            panel: panel(
+                Color::from_rgb(0x22, 0x22, 0x22),
+                Color::from_rgb(0x44, 0x44, 0x44),
+                text,
+            ),
+            toggle: toggle(
+                Color::from_rgb(0x55, 0x55, 0x55),
+                Color::from_rgb(0x2F, 0x6F, 0xD0),
+                text,
+            ),
+            stepper: secondary_button,
+            focus: Color::from_rgb(0x5A, 0x9B, 0xF5),
+            transition: Some(Transition::default()),
+        }
+    }
+
+    /// Creates a light [`Theme`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn light() -> Theme {
+        let text = Color::from_rgb(0x22, 0x22, 0x22);
+        let control = control(Color::WHITE, text);
+        let secondary_button = button(Color::from_rgb(0xDD, 0xDD, 0xDD), text);
+
+        Theme {
+            text,
+            primary_button: button(
+                Color::from_rgb(0x3C, 0x82, 0xE6),
+                Color::WHITE,
+            ),
+            secondary_button,
+            positive_button: button(
+                Color::from_rgb(0x3A, 0xB8, 0x6C),
+                Color::WHITE,
+            ),
+            checkbox: control,
+            radio: control,
+            slider: control,
+            panel: panel(
+                Color::from_rgb(0xF2, 0xF2, 0xF2),
+                Color::from_rgb(0xC8, 0xC8, 0xC8),
+                text,
+            ),
+            toggle: toggle(
+                Color::from_rgb(0xC8, 0xC8, 0xC8),
+                Color::from_rgb(0x3C, 0x82, 0xE6),
+                Color::WHITE,
+            ),
+            stepper: secondary_button,
+            focus: Color::from_rgb(0x1F, 0x6F, 0xE0),
+            transition: Some(Transition::default()),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::dark()
+    }
+}
+
+fn button(background: Color, text_color: Color) -> Style {
+    let active = Appearance {
+        background,
+        border_color: darken(background, 0.2),
+        border_width: 1.0,
+        border_radius: 5.0,
+        text_color,
+    };
+
+    Style::new(active)
+        .hovered(Appearance {
+            background: lighten(background, 0.1),
+            ..active
+        })
+        .pressed(Appearance {
+            background: darken(background, 0.1),
+            ..active
+        })
+        .disabled(disabled(active))
+}
+
+fn control(background: Color, text_color: Color) -> Style {
+    let active = Appearance {
+        background,
+        border_color: mix(background, text_color, 0.5),
+        border_width: 2.0,
+        border_radius: 4.0,
+        text_color,
+    };
+
+    Style::new(active)
+        .hovered(Appearance {
+            border_color: text_color,
+            ..active
+        })
+        .pressed(Appearance {
+            background: mix(background, text_color, 0.2),
+            border_color: text_color,
+            ..active
+        })
+        .disabled(disabled(active))
+}
+
+fn panel(background: Color, border_color: Color, text_color: Color) -> Style {
+    Style::new(Appearance {
+        background,
+        border_color,
+        border_width: 1.0,
+        border_radius: 6.0,
+        text_color,
+    })
+    .padding(20)
+}
+
+fn toggle(track: Color, accent: Color, knob: Color) -> Style {
+    let active = Appearance {
+        background: track,
+        border_color: accent,
+        border_width: 0.0,
+        border_radius: 0.0,
+        text_color: knob,
+    };
+
+    Style::new(active)
+        .hovered(Appearance {
+            background: lighten(track, 0.1),
+            border_color: lighten(accent, 0.1),
+            ..active
+        })
+        .disabled(disabled(active))
+}
+
+fn disabled(appearance: Appearance) -> Appearance {
+    Appearance {
+        background: Color {
+            a: 0.5,
+            ..appearance.background
+        },
+        border_color: Color {
+            a: 0.5,
+            ..appearance.border_color
+        },
+        text_color: Color {
+            a: 0.5,
+            ..appearance.text_color
+        },
+        ..appearance
+    }
+}
+
+fn lighten(color: Color, amount: f32) -> Color {
+    mix(color, Color::WHITE, amount)
+}
+
+fn darken(color: Color, amount: f32) -> Color {
+    mix(color, Color::BLACK, amount)
+}
+
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a,
+    }
+}
@@ -9,7 +9,8 @@
 //! ```
 //!
 //! However, if you want to use a custom renderer, you will need to work with
-//! the definitions of [`Row`], [`Column`], and [`Panel`] found in this module.
+//! the definitions of [`Row`], [`Column`], [`Grid`], [`Stack`], [`Modal`],
+//! [`Panel`], and [`Tooltip`] found in this module.
 //!
 //! # Customization
 //! Every drawable widget has its own module with a `Renderer` trait that must
@@ -21,27 +22,51 @@
 //! [`ui` module]: ../index.html
 //! [`Row`]: struct.Row.html
 //! [`Column`]: struct.Column.html
+//! [`Grid`]: grid/struct.Grid.html
+//! [`Stack`]: struct.Stack.html
+//! [`Modal`]: modal/struct.Modal.html
 //! [`Panel`]: struct.Panel.html
+//! [`Tooltip`]: struct.Tooltip.html
 //! [`Renderer`]: ../struct.Renderer.html
 mod column;
 mod row;
+mod stack;
 
 pub mod button;
 pub mod checkbox;
+pub mod grid;
 pub mod image;
+pub mod modal;
 pub mod panel;
+pub mod paragraph;
 pub mod progress_bar;
 pub mod radio;
 pub mod slider;
+pub mod spinner;
+pub mod stepper;
 pub mod text;
+pub mod text_input;
+pub mod toggle;
+pub mod tooltip;
+pub mod viewport;
 
 pub use self::image::Image;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use column::Column;
-pub use panel::Panel;
+pub use grid::Grid;
+pub use modal::Modal;
+pub use panel::{FloatingLayer, Panel};
+pub use paragraph::Paragraph;
 pub use progress_bar::ProgressBar;
-pub use radio::Radio;
+pub use radio::{Radio, RadioGroup};
 pub use row::Row;
 pub use slider::Slider;
+pub use spinner::Spinner;
+pub use stack::Stack;
+pub use stepper::Stepper;
 pub use text::Text;
+pub use text_input::TextInput;
+pub use toggle::Toggle;
+pub use tooltip::Tooltip;
+pub use viewport::Viewport;
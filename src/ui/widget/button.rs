@@ -9,8 +9,10 @@
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Action, Align, Element, Event, Focus, Hasher, Id, Layout, MouseCursor,
+    Node, Style, Widget,
 };
+use crate::ui::theme;
 
 use std::hash::Hash;
 
@@ -45,6 +47,8 @@ pub struct Button<'a, Message> {
     class: Class,
     on_press: Option<Message>,
     style: Style,
+    theme_style: Option<theme::Style>,
+    id: Option<Id>,
 }
 
 impl<'a, Message> std::fmt::Debug for Button<'a, Message>
@@ -58,6 +62,8 @@ where
             .field("class", &self.class)
             .field("on_press", &self.on_press)
             .field("style", &self.style)
+            .field("theme_style", &self.theme_style)
+            .field("id", &self.id)
             .finish()
     }
 }
@@ -72,12 +78,16 @@ impl<'a, Message> Button<'a, Message> {
     /// [`Class`]: enum.Class.html
     /// [`Class::Primary`]: enum.Class.html#variant.Primary
     pub fn new(state: &'a mut State, label: &str) -> Self {
+        state.is_disabled = false;
+
         Button {
             state,
             label: String::from(label),
             class: Class::Primary,
             on_press: None,
             style: Style::default().min_width(100),
+            theme_style: None,
+            id: None,
         }
     }
 
@@ -118,19 +128,61 @@ impl<'a, Message> Button<'a, Message> {
         self
     }
 
+    /// Sets the [`theme::Style`] of the [`Button`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Button`]: struct.Button.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
+    /// The message is cloned every time the [`Button`] is pressed, so it can
+    /// carry data, like the index of the item the [`Button`] belongs to.
+    ///
     /// [`Button`]: struct.Button.html
     pub fn on_press(mut self, msg: Message) -> Self {
         self.on_press = Some(msg);
         self
     }
+
+    /// Sets the [`Id`] of the [`Button`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Button`]: struct.Button.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`Button`] is disabled or not.
+    ///
+    /// A disabled [`Button`] does not react to user interactions and never produces messages and
+    /// it is drawn using the disabled [`Appearance`] of its style. Its layout
+    /// does not change.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        self.state.is_disabled = is_disabled;
+
+        if is_disabled {
+            self.state.is_pressed = false;
+        }
+
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Button<'a, Message>
 where
     Renderer: self::Renderer,
-    Message: Copy + std::fmt::Debug,
+    Message: Clone + std::fmt::Debug,
 {
     fn node(&self, _renderer: &Renderer) -> Node {
         Node::new(self.style.height(50))
@@ -143,12 +195,27 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.state.is_disabled {
+            return;
+        }
+
+        if self.state.is_focused
+            && Action::from_event(&event) == Some(Action::Activate)
+        {
+            if let Some(on_press) = &self.on_press {
+                messages.push(on_press.clone());
+            }
+
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => {
-                if let Some(on_press) = self.on_press {
+                if let Some(on_press) = &self.on_press {
                     let bounds = layout.bounds();
 
                     match state {
@@ -163,7 +230,7 @@ where
                             self.state.is_pressed = false;
 
                             if is_clicked {
-                                messages.push(on_press);
+                                messages.push(on_press.clone());
                             }
                         }
                     }
@@ -185,12 +252,23 @@ where
             self.state,
             &self.label,
             self.class,
+            self.theme_style,
         )
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.state.is_focused =
+            if self.on_press.is_none() || self.state.is_disabled {
+                focus.skip(self.id, layout.bounds());
+                false
+            } else {
+                focus.register(self.id, layout.bounds(), false)
+            };
+    }
 }
 
 /// The local state of a [`Button`].
@@ -199,6 +277,8 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_disabled: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -216,6 +296,20 @@ impl State {
     pub fn is_pressed(&self) -> bool {
         self.is_pressed
     }
+
+    /// Returns whether the associated [`Button`] is disabled or not.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+
+    /// Returns whether the associated [`Button`] is focused or not.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 /// The type of a [`Button`].
@@ -257,10 +351,12 @@ pub trait Renderer {
     ///   * the local state of the [`Button`]
     ///   * the label of the [`Button`]
     ///   * the [`Class`] of the [`Button`]
+    ///   * the custom [`theme::Style`] of the [`Button`], if any
     ///
     /// [`Button`]: struct.Button.html
     /// [`State`]: struct.State.html
     /// [`Class`]: enum.Class.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
     fn draw(
         &mut self,
         cursor_position: Point,
@@ -268,6 +364,7 @@ pub trait Renderer {
         state: &State,
         label: &str,
         class: Class,
+        style: Option<theme::Style>,
     ) -> MouseCursor;
 }
 
@@ -275,7 +372,7 @@ impl<'a, Message, Renderer> From<Button<'a, Message>>
     for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
-    Message: 'static + Copy + std::fmt::Debug,
+    Message: 'static + Clone + std::fmt::Debug,
 {
     fn from(button: Button<'a, Message>) -> Element<'a, Message, Renderer> {
         Element::new(button)
@@ -6,8 +6,10 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Action, Align, Element, Event, Focus, Hasher, Id, Layout, MouseCursor,
+    Node, Widget,
 };
+use crate::ui::theme;
 use crate::ui::widget::{text, Column, Row, Text};
 
 /// A box that can be checked.
@@ -40,7 +42,11 @@ pub struct Checkbox<Message> {
     is_checked: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
-    label_color: Color,
+    label_color: Option<Color>,
+    theme_style: Option<theme::Style>,
+    is_disabled: bool,
+    id: Option<Id>,
+    is_focused: bool,
 }
 
 impl<Message> std::fmt::Debug for Checkbox<Message> {
@@ -49,6 +55,9 @@ impl<Message> std::fmt::Debug for Checkbox<Message> {
             .field("is_checked", &self.is_checked)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("theme_style", &self.theme_style)
+            .field("is_disabled", &self.is_disabled)
+            .field("id", &self.id)
             .finish()
     }
 }
@@ -72,7 +81,11 @@ impl<Message> Checkbox<Message> {
             is_checked,
             on_toggle: Box::new(f),
             label: String::from(label),
-            label_color: Color::WHITE,
+            label_color: None,
+            theme_style: None,
+            is_disabled: false,
+            id: None,
+            is_focused: false,
         }
     }
 
@@ -81,7 +94,41 @@ impl<Message> Checkbox<Message> {
     /// [`Color`]: ../../../../graphics/struct.Color.html
     /// [`Checkbox`]: struct.Checkbox.html
     pub fn label_color(mut self, color: Color) -> Self {
-        self.label_color = color;
+        self.label_color = Some(color);
+        self
+    }
+
+    /// Sets the [`theme::Style`] of the [`Checkbox`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Sets whether the [`Checkbox`] is disabled or not.
+    ///
+    /// A disabled [`Checkbox`] does not react to user interactions and never
+    /// produces messages. It is drawn using the disabled [`Appearance`] of
+    /// its style, and its layout does not change.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Checkbox`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
         self
     }
 }
@@ -106,10 +153,22 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            return;
+        }
+
+        if self.is_focused
+            && Action::from_event(&event) == Some(Action::Activate)
+        {
+            messages.push((self.on_toggle)(!self.is_checked));
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
                 let mouse_over = layout
                     .children()
@@ -149,12 +208,23 @@ where
             children[0].bounds(),
             text_bounds,
             self.is_checked,
+            self.theme_style,
+            self.is_disabled,
         )
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.is_focused = if self.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            false
+        } else {
+            focus.register(self.id, layout.bounds(), false)
+        };
+    }
 }
 
 /// The renderer of a [`Checkbox`].
@@ -172,14 +242,19 @@ pub trait Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * the bounds of the label of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is checked or not
+    ///   * the custom [`theme::Style`] of the [`Checkbox`], if any
+    ///   * whether the [`Checkbox`] is disabled or not
     ///
     /// [`Checkbox`]: struct.Checkbox.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
     fn draw(
         &mut self,
         cursor_position: Point,
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_checked: bool,
+        style: Option<theme::Style>,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
@@ -0,0 +1,465 @@
+//! Place your widgets in rows and columns.
+use std::hash::Hash;
+
+use stretch::style::{Dimension, FlexDirection};
+
+use crate::graphics::Point;
+use crate::ui::core::{
+    Align, Element, Event, Focus, Hasher, Layout, MouseCursor, Node, Style,
+    Widget,
+};
+
+/// A container that places its contents in rows and columns.
+///
+/// Children fill the [`Grid`] row by row, from left to right. Every column
+/// has its own [`Width`], and the cells of the same row share the same height.
+///
+/// A [`Grid`] will try to fill the horizontal space of its container.
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Width`]: enum.Width.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{grid, Align, Grid, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// Grid::<Message>::with_columns(3)
+///     .column_width(0, grid::Width::Fixed(64))
+///     .spacing(10)
+///     .align_y(Align::Center)
+///     .push(Text::new("Sword"))
+///     .push(Text::new("A sharp sword"))
+///     .push(Text::new("100 gold"))
+///     .push_spanning(Text::new("Sold out!"), 2);
+/// ```
+pub struct Grid<'a, Message, Renderer> {
+    style: Style,
+    columns: Vec<Width>,
+    spacing: u16,
+    align_x: Align,
+    align_y: Align,
+    rows: Vec<Vec<Cell<'a, Message, Renderer>>>,
+    is_row_closed: bool,
+}
+
+struct Cell<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    span: usize,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Grid<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<Vec<_>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|cell| &cell.content).collect())
+            .collect();
+
+        f.debug_struct("Grid")
+            .field("style", &self.style)
+            .field("columns", &self.columns)
+            .field("spacing", &self.spacing)
+            .field("align_x", &self.align_x)
+            .field("align_y", &self.align_y)
+            .field("rows", &rows)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given amount of columns.
+    ///
+    /// By default, every column has a [`Width::Portion`] of `1`, so they
+    /// share the available space equally.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Width::Portion`]: enum.Width.html#variant.Portion
+    pub fn with_columns(columns: usize) -> Self {
+        let mut style = Style::default().fill_width();
+        style.0.flex_direction = FlexDirection::Column;
+        style.0.align_items = Align::Stretch.into();
+
+        Grid {
+            style,
+            columns: vec![Width::Portion(1); columns.max(1)],
+            spacing: 0,
+            align_x: Align::Start,
+            align_y: Align::Start,
+            rows: Vec::new(),
+            is_row_closed: true,
+        }
+    }
+
+    /// Sets the [`Width`] of a column of the [`Grid`].
+    ///
+    /// Columns out of range are ignored.
+    ///
+    /// [`Width`]: enum.Width.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn column_width(mut self, column: usize, width: Width) -> Self {
+        if let Some(current) = self.columns.get_mut(column) {
+            *current = width;
+        }
+
+        self
+    }
+
+    /// Sets the spacing _between_ rows and columns in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn spacing(mut self, px: u16) -> Self {
+        self.spacing = px;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding(mut self, px: u32) -> Self {
+        self.style = self.style.padding(px);
+        self
+    }
+
+    /// Sets the width of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.style = self.style.max_width(max_width);
+        self
+    }
+
+    /// Sets the alignment of the [`Grid`] itself.
+    ///
+    /// This is useful if you want to override the default alignment given by
+    /// the parent container.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_self(mut self, align: Align) -> Self {
+        self.style = self.style.align_self(align);
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of each cell of the
+    /// [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of each cell of the
+    /// [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.align_y = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the next cell of the [`Grid`].
+    ///
+    /// A new row is started when the current one is full.
+    ///
+    /// [`Element`]: ../../core/struct.Element.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_spanning(child, 1)
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] that spans multiple columns.
+    ///
+    /// A new row is started when the [`Element`] does not fit in the current
+    /// one.
+    ///
+    /// [`Element`]: ../../core/struct.Element.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push_spanning<E>(mut self, child: E, columns: usize) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let span = columns.max(1).min(self.columns.len());
+
+        let has_room = self.rows.last().map_or(false, |row| {
+            row.iter().map(|cell| cell.span).sum::<usize>() + span
+                <= self.columns.len()
+        });
+
+        if self.is_row_closed || !has_room {
+            self.rows.push(Vec::new());
+            self.is_row_closed = false;
+        }
+
+        if let Some(row) = self.rows.last_mut() {
+            row.push(Cell {
+                content: child.into(),
+                span,
+            });
+        }
+
+        self
+    }
+
+    /// Adds a whole row of elements to the [`Grid`].
+    ///
+    /// The row always starts on a new line, and the next [`push`] will start
+    /// another one. Extra elements that do not fit in the columns of the
+    /// [`Grid`] are placed in the rows below.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`push`]: #method.push
+    pub fn push_row<E>(mut self, row: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.is_row_closed = true;
+
+        for child in row {
+            self = self.push(child);
+        }
+
+        self.is_row_closed = true;
+        self
+    }
+
+    // The flex style of a cell covering the given columns
+    fn cell_style(&self, columns: &[Width]) -> Style {
+        let mut style = Style::default();
+        style.0.flex_direction = FlexDirection::Column;
+        style.0.align_items = self.align_x.into();
+        style.0.justify_content = self.align_y.into();
+
+        // Columns grow proportionally from a fixed basis, so cells spanning
+        // the same columns line up across rows
+        let gaps = columns.len().saturating_sub(1) as f32 * self.spacing as f32;
+
+        let fixed: f32 = columns
+            .iter()
+            .map(|width| match width {
+                Width::Fixed(px) => *px as f32,
+                Width::Portion(_) => 0.0,
+            })
+            .sum();
+
+        let portions: f32 = columns
+            .iter()
+            .map(|width| match width {
+                Width::Fixed(_) => 0.0,
+                Width::Portion(portion) => f32::from(*portion),
+            })
+            .sum();
+
+        style.0.flex_basis = Dimension::Points(fixed + gaps);
+        style.0.flex_grow = portions;
+        style.0.flex_shrink = 0.0;
+        style.0.min_size.width = Dimension::Points(0.0);
+
+        style
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let spacing = Dimension::Points(self.spacing as f32);
+        let last_row = self.rows.len().saturating_sub(1);
+
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut cells = Vec::with_capacity(row.len() + 1);
+                let mut column = 0;
+
+                for cell in row {
+                    let mut content = cell.content.widget.node(renderer);
+
+                    if self.align_y == Align::Stretch {
+                        let mut style = content.0.style();
+                        style.flex_grow = 1.0;
+
+                        content.0.set_style(style);
+                    }
+
+                    let mut style = self
+                        .cell_style(&self.columns[column..column + cell.span]);
+                    column += cell.span;
+
+                    if column < self.columns.len() {
+                        style.0.margin.end = spacing;
+                    }
+
+                    cells.push(Node::with_children(style, vec![content]));
+                }
+
+                // Incomplete rows are filled with an empty cell, keeping the
+                // columns aligned
+                if column < self.columns.len() {
+                    cells.push(Node::new(
+                        self.cell_style(&self.columns[column..]),
+                    ));
+                }
+
+                let mut style = Style::default();
+                style.0.align_items = Align::Stretch.into();
+
+                if i < last_row {
+                    style.0.margin.bottom = spacing;
+                }
+
+                Node::with_children(style, cells)
+            })
+            .collect();
+
+        Node::with_children(self.style, rows)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        for (row, layout) in self.rows.iter_mut().zip(layout.children()) {
+            for (cell, layout) in row.iter_mut().zip(layout.children()) {
+                if let Some(layout) = layout.children().next() {
+                    cell.content.widget.on_event(
+                        event,
+                        layout,
+                        cursor_position,
+                        messages,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let mut cursor = MouseCursor::OutOfBounds;
+
+        for (row, layout) in self.rows.iter().zip(layout.children()) {
+            for (cell, layout) in row.iter().zip(layout.children()) {
+                if let Some(layout) = layout.children().next() {
+                    let new_cursor = cell.content.widget.draw(
+                        renderer,
+                        layout,
+                        cursor_position,
+                    );
+
+                    if new_cursor != MouseCursor::OutOfBounds {
+                        cursor = new_cursor;
+                    }
+                }
+            }
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.columns.hash(state);
+        self.spacing.hash(state);
+        (self.align_x as u8).hash(state);
+        (self.align_y as u8).hash(state);
+
+        for row in &self.rows {
+            row.len().hash(state);
+
+            for cell in row {
+                cell.span.hash(state);
+                cell.content.widget.hash(state);
+            }
+        }
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.rows
+            .iter()
+            .flatten()
+            .any(|cell| cell.content.widget.captures_keyboard())
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        for (row, layout) in self.rows.iter_mut().zip(layout.children()) {
+            for (cell, layout) in row.iter_mut().zip(layout.children()) {
+                if let Some(layout) = layout.children().next() {
+                    cell.content.widget.focus(layout, focus);
+                }
+            }
+        }
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        for (row, layout) in self.rows.iter_mut().zip(layout.children()) {
+            for (cell, layout) in row.iter_mut().zip(layout.children()) {
+                if let Some(layout) = layout.children().next() {
+                    cell.content.widget.tick(layout, cursor_position, messages);
+                }
+            }
+        }
+    }
+}
+
+/// The width of a column of a [`Grid`].
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// A fixed width in pixels.
+    Fixed(u32),
+
+    /// A portion of the space left by the fixed columns.
+    ///
+    /// For instance, a column with a portion of `2` is twice as wide as a
+    /// column with a portion of `1`.
+    Portion(u16),
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a,
+    Message: 'static,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
@@ -0,0 +1,206 @@
+//! Show a dialog on top of the rest of your user interface.
+use crate::graphics::{Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    self, Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style,
+    Widget,
+};
+use crate::ui::widget::Stack;
+
+/// A dialog placed on top of some content, which is dimmed and cannot be
+/// interacted with while the [`Modal`] is shown.
+///
+/// A [`Modal`] is a [`Stack`] with a backdrop layer that blocks user
+/// interactions and a layer with the dialog centered on top of it. Keep
+/// building your content as usual and wrap it in a [`Modal`] only while the
+/// dialog is open.
+///
+/// It can be turned into an [`Element`] when the associated
+/// [`core::Renderer`] implements the [`modal::Renderer`] trait.
+///
+/// [`Modal`]: struct.Modal.html
+/// [`Stack`]: ../struct.Stack.html
+/// [`Element`]: ../../core/struct.Element.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`modal::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{button, Button, Column, Element, Modal, Panel, Text};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     Resume,
+/// }
+///
+/// fn layout<'a>(
+///     game: Column<'a, Message>,
+///     is_paused: bool,
+///     resume: &'a mut button::State,
+/// ) -> Element<'a, Message> {
+///     if is_paused {
+///         let menu = Panel::new(
+///             Column::new()
+///                 .push(Text::new("Paused"))
+///                 .push(Button::new(resume, "Resume").on_press(Message::Resume)),
+///         )
+///         .width(300);
+///
+///         Modal::new(game, menu).on_dismiss(Message::Resume).into()
+///     } else {
+///         game.into()
+///     }
+/// }
+/// ```
+pub struct Modal<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    dialog: Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Modal<'a, Message, Renderer>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Modal")
+            .field("content", &self.content)
+            .field("dialog", &self.dialog)
+            .field("on_dismiss", &self.on_dismiss)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer> {
+    /// Creates a new [`Modal`] showing a dialog on top of some content.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<C, D>(content: C, dialog: D) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        D: Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            content: content.into(),
+            dialog: dialog.into(),
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the backdrop of the
+    /// [`Modal`] is clicked.
+    ///
+    /// By default, clicking the backdrop does nothing.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+// The layer that dims and blocks the content below a dialog
+struct Backdrop<Message> {
+    on_dismiss: Option<Message>,
+}
+
+impl<Message> std::fmt::Debug for Backdrop<Message>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Backdrop")
+            .field("on_dismiss", &self.on_dismiss)
+            .finish()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Backdrop<Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone + std::fmt::Debug,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(Style::default())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if let Some(on_dismiss) = &self.on_dismiss {
+                    if layout.bounds().contains(cursor_position) {
+                        messages.push(on_dismiss.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+
+        renderer.draw(bounds);
+
+        if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    fn hash(&self, _state: &mut Hasher) {}
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws the backdrop of a [`Modal`], which covers the content below its
+    /// dialog.
+    ///
+    /// It receives the bounds of the backdrop.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    fn draw(&mut self, bounds: Rectangle<f32>);
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + core::Renderer,
+    Message: 'static + Clone + std::fmt::Debug,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        let backdrop = Backdrop {
+            on_dismiss: modal.on_dismiss,
+        };
+
+        Stack::new(modal.content)
+            .push_blocking(Element::new(backdrop))
+            .push_aligned(modal.dialog, Align::Center, Align::Center)
+            .into()
+    }
+}
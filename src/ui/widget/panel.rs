@@ -1,10 +1,36 @@
-//! Wrap your widgets in a box.
+//! Wrap your widgets in a box, or in floating panels that users can move.
+//!
+//! A [`Floating`] panel has some local [`State`].
+//!
+//! [`Floating`]: struct.Floating.html
+//! [`State`]: struct.State.html
 use std::hash::Hash;
+use std::time::Instant;
 
-use crate::graphics::{Point, Rectangle};
+use stretch::geometry;
+use stretch::style::{Dimension, FlexDirection, PositionType};
+
+use crate::graphics::{Point, Rectangle, Vector};
+use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    self, Align, Element, Event, Focus, Hasher, Layout, MouseCursor, Node,
+    Style, Widget,
 };
+use crate::ui::theme;
+use crate::ui::widget::stack::hidden_cursor;
+
+// The height of the title bar of a floating panel
+const TITLE_BAR_HEIGHT: f32 = 32.0;
+
+// The size of the corner used to resize a floating panel
+const RESIZE_HANDLE_SIZE: f32 = 16.0;
+
+// The minimum size of a floating panel, when resized
+const MIN_WIDTH: f32 = 120.0;
+const MIN_HEIGHT: f32 = 80.0;
+
+// The padding around the content of a floating panel
+const FLOATING_PADDING: u16 = 10;
 
 /// A box that can wrap a widget.
 ///
@@ -32,6 +58,7 @@ use crate::ui::core::{
 pub struct Panel<'a, Message, Renderer> {
     style: Style,
     content: Element<'a, Message, Renderer>,
+    theme_style: Option<theme::Style>,
 }
 
 impl<'a, Message, Renderer> std::fmt::Debug for Panel<'a, Message, Renderer> {
@@ -39,6 +66,7 @@ impl<'a, Message, Renderer> std::fmt::Debug for Panel<'a, Message, Renderer> {
         f.debug_struct("Panel")
             .field("style", &self.style)
             .field("content", &self.content)
+            .field("theme_style", &self.theme_style)
             .finish()
     }
 }
@@ -55,6 +83,7 @@ impl<'a, Message, Renderer> Panel<'a, Message, Renderer> {
         Panel {
             style: Style::default().padding(20),
             content: content.into(),
+            theme_style: None,
         }
     }
 
@@ -73,6 +102,42 @@ impl<'a, Message, Renderer> Panel<'a, Message, Renderer> {
         self.style = self.style.max_width(max_width);
         self
     }
+
+    /// Sets the [`theme::Style`] of the [`Panel`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// The padding of the [`theme::Style`] replaces the padding of the
+    /// [`Panel`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Panel`]: struct.Panel.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.style = self.style.padding(u32::from(style.padding));
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Creates a new [`Floating`] panel with the given title, local
+    /// [`State`], and content.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    /// [`State`]: struct.State.html
+    pub fn floating<E>(
+        title: &str,
+        state: &'a mut State,
+        content: E,
+    ) -> Floating<'a, Message, Renderer>
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Floating {
+            title: String::from(title),
+            state,
+            content: content.into(),
+            theme_style: None,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -112,7 +177,7 @@ where
     ) -> MouseCursor {
         let bounds = layout.bounds();
         let mut cursor = MouseCursor::OutOfBounds;
-        renderer.draw(bounds);
+        renderer.draw(bounds, self.theme_style);
 
         [&self.content].iter().zip(layout.children()).for_each(
             |(child, layout)| {
@@ -139,6 +204,27 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
+
+    fn captures_keyboard(&self) -> bool {
+        self.content.widget.captures_keyboard()
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        if let Some(layout) = layout.children().next() {
+            self.content.widget.focus(layout, focus);
+        }
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content.widget.tick(layout, cursor_position, messages);
+        }
+    }
 }
 
 /// The renderer of a [`Panel`].
@@ -151,10 +237,36 @@ where
 pub trait Renderer {
     /// Draws a [`Panel`].
     ///
-    /// It receives the bounds of the [`Panel`].
+    /// It receives the bounds of the [`Panel`] and its custom
+    /// [`theme::Style`], if any.
     ///
     /// [`Panel`]: struct.Panel.html
-    fn draw(&mut self, bounds: Rectangle<f32>);
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    fn draw(&mut self, bounds: Rectangle<f32>, style: Option<theme::Style>);
+
+    /// Draws the frame of a [`Floating`] panel, below its content.
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`Floating`] panel, including its title bar
+    ///   * the title of the [`Floating`] panel
+    ///   * the local state of the [`Floating`] panel
+    ///   * the custom [`theme::Style`] of the [`Floating`] panel, if any
+    ///
+    /// Use [`Part::bounds`] to find where the title bar, the collapse button,
+    /// and the resize handle are.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Part::bounds`]: enum.Part.html#method.bounds
+    fn draw_floating(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        state: &State,
+        style: Option<theme::Style>,
+    ) -> MouseCursor;
 }
 
 impl<'a, Message, Renderer> From<Panel<'a, Message, Renderer>>
@@ -169,3 +281,704 @@ where
         Element::new(panel)
     }
 }
+
+/// A panel with a title bar that can be moved, resized, and collapsed.
+///
+/// Users drag a [`Floating`] panel by its title bar, resize it with its
+/// bottom-right corner, and collapse it with the button in its title bar. Its
+/// position, size, and whether it is collapsed are kept in its [`State`].
+///
+/// [`Floating`] panels live in a [`FloatingLayer`], which keeps them inside
+/// of its bounds and draws the panel that was clicked last on top of the
+/// rest.
+///
+/// [`Floating`]: struct.Floating.html
+/// [`State`]: struct.State.html
+/// [`FloatingLayer`]: struct.FloatingLayer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{panel, Column, FloatingLayer, Panel, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// fn tools<'a>(
+///     game: Column<'a, Message>,
+///     inspector: &'a mut panel::State,
+///     console: &'a mut panel::State,
+/// ) -> FloatingLayer<'a, Message> {
+///     FloatingLayer::new(game)
+///         .push(Panel::floating("Inspector", inspector, Text::new("...")))
+///         .push(Panel::floating("Console", console, Text::new("...")))
+/// }
+/// ```
+pub struct Floating<'a, Message, Renderer> {
+    title: String,
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    theme_style: Option<theme::Style>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for Floating<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Floating")
+            .field("title", &self.title)
+            .field("state", &self.state)
+            .field("content", &self.content)
+            .field("theme_style", &self.theme_style)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Floating<'a, Message, Renderer> {
+    /// Sets the [`theme::Style`] of the [`Floating`] panel, overriding the
+    /// one in the current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Floating`]: struct.Floating.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    fn node(&self, renderer: &Renderer) -> Node {
+        let bounds = self.state.visible_bounds();
+        let padding = f32::from(
+            self.theme_style
+                .map_or(FLOATING_PADDING, |style| style.padding),
+        );
+
+        let mut style = Style::default();
+        style.0.position_type = PositionType::Absolute;
+        style.0.position = geometry::Rect {
+            start: Dimension::Points(bounds.x),
+            end: Dimension::Undefined,
+            top: Dimension::Points(bounds.y),
+            bottom: Dimension::Undefined,
+        };
+        style.0.size = geometry::Size {
+            width: Dimension::Points(bounds.width),
+            height: Dimension::Points(bounds.height),
+        };
+        style.0.padding = geometry::Rect {
+            start: Dimension::Points(padding),
+            end: Dimension::Points(padding),
+            top: Dimension::Points(TITLE_BAR_HEIGHT + padding),
+            bottom: Dimension::Points(padding),
+        };
+        style.0.flex_direction = FlexDirection::Column;
+
+        let children = if self.state.is_collapsed {
+            Vec::new()
+        } else {
+            vec![self.content.widget.node(renderer)]
+        };
+
+        Node::with_children(style, children)
+    }
+
+    // Moves or resizes the panel following the cursor, keeping it inside of
+    // the given area
+    fn drag(&mut self, cursor_position: Point, area: Rectangle<f32>) {
+        let cursor =
+            Point::new(cursor_position.x - area.x, cursor_position.y - area.y);
+
+        match self.state.interaction {
+            Some(Interaction::Moving { grab }) => {
+                let position = cursor - grab;
+
+                self.state.bounds.x = position.x;
+                self.state.bounds.y = position.y;
+            }
+            Some(Interaction::Resizing { grab }) => {
+                let corner = cursor - grab;
+
+                self.state.bounds.width = corner.x - self.state.bounds.x;
+                self.state.bounds.height = corner.y - self.state.bounds.y;
+            }
+            None => {}
+        }
+
+        self.state.clamp(area);
+    }
+}
+
+/// A container that shows [`Floating`] panels on top of some content.
+///
+/// The content of a [`FloatingLayer`] determines its size, and its
+/// [`Floating`] panels are kept inside of it. Put it at the root of your
+/// user interface to let users move the panels anywhere in the window.
+///
+/// The panel clicked last is drawn on top of the rest, and only the topmost
+/// panel under the cursor receives mouse clicks and scrolls.
+///
+/// [`Floating`]: struct.Floating.html
+/// [`FloatingLayer`]: struct.FloatingLayer.html
+pub struct FloatingLayer<'a, Message, Renderer> {
+    style: Style,
+    base: Element<'a, Message, Renderer>,
+    panels: Vec<Floating<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for FloatingLayer<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FloatingLayer")
+            .field("style", &self.style)
+            .field("base", &self.base)
+            .field("panels", &self.panels)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> FloatingLayer<'a, Message, Renderer> {
+    /// Creates a [`FloatingLayer`] on top of the given content.
+    ///
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn new<E>(base: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut style = Style::default();
+        style.0.flex_direction = FlexDirection::Column;
+        style.0.align_items = Align::Stretch.into();
+
+        FloatingLayer {
+            style,
+            base: base.into(),
+            panels: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`FloatingLayer`] in pixels.
+    ///
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`FloatingLayer`] in pixels.
+    ///
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Makes the [`FloatingLayer`] fill all the horizontal available space.
+    ///
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn fill_width(mut self) -> Self {
+        self.style = self.style.fill_width();
+        self
+    }
+
+    /// Makes the [`FloatingLayer`] fill all the vertical available space.
+    ///
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn fill_height(mut self) -> Self {
+        self.style = self.style.fill_height();
+        self
+    }
+
+    /// Adds a [`Floating`] panel to the [`FloatingLayer`].
+    ///
+    /// Panels are drawn in the order they were last clicked, and in the
+    /// order they were pushed otherwise.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn push(mut self, panel: Floating<'a, Message, Renderer>) -> Self {
+        let raised_at = panel.state.raised_at;
+
+        let index = self
+            .panels
+            .iter()
+            .position(|other| other.state.raised_at > raised_at)
+            .unwrap_or(self.panels.len());
+
+        self.panels.insert(index, panel);
+        self
+    }
+
+    // The index of the topmost panel under the cursor, if any
+    fn topmost_hovered(
+        &self,
+        layouts: &[Layout<'_>],
+        cursor_position: Point,
+    ) -> Option<usize> {
+        layouts
+            .iter()
+            .rposition(|layout| layout.bounds().contains(cursor_position))
+    }
+
+    fn dragged(&self) -> Option<usize> {
+        self.panels
+            .iter()
+            .position(|panel| panel.state.interaction.is_some())
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for FloatingLayer<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let children = std::iter::once(self.base.widget.node(renderer))
+            .chain(self.panels.iter().map(|panel| panel.node(renderer)))
+            .collect();
+
+        Node::with_children(self.style, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let area = layout.bounds();
+        let mut children = layout.children();
+
+        let base = match children.next() {
+            Some(base) => base,
+            None => return,
+        };
+
+        let panels: Vec<_> = children.collect();
+        let topmost = self.topmost_hovered(&panels, cursor_position);
+
+        let is_pointed = match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if let Some(index) = topmost {
+                    let bounds = panels[index].bounds();
+                    let panel = &mut self.panels[index];
+
+                    panel.state.raised_at = Some(Instant::now());
+
+                    let part = [
+                        Part::CollapseButton,
+                        Part::TitleBar,
+                        Part::ResizeHandle,
+                    ]
+                    .iter()
+                    .cloned()
+                    .filter(|part| {
+                        *part != Part::ResizeHandle || !panel.state.is_collapsed
+                    })
+                    .find(|part| part.bounds(bounds).contains(cursor_position));
+
+                    match part {
+                        Some(Part::CollapseButton) => {
+                            panel.state.is_collapsed =
+                                !panel.state.is_collapsed;
+                            return;
+                        }
+                        Some(Part::TitleBar) => {
+                            panel.state.interaction =
+                                Some(Interaction::Moving {
+                                    grab: cursor_position
+                                        - Point::new(bounds.x, bounds.y),
+                                });
+                            return;
+                        }
+                        Some(Part::ResizeHandle) => {
+                            panel.state.interaction =
+                                Some(Interaction::Resizing {
+                                    grab: cursor_position
+                                        - Point::new(
+                                            bounds.x + bounds.width,
+                                            bounds.y + bounds.height,
+                                        ),
+                                });
+                            return;
+                        }
+                        None => {}
+                    }
+                }
+
+                true
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                for panel in &mut self.panels {
+                    panel.state.interaction = None;
+                }
+
+                false
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.dragged() {
+                    self.panels[index].drag(cursor_position, area);
+                    return;
+                }
+
+                false
+            }
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            })
+            | Event::Mouse(mouse::Event::WheelScrolled { .. }) => true,
+            _ => false,
+        };
+
+        // Only the topmost panel under the cursor gets clicks and scrolls
+        let cursor_for = |index: Option<usize>| {
+            if is_pointed && topmost.is_some() && index != topmost {
+                hidden_cursor()
+            } else {
+                cursor_position
+            }
+        };
+
+        for (index, (panel, layout)) in
+            self.panels.iter_mut().zip(panels).enumerate().rev()
+        {
+            if let Some(content) = layout.children().next() {
+                panel.content.widget.on_event(
+                    event,
+                    content,
+                    cursor_for(Some(index)),
+                    messages,
+                );
+            }
+        }
+
+        self.base
+            .widget
+            .on_event(event, base, cursor_for(None), messages);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let mut children = layout.children();
+
+        let base = match children.next() {
+            Some(base) => base,
+            None => return MouseCursor::OutOfBounds,
+        };
+
+        let panels: Vec<_> = children.collect();
+        let dragged = self.dragged();
+
+        let topmost =
+            dragged.or_else(|| self.topmost_hovered(&panels, cursor_position));
+
+        let cursor_for = |index: Option<usize>| {
+            if topmost.is_some() && index != topmost {
+                hidden_cursor()
+            } else {
+                cursor_position
+            }
+        };
+
+        let mut cursor =
+            self.base.widget.draw(renderer, base, cursor_for(None));
+
+        for (index, (panel, layout)) in
+            self.panels.iter().zip(panels).enumerate()
+        {
+            renderer.start_layer();
+
+            let cursor_position = cursor_for(Some(index));
+
+            let frame_cursor = renderer.draw_floating(
+                cursor_position,
+                layout.bounds(),
+                &panel.title,
+                panel.state,
+                panel.theme_style,
+            );
+
+            let content_cursor = match layout.children().next() {
+                Some(content) => panel.content.widget.draw(
+                    renderer,
+                    content,
+                    cursor_position,
+                ),
+                None => MouseCursor::OutOfBounds,
+            };
+
+            if topmost == Some(index) {
+                cursor = if content_cursor == MouseCursor::OutOfBounds {
+                    frame_cursor
+                } else {
+                    content_cursor
+                };
+            }
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.base.widget.hash(state);
+
+        for panel in &self.panels {
+            let bounds = panel.state.visible_bounds();
+
+            (bounds.x as i32).hash(state);
+            (bounds.y as i32).hash(state);
+            (bounds.width as i32).hash(state);
+            (bounds.height as i32).hash(state);
+            panel.theme_style.map(|style| style.padding).hash(state);
+
+            panel.content.widget.hash(state);
+        }
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.base.widget.captures_keyboard()
+            || self
+                .panels
+                .iter()
+                .any(|panel| panel.content.widget.captures_keyboard())
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        let mut children = layout.children();
+
+        if let Some(base) = children.next() {
+            self.base.widget.focus(base, focus);
+        }
+
+        for (panel, layout) in self.panels.iter_mut().zip(children) {
+            if let Some(content) = layout.children().next() {
+                panel.content.widget.focus(content, focus);
+            }
+        }
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let area = layout.bounds();
+        let mut children = layout.children();
+
+        if let Some(base) = children.next() {
+            self.base.widget.tick(base, cursor_position, messages);
+        }
+
+        for (panel, layout) in self.panels.iter_mut().zip(children) {
+            // The layer may have shrunk, like when the window is resized
+            panel.state.clamp(area);
+
+            if let Some(content) = layout.children().next() {
+                panel
+                    .content
+                    .widget
+                    .tick(content, cursor_position, messages);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<FloatingLayer<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + core::Renderer + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        layer: FloatingLayer<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(layer)
+    }
+}
+
+/// A part of a [`Floating`] panel.
+///
+/// [`Floating`]: struct.Floating.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    /// The title bar, used to move the panel.
+    TitleBar,
+
+    /// The button that collapses the panel, at the end of the title bar.
+    CollapseButton,
+
+    /// The bottom-right corner, used to resize the panel.
+    ResizeHandle,
+}
+
+impl Part {
+    /// Returns the bounds of the [`Part`], given the bounds of its
+    /// [`Floating`] panel.
+    ///
+    /// [`Part`]: enum.Part.html
+    /// [`Floating`]: struct.Floating.html
+    pub fn bounds(self, bounds: Rectangle<f32>) -> Rectangle<f32> {
+        let bar = TITLE_BAR_HEIGHT.min(bounds.height);
+
+        match self {
+            Part::TitleBar => Rectangle {
+                width: (bounds.width - bar).max(0.0),
+                height: bar,
+                ..bounds
+            },
+            Part::CollapseButton => Rectangle {
+                x: bounds.x + bounds.width - bar,
+                width: bar,
+                height: bar,
+                ..bounds
+            },
+            Part::ResizeHandle => Rectangle {
+                x: bounds.x + bounds.width - RESIZE_HANDLE_SIZE,
+                y: bounds.y + bounds.height - RESIZE_HANDLE_SIZE,
+                width: RESIZE_HANDLE_SIZE,
+                height: RESIZE_HANDLE_SIZE,
+            },
+        }
+    }
+}
+
+/// The local state of a [`Floating`] panel.
+///
+/// Keep it in your game to remember where the panel is between frames.
+///
+/// [`Floating`]: struct.Floating.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    bounds: Rectangle<f32>,
+    is_collapsed: bool,
+    interaction: Option<Interaction>,
+    raised_at: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Interaction {
+    // The cursor position relative to the top-left corner of the panel
+    Moving { grab: Vector },
+
+    // The cursor position relative to the bottom-right corner of the panel
+    Resizing { grab: Vector },
+}
+
+impl State {
+    /// Creates a new [`State`] for a panel of 300x200 pixels, placed near
+    /// the top-left corner of its [`FloatingLayer`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn new() -> State {
+        State::with_bounds(Rectangle {
+            x: 20.0,
+            y: 20.0,
+            width: 300.0,
+            height: 200.0,
+        })
+    }
+
+    /// Creates a new [`State`] for a panel with the given bounds, relative
+    /// to the top-left corner of its [`FloatingLayer`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn with_bounds(bounds: Rectangle<f32>) -> State {
+        State {
+            bounds,
+            is_collapsed: false,
+            interaction: None,
+            raised_at: None,
+        }
+    }
+
+    /// Returns the bounds of the associated [`Floating`] panel, relative to
+    /// the top-left corner of its [`FloatingLayer`].
+    ///
+    /// The height does not change when the panel is collapsed.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    /// [`FloatingLayer`]: struct.FloatingLayer.html
+    pub fn bounds(&self) -> Rectangle<f32> {
+        self.bounds
+    }
+
+    /// Returns whether the associated [`Floating`] panel is collapsed or not.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    pub fn is_collapsed(&self) -> bool {
+        self.is_collapsed
+    }
+
+    /// Collapses or expands the associated [`Floating`] panel.
+    ///
+    /// [`Floating`]: struct.Floating.html
+    pub fn set_collapsed(&mut self, is_collapsed: bool) {
+        self.is_collapsed = is_collapsed;
+    }
+
+    /// Returns the [`Part`] of the associated [`Floating`] panel being
+    /// dragged, if any.
+    ///
+    /// [`Part`]: enum.Part.html
+    /// [`Floating`]: struct.Floating.html
+    pub fn dragged(&self) -> Option<Part> {
+        self.interaction.map(|interaction| match interaction {
+            Interaction::Moving { .. } => Part::TitleBar,
+            Interaction::Resizing { .. } => Part::ResizeHandle,
+        })
+    }
+
+    // The bounds of the panel on screen, which only shows its title bar when
+    // collapsed
+    fn visible_bounds(&self) -> Rectangle<f32> {
+        Rectangle {
+            height: if self.is_collapsed {
+                TITLE_BAR_HEIGHT
+            } else {
+                self.bounds.height
+            },
+            ..self.bounds
+        }
+    }
+
+    // Keeps the panel inside of the given area
+    fn clamp(&mut self, area: Rectangle<f32>) {
+        let bounds = &mut self.bounds;
+
+        bounds.width =
+            bounds.width.max(MIN_WIDTH).min(area.width.max(MIN_WIDTH));
+        bounds.height = bounds
+            .height
+            .max(MIN_HEIGHT)
+            .min(area.height.max(MIN_HEIGHT));
+
+        let height = if self.is_collapsed {
+            TITLE_BAR_HEIGHT
+        } else {
+            bounds.height
+        };
+
+        bounds.x = bounds.x.min(area.width - bounds.width).max(0.0);
+        bounds.y = bounds.y.min(area.height - height).max(0.0);
+    }
+}
+
+impl Default for State {
+    fn default() -> State {
+        State::new()
+    }
+}
@@ -0,0 +1,423 @@
+//! Write paragraphs of formatted text.
+use crate::graphics::{
+    Color, FontStyle, HorizontalAlignment, Point, Rectangle,
+};
+use crate::ui::core::{
+    Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of [`Span`]s with different colors and styles.
+///
+/// A [`Paragraph`] wraps its content to the width given by the layout, and
+/// its height grows with the amount of lines. Therefore, it is a good fit for
+/// dialogs, descriptions, or changelogs.
+///
+/// You can build a [`Paragraph`] from a minimal markup with
+/// [`Paragraph::with_markup`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`paragraph::Renderer`] trait.
+///
+/// [`Span`]: struct.Span.html
+/// [`Paragraph`]: struct.Paragraph.html
+/// [`Paragraph::with_markup`]: #method.with_markup
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`paragraph::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::Color;
+/// use coffee::ui::paragraph::{Paragraph, Span};
+///
+/// Paragraph::new("The merchant offers you ")
+///     .push(Span::new("a rusty sword").color(Color::RED).bold())
+///     .push(Span::new(" for 3 gold coins."))
+///     .size(24);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    spans: Vec<Span>,
+    size: u16,
+    color: Option<Color>,
+    style: Style,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+impl Paragraph {
+    /// Creates a new [`Paragraph`] with the given plain text.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn new(content: &str) -> Self {
+        let spans = if content.is_empty() {
+            Vec::new()
+        } else {
+            vec![Span::new(content)]
+        };
+
+        Paragraph {
+            spans,
+            size: 20,
+            color: None,
+            style: Style::default().fill_width(),
+            horizontal_alignment: HorizontalAlignment::Left,
+        }
+    }
+
+    /// Creates a new [`Paragraph`] by parsing the given markup.
+    ///
+    /// The markup supports:
+    ///   * `**bold**` text
+    ///   * `*italic*` text
+    ///   * `[colored]{#ff0000}` text, using a hexadecimal RGB color
+    ///
+    /// Any other character is kept as it is. Use a backslash to write a
+    /// character that would be parsed otherwise, like `\*`.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::paragraph::Paragraph;
+    ///
+    /// let notes = Paragraph::with_markup(
+    ///     "**Version 1.2** fixes a [crash]{#ff4040} when *saving* the game.",
+    /// );
+    ///
+    /// assert_eq!(notes.spans().len(), 6);
+    /// ```
+    pub fn with_markup(markup: &str) -> Self {
+        Paragraph {
+            spans: parse(markup),
+            ..Paragraph::new("")
+        }
+    }
+
+    /// Adds a [`Span`] at the end of the [`Paragraph`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn push(mut self, span: Span) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the size of the text of the [`Paragraph`] in pixels.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the color of the [`Span`]s of the [`Paragraph`] that do not have
+    /// a color of their own.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the width of the [`Paragraph`] boundaries in pixels.
+    ///
+    /// By default, a [`Paragraph`] fills the width of its container.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the lines of the [`Paragraph`].
+    ///
+    /// [`HorizontalAlignment`]: ../../../graphics/enum.HorizontalAlignment.html
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Returns the [`Span`]s of the [`Paragraph`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Paragraph
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        renderer.node(self.style, &self.spans, f32::from(self.size))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(
+            layout.bounds(),
+            &self.spans,
+            f32::from(self.size),
+            self.color,
+            self.horizontal_alignment,
+        );
+
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.size.hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.style.hash(state);
+        }
+    }
+}
+
+/// A piece of a [`Paragraph`] with its own color and style.
+///
+/// [`Paragraph`]: struct.Paragraph.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The text of the [`Span`]
+    ///
+    /// [`Span`]: struct.Span.html
+    pub content: String,
+
+    /// The color of the [`Span`], if it has one
+    ///
+    /// [`Span`]: struct.Span.html
+    pub color: Option<Color>,
+
+    /// The [`FontStyle`] of the [`Span`]
+    ///
+    /// [`FontStyle`]: ../../../graphics/enum.FontStyle.html
+    /// [`Span`]: struct.Span.html
+    pub style: FontStyle,
+}
+
+impl Span {
+    /// Creates a new [`Span`] with the given text.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new(content: &str) -> Span {
+        Span {
+            content: String::from(content),
+            color: None,
+            style: FontStyle::Regular,
+        }
+    }
+
+    /// Sets the color of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn color(mut self, color: Color) -> Span {
+        self.color = Some(color);
+        self
+    }
+
+    /// Makes the [`Span`] bold.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn bold(mut self) -> Span {
+        self.style = style(true, is_italic(self.style));
+        self
+    }
+
+    /// Makes the [`Span`] italic.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn italic(mut self) -> Span {
+        self.style = style(is_bold(self.style), true);
+        self
+    }
+}
+
+/// The renderer of a [`Paragraph`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Paragraph`] in your user interface.
+///
+/// [`Paragraph`]: struct.Paragraph.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Creates a [`Node`] with the given [`Style`] for the provided [`Span`]s
+    /// and size.
+    ///
+    /// The [`Node`] must be measured using the width given by the layout
+    /// engine, so its height accounts for the wrapped lines. You can use
+    /// [`Node::with_measure`] to do this.
+    ///
+    /// [`Node`]: ../../core/struct.Node.html
+    /// [`Style`]: ../../core/struct.Style.html
+    /// [`Span`]: struct.Span.html
+    /// [`Node::with_measure`]: ../../core/struct.Node.html#method.with_measure
+    fn node(&self, style: Style, spans: &[Span], size: f32) -> Node;
+
+    /// Draws a [`Paragraph`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Paragraph`]
+    ///   * the [`Span`]s of the [`Paragraph`]
+    ///   * the size of the text
+    ///   * the color of the [`Span`]s without one, if any
+    ///   * the [`HorizontalAlignment`] of the lines
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    /// [`Span`]: struct.Span.html
+    /// [`HorizontalAlignment`]: ../../../graphics/enum.HorizontalAlignment.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        spans: &[Span],
+        size: f32,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+    );
+}
+
+impl<'a, Message, Renderer> From<Paragraph> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(paragraph: Paragraph) -> Element<'a, Message, Renderer> {
+        Element::new(paragraph)
+    }
+}
+
+fn style(is_bold: bool, is_italic: bool) -> FontStyle {
+    match (is_bold, is_italic) {
+        (false, false) => FontStyle::Regular,
+        (true, false) => FontStyle::Bold,
+        (false, true) => FontStyle::Italic,
+        (true, true) => FontStyle::BoldItalic,
+    }
+}
+
+fn is_bold(style: FontStyle) -> bool {
+    style == FontStyle::Bold || style == FontStyle::BoldItalic
+}
+
+fn is_italic(style: FontStyle) -> bool {
+    style == FontStyle::Italic || style == FontStyle::BoldItalic
+}
+
+// Turns markup into spans, keeping any malformed markup as plain text
+fn parse(markup: &str) -> Vec<Span> {
+    let chars: Vec<char> = markup.chars().collect();
+
+    let mut spans = Vec::new();
+    let mut content = String::new();
+    let mut is_bold = false;
+    let mut is_italic = false;
+
+    // The color of the current colored span and the position where it ends
+    let mut color: Option<(Color, usize)> = None;
+
+    let mut i = 0;
+
+    while i < chars.len() {
+        let current =
+            (color.map(|(color, _)| color), style(is_bold, is_italic));
+
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                content.push(chars[i + 1]);
+                i += 2;
+            }
+            '*' => {
+                flush(&mut spans, &mut content, current);
+
+                if chars.get(i + 1) == Some(&'*') {
+                    is_bold = !is_bold;
+                    i += 2;
+                } else {
+                    is_italic = !is_italic;
+                    i += 1;
+                }
+            }
+            '[' if color.is_none() => {
+                if let Some(tag) = color_tag(&chars, i) {
+                    flush(&mut spans, &mut content, current);
+                    color = Some(tag);
+                } else {
+                    content.push('[');
+                }
+
+                i += 1;
+            }
+            ']' if color.map(|(_, end)| end) == Some(i) => {
+                flush(&mut spans, &mut content, current);
+
+                color = None;
+                i += 1 + COLOR_TAG_LENGTH;
+            }
+            c => {
+                content.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    flush(
+        &mut spans,
+        &mut content,
+        (color.map(|(color, _)| color), style(is_bold, is_italic)),
+    );
+
+    spans
+}
+
+// Turns the pending content into a span, if there is any
+fn flush(
+    spans: &mut Vec<Span>,
+    content: &mut String,
+    (color, style): (Option<Color>, FontStyle),
+) {
+    if !content.is_empty() {
+        spans.push(Span {
+            content: std::mem::replace(content, String::new()),
+            color,
+            style,
+        });
+    }
+}
+
+// The length of a color tag, like `{#ff0000}`
+const COLOR_TAG_LENGTH: usize = 9;
+
+// Finds the end of a colored span starting at the given position, and parses
+// its color
+fn color_tag(chars: &[char], start: usize) -> Option<(Color, usize)> {
+    let end = start + chars[start..].iter().position(|c| *c == ']')?;
+
+    let tag: String = chars
+        .get(end + 1..end + 1 + COLOR_TAG_LENGTH)?
+        .iter()
+        .collect();
+
+    if !tag.is_ascii() || !tag.starts_with("{#") || !tag.ends_with('}') {
+        return None;
+    }
+
+    let rgb = u32::from_str_radix(&tag[2..8], 16).ok()?;
+
+    Some((Color::from_rgb_u32(rgb), end))
+}
@@ -1,44 +1,63 @@
 //! Displays action progress to your users.
+use std::hash::Hash;
+use std::ops::RangeInclusive;
 
-use crate::graphics::{
-    Point, Rectangle,
-};
+use crate::graphics::{Color, Point, Rectangle};
 use crate::ui::core::{
-    Style, Node, Element, MouseCursor, Layout, Hasher, Widget,
+    Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
 };
 
-use std::hash::Hash;
-
-/// A widget that displays a progress of an action.
-/// 
+/// A widget that displays the progress of an action.
+///
+/// A [`ProgressBar`] will try to fill the horizontal space of its container.
+///
 /// It implements [`Widget`] when the associated [`core::Renderer`] implements
-/// the [`button::Renderer`] trait.
+/// the [`progress_bar::Renderer`] trait.
 ///
+/// [`ProgressBar`]: struct.ProgressBar.html
 /// [`Widget`]: ../../core/trait.Widget.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 /// [`progress_bar::Renderer`]: trait.Renderer.html
+///
 /// # Example
 ///
 /// ```
+/// use coffee::graphics::Color;
 /// use coffee::ui::ProgressBar;
 ///
-/// let progress = 0.75;
+/// let health = 75.0;
 ///
-/// ProgressBar::new(progress);
+/// ProgressBar::new(0.0..=100.0, health)
+///     .fill_color(Color::RED)
+///     .label("75 / 100");
 /// ```
 #[derive(Debug)]
 pub struct ProgressBar {
-    progress: f32,
+    range: RangeInclusive<f32>,
+    value: f32,
+    fill_color: Option<Color>,
+    background_color: Option<Color>,
+    label: Option<String>,
     style: Style,
 }
 
 impl ProgressBar {
-    /// Creates a new [`ProgressBar`] with given progress.
+    /// Creates a new [`ProgressBar`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`ProgressBar`]
+    ///
+    /// Values outside of the range are clamped.
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
-    pub fn new(progress: f32) -> Self {
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
         ProgressBar {
-            progress,
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            fill_color: None,
+            background_color: None,
+            label: None,
             style: Style::default().fill_width(),
         }
     }
@@ -58,11 +77,47 @@ impl ProgressBar {
         self.style = self.style.fill_width();
         self
     }
+
+    /// Sets the [`Color`] of the filled part of the [`ProgressBar`].
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    /// Sets the background [`Color`] of the [`ProgressBar`].
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Sets a label drawn on top of the [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    fn progress(&self) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if end > start {
+            (self.value - start) / (end - start)
+        } else {
+            1.0
+        }
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for ProgressBar
 where
-    Renderer: self::Renderer 
+    Renderer: self::Renderer,
 {
     fn node(&self, _renderer: &Renderer) -> Node {
         Node::new(self.style.height(50))
@@ -76,7 +131,12 @@ where
     ) -> MouseCursor {
         renderer.draw(
             layout.bounds(),
-            self.progress,
+            self.progress(),
+            Colors {
+                fill: self.fill_color,
+                background: self.background_color,
+            },
+            self.label.as_deref(),
         );
 
         MouseCursor::OutOfBounds
@@ -87,6 +147,20 @@ where
     }
 }
 
+/// The custom colors of a [`ProgressBar`].
+///
+/// A `None` color means the renderer should use its default.
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Colors {
+    /// The color of the filled part
+    pub fill: Option<Color>,
+
+    /// The color of the background
+    pub background: Option<Color>,
+}
+
 /// The renderer of a [`ProgressBar`].
 ///
 /// Your [`core::Renderer`] will need to implement this trait before being
@@ -99,13 +173,18 @@ pub trait Renderer {
     ///
     /// It receives:
     ///   * the bounds of the [`ProgressBar`]
-    ///   * the progress of the [`ProgressBar`]
-    ///   
+    ///   * the progress of the [`ProgressBar`], between `0.0` and `1.0`
+    ///   * the custom [`Colors`] of the [`ProgressBar`]
+    ///   * the label of the [`ProgressBar`], if any
+    ///
     /// [`ProgressBar`]: struct.ProgressBar.html
+    /// [`Colors`]: struct.Colors.html
     fn draw(
         &mut self,
         bounds: Rectangle<f32>,
         progress: f32,
+        colors: Colors,
+        label: Option<&str>,
     );
 }
 
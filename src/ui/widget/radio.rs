@@ -2,10 +2,13 @@
 use crate::graphics::{
     Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
 };
+use crate::input::keyboard::{self, KeyCode};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Action, Align, Element, Event, Focus, Hasher, Id, Layout, MouseCursor,
+    Node, Style, Widget,
 };
+use crate::ui::theme;
 use crate::ui::widget::{text, Column, Row, Text};
 
 use std::hash::Hash;
@@ -54,7 +57,11 @@ pub struct Radio<Message> {
     is_selected: bool,
     on_click: Message,
     label: String,
-    label_color: Color,
+    label_color: Option<Color>,
+    theme_style: Option<theme::Style>,
+    is_disabled: bool,
+    id: Option<Id>,
+    is_focused: bool,
 }
 
 impl<Message> std::fmt::Debug for Radio<Message>
@@ -67,6 +74,9 @@ where
             .field("on_click", &self.on_click)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("theme_style", &self.theme_style)
+            .field("is_disabled", &self.is_disabled)
+            .field("id", &self.id)
             .finish()
     }
 }
@@ -84,14 +94,18 @@ impl<Message> Radio<Message> {
     /// [`Radio`]: struct.Radio.html
     pub fn new<F, V>(value: V, label: &str, selected: Option<V>, f: F) -> Self
     where
-        V: Eq + Copy,
+        V: Eq,
         F: 'static + Fn(V) -> Message,
     {
         Radio {
-            is_selected: Some(value) == selected,
+            is_selected: selected.as_ref() == Some(&value),
             on_click: f(value),
             label: String::from(label),
-            label_color: Color::WHITE,
+            label_color: None,
+            theme_style: None,
+            is_disabled: false,
+            id: None,
+            is_focused: false,
         }
     }
 
@@ -100,7 +114,41 @@ impl<Message> Radio<Message> {
     /// [`Color`]: ../../../../graphics/struct.Color.html
     /// [`Radio`]: struct.Radio.html
     pub fn label_color(mut self, color: Color) -> Self {
-        self.label_color = color;
+        self.label_color = Some(color);
+        self
+    }
+
+    /// Sets the [`theme::Style`] of the [`Radio`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Radio`]: struct.Radio.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Sets whether the [`Radio`] is disabled or not.
+    ///
+    /// A disabled [`Radio`] does not react to user interactions and never
+    /// produces messages. It is drawn using the disabled [`Appearance`] of
+    /// its style, and its layout does not change.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Radio`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Radio`]: struct.Radio.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
         self
     }
 }
@@ -108,7 +156,7 @@ impl<Message> Radio<Message> {
 impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message>
 where
     Renderer: self::Renderer + text::Renderer,
-    Message: Copy + std::fmt::Debug,
+    Message: Clone + std::fmt::Debug,
 {
     fn node(&self, renderer: &Renderer) -> Node {
         Row::<(), Renderer>::new()
@@ -126,13 +174,25 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            return;
+        }
+
+        if self.is_focused
+            && Action::from_event(&event) == Some(Action::Activate)
+        {
+            messages.push(self.on_click.clone());
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
                 if layout.bounds().contains(cursor_position) {
-                    messages.push(self.on_click);
+                    messages.push(self.on_click.clone());
                 }
             }
             _ => {}
@@ -166,12 +226,390 @@ where
             children[0].bounds(),
             layout.bounds(),
             self.is_selected,
+            self.theme_style,
+            self.is_disabled,
         )
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.is_focused = if self.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            false
+        } else {
+            focus.register(self.id, layout.bounds(), false)
+        };
+    }
+}
+
+/// A group of [`Radio`] buttons representing mutually exclusive choices.
+///
+/// A [`RadioGroup`] lays out its options in a column, or in a row if it is
+/// [`horizontal`], and produces a message with the value of the option that
+/// gets selected.
+///
+/// The whole group takes a single place in the focus order. When focused, the
+/// arrow keys, or the d-pad of a gamepad, select the previous or the next
+/// option, wrapping around.
+///
+/// It implements [`Widget`] when the [`core::Renderer`] implements the
+/// [`radio::Renderer`] trait.
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+/// [`horizontal`]: #method.horizontal
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`radio::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::RadioGroup;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Difficulty {
+///     Easy,
+///     Normal,
+///     Hard,
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     DifficultySelected(Difficulty),
+/// }
+///
+/// let difficulty = Difficulty::Normal;
+///
+/// RadioGroup::new(
+///     &[
+///         (Difficulty::Easy, "Easy"),
+///         (Difficulty::Normal, "Normal"),
+///         (Difficulty::Hard, "Hard"),
+///     ],
+///     Some(difficulty),
+///     Message::DifficultySelected,
+/// )
+/// .horizontal();
+/// ```
+pub struct RadioGroup<T, Message> {
+    values: Vec<T>,
+    radios: Vec<Radio<Message>>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message>,
+    spacing: u16,
+    is_horizontal: bool,
+    is_disabled: bool,
+    id: Option<Id>,
+    is_focused: bool,
+}
+
+impl<T, Message> std::fmt::Debug for RadioGroup<T, Message>
+where
+    T: std::fmt::Debug,
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioGroup")
+            .field("values", &self.values)
+            .field("radios", &self.radios)
+            .field("selected", &self.selected)
+            .field("spacing", &self.spacing)
+            .field("is_horizontal", &self.is_horizontal)
+            .field("is_disabled", &self.is_disabled)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T, Message> RadioGroup<T, Message>
+where
+    T: Clone + Eq,
+{
+    /// Creates a new [`RadioGroup`].
+    ///
+    /// It expects:
+    ///   * the options of the [`RadioGroup`], as pairs of values and labels
+    ///   * the current selected value
+    ///   * a function that will be called when an option is selected. It
+    ///   receives the value of the option and must produce a `Message`.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn new<F>(options: &[(T, &str)], selected: Option<T>, f: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let radios = options
+            .iter()
+            .map(|(value, &label)| Radio {
+                is_selected: selected.as_ref() == Some(value),
+                on_click: f(value.clone()),
+                label: String::from(label),
+                label_color: None,
+                theme_style: None,
+                is_disabled: false,
+                id: None,
+                is_focused: false,
+            })
+            .collect();
+
+        RadioGroup {
+            values: options.iter().map(|(value, _)| value.clone()).collect(),
+            radios,
+            selected: options
+                .iter()
+                .position(|(value, _)| selected.as_ref() == Some(value)),
+            on_select: Box::new(f),
+            spacing: 20,
+            is_horizontal: false,
+            is_disabled: false,
+            id: None,
+            is_focused: false,
+        }
+    }
+
+    /// Lays out the options of the [`RadioGroup`] in a row, instead of a
+    /// column.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn horizontal(mut self) -> Self {
+        self.is_horizontal = true;
+        self
+    }
+
+    /// Sets the spacing _between_ the options of the [`RadioGroup`] in
+    /// pixels.
+    ///
+    /// By default, it is 20 pixels.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, px: u16) -> Self {
+        self.spacing = px;
+        self
+    }
+
+    /// Sets the [`Color`] of the labels of the [`RadioGroup`].
+    ///
+    /// [`Color`]: ../../../../graphics/struct.Color.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn label_color(mut self, color: Color) -> Self {
+        for radio in &mut self.radios {
+            radio.label_color = Some(color);
+        }
+
+        self
+    }
+
+    /// Sets the [`theme::Style`] of the options of the [`RadioGroup`],
+    /// overriding the one in the current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        for radio in &mut self.radios {
+            radio.theme_style = Some(style);
+        }
+
+        self
+    }
+
+    /// Sets whether the [`RadioGroup`] is disabled or not.
+    ///
+    /// A disabled [`RadioGroup`] does not react to user interactions and
+    /// never produces messages. Its options are drawn using the disabled
+    /// [`Appearance`] of their style, and its layout does not change.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+
+        for radio in &mut self.radios {
+            radio.is_disabled = is_disabled;
+        }
+
+        self
+    }
+
+    /// Sets the [`Id`] of the [`RadioGroup`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    fn select(&self, index: usize, messages: &mut Vec<Message>) {
+        if Some(index) != self.selected {
+            messages.push((self.on_select)(self.values[index].clone()));
+        }
+    }
+}
+
+impl<T, Message, Renderer> Widget<Message, Renderer> for RadioGroup<T, Message>
+where
+    T: Clone + Eq,
+    Renderer: self::Renderer + text::Renderer,
+    Message: Clone + std::fmt::Debug,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut style = Style::default();
+
+        if !self.is_horizontal {
+            style.0.flex_direction = stretch::style::FlexDirection::Column;
+        }
+
+        let spacing =
+            stretch::style::Dimension::Points(f32::from(self.spacing));
+        let last = self.radios.len().saturating_sub(1);
+
+        let children = self
+            .radios
+            .iter()
+            .enumerate()
+            .map(|(i, radio)| {
+                let mut node = radio.node(renderer);
+
+                if i < last {
+                    let mut style = node.0.style();
+
+                    if self.is_horizontal {
+                        style.margin.end = spacing;
+                    } else {
+                        style.margin.bottom = spacing;
+                    }
+
+                    node.0.set_style(style);
+                }
+
+                node
+            })
+            .collect();
+
+        Node::with_children(style, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.is_disabled || self.values.is_empty() {
+            return;
+        }
+
+        if self.is_focused {
+            let last = self.values.len() - 1;
+
+            let direction = match event {
+                Event::Keyboard(keyboard::Event::Input {
+                    state: ButtonState::Pressed,
+                    key_code: Some(KeyCode::Up),
+                    ..
+                }) => Some(Action::Decrease),
+                Event::Keyboard(keyboard::Event::Input {
+                    state: ButtonState::Pressed,
+                    key_code: Some(KeyCode::Down),
+                    ..
+                }) => Some(Action::Increase),
+                _ => Action::from_event(&event),
+            };
+
+            match direction {
+                Some(Action::Activate) => {
+                    self.select(self.selected.unwrap_or(0), messages);
+                    return;
+                }
+                Some(Action::Decrease) => {
+                    let previous = match self.selected {
+                        Some(0) | None => last,
+                        Some(i) => i - 1,
+                    };
+
+                    self.select(previous, messages);
+                    return;
+                }
+                Some(Action::Increase) => {
+                    let next = match self.selected {
+                        Some(i) if i < last => i + 1,
+                        _ => 0,
+                    };
+
+                    self.select(next, messages);
+                    return;
+                }
+                None => {}
+            }
+        }
+
+        self.radios.iter_mut().zip(layout.children()).for_each(
+            |(radio, layout)| {
+                Widget::<Message, Renderer>::on_event(
+                    radio,
+                    event,
+                    layout,
+                    cursor_position,
+                    messages,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let mut cursor = MouseCursor::OutOfBounds;
+
+        self.radios.iter().zip(layout.children()).for_each(
+            |(radio, layout)| {
+                let new_cursor = radio.draw(renderer, layout, cursor_position);
+
+                if new_cursor != MouseCursor::OutOfBounds {
+                    cursor = new_cursor;
+                }
+            },
+        );
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.spacing.hash(state);
+        self.is_horizontal.hash(state);
+
+        for radio in &self.radios {
+            radio.label.hash(state);
+        }
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        // The group is focused through its selected option, or its first one
+        let bounds = layout
+            .children()
+            .nth(self.selected.unwrap_or(0))
+            .map(|layout| layout.bounds());
+
+        self.is_focused = match bounds {
+            Some(bounds) if !self.is_disabled => {
+                focus.register(self.id, bounds, false)
+            }
+            Some(bounds) => {
+                focus.skip(self.id, bounds);
+                false
+            }
+            None => false,
+        };
+    }
 }
 
 /// The renderer of a [`Radio`] button.
@@ -189,14 +627,19 @@ pub trait Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * the bounds of the label of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
+    ///   * the custom [`theme::Style`] of the [`Radio`], if any
+    ///   * whether the [`Radio`] is disabled or not
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
     fn draw(
         &mut self,
         cursor_position: Point,
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_selected: bool,
+        style: Option<theme::Style>,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
@@ -204,9 +647,21 @@ impl<'a, Message, Renderer> From<Radio<Message>>
     for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer,
-    Message: 'static + Copy + std::fmt::Debug,
+    Message: 'static + Clone + std::fmt::Debug,
 {
     fn from(checkbox: Radio<Message>) -> Element<'a, Message, Renderer> {
         Element::new(checkbox)
     }
 }
+
+impl<'a, T, Message, Renderer> From<RadioGroup<T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Eq,
+    Renderer: self::Renderer + text::Renderer,
+    Message: 'static + Clone + std::fmt::Debug,
+{
+    fn from(group: RadioGroup<T, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(group)
+    }
+}
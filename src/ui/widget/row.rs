@@ -2,8 +2,8 @@ use std::hash::Hash;
 
 use crate::graphics::Point;
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
-    Widget,
+    Align, Element, Event, Focus, Hasher, Justify, Layout, MouseCursor, Node,
+    Style, Widget,
 };
 
 /// A container that places its contents horizontally.
@@ -205,6 +205,32 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
             child.widget.hash(state);
         }
     }
+
+    fn captures_keyboard(&self) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.widget.captures_keyboard())
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.widget.focus(layout, focus));
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.tick(layout, cursor_position, messages)
+            },
+        );
+    }
 }
 
 impl<'a, Message, Renderer> From<Row<'a, Message, Renderer>>
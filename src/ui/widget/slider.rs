@@ -6,17 +6,29 @@
 //! [`State`]: struct.State.html
 use std::hash::Hash;
 use std::ops::RangeInclusive;
+use std::time::Instant;
 
 use crate::graphics::{Point, Rectangle};
-use crate::input::{mouse, ButtonState};
+use crate::input::{mouse, ButtonState, Mouse};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Action, Element, Event, Focus, Hasher, Id, Layout, MouseCursor, Node,
+    Style, Widget,
 };
+use crate::ui::theme;
+use crate::ui::widget::tooltip;
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+// The fraction of the range that arrow keys move a focused slider
+const KEYBOARD_STEP: f32 = 0.05;
+
+/// A bar and a handle that selects a single value from a range of values.
+///
+/// A [`Slider`] is horizontal by default and will try to fill the horizontal
+/// space of its container. A vertical [`Slider`] fills the vertical space
+/// instead, and its values increase upwards.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// When focused, the arrow keys move the handle. The value can also snap to
+/// [`step`]s, be shown next to the cursor while dragging, and be reset by
+/// double-clicking.
 ///
 /// It implements [`Widget`] when the associated [`core::Renderer`] implements
 /// the [`slider::Renderer`] trait.
@@ -25,6 +37,7 @@ use crate::ui::core::{
 /// [`Widget`]: ../../core/trait.Widget.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 /// [`slider::Renderer`]: trait.Renderer.html
+/// [`step`]: #method.step
 ///
 /// # Example
 /// ```
@@ -37,7 +50,10 @@ use crate::ui::core::{
 /// let state = &mut slider::State::new();
 /// let value = 50.0;
 ///
-/// Slider::new(state, 0.0..=100.0, value, Message::SliderChanged);
+/// Slider::new(state, 0.0..=100.0, value, Message::SliderChanged)
+///     .step(5.0)
+///     .default_value(50.0)
+///     .value_label(|volume| format!("{}%", volume));
 /// ```
 ///
 /// ![Slider drawn by the built-in renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
@@ -45,8 +61,13 @@ pub struct Slider<'a, Message> {
     state: &'a mut State,
     range: RangeInclusive<f32>,
     value: f32,
+    step: Option<f32>,
+    default_value: Option<f32>,
     on_change: Box<dyn Fn(f32) -> Message>,
+    value_label: Option<Box<dyn Fn(f32) -> String>>,
     style: Style,
+    theme_style: Option<theme::Style>,
+    id: Option<Id>,
 }
 
 impl<'a, Message> std::fmt::Debug for Slider<'a, Message> {
@@ -55,7 +76,11 @@ impl<'a, Message> std::fmt::Debug for Slider<'a, Message> {
             .field("state", &self.state)
             .field("range", &self.range)
             .field("value", &self.value)
+            .field("step", &self.step)
+            .field("default_value", &self.default_value)
             .field("style", &self.style)
+            .field("theme_style", &self.theme_style)
+            .field("id", &self.id)
             .finish()
     }
 }
@@ -82,12 +107,20 @@ impl<'a, Message> Slider<'a, Message> {
     where
         F: 'static + Fn(f32) -> Message,
     {
+        state.is_disabled = false;
+        state.orientation = Orientation::Horizontal;
+
         Slider {
             state,
             value: value.max(*range.start()).min(*range.end()),
             range,
+            step: None,
+            default_value: None,
             on_change: Box::new(on_change),
+            value_label: None,
             style: Style::default().min_width(100).fill_width(),
+            theme_style: None,
+            id: None,
         }
     }
 
@@ -98,14 +131,140 @@ impl<'a, Message> Slider<'a, Message> {
         self.style = self.style.width(width);
         self
     }
+
+    /// Sets the height of the [`Slider`] in pixels.
+    ///
+    /// This is only useful for a vertical [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`Slider`].
+    ///
+    /// A vertical [`Slider`] will try to fill the vertical space of its
+    /// container.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.state.orientation = orientation;
+        self.style = match orientation {
+            Orientation::Horizontal => {
+                Style::default().min_width(100).fill_width()
+            }
+            Orientation::Vertical => {
+                Style::default().min_height(100).fill_height()
+            }
+        };
+        self
+    }
+
+    /// Makes the value of the [`Slider`] snap to multiples of the given step,
+    /// counting from the start of its range.
+    ///
+    /// The arrow keys move a focused [`Slider`] by one step.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        if step > 0.0 {
+            self.step = Some(step);
+        }
+
+        self
+    }
+
+    /// Sets the value that the [`Slider`] is reset to when double-clicked.
+    ///
+    /// By default, double-clicking does nothing special.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn default_value(mut self, value: f32) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Shows the value of the [`Slider`] next to the cursor while it is being
+    /// dragged.
+    ///
+    /// The given function turns the value into the text shown.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn value_label<F>(mut self, value_label: F) -> Self
+    where
+        F: 'static + Fn(f32) -> String,
+    {
+        self.value_label = Some(Box::new(value_label));
+        self
+    }
+
+    /// Sets the [`theme::Style`] of the [`Slider`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Slider`]: struct.Slider.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Slider`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`Slider`] is disabled or not.
+    ///
+    /// A disabled [`Slider`] does not react to user interactions and never
+    /// produces messages and it is drawn using the disabled [`Appearance`] of
+    /// its style. Its layout does not change.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        self.state.is_disabled = is_disabled;
+
+        if is_disabled {
+            self.state.is_dragging = false;
+        }
+
+        self
+    }
+
+    fn change(&self, value: f32, messages: &mut Vec<Message>) {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let value = value.max(start).min(end);
+
+        let value = match self.step {
+            Some(step) => {
+                (start + ((value - start) / step).round() * step).min(end)
+            }
+            None => value,
+        };
+
+        if (value - self.value).abs() > std::f32::EPSILON {
+            messages.push((self.on_change)(value));
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Slider<'a, Message>
 where
-    Renderer: self::Renderer,
+    Renderer: self::Renderer + tooltip::Renderer,
 {
     fn node(&self, _renderer: &Renderer) -> Node {
-        Node::new(self.style.height(25))
+        match self.state.orientation {
+            Orientation::Horizontal => Node::new(self.style.height(25)),
+            Orientation::Vertical => Node::new(self.style.width(25)),
+        }
     }
 
     fn on_event(
@@ -115,31 +274,69 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
-        let mut change = || {
-            let bounds = layout.bounds();
-
-            if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
-            } else {
-                let percent = (cursor_position.x - bounds.x) / bounds.width;
-                let value = (self.range.end() - self.range.start()) * percent
-                    + self.range.start();
-
-                messages.push((self.on_change)(value));
+        if self.state.is_disabled {
+            return;
+        }
+
+        if self.state.is_focused {
+            let (start, end) = (*self.range.start(), *self.range.end());
+            let step = self.step.unwrap_or((end - start) * KEYBOARD_STEP);
+
+            match Action::from_event(&event) {
+                Some(Action::Decrease) => {
+                    self.change(self.value - step, messages);
+                    return;
+                }
+                Some(Action::Increase) => {
+                    self.change(self.value + step, messages);
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let bounds = layout.bounds();
+
+        let percent = match self.state.orientation {
+            Orientation::Horizontal => {
+                (cursor_position.x - bounds.x) / bounds.width
+            }
+            Orientation::Vertical => {
+                1.0 - (cursor_position.y - bounds.y) / bounds.height
             }
         };
 
+        let value = (self.range.end() - self.range.start())
+            * percent.max(0.0).min(1.0)
+            + self.range.start();
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => match state {
                 ButtonState::Pressed => {
-                    if layout.bounds().contains(cursor_position) {
-                        change();
-                        self.state.is_dragging = true;
+                    if bounds.contains(cursor_position) {
+                        let now = Instant::now();
+
+                        let is_double_click =
+                            self.state.last_click.map_or(false, |last_click| {
+                                now.duration_since(last_click)
+                                    <= Mouse::DEFAULT_DOUBLE_CLICK_INTERVAL
+                            });
+
+                        match self.default_value {
+                            Some(default_value) if is_double_click => {
+                                self.change(default_value, messages);
+                                self.state.last_click = None;
+                            }
+                            _ => {
+                                self.change(value, messages);
+                                self.state.is_dragging = true;
+                                self.state.last_click = Some(now);
+                            }
+                        }
                     }
                 }
                 ButtonState::Released => {
@@ -148,7 +345,7 @@ where
             },
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if self.state.is_dragging {
-                    change();
+                    self.change(value, messages);
                 }
             }
             _ => {}
@@ -161,17 +358,42 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
+        let cursor = renderer.draw(
             cursor_position,
             layout.bounds(),
             self.state,
             self.range.clone(),
             self.value,
-        )
+            self.theme_style,
+        );
+
+        if let Some(value_label) = &self.value_label {
+            if self.state.is_dragging {
+                tooltip::Renderer::draw(
+                    renderer,
+                    cursor_position,
+                    layout.bounds(),
+                    &tooltip::Content::Text(value_label(self.value)),
+                    tooltip::Placement::FollowCursor,
+                );
+            }
+        }
+
+        cursor
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
+        self.state.orientation.hash(state);
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.state.is_focused = if self.state.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            false
+        } else {
+            focus.register(self.id, layout.bounds(), false)
+        };
     }
 }
 
@@ -181,6 +403,10 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_disabled: bool,
+    is_focused: bool,
+    orientation: Orientation,
+    last_click: Option<Instant>,
 }
 
 impl State {
@@ -198,6 +424,46 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Returns whether the associated [`Slider`] is disabled or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+
+    /// Returns whether the associated [`Slider`] is focused or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Orientation`] of the associated [`Slider`].
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+}
+
+/// The orientation of a [`Slider`].
+///
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// From left to right
+    Horizontal,
+
+    /// From bottom to top
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Orientation {
+        Orientation::Horizontal
+    }
 }
 
 /// The renderer of a [`Slider`].
@@ -213,13 +479,16 @@ pub trait Renderer {
     /// It receives:
     ///   * the current cursor position
     ///   * the bounds of the [`Slider`]
-    ///   * the local state of the [`Slider`]
+    ///   * the local state of the [`Slider`], which contains its
+    ///   [`Orientation`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * the custom [`theme::Style`] of the [`Slider`], if any
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
-    /// [`Class`]: enum.Class.html
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
     fn draw(
         &mut self,
         cursor_position: Point,
@@ -227,13 +496,14 @@ pub trait Renderer {
         state: &State,
         range: RangeInclusive<f32>,
         value: f32,
+        style: Option<theme::Style>,
     ) -> MouseCursor;
 }
 
 impl<'a, Message, Renderer> From<Slider<'a, Message>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: self::Renderer,
+    Renderer: self::Renderer + tooltip::Renderer,
     Message: 'static,
 {
     fn from(slider: Slider<'a, Message>) -> Element<'a, Message, Renderer> {
@@ -0,0 +1,146 @@
+//! Tell your users that something is happening, without knowing how long it
+//! will take.
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::graphics::{Color, Point, Rectangle};
+use crate::ui::core::{
+    Align, Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// An indeterminate progress indicator that spins.
+///
+/// A [`Spinner`] is animated using the time you provide. Normally, you will
+/// want to use [`Window::total_time`] in your [`UserInterface::layout`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`spinner::Renderer`] trait.
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [`Window::total_time`]: ../../../graphics/struct.Window.html#method.total_time
+/// [`UserInterface::layout`]: ../../trait.UserInterface.html#tymethod.layout
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`spinner::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::Spinner;
+/// use std::time::Duration;
+///
+/// # let time = Duration::from_millis(1500);
+/// // `time` would normally be `window.total_time()`
+/// Spinner::new(time).size(48);
+/// ```
+#[derive(Debug)]
+pub struct Spinner {
+    time: Duration,
+    size: u16,
+    color: Option<Color>,
+    style: Style,
+}
+
+impl Spinner {
+    /// Creates a new [`Spinner`] at the given point in time of its animation.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn new(time: Duration) -> Self {
+        Spinner {
+            time,
+            size: 32,
+            color: None,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`Spinner`] in pixels.
+    ///
+    /// By default, it is `32`.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Spinner`].
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the alignment of the [`Spinner`] itself.
+    ///
+    /// This is useful if you want to override the default alignment given by
+    /// the parent container.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn align_self(mut self, align: Align) -> Self {
+        self.style = self.style.align_self(align);
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        let size = u32::from(self.size);
+
+        Node::new(self.style.width(size).height(size))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(layout.bounds(), self.time, self.color);
+
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Spinner`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Spinner`] in your user interface.
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Spinner`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Spinner`]
+    ///   * the point in time of the animation
+    ///   * the custom color of the [`Spinner`], if any
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        time: Duration,
+        color: Option<Color>,
+    );
+}
+
+impl<'a, Message, Renderer> From<Spinner> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(spinner: Spinner) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
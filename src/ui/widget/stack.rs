@@ -0,0 +1,481 @@
+use std::hash::Hash;
+
+use stretch::geometry;
+use stretch::style::{Dimension, FlexDirection, PositionType};
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    self, Align, Element, Event, Focus, Hasher, Layout, MouseCursor, Node,
+    Style, Widget,
+};
+
+/// A container that places its contents on top of each other.
+///
+/// A [`Stack`] has a base layer that determines its size. The rest of the
+/// layers are placed on top of it, in the order they are pushed, using the
+/// same bounds.
+///
+/// Only the topmost layer under the cursor receives mouse clicks and scrolls,
+/// and it is the only one that shows hover effects.
+///
+/// [`Stack`]: struct.Stack.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::Image;
+/// use coffee::ui::{button, Align, Button, Stack};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     ZoomIn,
+/// }
+///
+/// fn minimap<'a>(
+///     map: &Image,
+///     zoom_in: &'a mut button::State,
+/// ) -> Stack<'a, Message> {
+///     Stack::new(coffee::ui::Image::new(map))
+///         .push_aligned(
+///             Button::new(zoom_in, "+").on_press(Message::ZoomIn),
+///             Align::End,
+///             Align::Start,
+///         )
+/// }
+/// ```
+pub struct Stack<'a, Message, Renderer> {
+    style: Style,
+    base: Element<'a, Message, Renderer>,
+    layers: Vec<StackLayer<'a, Message, Renderer>>,
+}
+
+struct StackLayer<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    position: Position,
+    is_blocking: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Aligned { x: Align, y: Align },
+    Offset { x: u32, y: u32 },
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Stack<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let layers: Vec<_> =
+            self.layers.iter().map(|layer| &layer.content).collect();
+
+        f.debug_struct("Stack")
+            .field("style", &self.style)
+            .field("base", &self.base)
+            .field("layers", &layers)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates a new [`Stack`] with the given base layer.
+    ///
+    /// The base layer determines the size of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new<E>(base: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut style = Style::default();
+        style.0.flex_direction = FlexDirection::Column;
+        style.0.align_items = Align::Stretch.into();
+
+        Stack {
+            style,
+            base: base.into(),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`] in pixels.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Stack`] in pixels.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Makes the [`Stack`] fill all the horizontal available space.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn fill_width(mut self) -> Self {
+        self.style = self.style.fill_width();
+        self
+    }
+
+    /// Makes the [`Stack`] fill all the vertical available space.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn fill_height(mut self) -> Self {
+        self.style = self.style.fill_height();
+        self
+    }
+
+    /// Sets the alignment of the [`Stack`] itself.
+    ///
+    /// This is useful if you want to override the default alignment given by
+    /// the parent container.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_self(mut self, align: Align) -> Self {
+        self.style = self.style.align_self(align);
+        self
+    }
+
+    /// Adds a layer on top of the [`Stack`], placed at its top-left corner.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(self, layer: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_aligned(layer, Align::Start, Align::Start)
+    }
+
+    /// Adds a layer on top of the [`Stack`], aligned horizontally and
+    /// vertically inside of it.
+    ///
+    /// Use [`Align::Stretch`] to make the layer fill the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Align::Stretch`]: ../core/enum.Align.html#variant.Stretch
+    pub fn push_aligned<E>(self, layer: E, x: Align, y: Align) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_layer(layer.into(), Position::Aligned { x, y }, false)
+    }
+
+    /// Adds a layer on top of the [`Stack`], placed at the given offset in
+    /// pixels from its top-left corner.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push_at<E>(self, layer: E, x: u32, y: u32) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_layer(layer.into(), Position::Offset { x, y }, false)
+    }
+
+    // Adds a layer that fills the stack and hides the layers below from user
+    // interactions
+    pub(crate) fn push_blocking<E>(self, layer: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_layer(
+            layer.into(),
+            Position::Aligned {
+                x: Align::Stretch,
+                y: Align::Stretch,
+            },
+            true,
+        )
+    }
+
+    fn push_layer(
+        mut self,
+        content: Element<'a, Message, Renderer>,
+        position: Position,
+        is_blocking: bool,
+    ) -> Self {
+        self.layers.push(StackLayer {
+            content,
+            position,
+            is_blocking,
+        });
+
+        self
+    }
+
+    fn widgets(&self) -> Vec<&(dyn Widget<Message, Renderer> + 'a)> {
+        std::iter::once(&self.base)
+            .chain(self.layers.iter().map(|layer| &layer.content))
+            .map(|element| element.widget.as_ref())
+            .collect()
+    }
+
+    fn widgets_mut(
+        &mut self,
+    ) -> Vec<&mut (dyn Widget<Message, Renderer> + 'a)> {
+        let Stack { base, layers, .. } = self;
+
+        std::iter::once(base)
+            .chain(layers.iter_mut().map(|layer| &mut layer.content))
+            .map(|element| element.widget.as_mut())
+            .collect()
+    }
+
+    // The index of the lowest layer that can be interacted with
+    fn lowest_active(&self) -> usize {
+        self.layers
+            .iter()
+            .rposition(|layer| layer.is_blocking)
+            .map_or(0, |index| index + 1)
+    }
+
+    // The index of the topmost active layer under the cursor, if any
+    fn topmost_hovered(
+        &self,
+        bounds: &[Rectangle<f32>],
+        cursor_position: Point,
+    ) -> Option<usize> {
+        (self.lowest_active()..bounds.len())
+            .rev()
+            .find(|&index| bounds[index].contains(cursor_position))
+    }
+}
+
+// The layouts of the contents of every layer, from bottom to top
+fn contents<'a>(layers: &'a [Layout<'a>]) -> Vec<Layout<'a>> {
+    layers
+        .iter()
+        .enumerate()
+        .filter_map(|(index, layer)| {
+            if index == 0 {
+                Some(*layer)
+            } else {
+                layer.children().next()
+            }
+        })
+        .collect()
+}
+
+// The cursor position given to layers that are covered by another one
+pub(crate) fn hidden_cursor() -> Point {
+    Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut children = vec![self.base.widget.node(renderer)];
+
+        for layer in &self.layers {
+            let mut content = layer.content.widget.node(renderer);
+
+            let mut style = Style::default();
+            style.0.position_type = PositionType::Absolute;
+            style.0.position = geometry::Rect {
+                start: Dimension::Points(0.0),
+                end: Dimension::Undefined,
+                top: Dimension::Points(0.0),
+                bottom: Dimension::Undefined,
+            };
+            style.0.size = geometry::Size {
+                width: Dimension::Percent(1.0),
+                height: Dimension::Percent(1.0),
+            };
+            style.0.flex_direction = FlexDirection::Column;
+
+            let mut content_style = content.0.style();
+
+            match layer.position {
+                Position::Aligned { x, y } => {
+                    style.0.align_items = x.into();
+                    style.0.justify_content = y.into();
+
+                    if y == Align::Stretch {
+                        content_style.flex_grow = 1.0;
+                    }
+                }
+                Position::Offset { x, y } => {
+                    content_style.margin.start = Dimension::Points(x as f32);
+                    content_style.margin.top = Dimension::Points(y as f32);
+                }
+            }
+
+            content.0.set_style(content_style);
+            children.push(Node::with_children(style, vec![content]));
+        }
+
+        Node::with_children(self.style, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let layers: Vec<_> = layout.children().collect();
+        let contents = contents(&layers);
+        let bounds: Vec<_> =
+            contents.iter().map(|layout| layout.bounds()).collect();
+
+        let lowest = self.lowest_active();
+        let topmost = self.topmost_hovered(&bounds, cursor_position);
+
+        // Only the topmost layer gets to handle clicks and scrolls
+        let is_pointed = match event {
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            })
+            | Event::Mouse(mouse::Event::WheelScrolled { .. }) => true,
+            _ => false,
+        };
+
+        for (index, (widget, layout)) in self
+            .widgets_mut()
+            .into_iter()
+            .zip(contents.into_iter())
+            .enumerate()
+            .rev()
+        {
+            if index < lowest {
+                break;
+            }
+
+            let is_covered = topmost.map_or(false, |topmost| index < topmost);
+
+            let cursor_position = if is_covered && is_pointed {
+                hidden_cursor()
+            } else {
+                cursor_position
+            };
+
+            widget.on_event(event, layout, cursor_position, messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let layers: Vec<_> = layout.children().collect();
+        let contents = contents(&layers);
+        let bounds: Vec<_> =
+            contents.iter().map(|layout| layout.bounds()).collect();
+
+        let lowest = self.lowest_active();
+        let topmost = self.topmost_hovered(&bounds, cursor_position);
+
+        let mut cursor = MouseCursor::OutOfBounds;
+
+        for (index, (widget, layout)) in
+            self.widgets().into_iter().zip(contents).enumerate()
+        {
+            if index > 0 {
+                renderer.start_layer();
+            }
+
+            let is_covered = index < lowest
+                || topmost.map_or(false, |topmost| index < topmost);
+
+            let new_cursor = widget.draw(
+                renderer,
+                layout,
+                if is_covered {
+                    hidden_cursor()
+                } else {
+                    cursor_position
+                },
+            );
+
+            if topmost == Some(index) {
+                cursor = new_cursor;
+            }
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.base.widget.hash(state);
+
+        for layer in &self.layers {
+            match layer.position {
+                Position::Aligned { x, y } => {
+                    (x as u8).hash(state);
+                    (y as u8).hash(state);
+                }
+                Position::Offset { x, y } => {
+                    x.hash(state);
+                    y.hash(state);
+                }
+            }
+
+            layer.content.widget.hash(state);
+        }
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        let lowest = self.lowest_active();
+
+        self.widgets()
+            .into_iter()
+            .skip(lowest)
+            .any(|widget| widget.captures_keyboard())
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        let lowest = self.lowest_active();
+        let layers: Vec<_> = layout.children().collect();
+
+        for (widget, layout) in self
+            .widgets_mut()
+            .into_iter()
+            .zip(contents(&layers))
+            .skip(lowest)
+        {
+            widget.focus(layout, focus);
+        }
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let lowest = self.lowest_active();
+        let layers: Vec<_> = layout.children().collect();
+
+        for (widget, layout) in self
+            .widgets_mut()
+            .into_iter()
+            .zip(contents(&layers))
+            .skip(lowest)
+        {
+            widget.tick(layout, cursor_position, messages);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + core::Renderer,
+    Message: 'static,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
@@ -0,0 +1,518 @@
+//! Let your users pick a whole number with buttons or by typing it.
+//!
+//! A [`Stepper`] has some local [`State`].
+//!
+//! [`Stepper`]: struct.Stepper.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Action, Element, Event, Focus, Hasher, Id, Layout, MouseCursor, Node,
+    Style, Widget,
+};
+use crate::ui::theme;
+
+// How long a button has to be held before the value starts repeating
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+// How often the value changes while a button is held
+const REPEAT_INTERVAL: Duration = Duration::from_millis(80);
+
+/// A whole number between a decrement and an increment button.
+///
+/// Holding one of the buttons keeps changing the value. When focused, the
+/// arrow keys change the value too. Clicking the value lets your users type
+/// a new one, which is applied with `Enter` or by clicking somewhere else, and
+/// discarded with `Escape`. The value is always kept inside the range of the
+/// [`Stepper`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`stepper::Renderer`] trait.
+///
+/// [`Stepper`]: struct.Stepper.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`stepper::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{stepper, Stepper};
+///
+/// pub enum Message {
+///     PlayersChanged(i32),
+/// }
+///
+/// let state = &mut stepper::State::new();
+/// let players = 2;
+///
+/// Stepper::new(state, 1..=4, players, 1, Message::PlayersChanged);
+/// ```
+pub struct Stepper<'a, Message> {
+    state: &'a mut State,
+    range: RangeInclusive<i32>,
+    value: i32,
+    step: i32,
+    on_change: Box<dyn Fn(i32) -> Message>,
+    style: Style,
+    theme_style: Option<theme::Style>,
+    id: Option<Id>,
+}
+
+impl<'a, Message> std::fmt::Debug for Stepper<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Stepper")
+            .field("state", &self.state)
+            .field("range", &self.range)
+            .field("value", &self.value)
+            .field("step", &self.step)
+            .field("style", &self.style)
+            .field("theme_style", &self.theme_style)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<'a, Message> Stepper<'a, Message> {
+    /// Creates a new [`Stepper`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Stepper`]
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Stepper`]
+    ///   * the amount added or subtracted by the buttons
+    ///   * a function that will be called when the value changes. It receives
+    ///   the new value of the [`Stepper`] and must produce a `Message`.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<i32>,
+        value: i32,
+        step: i32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(i32) -> Message,
+    {
+        state.is_disabled = false;
+
+        Stepper {
+            state,
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            step: step.max(1),
+            on_change: Box::new(on_change),
+            style: Style::default().width(200),
+            theme_style: None,
+            id: None,
+        }
+    }
+
+    /// Sets the width of the [`Stepper`] in pixels.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the [`theme::Style`] of the buttons of the [`Stepper`],
+    /// overriding the one in the current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Stepper`]: struct.Stepper.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Stepper`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`Stepper`] is disabled or not.
+    ///
+    /// A disabled [`Stepper`] does not react to user interactions and never
+    /// produces messages. It is drawn using the disabled [`Appearance`] of
+    /// its style, and its layout does not change.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        self.state.is_disabled = is_disabled;
+
+        if is_disabled {
+            self.state.release();
+            self.state.input = None;
+        }
+
+        self
+    }
+
+    fn change(&self, delta: i32, messages: &mut Vec<Message>) {
+        self.set(self.value.saturating_add(delta), messages);
+    }
+
+    fn set(&self, value: i32, messages: &mut Vec<Message>) {
+        let value = value.max(*self.range.start()).min(*self.range.end());
+
+        if value != self.value {
+            messages.push((self.on_change)(value));
+        }
+    }
+
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        if let Some(input) = self.state.input.take() {
+            if let Ok(value) = input.parse() {
+                self.set(value, messages);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Stepper<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style.height(50))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.state.is_disabled {
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        if self.state.is_focused {
+            match event {
+                Event::Keyboard(keyboard::Event::TextEntered { character })
+                    if character.is_ascii_digit()
+                        || (character == '-' && *self.range.start() < 0) =>
+                {
+                    let input =
+                        self.state.input.get_or_insert_with(String::new);
+
+                    if character != '-' || input.is_empty() {
+                        input.push(character);
+                    }
+
+                    return;
+                }
+                Event::Keyboard(keyboard::Event::Input {
+                    state: ButtonState::Pressed,
+                    key_code: Some(key_code),
+                    ..
+                }) if self.state.input.is_some() => match key_code {
+                    KeyCode::Return | KeyCode::NumpadEnter => {
+                        self.commit(messages);
+                        return;
+                    }
+                    KeyCode::Escape => {
+                        self.state.input = None;
+                        return;
+                    }
+                    KeyCode::Back => {
+                        if let Some(input) = &mut self.state.input {
+                            let _ = input.pop();
+                        }
+
+                        return;
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+
+            match Action::from_event(&event) {
+                Some(Action::Decrease) => {
+                    self.change(-self.step, messages);
+                    return;
+                }
+                Some(Action::Increase) => {
+                    self.change(self.step, messages);
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                let part = [Part::Decrement, Part::Value, Part::Increment]
+                    .iter()
+                    .cloned()
+                    .find(|part| part.bounds(bounds).contains(cursor_position));
+
+                if part != Some(Part::Value) {
+                    self.commit(messages);
+                }
+
+                match part {
+                    Some(Part::Decrement) => {
+                        self.change(-self.step, messages);
+                        self.state.press(Part::Decrement);
+                    }
+                    Some(Part::Increment) => {
+                        self.change(self.step, messages);
+                        self.state.press(Part::Increment);
+                    }
+                    Some(Part::Value) => {
+                        if self.state.input.is_none() {
+                            self.state.input = Some(String::new());
+                        }
+                    }
+                    None => {}
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+                ..
+            }) => {
+                self.state.release();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let value = match &self.state.input {
+            Some(input) => input.clone(),
+            None => self.value.to_string(),
+        };
+
+        renderer.draw(
+            cursor_position,
+            layout.bounds(),
+            self.state,
+            &value,
+            self.theme_style,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.state.is_focused = if self.state.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            false
+        } else {
+            focus.register(self.id, layout.bounds(), self.state.input.is_some())
+        };
+
+        if !self.state.is_focused {
+            self.state.input = None;
+        }
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let (part, repeat_at) = match (self.state.pressed, self.state.repeat_at)
+        {
+            (Some(part), Some(repeat_at)) => (part, repeat_at),
+            _ => return,
+        };
+
+        let now = Instant::now();
+
+        if now < repeat_at
+            || !part.bounds(layout.bounds()).contains(cursor_position)
+        {
+            return;
+        }
+
+        match part {
+            Part::Decrement => self.change(-self.step, messages),
+            Part::Increment => self.change(self.step, messages),
+            Part::Value => {}
+        }
+
+        self.state.repeat_at = Some(now + REPEAT_INTERVAL);
+    }
+}
+
+/// A part of a [`Stepper`].
+///
+/// [`Stepper`]: struct.Stepper.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    /// The button that decreases the value, on the left.
+    Decrement,
+
+    /// The value, in the middle.
+    Value,
+
+    /// The button that increases the value, on the right.
+    Increment,
+}
+
+impl Part {
+    /// Returns the bounds of the [`Part`], given the bounds of its
+    /// [`Stepper`].
+    ///
+    /// The buttons are as wide as the [`Stepper`] is tall.
+    ///
+    /// [`Part`]: enum.Part.html
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn bounds(self, bounds: Rectangle<f32>) -> Rectangle<f32> {
+        let button = bounds.height.min(bounds.width / 2.0);
+
+        match self {
+            Part::Decrement => Rectangle {
+                width: button,
+                ..bounds
+            },
+            Part::Value => Rectangle {
+                x: bounds.x + button,
+                width: bounds.width - button * 2.0,
+                ..bounds
+            },
+            Part::Increment => Rectangle {
+                x: bounds.x + bounds.width - button,
+                width: button,
+                ..bounds
+            },
+        }
+    }
+}
+
+/// The local state of a [`Stepper`].
+///
+/// [`Stepper`]: struct.Stepper.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct State {
+    pressed: Option<Part>,
+    repeat_at: Option<Instant>,
+    input: Option<String>,
+    is_disabled: bool,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the button of the associated [`Stepper`] that is being
+    /// pressed, if any.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn pressed(&self) -> Option<Part> {
+        self.pressed
+    }
+
+    /// Returns whether a new value is being typed in the associated
+    /// [`Stepper`] or not.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn is_editing(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Returns whether the associated [`Stepper`] is disabled or not.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+
+    /// Returns whether the associated [`Stepper`] is focused or not.
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn press(&mut self, part: Part) {
+        self.pressed = Some(part);
+        self.repeat_at = Some(Instant::now() + REPEAT_DELAY);
+    }
+
+    fn release(&mut self) {
+        self.pressed = None;
+        self.repeat_at = None;
+    }
+}
+
+/// The renderer of a [`Stepper`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Stepper`] in your user interface.
+///
+/// [`Stepper`]: struct.Stepper.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Stepper`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`Stepper`], which can be split with
+    ///   [`Part::bounds`]
+    ///   * the local state of the [`Stepper`]
+    ///   * the value to show, which may be partially typed
+    ///   * the custom [`theme::Style`] of the buttons of the [`Stepper`], if
+    ///   any
+    ///
+    /// [`Stepper`]: struct.Stepper.html
+    /// [`Part::bounds`]: enum.Part.html#method.bounds
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &State,
+        value: &str,
+        style: Option<theme::Style>,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<Stepper<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(stepper: Stepper<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(stepper)
+    }
+}
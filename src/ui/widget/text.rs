@@ -33,7 +33,7 @@ use std::hash::Hash;
 pub struct Text {
     content: String,
     size: u16,
-    color: Color,
+    color: Option<Color>,
     style: Style,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
@@ -47,7 +47,7 @@ impl Text {
         Text {
             content: String::from(label),
             size: 20,
-            color: Color::WHITE,
+            color: None,
             style: Style::default().fill_width(),
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
@@ -64,10 +64,14 @@ impl Text {
 
     /// Sets the [`Color`] of the [`Text`].
     ///
+    /// By default, the renderer chooses the [`Color`]. The built-in renderer
+    /// uses the text color of the current [`Theme`], or white.
+    ///
     /// [`Text`]: struct.Text.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
     /// [`Color`]: ../../../graphics/struct.Color.html
     pub fn color(mut self, color: Color) -> Self {
-        self.color = color;
+        self.color = Some(color);
         self
     }
 
@@ -169,7 +173,7 @@ pub trait Renderer {
     ///   * the bounds of the [`Text`]
     ///   * the contents of the [`Text`]
     ///   * the size of the [`Text`]
-    ///   * the color of the [`Text`]
+    ///   * the color of the [`Text`], if it has a custom one
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
     ///
@@ -181,7 +185,7 @@ pub trait Renderer {
         bounds: Rectangle<f32>,
         content: &str,
         size: f32,
-        color: Color,
+        color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     );
@@ -0,0 +1,728 @@
+//! Allow your users to type a line of text.
+//!
+//! A [`TextInput`] has some local [`State`].
+//!
+//! [`TextInput`]: struct.TextInput.html
+//! [`State`]: struct.State.html
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::graphics::{Clipboard, Point, Rectangle};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Focus, Hasher, Id, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// The space between the bounds of a [`TextInput`] and its text.
+///
+/// [`TextInput`]: struct.TextInput.html
+pub const PADDING: f32 = 8.0;
+
+/// The time the cursor of a focused [`TextInput`] stays visible, or hidden,
+/// while blinking.
+///
+/// [`TextInput`]: struct.TextInput.html
+const BLINK_INTERVAL_MILLIS: u128 = 530;
+
+/// A field that can be filled with a single line of text.
+///
+/// A [`TextInput`] gets focused when clicked. While focused, it captures the
+/// keyboard input, so it never reaches your [`Game::Input`]. It supports:
+///
+///   * selecting text with the mouse or with `Shift` and the arrow keys,
+///   * cutting, copying and pasting with `Ctrl` (or `Cmd`) and `X`, `C` and
+///   `V`, and selecting everything with `A`,
+///   * scrolling horizontally when the text does not fit.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`text_input::Renderer`] trait.
+///
+/// [`TextInput`]: struct.TextInput.html
+/// [`Game::Input`]: ../../../trait.Game.html#associatedtype.Input
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`text_input::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{text_input, TextInput};
+///
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     NameChanged(String),
+///     NameSubmitted,
+/// }
+///
+/// let state = &mut text_input::State::new();
+/// let name = "Ferris";
+///
+/// TextInput::new(state, "Your name", name, Message::NameChanged)
+///     .max_length(20)
+///     .on_submit(Message::NameSubmitted);
+/// ```
+pub struct TextInput<'a, Message> {
+    state: &'a mut State,
+    placeholder: String,
+    value: String,
+    size: u16,
+    max_length: Option<usize>,
+    on_change: Box<dyn Fn(String) -> Message>,
+    on_submit: Option<Message>,
+    style: Style,
+    id: Option<Id>,
+}
+
+impl<'a, Message> std::fmt::Debug for TextInput<'a, Message>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextInput")
+            .field("state", &self.state)
+            .field("placeholder", &self.placeholder)
+            .field("value", &self.value)
+            .field("size", &self.size)
+            .field("max_length", &self.max_length)
+            .field("on_submit", &self.on_submit)
+            .field("style", &self.style)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<'a, Message> TextInput<'a, Message> {
+    /// Creates a new [`TextInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`TextInput`]
+    ///   * the placeholder shown while the [`TextInput`] is empty
+    ///   * the current value of the [`TextInput`]
+    ///   * a function that will be called when the text changes. It receives
+    ///   the new value of the [`TextInput`] and must produce a `Message`.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        state.is_disabled = false;
+
+        TextInput {
+            state,
+            placeholder: String::from(placeholder),
+            value: String::from(value),
+            size: 20,
+            max_length: None,
+            on_change: Box::new(on_change),
+            on_submit: None,
+            style: Style::default().min_width(100).fill_width(),
+            id: None,
+        }
+    }
+
+    /// Sets the width of the [`TextInput`] in pixels.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the text size of the [`TextInput`] in pixels.
+    ///
+    /// By default, it is `20`.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the maximum amount of characters of the [`TextInput`].
+    ///
+    /// Typing or pasting stops adding characters once it is reached.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the message that will be produced when `Enter` is pressed while
+    /// the [`TextInput`] is focused.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TextInput`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`TextInput`] is disabled or not.
+    ///
+    /// A disabled [`TextInput`] does not react to user interactions, cannot be focused, and
+    /// it is drawn using the disabled [`Appearance`] of its style. Its layout
+    /// does not change.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        self.state.is_disabled = is_disabled;
+
+        if is_disabled {
+            self.state.unfocus();
+        }
+
+        self
+    }
+
+    fn text_bounds(&self, bounds: Rectangle<f32>) -> Rectangle<f32> {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: (bounds.width - 2.0 * PADDING).max(0.0),
+            height: f32::from(self.size),
+        }
+    }
+
+    fn length(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.value.len())
+    }
+
+    fn selected_text(&self) -> Option<String> {
+        self.state.selection().map(|(start, end)| {
+            String::from(
+                &self.value[self.byte_index(start)..self.byte_index(end)],
+            )
+        })
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.state.selection() {
+            Some((start, end)) => {
+                let range = self.byte_index(start)..self.byte_index(end);
+
+                self.value.replace_range(range, "");
+                self.state.cursor = start;
+                self.state.anchor = None;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, text: &str) {
+        let _ = self.delete_selection();
+
+        let available = match self.max_length {
+            Some(max_length) => max_length.saturating_sub(self.length()),
+            None => usize::max_value(),
+        };
+
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(available)
+            .collect();
+
+        let index = self.byte_index(self.state.cursor);
+
+        self.value.insert_str(index, &text);
+        self.state.cursor += text.chars().count();
+    }
+
+    fn move_cursor(&mut self, cursor: usize, is_selecting: bool) {
+        if is_selecting {
+            if self.state.anchor.is_none() {
+                self.state.anchor = Some(self.state.cursor);
+            }
+        } else {
+            self.state.anchor = None;
+        }
+
+        self.state.cursor = cursor;
+    }
+
+    fn on_key_pressed(
+        &mut self,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        messages: &mut Vec<Message>,
+    ) where
+        Message: Clone,
+    {
+        let is_command = modifiers.ctrl || modifiers.logo;
+        let is_selecting = modifiers.shift;
+        let cursor = self.state.cursor;
+        let value = self.value.clone();
+
+        match key_code {
+            KeyCode::Left => match self.state.selection() {
+                Some((start, _)) if !is_selecting => {
+                    self.move_cursor(start, false)
+                }
+                _ => self.move_cursor(cursor.saturating_sub(1), is_selecting),
+            },
+            KeyCode::Right => match self.state.selection() {
+                Some((_, end)) if !is_selecting => self.move_cursor(end, false),
+                _ => self
+                    .move_cursor((cursor + 1).min(self.length()), is_selecting),
+            },
+            KeyCode::Home => self.move_cursor(0, is_selecting),
+            KeyCode::End => self.move_cursor(self.length(), is_selecting),
+            KeyCode::Back => {
+                if !self.delete_selection() && cursor > 0 {
+                    let index = self.byte_index(cursor - 1);
+
+                    let _ = self.value.remove(index);
+                    self.state.cursor = cursor - 1;
+                }
+            }
+            KeyCode::Delete => {
+                if !self.delete_selection() && cursor < self.length() {
+                    let index = self.byte_index(cursor);
+
+                    let _ = self.value.remove(index);
+                }
+            }
+            KeyCode::A if is_command => {
+                self.state.anchor = Some(0);
+                self.state.cursor = self.length();
+            }
+            KeyCode::C if is_command => {
+                if let Some(text) = self.selected_text() {
+                    Clipboard::new().write_text(&text);
+                }
+            }
+            KeyCode::X if is_command => {
+                if let Some(text) = self.selected_text() {
+                    Clipboard::new().write_text(&text);
+                    let _ = self.delete_selection();
+                }
+            }
+            KeyCode::V if is_command => {
+                if let Some(text) = Clipboard::new().read_text() {
+                    self.insert(&text);
+                }
+            }
+            KeyCode::Return | KeyCode::NumpadEnter => {
+                if let Some(on_submit) = &self.on_submit {
+                    messages.push(on_submit.clone());
+                }
+            }
+            KeyCode::Escape => {
+                self.state.unfocus();
+            }
+            _ => {}
+        }
+
+        if self.value != value {
+            messages.push((self.on_change)(self.value.clone()));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TextInput<'a, Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone + std::fmt::Debug,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style.height(u32::from(self.size) + 2 * PADDING as u32))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.state.is_disabled {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let text_bounds = self.text_bounds(bounds);
+        let length = self.length();
+
+        // The value may have been changed since the last event
+        self.state.cursor = self.state.cursor.min(length);
+        self.state.anchor = self.state.anchor.map(|anchor| anchor.min(length));
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state,
+                ..
+            }) => match state {
+                ButtonState::Pressed => {
+                    if bounds.contains(cursor_position) {
+                        let index = self
+                            .state
+                            .index_at(cursor_position.x - text_bounds.x)
+                            .min(length);
+
+                        if !self.state.is_focused {
+                            self.state.focus();
+                        }
+
+                        self.move_cursor(index, self.state.modifiers.shift);
+
+                        if self.state.anchor.is_none() {
+                            self.state.anchor = Some(index);
+                        }
+
+                        self.state.is_dragging = true;
+                        self.state.last_activity = Some(Instant::now());
+                    } else {
+                        self.state.unfocus();
+                    }
+                }
+                ButtonState::Released => {
+                    self.state.is_dragging = false;
+
+                    if self.state.anchor == Some(self.state.cursor) {
+                        self.state.anchor = None;
+                    }
+                }
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.state.is_dragging {
+                    self.state.cursor = self
+                        .state
+                        .index_at(cursor_position.x - text_bounds.x)
+                        .min(length);
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged {
+                modifiers,
+            }) => {
+                self.state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: Some(key_code),
+                modifiers,
+                ..
+            }) if self.state.is_focused => {
+                self.state.modifiers = modifiers;
+                self.state.last_activity = Some(Instant::now());
+                self.on_key_pressed(key_code, modifiers, messages);
+            }
+            Event::Keyboard(keyboard::Event::TextEntered { character })
+                if self.state.is_focused =>
+            {
+                let mut buffer = [0; 4];
+                let value = self.value.clone();
+
+                self.insert(character.encode_utf8(&mut buffer));
+                self.state.last_activity = Some(Instant::now());
+
+                if self.value != value {
+                    messages.push((self.on_change)(self.value.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let text_bounds = self.text_bounds(bounds);
+
+        self.state.measure(renderer, &self.value, self.size);
+        self.state.scroll_to_cursor(text_bounds.width);
+
+        renderer.draw(
+            cursor_position,
+            bounds,
+            text_bounds,
+            self.state,
+            &self.value,
+            &self.placeholder,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.size.hash(state);
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        if self.state.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            return;
+        }
+
+        let is_focused =
+            focus.register(self.id, layout.bounds(), self.state.is_focused);
+
+        if is_focused && !self.state.is_focused {
+            self.state.focus();
+        } else if !is_focused && self.state.is_focused {
+            self.state.unfocus();
+        }
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.state.is_focused
+    }
+}
+
+/// The local state of a [`TextInput`].
+///
+/// Positions in the text are measured in characters.
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_focused: bool,
+    is_disabled: bool,
+    is_dragging: bool,
+    cursor: usize,
+    anchor: Option<usize>,
+    modifiers: keyboard::Modifiers,
+    last_activity: Option<Instant>,
+    measured: RefCell<(String, u16)>,
+    offsets: RefCell<Vec<f32>>,
+    scroll: Cell<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Creates a new [`State`] that is focused.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn focused() -> State {
+        let mut state = State::default();
+        state.focus();
+        state
+    }
+
+    /// Returns whether the associated [`TextInput`] is focused or not.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns whether the associated [`TextInput`] is disabled or not.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+
+    /// Focuses the associated [`TextInput`], placing the cursor at the end
+    /// of its text.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+        self.cursor = usize::max_value();
+        self.anchor = None;
+        self.last_activity = Some(Instant::now());
+    }
+
+    /// Unfocuses the associated [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+        self.anchor = None;
+    }
+
+    /// Returns the position of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+            .min(self.offsets.borrow().len().saturating_sub(1))
+    }
+
+    /// Returns the start and the end of the selected text, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor => {
+                Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the blinking cursor should be drawn or not.
+    pub fn is_cursor_visible(&self) -> bool {
+        match self.last_activity {
+            Some(last_activity) if self.is_focused => {
+                (last_activity.elapsed().as_millis() / BLINK_INTERVAL_MILLIS)
+                    % 2
+                    == 0
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the horizontal offset of the given position, relative to the
+    /// start of the text.
+    pub fn offset(&self, index: usize) -> f32 {
+        let offsets = self.offsets.borrow();
+
+        offsets
+            .get(index)
+            .or_else(|| offsets.last())
+            .cloned()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the amount of pixels the text is scrolled horizontally.
+    pub fn scroll(&self) -> f32 {
+        self.scroll.get()
+    }
+
+    fn index_at(&self, x: f32) -> usize {
+        let x = x + self.scroll.get();
+
+        self.offsets
+            .borrow()
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - x)
+                    .abs()
+                    .partial_cmp(&(*b - x).abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+
+    fn measure<Renderer: self::Renderer>(
+        &self,
+        renderer: &Renderer,
+        value: &str,
+        size: u16,
+    ) {
+        let mut measured = self.measured.borrow_mut();
+        let mut offsets = self.offsets.borrow_mut();
+
+        if measured.0 == value && measured.1 == size && !offsets.is_empty() {
+            return;
+        }
+
+        let mut buffer = [0; 4];
+        let mut x = 0.0;
+
+        offsets.clear();
+        offsets.push(x);
+
+        for c in value.chars() {
+            x += renderer.measure(c.encode_utf8(&mut buffer), f32::from(size));
+            offsets.push(x);
+        }
+
+        *measured = (String::from(value), size);
+    }
+
+    fn scroll_to_cursor(&self, width: f32) {
+        let cursor = self.offset(self.cursor());
+        let total = self.offset(usize::max_value());
+        let mut scroll = self.scroll.get();
+
+        if cursor - scroll > width {
+            scroll = cursor - width;
+        }
+
+        if cursor < scroll {
+            scroll = cursor;
+        }
+
+        self.scroll.set(scroll.min(total - width).max(0.0));
+    }
+}
+
+/// The renderer of a [`TextInput`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`TextInput`] in your user interface.
+///
+/// [`TextInput`]: struct.TextInput.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Returns the width of the given text with the given size, including
+    /// any trailing whitespace.
+    fn measure(&self, content: &str, size: f32) -> f32;
+
+    /// Draws a [`TextInput`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`TextInput`]
+    ///   * the bounds of its text, whose height is the text size
+    ///   * the local state of the [`TextInput`], which describes the cursor,
+    ///   the selection and the horizontal scroll
+    ///   * the current value of the [`TextInput`]
+    ///   * the placeholder of the [`TextInput`]
+    ///
+    /// Only the part of the text inside the text bounds should be drawn.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        text_bounds: Rectangle<f32>,
+        state: &State,
+        value: &str,
+        placeholder: &str,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<TextInput<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static + Clone + std::fmt::Debug,
+{
+    fn from(
+        text_input: TextInput<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_input)
+    }
+}
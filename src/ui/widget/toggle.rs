@@ -0,0 +1,303 @@
+//! Show on/off switches.
+//!
+//! A [`Toggle`] has some local [`State`].
+//!
+//! [`Toggle`]: struct.Toggle.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Action, Element, Event, Focus, Hasher, Id, Layout, MouseCursor, Node,
+    Style, Widget,
+};
+use crate::ui::theme;
+
+// The duration of the knob animation
+const ANIMATION_MILLIS: u128 = 150;
+
+/// A switch that can be turned on and off.
+///
+/// Unlike a [`Checkbox`], a [`Toggle`] has no label and its knob slides when
+/// it changes.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`toggle::Renderer`] trait.
+///
+/// [`Checkbox`]: ../checkbox/struct.Checkbox.html
+/// [`Toggle`]: struct.Toggle.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`toggle::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{toggle, Toggle};
+///
+/// pub enum Message {
+///     VsyncToggled(bool),
+/// }
+///
+/// let state = &mut toggle::State::new();
+/// let is_vsync_enabled = true;
+///
+/// Toggle::new(state, is_vsync_enabled, Message::VsyncToggled);
+/// ```
+pub struct Toggle<'a, Message> {
+    state: &'a mut State,
+    is_on: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message>,
+    style: Style,
+    theme_style: Option<theme::Style>,
+    id: Option<Id>,
+}
+
+impl<'a, Message> std::fmt::Debug for Toggle<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Toggle")
+            .field("state", &self.state)
+            .field("is_on", &self.is_on)
+            .field("style", &self.style)
+            .field("theme_style", &self.theme_style)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<'a, Message> Toggle<'a, Message> {
+    /// Creates a new [`Toggle`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Toggle`]
+    ///   * a boolean describing whether the [`Toggle`] is on or not
+    ///   * a function that will be called when the [`Toggle`] is clicked. It
+    ///   receives the new state of the [`Toggle`] and must produce a
+    ///   `Message`.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, is_on: bool, on_toggle: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        if state.is_on == Some(!is_on) {
+            state.changed_at = Some(Instant::now());
+        }
+
+        state.is_on = Some(is_on);
+        state.is_disabled = false;
+
+        Toggle {
+            state,
+            is_on,
+            on_toggle: Box::new(on_toggle),
+            style: Style::default().width(50).height(28),
+            theme_style: None,
+            id: None,
+        }
+    }
+
+    /// Sets the [`theme::Style`] of the [`Toggle`], overriding the one in the
+    /// current [`Theme`].
+    ///
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    /// [`Toggle`]: struct.Toggle.html
+    /// [`Theme`]: ../../theme/struct.Theme.html
+    pub fn style(mut self, style: theme::Style) -> Self {
+        self.theme_style = Some(style);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Toggle`], allowing you to focus it
+    /// programmatically.
+    ///
+    /// [`Id`]: ../../core/struct.Id.html
+    /// [`Toggle`]: struct.Toggle.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`Toggle`] is disabled or not.
+    ///
+    /// A disabled [`Toggle`] does not react to user interactions and never
+    /// produces messages. It is drawn using the disabled [`Appearance`] of
+    /// its style, and its layout does not change.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    /// [`Appearance`]: ../../theme/struct.Appearance.html
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        self.state.is_disabled = is_disabled;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Toggle<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.state.is_disabled {
+            return;
+        }
+
+        if self.state.is_focused
+            && Action::from_event(&event) == Some(Action::Activate)
+        {
+            messages.push((self.on_toggle)(!self.is_on));
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if layout.bounds().contains(cursor_position) {
+                    messages.push((self.on_toggle)(!self.is_on));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(
+            cursor_position,
+            layout.bounds(),
+            self.state,
+            self.theme_style,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.state.is_focused = if self.state.is_disabled {
+            focus.skip(self.id, layout.bounds());
+            false
+        } else {
+            focus.register(self.id, layout.bounds(), false)
+        };
+    }
+}
+
+/// The local state of a [`Toggle`].
+///
+/// [`Toggle`]: struct.Toggle.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_on: Option<bool>,
+    changed_at: Option<Instant>,
+    is_disabled: bool,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the associated [`Toggle`] is on or not.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    pub fn is_on(&self) -> bool {
+        self.is_on.unwrap_or(false)
+    }
+
+    /// Returns the position of the knob of the associated [`Toggle`], from
+    /// `0.0` (off) to `1.0` (on).
+    ///
+    /// The knob slides for a short time after the [`Toggle`] changes.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    pub fn knob_position(&self) -> f32 {
+        let progress = self.changed_at.map_or(1.0, |changed_at| {
+            (changed_at.elapsed().as_millis() as f32 / ANIMATION_MILLIS as f32)
+                .min(1.0)
+        });
+
+        if self.is_on() {
+            progress
+        } else {
+            1.0 - progress
+        }
+    }
+
+    /// Returns whether the associated [`Toggle`] is disabled or not.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+
+    /// Returns whether the associated [`Toggle`] is focused or not.
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// The renderer of a [`Toggle`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Toggle`] in your user interface.
+///
+/// [`Toggle`]: struct.Toggle.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Toggle`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`Toggle`]
+    ///   * the local state of the [`Toggle`], which contains the position of
+    ///   its knob
+    ///   * the custom [`theme::Style`] of the [`Toggle`], if any
+    ///
+    /// [`Toggle`]: struct.Toggle.html
+    /// [`theme::Style`]: ../../theme/struct.Style.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &State,
+        style: Option<theme::Style>,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<Toggle<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(toggle: Toggle<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(toggle)
+    }
+}
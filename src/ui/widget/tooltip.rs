@@ -0,0 +1,354 @@
+//! Show a small hint when your users hover a widget.
+//!
+//! A [`Tooltip`] has some local [`State`] and a [`Placement`].
+//!
+//! [`Tooltip`]: struct.Tooltip.html
+//! [`State`]: struct.State.html
+//! [`Placement`]: enum.Placement.html
+use std::time::{Duration, Instant};
+
+use crate::graphics::{Image, Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Focus, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A widget that shows some [`Content`] on top of everything else when its
+/// wrapped widget is hovered for a while.
+///
+/// The wrapped widget keeps receiving every event, so it can still be hovered
+/// and clicked as usual. Pressing a mouse button hides the tooltip until the
+/// cursor leaves the widget.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`tooltip::Renderer`] trait.
+///
+/// [`Content`]: enum.Content.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`tooltip::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{button, tooltip, Button, Tooltip};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     Save,
+/// }
+///
+/// let button = &mut button::State::new();
+/// let tooltip = &mut tooltip::State::new();
+///
+/// Tooltip::new(
+///     tooltip,
+///     Button::new(button, "Save").on_press(Message::Save),
+///     "Saves the current game",
+/// )
+/// .delay(Duration::from_millis(300))
+/// .placement(tooltip::Placement::Bottom);
+/// ```
+pub struct Tooltip<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    tooltip: Content,
+    delay: Duration,
+    placement: Placement,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Tooltip<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tooltip")
+            .field("state", &self.state)
+            .field("content", &self.content)
+            .field("tooltip", &self.tooltip)
+            .field("delay", &self.delay)
+            .field("placement", &self.placement)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer> {
+    /// Creates a new [`Tooltip`] with some local [`State`] that shows the
+    /// given [`Content`] when the provided widget is hovered.
+    ///
+    /// By default, the [`Tooltip`] is shown after 500 milliseconds and
+    /// follows the cursor.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    /// [`Content`]: enum.Content.html
+    pub fn new<E, C>(state: &'a mut State, content: E, tooltip: C) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+        C: Into<Content>,
+    {
+        Tooltip {
+            state,
+            content: content.into(),
+            tooltip: tooltip.into(),
+            delay: Duration::from_millis(500),
+            placement: Placement::default(),
+        }
+    }
+
+    /// Sets how long the widget needs to be hovered before the [`Tooltip`]
+    /// is shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the [`Placement`] of the [`Tooltip`].
+    ///
+    /// [`Placement`]: enum.Placement.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let is_hovered = layout.bounds().contains(Point::new(x, y));
+
+                self.state.hover(is_hovered);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.state.hover(false);
+            }
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if self.state.is_hovered() {
+                    self.state.is_dismissed = true;
+                }
+            }
+            _ => {}
+        }
+
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let cursor =
+            self.content.widget.draw(renderer, layout, cursor_position);
+
+        if self.state.is_visible(self.delay) {
+            renderer.draw(
+                cursor_position,
+                bounds,
+                &self.tooltip,
+                self.placement,
+            );
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.content.widget.captures_keyboard()
+    }
+
+    fn focus(&mut self, layout: Layout<'_>, focus: &mut Focus) {
+        self.content.widget.focus(layout, focus)
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.widget.tick(layout, cursor_position, messages)
+    }
+}
+
+/// The content shown by a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone)]
+pub enum Content {
+    /// Some text
+    Text(String),
+
+    /// An [`Image`], drawn at its natural size
+    ///
+    /// [`Image`]: ../../../graphics/struct.Image.html
+    Image(Image),
+}
+
+impl From<&str> for Content {
+    fn from(text: &str) -> Content {
+        Content::Text(String::from(text))
+    }
+}
+
+impl From<String> for Content {
+    fn from(text: String) -> Content {
+        Content::Text(text)
+    }
+}
+
+impl From<Image> for Content {
+    fn from(image: Image) -> Content {
+        Content::Image(image)
+    }
+}
+
+/// Where a [`Tooltip`] is shown.
+///
+/// Independently of the [`Placement`], a renderer should keep a [`Tooltip`]
+/// inside the window.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [`Placement`]: enum.Placement.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// Next to the mouse cursor, following it
+    FollowCursor,
+
+    /// Above the wrapped widget
+    Top,
+
+    /// Below the wrapped widget
+    Bottom,
+
+    /// On the left side of the wrapped widget
+    Left,
+
+    /// On the right side of the wrapped widget
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Placement {
+        Placement::FollowCursor
+    }
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    hovered_since: Option<Instant>,
+    is_dismissed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the widget wrapped by the associated [`Tooltip`] is
+    /// currently being hovered or not.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn is_hovered(&self) -> bool {
+        self.hovered_since.is_some()
+    }
+
+    /// Returns whether the associated [`Tooltip`] should be shown after the
+    /// given delay or not.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn is_visible(&self, delay: Duration) -> bool {
+        match self.hovered_since {
+            Some(hovered_since) if !self.is_dismissed => {
+                hovered_since.elapsed() >= delay
+            }
+            _ => false,
+        }
+    }
+
+    fn hover(&mut self, is_hovered: bool) {
+        if !is_hovered {
+            self.hovered_since = None;
+            self.is_dismissed = false;
+        } else if self.hovered_since.is_none() {
+            self.hovered_since = Some(Instant::now());
+        }
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a visible [`Tooltip`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the wrapped widget
+    ///   * the [`Content`] of the [`Tooltip`]
+    ///   * the [`Placement`] of the [`Tooltip`]
+    ///
+    /// The [`Tooltip`] should be drawn on top of everything else and be kept
+    /// inside the window.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Content`]: enum.Content.html
+    /// [`Placement`]: enum.Placement.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        content: &Content,
+        placement: Placement,
+    );
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
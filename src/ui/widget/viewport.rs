@@ -0,0 +1,319 @@
+//! Embed the rendering of your game in your user interface.
+//!
+//! A [`Viewport`] has some local [`State`].
+//!
+//! [`Viewport`]: struct.Viewport.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::graphics::{Canvas, Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// A region of the user interface that shows a [`Canvas`].
+///
+/// A [`Viewport`] reserves some space in the layout and draws a [`Canvas`]
+/// stretched to fill it, together with the rest of the widgets. Render your
+/// game view into the [`Canvas`] in [`Game::draw`], and it will be placed
+/// wherever the layout puts the [`Viewport`].
+///
+/// The user interface is laid out after [`Game::draw`] is called. Therefore,
+/// [`State::bounds`] returns the bounds computed during the previous frame,
+/// which you can use to keep the size of the [`Canvas`] in sync with the
+/// [`Viewport`].
+///
+/// By default, mouse events inside a [`Viewport`] reach your [`Game::Input`]
+/// as usual. If you set an [`on_event`] handler, the [`Viewport`] captures
+/// them and produces messages instead, with coordinates relative to its
+/// top-left corner.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`viewport::Renderer`] trait.
+///
+/// [`Canvas`]: ../../../graphics/struct.Canvas.html
+/// [`Viewport`]: struct.Viewport.html
+/// [`Game::draw`]: ../../../trait.Game.html#tymethod.draw
+/// [`Game::Input`]: ../../../trait.Game.html#associatedtype.Input
+/// [`State::bounds`]: struct.State.html#method.bounds
+/// [`on_event`]: #method.on_event
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`viewport::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::Canvas;
+/// use coffee::input::mouse;
+/// use coffee::ui::{viewport, Viewport};
+///
+/// pub enum Message {
+///     PreviewEvent(mouse::Event),
+/// }
+///
+/// fn preview<'a>(
+///     state: &'a mut viewport::State,
+///     canvas: &'a Canvas,
+/// ) -> Viewport<'a, Message> {
+///     Viewport::new(state, canvas)
+///         .width(300)
+///         .height(400)
+///         .on_event(Message::PreviewEvent)
+/// }
+/// ```
+pub struct Viewport<'a, Message> {
+    state: &'a mut State,
+    canvas: &'a Canvas,
+    on_event: Option<Box<dyn Fn(mouse::Event) -> Message>>,
+    style: Style,
+}
+
+impl<'a, Message> std::fmt::Debug for Viewport<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Viewport")
+            .field("state", &self.state)
+            .field("canvas", &self.canvas)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, Message> Viewport<'a, Message> {
+    /// Creates a new [`Viewport`] showing the given [`Canvas`].
+    ///
+    /// By default, it has the size of the [`Canvas`].
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    /// [`Canvas`]: ../../../graphics/struct.Canvas.html
+    pub fn new(state: &'a mut State, canvas: &'a Canvas) -> Self {
+        Viewport {
+            state,
+            canvas,
+            on_event: None,
+            style: Style::default()
+                .width(u32::from(canvas.width()))
+                .height(u32::from(canvas.height())),
+        }
+    }
+
+    /// Sets the width of the [`Viewport`] in pixels.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Viewport`] in pixels.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Makes the [`Viewport`] fill the horizontal space of its container.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn fill_width(mut self) -> Self {
+        self.style = self.style.fill_width();
+        self
+    }
+
+    /// Makes the [`Viewport`] fill the vertical space of its container.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn fill_height(mut self) -> Self {
+        self.style = self.style.fill_height();
+        self
+    }
+
+    /// Sets the function that turns the mouse events of the [`Viewport`]
+    /// into messages.
+    ///
+    /// The [`Viewport`] receives the events produced while the cursor is
+    /// inside of it, and the events of a drag started inside of it. The
+    /// positions of the events are relative to its top-left corner.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn on_event<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(mouse::Event) -> Message,
+    {
+        self.on_event = Some(Box::new(f));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Viewport<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let on_event = match &self.on_event {
+            Some(on_event) => on_event,
+            None => return,
+        };
+
+        let bounds = layout.bounds();
+        let is_inside = bounds.contains(cursor_position);
+
+        let event = match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                if !is_inside && !self.state.is_dragging {
+                    return;
+                }
+
+                mouse::Event::CursorMoved {
+                    x: x - bounds.x,
+                    y: y - bounds.y,
+                }
+            }
+            Event::Mouse(
+                event @ mouse::Event::Input {
+                    state: ButtonState::Pressed,
+                    ..
+                },
+            ) => {
+                if !is_inside {
+                    return;
+                }
+
+                self.state.is_dragging = true;
+                event
+            }
+            Event::Mouse(
+                event @ mouse::Event::Input {
+                    state: ButtonState::Released,
+                    ..
+                },
+            ) => {
+                if !is_inside && !self.state.is_dragging {
+                    return;
+                }
+
+                self.state.is_dragging = false;
+                event
+            }
+            Event::Mouse(event @ mouse::Event::WheelScrolled { .. }) => {
+                if !is_inside {
+                    return;
+                }
+
+                event
+            }
+            _ => return,
+        };
+
+        messages.push(on_event(event));
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+
+        renderer.draw(bounds, self.canvas);
+
+        if self.on_event.is_some()
+            && (self.state.is_dragging || bounds.contains(cursor_position))
+        {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+
+    fn tick(
+        &mut self,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+    ) {
+        self.state.bounds = Some(layout.bounds());
+    }
+}
+
+/// The local state of a [`Viewport`].
+///
+/// [`Viewport`]: struct.Viewport.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    bounds: Option<Rectangle<f32>>,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the bounds of the associated [`Viewport`] in the window, as
+    /// computed during the last frame.
+    ///
+    /// It returns `None` if the [`Viewport`] has not been laid out yet.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn bounds(&self) -> Option<Rectangle<f32>> {
+        self.bounds
+    }
+
+    /// Returns whether a mouse button was pressed inside the associated
+    /// [`Viewport`] and has not been released yet.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+}
+
+/// The renderer of a [`Viewport`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Viewport`] in your user interface.
+///
+/// [`Viewport`]: struct.Viewport.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Viewport`].
+    ///
+    /// It receives the bounds of the [`Viewport`] and the [`Canvas`] that
+    /// must be drawn to fill them.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    /// [`Canvas`]: ../../../graphics/struct.Canvas.html
+    fn draw(&mut self, bounds: Rectangle<f32>, canvas: &Canvas);
+}
+
+impl<'a, Message, Renderer> From<Viewport<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(viewport: Viewport<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(viewport)
+    }
+}
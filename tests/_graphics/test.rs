@@ -1,4 +1,5 @@
 use coffee::graphics::{Canvas, Gpu, Image};
+use image_rs as image;
 
 mod mesh;
 
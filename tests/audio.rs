@@ -0,0 +1,123 @@
+#![cfg(feature = "audio")]
+use coffee::audio::{Audio, Listener, PlayOptions, Sound};
+use coffee::graphics::Point;
+use std::time::Duration;
+
+// A mono, 16-bit PCM WAV file with the given samples
+fn wav(sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let mut bytes = Vec::new();
+
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    bytes
+}
+
+#[test]
+fn sounds_are_decoded_into_memory() {
+    let sound = Sound::from_bytes(&wav(8000, &[0; 4000])).unwrap();
+
+    assert_eq!(sound.duration(), Duration::from_millis(500));
+}
+
+#[test]
+fn invalid_sounds_fail_to_decode() {
+    assert!(Sound::from_bytes(b"not a sound").is_err());
+}
+
+#[test]
+fn silent_audio_plays_nothing() {
+    let audio = Audio::silent();
+    let sound = Sound::from_bytes(&wav(8000, &[0; 800])).unwrap();
+
+    let first = audio.play(&sound);
+    let second = audio.play(&sound);
+
+    assert!(audio.is_silent());
+    assert!(first.is_finished());
+    assert!(second.is_finished());
+}
+
+#[test]
+fn groups_keep_their_volume_when_muted() {
+    let audio = Audio::silent();
+    let sfx = audio.group(Audio::SFX);
+
+    sfx.set_volume(0.8);
+    sfx.mute();
+
+    assert!(audio.group(Audio::SFX).is_muted());
+    assert_eq!(audio.group(Audio::SFX).volume(), 0.8);
+
+    sfx.unmute();
+
+    assert!(!audio.group(Audio::SFX).is_muted());
+    assert_eq!(audio.master().volume(), 1.0);
+}
+
+#[test]
+fn positional_sounds_are_panned() {
+    let listener = Listener::new(Point::new(100.0, 100.0), 100.0);
+
+    let (left, right) = listener.gains(Point::new(100.0, 100.0));
+    assert!((left - 1.0).abs() < 0.001 && (right - 1.0).abs() < 0.001);
+
+    let (left, right) = listener.gains(Point::new(50.0, 100.0));
+    assert!(left > right);
+
+    let (left, right) = listener.gains(Point::new(300.0, 100.0));
+    assert_eq!((left, right), (0.0, 0.0));
+}
+
+#[test]
+fn finished_handles_can_be_moved() {
+    let audio = Audio::silent();
+    let listener = Listener::new(Point::new(0.0, 0.0), 100.0);
+    let sound = Sound::from_samples(1, 8000, vec![0; 80]);
+
+    let handle = audio.play_at(&sound, Point::new(10.0, 0.0), &listener);
+
+    assert!(handle.is_finished());
+    handle.set_position(Point::new(-10.0, 0.0), &listener);
+}
+
+#[test]
+fn speeds_are_clamped() {
+    let audio = Audio::silent();
+    let sound = Sound::from_samples(1, 8000, vec![0; 80]);
+
+    assert_eq!(PlayOptions::default().speed, 1.0);
+
+    audio.master().set_speed(10.0);
+    assert_eq!(audio.master().speed(), 4.0);
+
+    audio.master().set_speed(-1.0);
+    assert_eq!(audio.master().speed(), 0.0);
+
+    let handle = audio.play_with(
+        &sound,
+        PlayOptions {
+            speed: 0.0,
+            pan: -1.0,
+            ..PlayOptions::default()
+        },
+    );
+
+    handle.set_speed(std::f32::NAN);
+    assert!(handle.is_finished());
+}
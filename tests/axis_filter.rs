@@ -0,0 +1,68 @@
+use coffee::graphics::Vector;
+use coffee::input::{AxisFilter, Curve};
+
+const EPSILON: f32 = 1e-6;
+
+fn filters() -> Vec<AxisFilter> {
+    vec![
+        AxisFilter::NONE,
+        AxisFilter::default(),
+        AxisFilter {
+            dead_zone: 0.25,
+            curve: Curve::Squared,
+        },
+        AxisFilter {
+            dead_zone: 0.5,
+            curve: Curve::Custom(|value| value.powi(3)),
+        },
+    ]
+}
+
+#[test]
+fn full_deflection_reaches_one() {
+    for filter in filters() {
+        assert!((filter.apply(1.0) - 1.0).abs() < EPSILON);
+        assert!((filter.apply(-1.0) + 1.0).abs() < EPSILON);
+
+        let diagonal = Vector::new(1.0, 1.0).normalize();
+        let filtered = filter.apply_radial(diagonal);
+
+        assert!((filtered.norm() - 1.0).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn dead_zone_is_ignored() {
+    for filter in filters() {
+        assert_eq!(filter.apply(filter.dead_zone), 0.0);
+        assert_eq!(filter.apply(-filter.dead_zone), 0.0);
+
+        let inside = Vector::new(filter.dead_zone, 0.0);
+        assert_eq!(filter.apply_radial(inside), Vector::new(0.0, 0.0));
+    }
+}
+
+#[test]
+fn values_are_monotonic_and_bounded() {
+    for filter in filters() {
+        let mut previous = 0.0;
+
+        for step in 0..=100 {
+            let value = filter.apply(step as f32 / 100.0);
+
+            assert!(value >= previous);
+            assert!(value <= 1.0);
+
+            previous = value;
+        }
+    }
+}
+
+#[test]
+fn radial_dead_zone_preserves_direction() {
+    let filter = AxisFilter::default();
+    let stick = Vector::new(0.6, 0.3);
+    let filtered = filter.apply_radial(stick);
+
+    assert!((filtered.normalize() - stick.normalize()).norm() < EPSILON);
+}
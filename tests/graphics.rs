@@ -1,6 +1,6 @@
 #![cfg(not(target_os = "windows"))]
 use coffee::graphics::{
-    Color, Frame, Gpu, Point, Quad, Window, WindowSettings,
+    Color, Frame, Gpu, GpuSettings, Point, Quad, Window, WindowSettings,
 };
 use coffee::load::Task;
 use coffee::ui::{
@@ -23,6 +23,7 @@ fn graphics() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        gpu: GpuSettings::default(),
     })
 }
 
@@ -160,7 +161,7 @@ impl Game for Runner {
         })
     }
 
-    fn interact(&mut self, _input: &mut (), window: &mut Window) {
+    fn interact(&mut self, _input: &mut (), window: &mut Window) -> Result<()> {
         match self.state {
             State::Pending { .. } => self.run_next(window.gpu()),
             State::Running { .. } => self.run_next(window.gpu()),
@@ -169,9 +170,11 @@ impl Game for Runner {
             }
             _ => {}
         }
+
+        Ok(())
     }
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) -> Result<()> {
         frame.clear(Color {
             r: 0.3,
             g: 0.3,
@@ -222,6 +225,8 @@ impl Game for Runner {
             }
             _ => {}
         }
+
+        Ok(())
     }
 
     fn is_finished(&self) -> bool {
@@ -0,0 +1,748 @@
+use coffee::graphics::{
+    Canvas, Color, Font, Frame, GpuSettings, Image, Window, WindowSettings,
+};
+use coffee::headless::{self, Runner};
+use coffee::input::keyboard::{self, KeyCode, Modifiers};
+use coffee::input::{self, ButtonState, KeyboardAndMouse};
+use coffee::load::{Job, Join, Task};
+use coffee::time::{Cooldown, Interval};
+use coffee::{Error, Game, Result, Timer};
+
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Player {
+    position: u32,
+    is_running: bool,
+}
+
+impl Game for Player {
+    type Input = KeyboardAndMouse;
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Player> {
+        Task::succeed(|| Player {
+            position: 0,
+            is_running: false,
+        })
+    }
+
+    fn interact(
+        &mut self,
+        input: &mut KeyboardAndMouse,
+        _window: &mut Window,
+    ) -> Result<()> {
+        self.is_running = input.is_key_pressed(KeyCode::Right);
+
+        Ok(())
+    }
+
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        if self.is_running {
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct Drifter {
+    position: f32,
+    velocity: f32,
+    seed: u64,
+    is_pushing: bool,
+}
+
+impl Drifter {
+    fn state(&self) -> (u32, u32, u64) {
+        (self.position.to_bits(), self.velocity.to_bits(), self.seed)
+    }
+}
+
+impl Game for Drifter {
+    type Input = KeyboardAndMouse;
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Drifter> {
+        Task::succeed(|| Drifter {
+            position: 0.0,
+            velocity: 0.0,
+            seed: 42,
+            is_pushing: false,
+        })
+    }
+
+    fn interact(
+        &mut self,
+        input: &mut KeyboardAndMouse,
+        _window: &mut Window,
+    ) -> Result<()> {
+        self.is_pushing = input.is_key_pressed(KeyCode::Right);
+
+        Ok(())
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        // A seeded linear congruential generator
+        self.seed = self.seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let jitter = (self.seed >> 40) as f32 / (1 << 24) as f32 - 0.5;
+
+        if self.is_pushing {
+            self.velocity += 10.0;
+        }
+
+        self.velocity = self.velocity * 0.98 + jitter;
+        self.position += self.velocity
+            * window.tick_duration().as_secs_f32()
+            * window.total_time().as_secs_f32().sin();
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn settings() -> WindowSettings {
+    WindowSettings {
+        title: String::from("Headless integration tests - Coffee"),
+        size: (320, 240),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    }
+}
+
+fn key(state: ButtonState) -> input::Event {
+    input::Event::Keyboard {
+        event: keyboard::Event::Input {
+            state,
+            key_code: Some(KeyCode::Right),
+            scan_code: 0,
+            repeat: false,
+            modifiers: Modifiers::default(),
+        },
+        time: Instant::now(),
+    }
+}
+
+#[test]
+fn headless_runner_updates_deterministically() -> Result<()> {
+    let mut runner = Runner::<Player>::without_gpu(settings().size)?;
+
+    assert_eq!(runner.window().width(), 320.0);
+
+    runner.run(10)?;
+    assert_eq!(runner.game().position, 0);
+
+    runner.run_with_events(30, vec![key(ButtonState::Pressed)])?;
+    assert_eq!(runner.game().position, 30);
+
+    runner.run_with_events(5, vec![key(ButtonState::Released)])?;
+    assert_eq!(runner.game().position, 30);
+    assert_eq!(runner.ticks(), 45);
+
+    match runner.draw() {
+        Err(Error::GpuUnavailable) => {}
+        result => panic!("Unexpected draw result: {:?}", result),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn simulations_with_the_same_inputs_are_identical() -> Result<()> {
+    let inputs = || {
+        (0..300).map(|tick| match tick % 50 {
+            0 => vec![key(ButtonState::Pressed)],
+            20 => vec![key(ButtonState::Released)],
+            _ => vec![],
+        })
+    };
+
+    let first: Drifter = headless::simulate(settings(), 600, inputs())?;
+    let second: Drifter = headless::simulate(settings(), 600, inputs())?;
+
+    assert_eq!(first.state(), second.state());
+
+    Ok(())
+}
+
+trait Rate {
+    const TICKS_PER_SECOND: u16;
+}
+
+struct Slow;
+
+impl Rate for Slow {
+    const TICKS_PER_SECOND: u16 = 30;
+}
+
+struct Fast;
+
+impl Rate for Fast {
+    const TICKS_PER_SECOND: u16 = 240;
+}
+
+struct Typist<R: Rate> {
+    is_pressing: bool,
+    presses: u32,
+    updates: u32,
+    rate: PhantomData<R>,
+}
+
+impl<R: Rate> Game for Typist<R> {
+    type Input = KeyboardAndMouse;
+    type LoadingScreen = ();
+
+    const TICKS_PER_SECOND: u16 = R::TICKS_PER_SECOND;
+
+    fn load(_window: &Window) -> Task<Typist<R>> {
+        Task::succeed(|| Typist {
+            is_pressing: false,
+            presses: 0,
+            updates: 0,
+            rate: PhantomData,
+        })
+    }
+
+    fn interact(
+        &mut self,
+        input: &mut KeyboardAndMouse,
+        _window: &mut Window,
+    ) -> Result<()> {
+        self.is_pressing = input.was_key_pressed(KeyCode::Right);
+
+        Ok(())
+    }
+
+    fn update(&mut self, _window: &Window) -> Result<()> {
+        if self.is_pressing {
+            self.presses += 1;
+            self.is_pressing = false;
+        }
+
+        self.updates += 1;
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+// Taps a key every 3 frames during a second at 60 FPS
+fn tap_for_a_second<R: Rate>() -> Result<Runner<Typist<R>>> {
+    let mut runner = Runner::<Typist<R>>::without_gpu(settings().size)?;
+
+    for frame in 0..60 {
+        if frame % 3 == 0 {
+            runner.feed(key(ButtonState::Pressed));
+            runner.feed(key(ButtonState::Released));
+        }
+
+        let _ = runner.frame(Duration::from_micros(16_667))?;
+    }
+
+    Ok(runner)
+}
+
+#[test]
+fn key_presses_reach_updates_at_30_ticks_per_second() -> Result<()> {
+    let runner = tap_for_a_second::<Slow>()?;
+
+    // Half of the frames do not update, but presses are kept until a tick
+    assert_eq!(runner.game().updates, 30);
+    assert_eq!(runner.game().presses, 20);
+
+    Ok(())
+}
+
+#[test]
+fn key_presses_reach_updates_at_240_ticks_per_second() -> Result<()> {
+    let runner = tap_for_a_second::<Fast>()?;
+
+    // Every frame updates a few times, but presses are seen only once
+    assert_eq!(runner.game().updates, 240);
+    assert_eq!(runner.game().presses, 20);
+    assert!(!runner.input().was_key_pressed(KeyCode::Right));
+
+    Ok(())
+}
+
+struct Sluggish;
+
+impl Sluggish {
+    // The simulated duration of every update, which is longer than a tick
+    const UPDATE_COST: Duration = Duration::from_millis(30);
+}
+
+impl Game for Sluggish {
+    type Input = ();
+    type LoadingScreen = ();
+
+    const TICKS_PER_SECOND: u16 = 50;
+    const MAX_TICKS_PER_FRAME: u16 = 4;
+
+    fn load(_window: &Window) -> Task<Sluggish> {
+        Task::succeed(|| Sluggish)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn slow_updates_drop_the_backlog() -> Result<()> {
+    let mut runner = Runner::<Sluggish>::without_gpu(settings().size)?;
+    let mut elapsed = Duration::from_millis(20);
+    let mut ticks = Vec::new();
+
+    for _ in 0..8 {
+        let frame_ticks = runner.frame(elapsed)?;
+
+        // Every frame lasts as long as the updates of the previous one
+        elapsed = Sluggish::UPDATE_COST * u32::from(frame_ticks);
+        ticks.push(frame_ticks);
+    }
+
+    // Catching up makes frames slower until they reach the cap
+    assert_eq!(ticks, vec![1, 1, 2, 3, 4, 4, 4, 4]);
+
+    // Each of the last 3 frames owed 6 ticks and dropped 2 of them
+    assert_eq!(runner.timer().dropped_ticks(), 6);
+    assert_eq!(runner.ticks(), 23);
+
+    // The backlog is gone, so frames stay bounded
+    assert_eq!(runner.frame(Duration::from_millis(5))?, 0);
+    assert_eq!(runner.frame(Duration::from_millis(5))?, 1);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn resizing_to_zero_area_is_harmless() -> Result<()> {
+    let mut runner = Runner::<Player>::new(settings())?;
+
+    for size in [(0, 0), (320, 240), (1, 0), (0, 1), (1, 1), (640, 480)]
+        .iter()
+        .cycle()
+        .take(120)
+    {
+        runner.resize(size.0, size.1);
+        runner.tick()?;
+        runner.draw()?;
+
+        assert_eq!(runner.window().is_minimized(), size.0 == 0 || size.1 == 0);
+    }
+
+    assert_eq!(runner.ticks(), 120);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn canvases_without_area_cannot_be_created() -> Result<()> {
+    let mut runner = Runner::<Player>::new(settings())?;
+    let gpu = runner.window_mut().gpu();
+
+    assert!(Canvas::new(gpu, 0, 0).is_err());
+    assert!(Canvas::new(gpu, 320, 0).is_err());
+    assert!(Canvas::new(gpu, 0, 240).is_err());
+    assert!(Canvas::new(gpu, 1, 1).is_ok());
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn dropped_images_free_their_textures() -> Result<()> {
+    let mut runner = Runner::<Player>::new(settings())?;
+    let before = runner.window_mut().gpu().memory_stats();
+
+    for _ in 0..10_000 {
+        let gpu = runner.window_mut().gpu();
+        let image = Image::from_colors(gpu, &[Color::WHITE; 16])?;
+
+        assert_eq!(
+            gpu.memory_stats().textures_alive,
+            before.textures_alive + 1
+        );
+
+        drop(image);
+    }
+
+    let canvas = Canvas::new(runner.window_mut().gpu(), 64, 64)?;
+    drop(canvas);
+
+    assert_eq!(runner.window_mut().gpu().memory_stats(), before);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn cached_assets_are_dropped_when_unused() -> Result<()> {
+    let mut runner = Runner::<Player>::new(settings())?;
+    let before = runner.window_mut().gpu().memory_stats();
+
+    let first = runner.load(Image::load("resources/ui.png"))?;
+    let second = runner.load(Image::load("resources/ui.png"))?;
+    let fonts = runner.load(
+        (
+            Font::load_from_path("resources/font/Inconsolata-Regular.ttf"),
+            Font::load_from_path("resources/font/Inconsolata-Regular.ttf"),
+        )
+            .join(),
+    )?;
+
+    let gpu = runner.window_mut().gpu();
+
+    assert_eq!(gpu.memory_stats().textures_alive, before.textures_alive + 1);
+    assert_eq!(gpu.cache().len(), 2);
+
+    // Assets in use survive the end of a frame
+    runner.draw()?;
+    assert_eq!(runner.window_mut().gpu().cache().len(), 2);
+
+    drop(first);
+    drop(fonts);
+    runner.draw()?;
+    assert_eq!(runner.window_mut().gpu().cache().len(), 1);
+
+    drop(second);
+    runner.draw()?;
+
+    let gpu = runner.window_mut().gpu();
+
+    assert!(gpu.cache().is_empty());
+    assert_eq!(gpu.memory_stats().textures_alive, before.textures_alive);
+
+    Ok(())
+}
+
+static PANIC_REPORTED: AtomicBool = AtomicBool::new(false);
+
+struct Fragile;
+
+impl Game for Fragile {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Fragile> {
+        Task::succeed(|| Fragile)
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        if window.total_ticks() == 3 {
+            panic!("Fragile broke");
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_panic(_info: &panic::PanicInfo<'_>) {
+        PANIC_REPORTED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn panics_in_update_are_reported() -> Result<()> {
+    let mut runner = Runner::<Fragile>::without_gpu(settings().size)?;
+
+    runner.run(2)?;
+    assert!(!PANIC_REPORTED.load(Ordering::SeqCst));
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| runner.tick()));
+
+    assert!(outcome.is_err());
+    assert!(PANIC_REPORTED.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+static BYSTANDER_PANICS: AtomicUsize = AtomicUsize::new(0);
+
+struct Bystander;
+
+impl Game for Bystander {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Bystander> {
+        Task::succeed(|| Bystander)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_panic(_info: &panic::PanicInfo<'_>) {
+        let _ = BYSTANDER_PANICS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn panics_are_reported_to_games_of_the_same_thread() -> Result<()> {
+    let runner = Runner::<Bystander>::without_gpu(settings().size)?;
+
+    let other_thread = thread::spawn(|| panic!("Another thread broke"));
+    assert!(other_thread.join().is_err());
+    assert_eq!(BYSTANDER_PANICS.load(Ordering::SeqCst), 0);
+
+    assert!(panic::catch_unwind(|| panic!("This thread broke")).is_err());
+    assert_eq!(BYSTANDER_PANICS.load(Ordering::SeqCst), 1);
+
+    drop(runner);
+
+    assert!(panic::catch_unwind(|| panic!("This thread broke")).is_err());
+    assert_eq!(BYSTANDER_PANICS.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+struct SlowMotion;
+
+impl Game for SlowMotion {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<SlowMotion> {
+        Task::succeed(|| SlowMotion)
+    }
+
+    fn interact(&mut self, _input: &mut (), window: &mut Window) -> Result<()> {
+        match window.total_ticks() {
+            10 => window.clock_mut().set_scale(0.5),
+            20 => window.clock_mut().pause(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn game_time_can_be_scaled_and_paused() -> Result<()> {
+    let mut runner = Runner::<SlowMotion>::without_gpu(settings().size)?;
+    runner.run(30)?;
+
+    let window = runner.window();
+    let clock = window.clock();
+
+    assert_eq!(window.total_time(), window.tick_duration() * 30);
+    assert_eq!(clock.game_time(), window.tick_duration() * 15);
+    assert!(clock.is_paused());
+    assert_eq!(clock.delta(), Duration::from_secs(0));
+
+    Ok(())
+}
+
+struct Spawner {
+    spawned: u32,
+    interval: Interval,
+    dashes: u32,
+    cooldown: Cooldown,
+}
+
+impl Game for Spawner {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Spawner> {
+        Task::succeed(|| Spawner {
+            spawned: 0,
+            interval: Interval::every(Duration::from_millis(500)),
+            dashes: 0,
+            cooldown: Cooldown::new(Duration::from_secs(1)),
+        })
+    }
+
+    fn update(&mut self, window: &Window) -> Result<()> {
+        if self.interval.tick(window) {
+            self.spawned += 1;
+        }
+
+        if self.cooldown.is_ready(window) {
+            self.dashes += 1;
+            self.cooldown.start(window);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn intervals_and_cooldowns_follow_game_time() -> Result<()> {
+    let mut runner = Runner::<Spawner>::without_gpu(settings().size)?;
+    runner.run(Spawner::TICKS_PER_SECOND as u32 * 2)?;
+
+    // Both start counting at the first tick
+    assert_eq!(runner.game().spawned, 3);
+    assert_eq!(runner.game().dashes, 2);
+
+    runner.window_mut().clock_mut().pause();
+    runner.run(Spawner::TICKS_PER_SECOND as u32 * 2)?;
+
+    assert_eq!(runner.game().spawned, 3);
+    assert_eq!(runner.game().dashes, 2);
+
+    Ok(())
+}
+
+struct Painter {
+    canvas: Canvas,
+    repaints: u32,
+}
+
+impl Game for Painter {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Painter> {
+        Canvas::load(16, 16).map(|canvas| Painter {
+            canvas,
+            repaints: 0,
+        })
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_resume(&mut self, window: &mut Window) -> Result<()> {
+        if !self.canvas.is_valid(window.gpu()) {
+            self.canvas.as_target(window.gpu()).clear(Color::BLACK);
+            self.repaints += 1;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore]
+fn lost_canvases_are_reported_on_resume() -> Result<()> {
+    let mut runner = Runner::<Painter>::new(settings())?;
+
+    runner.run(5)?;
+    runner.suspend();
+    runner.resume()?;
+    assert_eq!(runner.game().repaints, 1);
+
+    runner.draw()?;
+    runner.suspend();
+    runner.resume()?;
+    assert_eq!(runner.game().repaints, 2);
+
+    Ok(())
+}
+
+#[test]
+fn loading_graphics_without_a_gpu_fails() {
+    match Runner::<Painter>::without_gpu(settings().size) {
+        Err(Error::GpuUnavailable) => {}
+        result => panic!("Unexpected runner: {:?}", result),
+    }
+}
+
+#[test]
+#[ignore]
+fn parallel_progress_never_goes_backwards() -> Result<()> {
+    const JOBS: usize = 8;
+
+    let mut runner = Runner::<Player>::new(settings())?;
+
+    // Later jobs are prepared faster, so they finish first
+    let jobs = (0..JOBS)
+        .map(|i| {
+            Job::new(
+                move || {
+                    thread::sleep(Duration::from_millis(
+                        10 * (JOBS - i) as u64,
+                    ));
+
+                    Ok(i)
+                },
+                |gpu, i| {
+                    let _ = Image::from_colors(gpu, &[Color::WHITE; 4])?;
+
+                    Ok(i)
+                },
+            )
+        })
+        .collect();
+
+    let mut percentages = Vec::new();
+
+    let results = runner
+        .load_with_progress(Task::parallel(jobs), |progress| {
+            percentages.push(progress.percentage())
+        })?;
+
+    assert_eq!(results, (0..JOBS).collect::<Vec<_>>());
+    assert!(percentages.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(percentages.last(), Some(&100.0));
+
+    Ok(())
+}
+
+#[test]
+fn loading_screens_are_drawn_while_threads_work() -> Result<()> {
+    let mut runner = Runner::<Player>::without_gpu(settings().size)?;
+    let mut reports = 0;
+
+    let generate = Task::on_thread(|| {
+        thread::sleep(Duration::from_millis(500));
+
+        Ok(42)
+    });
+
+    let result = runner.load_with_progress(generate, |_| reports += 1)?;
+
+    assert_eq!(result, 42);
+
+    // Around 15 redraws are expected, plus the first and last reports
+    assert!(reports > 5, "Only {} reports", reports);
+
+    let started_at = Instant::now();
+    let generate = Task::on_thread(|| {
+        thread::sleep(Duration::from_secs(5));
+
+        Ok(())
+    });
+
+    match runner.load(generate.timeout(Duration::from_millis(100))) {
+        Err(Error::Timeout) => {}
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    assert!(started_at.elapsed() < Duration::from_secs(1));
+
+    Ok(())
+}
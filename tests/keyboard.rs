@@ -0,0 +1,270 @@
+use coffee::graphics::{Frame, Window};
+use coffee::headless::Runner;
+use coffee::input::keyboard::{self, KeyCode, Keyboard, Modifiers, ScanCode};
+use coffee::input::shortcut::Chord;
+use coffee::input::{self, ButtonState};
+use coffee::load::Task;
+use coffee::{Game, Result, Timer};
+
+use std::time::Instant;
+
+const PRINTABLE_KEYS: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::A),
+    ("Z", KeyCode::Z),
+    ("0", KeyCode::Key0),
+    ("9", KeyCode::Key9),
+    ("Space", KeyCode::Space),
+    ("-", KeyCode::Minus),
+    ("=", KeyCode::Equals),
+    (",", KeyCode::Comma),
+    (".", KeyCode::Period),
+    ("/", KeyCode::Slash),
+    ("\\", KeyCode::Backslash),
+    (";", KeyCode::Semicolon),
+    (":", KeyCode::Colon),
+    ("'", KeyCode::Apostrophe),
+    ("`", KeyCode::Grave),
+    ("[", KeyCode::LBracket),
+    ("]", KeyCode::RBracket),
+    ("@", KeyCode::At),
+    ("IntlBackslash", KeyCode::OEM102),
+];
+
+#[test]
+fn printable_keys_can_be_parsed() {
+    for (name, key_code) in PRINTABLE_KEYS {
+        let chord: Chord = name.parse().expect("Parse chord");
+
+        assert_eq!(chord, Chord::new(Modifiers::default(), *key_code));
+    }
+}
+
+#[test]
+fn chords_round_trip() {
+    for (_, key_code) in PRINTABLE_KEYS {
+        let chord = Chord::new(
+            Modifiers {
+                ctrl: true,
+                shift: true,
+                ..Modifiers::default()
+            },
+            *key_code,
+        );
+
+        let parsed: Chord = chord.to_string().parse().expect("Parse chord");
+
+        assert_eq!(parsed, chord);
+    }
+}
+
+#[test]
+fn unknown_layout_has_no_names() {
+    assert_eq!(keyboard::localized_name(KeyCode::Y), None);
+    assert_eq!(keyboard::key_name(0), None);
+}
+
+struct Typist;
+
+impl Game for Typist {
+    type Input = Keyboard;
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Typist> {
+        Task::succeed(|| Typist)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+// Scancodes of a PC keyboard, as reported on Windows and Linux
+#[cfg(not(target_os = "macos"))]
+mod scan_code {
+    use coffee::input::keyboard::ScanCode;
+
+    pub const Y: ScanCode = 21;
+    pub const Z: ScanCode = 44;
+    pub const MINUS: ScanCode = 12;
+    pub const EQUALS: ScanCode = 13;
+    pub const LBRACKET: ScanCode = 26;
+    pub const RBRACKET: ScanCode = 27;
+    pub const SEMICOLON: ScanCode = 39;
+    pub const APOSTROPHE: ScanCode = 40;
+    pub const GRAVE: ScanCode = 41;
+    pub const BACKSLASH: ScanCode = 43;
+    pub const COMMA: ScanCode = 51;
+    pub const PERIOD: ScanCode = 52;
+    pub const SLASH: ScanCode = 53;
+    pub const INTL_BACKSLASH: ScanCode = 86;
+    pub const NUMPAD_1: ScanCode = 79;
+}
+
+#[cfg(target_os = "macos")]
+mod scan_code {
+    use coffee::input::keyboard::ScanCode;
+
+    pub const Y: ScanCode = 0x10;
+    pub const Z: ScanCode = 0x06;
+    pub const MINUS: ScanCode = 0x1B;
+    pub const EQUALS: ScanCode = 0x18;
+    pub const LBRACKET: ScanCode = 0x21;
+    pub const RBRACKET: ScanCode = 0x1E;
+    pub const SEMICOLON: ScanCode = 0x29;
+    pub const APOSTROPHE: ScanCode = 0x27;
+    pub const GRAVE: ScanCode = 0x32;
+    pub const BACKSLASH: ScanCode = 0x2A;
+    pub const COMMA: ScanCode = 0x2B;
+    pub const PERIOD: ScanCode = 0x2F;
+    pub const SLASH: ScanCode = 0x2C;
+    pub const INTL_BACKSLASH: ScanCode = 0x0A;
+    pub const NUMPAD_1: ScanCode = 0x53;
+}
+
+// Punctuation keys some backends report without a key code
+const PHYSICAL_KEYS: &[(ScanCode, KeyCode)] = &[
+    (scan_code::MINUS, KeyCode::Minus),
+    (scan_code::EQUALS, KeyCode::Equals),
+    (scan_code::LBRACKET, KeyCode::LBracket),
+    (scan_code::RBRACKET, KeyCode::RBracket),
+    (scan_code::SEMICOLON, KeyCode::Semicolon),
+    (scan_code::APOSTROPHE, KeyCode::Apostrophe),
+    (scan_code::GRAVE, KeyCode::Grave),
+    (scan_code::BACKSLASH, KeyCode::Backslash),
+    (scan_code::COMMA, KeyCode::Comma),
+    (scan_code::PERIOD, KeyCode::Period),
+    (scan_code::SLASH, KeyCode::Slash),
+    (scan_code::INTL_BACKSLASH, KeyCode::OEM102),
+];
+
+// The scancode, the key code reported by a backend that maps keys by their
+// physical position, the character produced, the expected key code, and its
+// localized name
+const GERMAN_LAYOUT: &[(ScanCode, Option<KeyCode>, char, KeyCode, &str)] = &[
+    (scan_code::Y, Some(KeyCode::Y), 'z', KeyCode::Z, "Z"),
+    (scan_code::Z, Some(KeyCode::Z), 'y', KeyCode::Y, "Y"),
+    (
+        scan_code::MINUS,
+        Some(KeyCode::Minus),
+        'ß',
+        KeyCode::Minus,
+        "ß",
+    ),
+    (scan_code::EQUALS, None, '´', KeyCode::Equals, "´"),
+    (scan_code::LBRACKET, None, 'ü', KeyCode::LBracket, "Ü"),
+    (scan_code::RBRACKET, None, '+', KeyCode::RBracket, "+"),
+    (scan_code::SEMICOLON, None, 'ö', KeyCode::Semicolon, "Ö"),
+    (scan_code::APOSTROPHE, None, 'ä', KeyCode::Apostrophe, "Ä"),
+    (scan_code::GRAVE, None, '^', KeyCode::Grave, "^"),
+    (scan_code::BACKSLASH, None, '#', KeyCode::Backslash, "#"),
+    (
+        scan_code::COMMA,
+        Some(KeyCode::Comma),
+        ',',
+        KeyCode::Comma,
+        ",",
+    ),
+    (
+        scan_code::PERIOD,
+        Some(KeyCode::Period),
+        '.',
+        KeyCode::Period,
+        ".",
+    ),
+    (scan_code::INTL_BACKSLASH, None, '<', KeyCode::OEM102, "<"),
+];
+
+fn runner() -> Runner<Typist> {
+    Runner::without_gpu((320, 240)).expect("Create runner")
+}
+
+fn key(
+    state: ButtonState,
+    scan_code: ScanCode,
+    key_code: Option<KeyCode>,
+) -> input::Event {
+    input::Event::Keyboard {
+        event: keyboard::Event::Input {
+            state,
+            key_code,
+            scan_code,
+            modifiers: Modifiers::default(),
+            repeat: false,
+        },
+        time: Instant::now(),
+    }
+}
+
+fn text(character: char) -> input::Event {
+    input::Event::Keyboard {
+        event: keyboard::Event::TextEntered { character },
+        time: Instant::now(),
+    }
+}
+
+#[test]
+fn punctuation_keys_have_key_codes() {
+    let mut runner = runner();
+
+    for (scan_code, key_code) in PHYSICAL_KEYS {
+        runner.feed(key(ButtonState::Pressed, *scan_code, None));
+        assert!(runner.input().is_key_pressed(*key_code), "{:?}", key_code);
+
+        runner.feed(key(ButtonState::Released, *scan_code, None));
+        assert!(!runner.input().is_key_pressed(*key_code), "{:?}", key_code);
+    }
+}
+
+#[test]
+fn key_codes_follow_the_keyboard_layout() {
+    let mut runner = runner();
+
+    for (scan_code, reported, character, key_code, _) in GERMAN_LAYOUT {
+        // The first press teaches the character of the key
+        runner.feed(key(ButtonState::Pressed, *scan_code, *reported));
+        runner.feed(text(*character));
+        runner.feed(key(ButtonState::Released, *scan_code, *reported));
+
+        if let Some(reported) = reported {
+            assert!(
+                !runner.input().is_key_pressed(*reported),
+                "{:?}",
+                reported
+            );
+        }
+
+        runner.feed(key(ButtonState::Pressed, *scan_code, *reported));
+        assert!(runner.input().is_key_pressed(*key_code), "{:?}", key_code);
+
+        runner.feed(key(ButtonState::Released, *scan_code, *reported));
+        assert!(!runner.input().is_key_pressed(*key_code), "{:?}", key_code);
+    }
+
+    for (scan_code, _, _, key_code, name) in GERMAN_LAYOUT {
+        assert_eq!(keyboard::localized_name(*key_code).as_deref(), Some(*name));
+        assert_eq!(keyboard::key_name(*scan_code).as_deref(), Some(*name));
+    }
+}
+
+#[test]
+fn numpad_keys_keep_their_key_codes() {
+    let mut runner = runner();
+
+    for _ in 0..2 {
+        runner.feed(key(
+            ButtonState::Pressed,
+            scan_code::NUMPAD_1,
+            Some(KeyCode::Numpad1),
+        ));
+        runner.feed(text('1'));
+
+        assert!(runner.input().is_key_pressed(KeyCode::Numpad1));
+        assert!(!runner.input().is_key_pressed(KeyCode::Key1));
+
+        runner.feed(key(
+            ButtonState::Released,
+            scan_code::NUMPAD_1,
+            Some(KeyCode::Numpad1),
+        ));
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "mint")]
+use coffee::graphics::{Point, Rectangle, Transformation, Vector};
+
+#[test]
+fn points_round_trip() {
+    let point = Point::new(1.5, -2.0);
+    let converted: mint::Point2<f32> = point.into();
+
+    assert_eq!(converted, mint::Point2 { x: 1.5, y: -2.0 });
+    assert_eq!(Point::from(converted), point);
+}
+
+#[test]
+fn vectors_round_trip() {
+    let vector = Vector::new(-3.0, 0.25);
+    let converted: mint::Vector2<f32> = vector.into();
+
+    assert_eq!(converted, mint::Vector2 { x: -3.0, y: 0.25 });
+    assert_eq!(Vector::from(converted), vector);
+}
+
+#[test]
+fn mint_types_are_accepted() {
+    let rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 10.0,
+        height: 10.0,
+    };
+
+    assert!(rectangle.contains(mint::Point2 { x: 5.0, y: 5.0 }));
+    assert!(!rectangle.contains(mint::Point2 { x: 15.0, y: 5.0 }));
+
+    assert_eq!(
+        Transformation::translate(mint::Vector2 { x: 2.0, y: 3.0 }),
+        Transformation::translate(Vector::new(2.0, 3.0))
+    );
+}
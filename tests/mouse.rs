@@ -0,0 +1,60 @@
+use coffee::input::keyboard::Modifiers;
+use coffee::input::mouse::{self, Button, DragStatus, Mouse};
+use coffee::input::{self, window, ButtonState, Input};
+
+use std::time::Instant;
+
+fn feed(mouse: &mut Mouse, event: mouse::Event) {
+    mouse.update(input::Event::Mouse {
+        event,
+        time: Instant::now(),
+    });
+}
+
+fn button(mouse: &mut Mouse, state: ButtonState) {
+    feed(
+        mouse,
+        mouse::Event::Input {
+            state,
+            button: Button::Left,
+            modifiers: Modifiers::default(),
+        },
+    );
+}
+
+fn move_to(mouse: &mut Mouse, x: f32, y: f32) {
+    feed(mouse, mouse::Event::CursorMoved { x, y });
+}
+
+#[test]
+fn cancelled_drags_keep_the_button_pressed() {
+    let mut mouse = Mouse::new();
+
+    button(&mut mouse, ButtonState::Pressed);
+    move_to(&mut mouse, 50.0, 0.0);
+
+    mouse.update(input::Event::Window {
+        event: window::Event::Unfocused,
+        time: Instant::now(),
+    });
+
+    let drag = mouse.drag(Button::Left).expect("Drag");
+
+    assert_eq!(drag.status, DragStatus::Cancelled);
+    assert!(mouse.is_button_pressed(Button::Left));
+
+    // Moving the cursor does not start a new drag
+    mouse.clear();
+    move_to(&mut mouse, 100.0, 0.0);
+
+    assert_eq!(mouse.drag(Button::Left), None);
+    assert!(mouse.is_button_pressed(Button::Left));
+
+    // Releasing the button finishes no drag and produces no click
+    button(&mut mouse, ButtonState::Released);
+
+    assert!(!mouse.is_button_pressed(Button::Left));
+    assert_eq!(mouse.drag(Button::Left), None);
+    assert!(!mouse.was_button_clicked(Button::Left));
+    assert!(mouse.last_button_hold(Button::Left).is_some());
+}
@@ -0,0 +1,151 @@
+use coffee::graphics::{Color, Point, Vector};
+use coffee::particles::{Curve, Emitter, Particle};
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+const EPSILON: f32 = 1e-4;
+
+fn fountain(seed: u64) -> Emitter {
+    Emitter::new()
+        .position(Point::new(100.0, 100.0))
+        .rate(120.0)
+        .lifetime(Duration::from_millis(500), Duration::from_secs(2))
+        .direction(-PI / 2.0, PI / 4.0)
+        .speed(50.0, 150.0)
+        .acceleration(Vector::new(0.0, 200.0))
+        .seed(seed)
+}
+
+#[test]
+fn emitters_are_deterministic() {
+    let mut a = fountain(42);
+    let mut b = fountain(42);
+
+    a.update(90);
+
+    for _ in 0..90 {
+        b.update(1);
+    }
+
+    let a: Vec<Particle> = a.particles().cloned().collect();
+    let b: Vec<Particle> = b.particles().cloned().collect();
+
+    assert!(!a.is_empty());
+    assert_eq!(a, b);
+}
+
+#[test]
+fn seeds_change_particles() {
+    let mut a = fountain(1);
+    let mut b = fountain(2);
+
+    a.update(10);
+    b.update(10);
+
+    assert_eq!(a.len(), b.len());
+    assert_ne!(
+        a.particles().cloned().collect::<Vec<_>>(),
+        b.particles().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn rate_spawns_particles_per_second() {
+    let mut emitter = Emitter::new()
+        .rate(30.0)
+        .lifetime(Duration::from_secs(10), Duration::from_secs(10));
+
+    emitter.update(60);
+    assert_eq!(emitter.len(), 30);
+
+    let mut emitter = Emitter::new()
+        .rate(30.0)
+        .lifetime(Duration::from_secs(10), Duration::from_secs(10))
+        .ticks_per_second(30);
+
+    emitter.update(30);
+    assert_eq!(emitter.len(), 30);
+}
+
+#[test]
+fn particles_die_after_their_lifetime() {
+    let mut emitter = Emitter::new()
+        .rate(0.0)
+        .lifetime(Duration::from_secs(1), Duration::from_secs(1));
+
+    emitter.burst(10);
+    emitter.update(59);
+    assert_eq!(emitter.len(), 10);
+
+    emitter.update(1);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn capacity_recycles_the_oldest_particles() {
+    let mut emitter = Emitter::new()
+        .rate(0.0)
+        .lifetime(Duration::from_secs(10), Duration::from_secs(10))
+        .capacity(100);
+
+    emitter.burst(100);
+    emitter.update(1);
+    emitter.burst(10);
+
+    assert_eq!(emitter.len(), 100);
+
+    let ages: Vec<f32> = emitter.particles().map(Particle::age).collect();
+
+    assert!(ages[..90].iter().all(|age| *age > 0.0));
+    assert!(ages[90..].iter().all(|age| *age < EPSILON));
+
+    emitter.burst(1_000_000);
+    assert_eq!(emitter.len(), 100);
+}
+
+#[test]
+fn stuck_emitters_stay_bounded() {
+    let mut emitter = Emitter::new()
+        .rate(100_000.0)
+        .lifetime(Duration::from_secs(60), Duration::from_secs(60))
+        .capacity(1_000);
+
+    emitter.update(600);
+
+    assert_eq!(emitter.len(), 1_000);
+}
+
+#[test]
+fn particles_follow_their_acceleration() {
+    let mut emitter = Emitter::new()
+        .rate(0.0)
+        .lifetime(Duration::from_secs(10), Duration::from_secs(10))
+        .direction(0.0, 0.0)
+        .speed(60.0, 60.0)
+        .acceleration(Vector::new(0.0, 60.0));
+
+    emitter.burst(1);
+    emitter.update(60);
+
+    let particle = emitter.particles().next().expect("Live particle");
+
+    assert!((particle.velocity() - Vector::new(60.0, 60.0)).norm() < EPSILON);
+    assert!((particle.position().x - 60.0).abs() < EPSILON);
+    assert!((29.0..31.0).contains(&particle.position().y));
+}
+
+#[test]
+fn curves_interpolate_between_keys() {
+    let size = Curve::new(vec![(1.0, 0.0), (0.0, 10.0), (0.5, 20.0)]);
+
+    assert_eq!(size.at(-1.0), 10.0);
+    assert_eq!(size.at(0.25), 15.0);
+    assert_eq!(size.at(0.75), 10.0);
+    assert_eq!(size.at(2.0), 0.0);
+
+    let fade = Curve::linear(Color::WHITE, Color::new(1.0, 1.0, 1.0, 0.0));
+
+    assert_eq!(fade.at(0.5), Color::new(1.0, 1.0, 1.0, 0.5));
+    assert_eq!(Curve::constant(3.0).at(0.7), 3.0);
+}
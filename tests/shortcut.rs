@@ -0,0 +1,191 @@
+use coffee::input::keyboard::{self, KeyCode, Modifiers};
+use coffee::input::shortcut::{Chord, ParseError};
+use coffee::input::{self, ButtonState, Input, Shortcut, Shortcuts};
+
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Save,
+    SaveAs,
+    DeleteLine,
+}
+
+fn shortcuts() -> Shortcuts<Command> {
+    let mut shortcuts = Shortcuts::new();
+
+    shortcuts.register(shortcut("Ctrl+S"), Command::Save);
+    shortcuts.register(shortcut("Ctrl+Shift+S"), Command::SaveAs);
+    shortcuts.register(shortcut("G X"), Command::DeleteLine);
+
+    shortcuts
+}
+
+fn shortcut(s: &str) -> Shortcut {
+    s.parse().expect("Parse shortcut")
+}
+
+fn key(
+    state: ButtonState,
+    key_code: KeyCode,
+    modifiers: Modifiers,
+    repeat: bool,
+) -> input::Event {
+    input::Event::Keyboard {
+        event: keyboard::Event::Input {
+            state,
+            key_code: Some(key_code),
+            scan_code: 0,
+            repeat,
+            modifiers,
+        },
+        time: Instant::now(),
+    }
+}
+
+fn press(
+    shortcuts: &mut Shortcuts<Command>,
+    key_code: KeyCode,
+    modifiers: Modifiers,
+) {
+    shortcuts.update(key(ButtonState::Pressed, key_code, modifiers, false));
+}
+
+fn ctrl(shift: bool) -> Modifiers {
+    Modifiers {
+        ctrl: true,
+        shift,
+        ..Modifiers::default()
+    }
+}
+
+#[test]
+fn parse_errors_are_descriptive() {
+    let error = |s: &str| s.parse::<Shortcut>().expect_err("Parse error");
+
+    assert_eq!(error("   "), ParseError::Empty);
+    assert_eq!(
+        error("Ctrl+"),
+        ParseError::MissingKey {
+            chord: String::from("Ctrl+"),
+        }
+    );
+    assert_eq!(
+        error("Ctrl+Hyper+S"),
+        ParseError::UnknownModifier {
+            chord: String::from("Ctrl+Hyper+S"),
+            modifier: String::from("Hyper"),
+        }
+    );
+    assert_eq!(
+        error("G Ctrl+Foo"),
+        ParseError::UnknownKey {
+            chord: String::from("Ctrl+Foo"),
+            key: String::from("Foo"),
+        }
+    );
+
+    assert_eq!(
+        error("Ctrl+Hyper+S").to_string(),
+        "Unknown modifier \"Hyper\" in chord \"Ctrl+Hyper+S\" \
+         (expected Ctrl, Shift, Alt, or Logo)"
+    );
+    assert_eq!(
+        error("Ctrl+Foo").to_string(),
+        "Unknown key \"Foo\" in chord \"Ctrl+Foo\""
+    );
+}
+
+#[test]
+fn shortcuts_are_parsed_case_insensitively() {
+    assert_eq!(
+        shortcut("control+SHIFT+s"),
+        Shortcut::from(Chord::new(ctrl(true), KeyCode::S))
+    );
+    assert_eq!(
+        shortcut("G X").chords(),
+        &[Chord::from(KeyCode::G), Chord::from(KeyCode::X)]
+    );
+    assert_eq!(shortcut("Ctrl+Shift+S").to_string(), "Ctrl+Shift+S");
+}
+
+#[test]
+fn modifiers_must_match_exactly() {
+    let mut shortcuts = shortcuts();
+
+    press(&mut shortcuts, KeyCode::S, ctrl(true));
+    assert_eq!(shortcuts.triggered(), &[Command::SaveAs]);
+
+    shortcuts.clear();
+
+    press(&mut shortcuts, KeyCode::S, ctrl(false));
+    assert_eq!(shortcuts.triggered(), &[Command::Save]);
+
+    shortcuts.clear();
+
+    press(&mut shortcuts, KeyCode::S, Modifiers::default());
+    press(
+        &mut shortcuts,
+        KeyCode::S,
+        Modifiers {
+            alt: true,
+            ..ctrl(false)
+        },
+    );
+    assert!(shortcuts.triggered().is_empty());
+}
+
+#[test]
+fn shortcuts_trigger_on_the_press_edge() {
+    let mut shortcuts = shortcuts();
+
+    // Pressing the modifier first does not trigger anything
+    shortcuts.update(key(
+        ButtonState::Pressed,
+        KeyCode::LControl,
+        ctrl(false),
+        false,
+    ));
+    press(&mut shortcuts, KeyCode::S, ctrl(false));
+
+    // Holding the key produces repeats, which are ignored
+    for _ in 0..10 {
+        shortcuts.update(key(
+            ButtonState::Pressed,
+            KeyCode::S,
+            ctrl(false),
+            true,
+        ));
+    }
+
+    shortcuts.update(key(
+        ButtonState::Released,
+        KeyCode::S,
+        ctrl(false),
+        false,
+    ));
+
+    assert_eq!(shortcuts.triggered(), &[Command::Save]);
+
+    shortcuts.clear();
+    assert!(shortcuts.triggered().is_empty());
+}
+
+#[test]
+fn sequences_trigger_on_their_last_chord() {
+    let mut shortcuts = shortcuts();
+
+    press(&mut shortcuts, KeyCode::G, Modifiers::default());
+    assert!(shortcuts.triggered().is_empty());
+
+    press(&mut shortcuts, KeyCode::X, Modifiers::default());
+    assert_eq!(shortcuts.triggered(), &[Command::DeleteLine]);
+
+    shortcuts.clear();
+
+    // Another key in between breaks the sequence
+    press(&mut shortcuts, KeyCode::G, Modifiers::default());
+    press(&mut shortcuts, KeyCode::Y, Modifiers::default());
+    press(&mut shortcuts, KeyCode::X, Modifiers::default());
+    assert!(shortcuts.triggered().is_empty());
+}
@@ -0,0 +1,86 @@
+#![cfg(any(feature = "data-json", feature = "data-ron"))]
+use coffee::storage::Storage;
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    volume: f32,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OldSettings {
+    volume: u8,
+}
+
+// A fresh directory for each test
+fn directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "coffee-storage-{}-{}",
+        name,
+        std::process::id()
+    ));
+
+    let _ = fs::remove_dir_all(&directory);
+
+    directory
+}
+
+fn settings() -> Settings {
+    Settings {
+        volume: 0.5,
+        name: String::from("Player"),
+    }
+}
+
+#[test]
+fn saved_values_can_be_loaded() -> coffee::Result<()> {
+    let storage = Storage::at(directory("round-trip"));
+
+    storage.save("settings", &settings())?;
+
+    assert_eq!(storage.load::<Settings>("settings")?, Some(settings()));
+    assert_eq!(storage.load::<Settings>("missing")?, None);
+
+    // Only the saved file is left behind
+    assert_eq!(fs::read_dir(storage.directory())?.count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn old_versions_are_migrated() -> coffee::Result<()> {
+    let directory = directory("migration");
+
+    Storage::at(&directory).save("settings", &OldSettings { volume: 50 })?;
+
+    let storage = Storage::at(&directory).version(1);
+
+    assert!(storage.load::<Settings>("settings").is_err());
+
+    let migrated = storage.load_with("settings", |old| {
+        assert_eq!(old.version()?, 0);
+
+        let old: OldSettings = old.deserialize()?;
+
+        Ok(Settings {
+            volume: f32::from(old.volume) / 100.0,
+            name: String::from("Player"),
+        })
+    })?;
+
+    assert_eq!(migrated, Some(settings()));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_keys_are_rejected() {
+    let storage = Storage::at(directory("keys"));
+
+    assert!(storage.save("../settings", &settings()).is_err());
+    assert!(storage.save("", &settings()).is_err());
+}
@@ -0,0 +1,108 @@
+use coffee::load::{self, Join, Task};
+use coffee::Error;
+
+use std::io;
+use std::path::PathBuf;
+
+#[test]
+fn total_work_of_composed_tasks() {
+    let a = Task::succeed(|| 1);
+    let b = Task::succeed(|| 2);
+    let c = Task::succeed(|| 3);
+
+    assert_eq!((a, b, c).join().total_work(), 3.0);
+}
+
+#[test]
+fn total_work_of_weighted_stages() {
+    let world = Task::stage_weighted(
+        "Loading world...",
+        5.0,
+        (Task::succeed(|| ()), Task::succeed(|| ())).join(),
+    );
+    let sounds = Task::stage("Loading sounds...", Task::succeed(|| ()));
+
+    assert_eq!(world.total_work(), 5.0);
+    assert_eq!((world, sounds).join().total_work(), 6.0);
+}
+
+#[test]
+fn total_work_of_chained_tasks() {
+    let manifest = (Task::succeed(|| 1), Task::succeed(|| 2)).join();
+
+    let chained = manifest
+        .and_then(|(a, b)| Task::succeed(move || a + b))
+        .and_then(|sum| Task::succeed(move || sum * 2));
+
+    // Each chained task doubles the work of the previous phases
+    assert_eq!(chained.total_work(), 8.0);
+}
+
+#[test]
+fn total_work_of_directory() -> std::io::Result<()> {
+    let root = std::env::temp_dir().join("coffee-load-from-dir");
+    let _ = std::fs::remove_dir_all(&root);
+
+    std::fs::create_dir_all(root.join("nested"))?;
+    std::fs::write(root.join("a.txt"), "a")?;
+    std::fs::write(root.join("b.txt"), "b")?;
+    std::fs::write(root.join("c.bin"), "c")?;
+    std::fs::write(root.join("nested/d.txt"), "d")?;
+
+    let load = |recursive| {
+        load::from_dir(&root, recursive, |path| {
+            match path.extension()?.to_str()? {
+                "txt" => Some(Task::succeed(|| ())),
+                _ => None,
+            }
+        })
+    };
+
+    assert_eq!(load(false).total_work(), 2.0);
+    assert_eq!(load(true).total_work(), 3.0);
+
+    std::fs::remove_dir_all(&root)
+}
+
+#[test]
+fn error_chain_display() {
+    let error = Error::Stage {
+        title: String::from("Loading terrain..."),
+        error: Box::new(Error::Asset {
+            path: PathBuf::from("terrain/grass.png"),
+            error: Box::new(Error::IO(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No such file or directory",
+            ))),
+        }),
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "Stage error in \"Loading terrain...\": \
+         Asset error in terrain/grass.png: \
+         IO error: No such file or directory"
+    );
+
+    match error.root() {
+        Error::IO(error) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+        _ => panic!("Unexpected root error"),
+    }
+}
+
+#[test]
+fn total_stages_of_composed_tasks() {
+    let terrain = Task::stage(
+        "Loading terrain...",
+        (
+            Task::stage("Loading grass...", Task::succeed(|| ())),
+            Task::stage("Loading water...", Task::succeed(|| ())),
+        )
+            .join(),
+    );
+    let sounds =
+        Task::stage_weighted("Loading sounds...", 2.0, Task::succeed(|| ()));
+    let map = Task::succeed(|| ());
+
+    assert_eq!((terrain, sounds, map).join().total_stages(), 4);
+}
@@ -0,0 +1,63 @@
+use coffee::input::keyboard::{self, KeyCode, Modifiers};
+use coffee::input::{self, ButtonState, Input, TextBuffer};
+
+use std::time::Instant;
+
+fn type_text(buffer: &mut TextBuffer, text: &str) {
+    for character in text.chars() {
+        buffer.update(input::Event::Keyboard {
+            event: keyboard::Event::TextEntered { character },
+            time: Instant::now(),
+        });
+    }
+}
+
+fn press(buffer: &mut TextBuffer, key_code: KeyCode) {
+    buffer.update(input::Event::Keyboard {
+        event: keyboard::Event::Input {
+            state: ButtonState::Pressed,
+            key_code: Some(key_code),
+            scan_code: 0,
+            repeat: false,
+            modifiers: Modifiers::default(),
+        },
+        time: Instant::now(),
+    });
+}
+
+#[test]
+fn committed_text_is_edited_by_characters() {
+    let mut buffer = TextBuffer::new();
+
+    // Text committed by an input method editor
+    type_text(&mut buffer, "日本語");
+    assert_eq!(buffer.cursor(), "日本語".len());
+
+    press(&mut buffer, KeyCode::Left);
+    press(&mut buffer, KeyCode::Back);
+    assert_eq!(buffer.text(), "日語");
+    assert_eq!(buffer.cursor(), "日".len());
+
+    press(&mut buffer, KeyCode::Delete);
+    press(&mut buffer, KeyCode::Delete);
+    assert_eq!(buffer.text(), "日");
+
+    press(&mut buffer, KeyCode::Home);
+    type_text(&mut buffer, "ab");
+    press(&mut buffer, KeyCode::End);
+    type_text(&mut buffer, "\u{8}c");
+
+    assert_eq!(buffer.text(), "ab日c");
+}
+
+#[test]
+fn pasted_text_is_inserted_at_the_cursor() {
+    let mut buffer = TextBuffer::new();
+    buffer.set_text("Hello!");
+
+    press(&mut buffer, KeyCode::Left);
+    buffer.insert(", wörld\n");
+
+    assert_eq!(buffer.text(), "Hello, wörld!");
+    assert_eq!(buffer.cursor(), "Hello, wörld".len());
+}
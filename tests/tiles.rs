@@ -0,0 +1,210 @@
+#![cfg(feature = "tiles")]
+use coffee::graphics::{
+    Color, Frame, GpuSettings, Point, Rectangle, Window, WindowSettings,
+};
+use coffee::headless::Runner;
+use coffee::load::Task;
+use coffee::tiles::{Property, Shape, TileMap};
+use coffee::{Error, Game, Result, Timer};
+
+use std::fs;
+use std::path::PathBuf;
+
+struct Empty;
+
+impl Game for Empty {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Empty> {
+        Task::succeed(|| Empty)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn settings() -> WindowSettings {
+    WindowSettings {
+        title: String::from("Tile map tests - Coffee"),
+        size: (320, 240),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+        gpu: GpuSettings::default(),
+    }
+}
+
+const MAP: &str = r##"{
+  "width": 2,
+  "height": 2,
+  "tilewidth": 16,
+  "tileheight": 16,
+  "orientation": "orthogonal",
+  "infinite": false,
+  "properties": [
+    { "name": "music", "type": "file", "value": "music/level.ogg" }
+  ],
+  "tilesets": [{
+    "firstgid": 1,
+    "name": "terrain",
+    "image": "terrain.png",
+    "imagewidth": 32,
+    "imageheight": 16,
+    "tilewidth": 16,
+    "tileheight": 16,
+    "margin": 0,
+    "spacing": 0,
+    "columns": 2,
+    "tilecount": 2,
+    "tiles": [{
+      "id": 1,
+      "animation": [
+        { "tileid": 0, "duration": 100 },
+        { "tileid": 1, "duration": 100 }
+      ],
+      "properties": [{ "name": "solid", "type": "bool", "value": true }]
+    }]
+  }],
+  "layers": [
+    {
+      "type": "tilelayer",
+      "name": "ground",
+      "width": 2,
+      "height": 2,
+      "data": [1, 2, 0, 2147483649],
+      "visible": true
+    },
+    {
+      "type": "group",
+      "name": "entities",
+      "offsetx": 8,
+      "offsety": 0,
+      "layers": [{
+        "type": "objectgroup",
+        "name": "spawns",
+        "objects": [
+          {
+            "id": 1,
+            "name": "player",
+            "type": "spawn",
+            "x": 0,
+            "y": 16,
+            "point": true,
+            "properties": [
+              { "name": "lives", "type": "int", "value": 3 },
+              { "name": "tint", "type": "color", "value": "#80ff0000" }
+            ]
+          },
+          {
+            "id": 2,
+            "x": 16,
+            "y": 0,
+            "width": 8,
+            "height": 4
+          },
+          {
+            "id": 3,
+            "x": 0,
+            "y": 0,
+            "polygon": [{ "x": 0, "y": 0 }, { "x": 4, "y": 4 }]
+          }
+        ]
+      }]
+    }
+  ]
+}"##;
+
+// Writes the map into a fresh directory for each test and returns its path
+fn write_map(name: &str, map: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "coffee-tiles-{}-{}",
+        name,
+        std::process::id()
+    ));
+
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).expect("Create directory");
+
+    let path = directory.join("level.json");
+    fs::write(&path, map).expect("Write map");
+
+    path
+}
+
+#[test]
+#[ignore]
+fn tiled_maps_are_loaded() -> Result<()> {
+    let path = write_map("load", MAP);
+    let directory = path.parent().expect("Map directory");
+
+    image_rs::RgbaImage::new(32, 16).save(directory.join("terrain.png"))?;
+
+    let mut runner = Runner::<Empty>::new(settings())?;
+    let map = runner.load(TileMap::load_tiled(&path))?;
+
+    assert_eq!((map.width(), map.height()), (2, 2));
+    assert_eq!(map.tile_size(), (16, 16));
+    assert_eq!(
+        map.properties().get("music"),
+        Some(&Property::File(directory.join("music/level.ogg")))
+    );
+
+    let ground = &map.tile_layers()[0];
+
+    assert_eq!(ground.name(), "ground");
+    assert_eq!(ground.tile(0, 0), Some(1));
+    assert_eq!(ground.tile(0, 1), None);
+    assert_eq!(ground.tile(1, 1), Some(1));
+    assert_eq!(ground.tile(2, 0), None);
+
+    assert_eq!(
+        map.tile_properties(2).and_then(|p| p.get("solid")),
+        Some(&Property::Bool(true))
+    );
+    assert_eq!(map.tile_properties(1), None);
+
+    let spawns = map.object_layer("spawns").expect("Object layer");
+    let objects = spawns.objects();
+
+    assert_eq!(objects.len(), 3);
+    assert_eq!(objects[0].name, "player");
+    assert_eq!(objects[0].kind, "spawn");
+    assert_eq!(objects[0].shape, Shape::Point(Point::new(8.0, 16.0)));
+    assert_eq!(objects[0].properties.get("lives"), Some(&Property::Int(3)));
+    assert_eq!(
+        objects[0].properties.get("tint"),
+        Some(&Property::Color(Color::new(1.0, 0.0, 0.0, 128.0 / 255.0)))
+    );
+    assert_eq!(
+        objects[1].shape,
+        Shape::Rectangle(Rectangle {
+            x: 24.0,
+            y: 0.0,
+            width: 8.0,
+            height: 4.0,
+        })
+    );
+    assert_eq!(
+        objects[2].shape,
+        Shape::Polygon(vec![Point::new(8.0, 0.0), Point::new(12.0, 4.0)])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_maps_fail_to_load() -> Result<()> {
+    let path =
+        write_map("unsupported", &MAP.replace("orthogonal", "isometric"));
+
+    let mut runner = Runner::<Empty>::without_gpu(settings().size)?;
+
+    match runner.load(TileMap::load_tiled(&path)) {
+        Err(Error::Asset { .. }) => {}
+        result => panic!("Unexpected map: {:?}", result.map(|_| ())),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use coffee::graphics::{Frame, Window};
+use coffee::headless::Runner;
+use coffee::load::Task;
+use coffee::{Game, Result, Timer};
+
+use std::time::Duration;
+
+// 50 ticks per second make every tick last exactly 20 milliseconds
+struct Clock;
+
+impl Game for Clock {
+    type Input = ();
+    type LoadingScreen = ();
+
+    const TICKS_PER_SECOND: u16 = 50;
+
+    fn load(_window: &Window) -> Task<Clock> {
+        Task::succeed(|| Clock)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+// 240 ticks per second run 4 ticks every frame of a 60 Hz display
+struct Spinner;
+
+impl Game for Spinner {
+    type Input = ();
+    type LoadingScreen = ();
+
+    const TICKS_PER_SECOND: u16 = 240;
+
+    fn load(_window: &Window) -> Task<Spinner> {
+        Task::succeed(|| Spinner)
+    }
+
+    fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn runner() -> Result<Runner<Clock>> {
+    Runner::without_gpu((320, 240))
+}
+
+fn ms(milliseconds: u64) -> Duration {
+    Duration::from_millis(milliseconds)
+}
+
+#[test]
+fn frame_time_accumulates_into_ticks() -> Result<()> {
+    let mut runner = runner()?;
+    let mut ticks = Vec::new();
+
+    for _ in 0..10 {
+        ticks.push(runner.frame(ms(7))?);
+    }
+
+    // A tick is due every time 20 milliseconds add up
+    assert_eq!(ticks, vec![0, 0, 1, 0, 0, 1, 0, 0, 1, 0]);
+    assert_eq!(runner.timer().ticks(), 3);
+    assert_eq!(runner.timer().total_time(), ms(60));
+    assert_eq!(runner.timer().tick_duration(), ms(20));
+
+    // The first frame starts the clock
+    assert_eq!(runner.timer().delta(), ms(7));
+    assert_eq!(runner.timer().elapsed(), ms(63));
+    assert!((runner.timer().fps() - 1000.0 / 7.0).abs() < 0.01);
+
+    Ok(())
+}
+
+#[test]
+fn long_frames_tick_many_times() -> Result<()> {
+    let mut runner = runner()?;
+
+    assert_eq!(runner.frame(ms(50))?, 2);
+    assert!(runner.timer().has_ticked());
+
+    // 10 milliseconds were left from the previous frame
+    assert_eq!(runner.frame(ms(5))?, 0);
+    assert!(!runner.timer().has_ticked());
+
+    assert_eq!(runner.frame(ms(5))?, 1);
+    assert!(runner.timer().has_ticked());
+
+    // Frames never tick more than `MAX_TICKS_PER_FRAME` times
+    assert_eq!(runner.frame(Duration::from_secs(1))?, 10);
+    assert_eq!(runner.timer().dropped_ticks(), 40);
+    assert_eq!(runner.timer().ticks(), 13);
+
+    Ok(())
+}
+
+#[test]
+fn interpolation_alpha_is_the_fraction_of_the_next_tick() -> Result<()> {
+    let mut runner = runner()?;
+
+    assert_eq!(runner.frame(ms(30))?, 1);
+    assert!((runner.timer().next_tick_proximity() - 0.5).abs() < 1e-6);
+
+    assert_eq!(runner.frame(ms(5))?, 0);
+    assert!((runner.timer().next_tick_proximity() - 0.75).abs() < 1e-6);
+
+    assert_eq!(runner.frame(ms(5))?, 1);
+    assert_eq!(runner.timer().next_tick_proximity(), 0.0);
+
+    // It stays below 1 even when ticks are dropped
+    let _ = runner.frame(Duration::from_millis(1_019))?;
+    assert!(runner.timer().next_tick_proximity() < 1.0);
+    assert!((runner.timer().next_tick_proximity() - 0.95).abs() < 1e-6);
+
+    Ok(())
+}
+
+#[test]
+fn many_ticks_per_frame_are_not_slow() -> Result<()> {
+    let mut runner = Runner::<Spinner>::without_gpu((320, 240))?;
+
+    for _ in 0..60 {
+        assert_eq!(runner.frame(Duration::from_micros(16_667))?, 4);
+    }
+
+    assert!(!runner.timer().is_running_slow());
+    assert_eq!(runner.timer().recommended_detail(), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn catching_up_after_a_hitch_is_slow() -> Result<()> {
+    let mut runner = runner()?;
+
+    for _ in 0..10 {
+        assert_eq!(runner.frame(ms(20))?, 1);
+    }
+
+    for _ in 0..3 {
+        assert_eq!(runner.frame(ms(60))?, 3);
+    }
+
+    // A frame is only judged when the next one starts
+    assert_eq!(runner.frame(ms(20))?, 1);
+    assert!(runner.timer().is_running_slow());
+    assert_eq!(runner.timer().recommended_detail(), 0.625);
+
+    Ok(())
+}
+
+#[test]
+fn dropping_ticks_is_slow() -> Result<()> {
+    let mut runner = runner()?;
+
+    for _ in 0..4 {
+        assert_eq!(runner.frame(Duration::from_secs(1))?, 10);
+    }
+
+    assert_eq!(runner.frame(ms(20))?, 1);
+    assert!(runner.timer().is_running_slow());
+    assert_eq!(runner.timer().recommended_detail(), 0.5);
+
+    // The slowdown is forgotten after 8 frames that keep up
+    for _ in 0..8 {
+        assert_eq!(runner.frame(ms(20))?, 1);
+    }
+
+    assert!(!runner.timer().is_running_slow());
+    assert_eq!(runner.timer().recommended_detail(), 1.0);
+
+    Ok(())
+}
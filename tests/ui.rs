@@ -0,0 +1,116 @@
+use coffee::graphics::{Color, FontStyle, Point, Rectangle};
+use coffee::ui::core::{
+    Element, Event, Hasher, Id, Layout, MouseCursor, Node, Style, Widget,
+};
+use coffee::ui::paragraph::{Paragraph, Span};
+use coffee::ui::Column;
+
+// A widget with a fixed size, so layouts can be tested without a renderer
+struct Block {
+    width: u32,
+    height: u32,
+}
+
+impl Widget<(), ()> for Block {
+    fn node(&self, _renderer: &()) -> Node {
+        Node::new(Style::default().width(self.width).height(self.height))
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<()>,
+    ) {
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut (),
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, _state: &mut Hasher) {}
+}
+
+fn block(width: u32, height: u32) -> Element<'static, (), ()> {
+    Element::new(Block { width, height })
+}
+
+#[test]
+fn bounds_of_identified_elements() {
+    let mut layout: Element<'_, (), ()> = Column::new()
+        .width(400)
+        .spacing(10)
+        .push(block(100, 50).id(Id::new("title")))
+        .push(block(200, 30).id(Id::new("play")))
+        .into();
+
+    assert_eq!(
+        layout.bounds(&(), Id::new("title")),
+        Some(Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 50.0,
+        })
+    );
+
+    assert_eq!(
+        layout.bounds(&(), Id::new("play")),
+        Some(Rectangle {
+            x: 0.0,
+            y: 60.0,
+            width: 200.0,
+            height: 30.0,
+        })
+    );
+
+    assert_eq!(layout.bounds(&(), Id::new("quit")), None);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn duplicate_ids_are_reported() {
+    let mut layout: Element<'_, (), ()> = Column::new()
+        .push(block(100, 50).id(Id::new("play")))
+        .push(block(100, 50).id(Id::new("play")))
+        .into();
+
+    let _ = layout.bounds(&(), Id::new("play"));
+}
+
+#[test]
+fn paragraph_markup() {
+    let paragraph =
+        Paragraph::with_markup("**Bold** and *italic* [red]{#ff0000} \\*");
+
+    assert_eq!(
+        paragraph.spans(),
+        &[
+            Span::new("Bold").bold(),
+            Span::new(" and "),
+            Span::new("italic").italic(),
+            Span::new(" "),
+            Span::new("red").color(Color::from_rgb(255, 0, 0)),
+            Span::new(" *"),
+        ][..]
+    );
+
+    assert_eq!(paragraph.spans()[0].style, FontStyle::Bold);
+}
+
+#[test]
+fn paragraph_malformed_markup() {
+    let paragraph = Paragraph::with_markup("[not a color]{#zzzzzz}");
+
+    assert_eq!(
+        paragraph.spans(),
+        &[Span::new("[not a color]{#zzzzzz}")][..]
+    );
+}